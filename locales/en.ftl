@@ -0,0 +1,333 @@
+# Messages of the interface in English, which other languages fall back to.
+# Hints of the shortcuts are written `Label: key`, separated by ` | `, as the
+# shortcuts box, the help and the [keys] of the config file read them so.
+
+## Shared by several screens
+
+loading = Loading...
+unknown = unknown
+usage-of = { $current } of { $max }
+usage-no-limit = { $current } of no limit
+
+## Errors of systemd
+
+error-authorization-required = You do not have the permission to do that. Try running the program with sudo.
+error-service-unknown = The requested service is not available or not running.
+error-no-reply = The service did not respond in time. It might be busy or not functioning properly.
+error-access-denied = Access denied. You don't have sufficient permissions for this operation.
+error-no-such-unit = The requested service unit doesn't exist.
+error-no-reply-within = No reply from { $what } within { $seconds }s (see [timeouts] in the config file)
+error-not-a-template = The selected unit is not a template
+error-no-following = Following is not available with { $scope }
+error-no-watching = Watching is not available with { $scope }
+app-no-unit-named = No unit named { $unit }
+app-config-reloaded = Config file reloaded
+app-invalid-config = Invalid config file: { $error }
+
+## Shortcuts box and help
+
+shortcuts-title = Shortcuts
+actions-heading = Actions
+global-exit = Exit
+global-lock = Lock
+global-reload-config = Reload config
+global-help = Help
+split-shortcuts = Switch pane: Tab | Close split: s
+help-title = Keys
+help-shortcuts = Scroll: ↑/↓ | Page: Page Up/Page Down | Close: Esc or ?
+help-everywhere = Everywhere
+help-split = Properties and log split
+help-unit-list = Unit list
+help-log = Log
+help-properties = Properties
+help-security = Security
+help-early-boot = Initial ramdisk units
+help-boot-times = Boot times
+help-dashboard = Dashboard
+help-triage = Why it does not start
+help-journal-search = Journal search
+help-tmpfiles = Directories and tmpfiles.d
+help-plugin-views = Plugin views
+
+## Quitting
+
+quit-title = Quit?
+quit-still-running = Still running:
+quit-shortcuts = Quit anyway: y or Ctrl + c | Stay: any other key
+
+## Unit list
+
+list-title = Systemd Units
+list-title-failed = Failed Systemd Units
+list-title-grouped = { $title } by { $grouping }
+list-grouping-slice = slice
+list-grouping-type = unit type
+list-grouping-state = active state
+list-refreshing = refreshing...
+list-actions-heading = Actions on the selected service
+list-shortcuts = Navigate: ↑/↓ | Actions menu: Enter | Start: s | Stop: x | Restart: r | Start/stop with a job mode: S | Enable: e | Disable: d | Refresh all: u | View logs: v | Search the journal: J | Why it does not start: w | Directories and tmpfiles.d: T | Kernel log: K | Initial ramdisk units: I | Boot times: B | Dashboard: D | Properties: p | New instance of template: n | Expand/collapse family or section: space | Recent units: m | Mark for a merged log: + | Clear marks: - | Star: * | Failed only: f | Sort: o | Group by slice/type/state: z | Columns: c | Support bundle: b
+list-go-to-activator = Go to { $activator }
+list-column-name = Name
+list-column-active = Active
+list-column-active-sub = Active (sub)
+list-column-state = Enablement
+list-column-load = Load
+list-column-path = Path
+list-column-memory = Memory
+list-column-cpu = CPU
+list-column-tasks = Tasks
+list-column-description = Description
+list-section-count = { $units } units
+list-section-count-failed = { $units } units, { $failed } failed
+list-sort-name = name
+list-sort-load = load state
+list-sort-active = active state
+list-sort-sub = sub state
+list-sort-description = description
+list-sort-ascending = ascending
+list-sort-descending = descending
+reader-heading = Systemd units
+reader-heading-failed = Failed systemd units
+reader-loading = { $heading }, loading
+reader-listed = { $heading }, { $units } listed by { $sort } { $direction }
+reader-listed-refreshing = { $heading }, { $units } listed by { $sort } { $direction }, refreshing
+reader-unit = Unit { $position } of { $units }: { $unit }
+reader-selected-unit = Selected unit { $position } of { $units }: { $unit }
+reader-section = section { $section }, { $units } units, { $failed } failed, { $state }
+reader-family = family { $family } of { $types }, { $state }
+reader-family-active = { $state }, { $active } of { $units } active
+reader-collapsed = collapsed
+reader-expanded = expanded
+reader-memory = memory { $usage }
+reader-tasks = tasks { $usage }
+reader-started-by = started by { $activator }
+reader-starred = starred
+reader-marked = marked
+reader-job-running = { $job } running
+reader-job-ended = { $job } { $result }
+list-fetched = fetched { $seconds }s ago
+list-hidden-by-filter = { $unit } is hidden by the filter
+list-no-longer-loaded = { $unit } is no longer loaded
+list-action-done = { $action } { $unit }: done
+list-new-instance = new instance { $instance }
+list-bundle-writing = { $count ->
+    [one] writing a support bundle of { $count } unit...
+   *[other] writing a support bundle of { $count } units...
+}
+list-bundle-written = support bundle written to { $path }
+list-bundle-failed = Could not write the support bundle: { $error }
+bundle-log-unreadable = Could not read the log: { $error }
+
+## Log
+
+log-title-oldest-first = { $units } logs (oldest at the top) · priority ≤ { $priority } · fetched { $seconds }s ago
+log-title-newest-first = { $units } logs (newest at the top) · priority ≤ { $priority } · fetched { $seconds }s ago
+log-title-last = last { $lines } entries
+log-title-whole-history = whole history
+log-title-boot = boot { $boot }
+log-title-since = since { $time }
+log-title-until = until { $time }
+log-title-verbose = time, PID, UID, host, identifier
+log-title-following = following
+log-title-filter = &{ $pattern } ({ $matching } of { $lines } lines)
+log-title-paused = paused, { $lines } new lines ({ $key } to show)
+log-title-selected = { $lines } lines selected
+log-title-copied = copied { $lines } lines
+log-title-forwarding = forwarding to { $target }
+log-title-written = written to { $path }
+log-title-clock-skew = clock skew: newest entry, from { $host }, dated { $ahead } in the future
+log-title-clock-ahead = clock skew: { $host } runs { $skew } ahead of this machine
+log-title-clock-behind = clock skew: { $host } runs { $skew } behind this machine
+log-boot-heading = Boot
+log-boot-shortcuts = Navigate: ↑/↓ | Show logs from the selected boot: Enter | Cancel: Esc
+log-time-range-heading = Time range
+log-time-range-shortcuts = Navigate: ↑/↓ | Show logs from the selected range: Enter | Cancel: Esc
+log-search-heading = Search
+log-search-shortcuts = Type to search | Confirm: Enter | Cancel: Esc
+log-select-heading = Select lines
+log-select-shortcuts = Extend: ↑/↓/g/G | Copy to the clipboard: y or Enter | Cancel: Esc
+log-filter-heading = Filter
+log-filter-shortcuts = Type a text or regex to keep matching lines | Confirm: Enter | Clear: Esc
+log-follow = Follow new entries
+log-stop-following = Stop following
+log-forward = Forward new lines
+log-stop-forwarding = Stop forwarding
+log-navigation = Scroll: ↑/↓/←/→ | Top/bottom: g/G | Switch tabs: Tab/Shift+Tab
+log-navigation-wrapped = Scroll: ↑/↓ | Top/bottom: g/G | Switch tabs: ←/→ or Tab
+log-wrap-lines = Wrap long lines
+log-cut-lines = Cut long lines
+log-newest-first = Newest first
+log-oldest-first = Oldest first
+log-shortcuts = { $navigation } | { $follow }: a | Fetch new entries: r | Save shown/all lines: w/W | { $forward }: F | { $wrap }: z | Timestamps ({ $timestamps }): T | { $order }: o | Split with the properties: s | Go back: q
+log-shortcuts-more = Priority: err 1 | warning 2 | info 3 | debug 4 | Boot: b | Time range: t | Entries fetched: L | Search: / | Next/previous match: n/N | Filter: & | Copy lines: V | PID/UID/host columns: M
+log-filter-title = Filter (regex)
+log-filter-title-text = Filter (not a valid regex, matching as text)
+log-boots-title = Show logs from boot
+log-time-range-title = Show logs from
+
+## Properties
+
+details-properties = properties
+details-refreshing = refreshing
+details-fetched = fetched { $seconds }s ago
+details-secrets-shown = secrets shown
+details-last-action = { $action } by { $user } here
+details-ago = { $age } ago
+details-shortcuts = Switch tabs: ←/→ or Tab | Reveal secrets: R | Split with the log: s | Go back: q
+details-shortcuts-revealed = Switch tabs: ←/→ or Tab | Hide secrets: R | Split with the log: s | Go back: q
+
+## Other screens
+
+security-title = { $unit } security — exposure
+security-mac-title = SELinux / AppArmor
+security-failed = systemd-analyze security could not assess the unit: { $error }
+security-shortcuts = Scroll: ↑/↓ | Switch tabs: ←/→ or Tab | Go back: q
+early-boot-title = Initial ramdisk units
+early-boot-title-switched = Initial ramdisk units of the current boot · switched root after { $seconds }
+early-boot-none = The current boot did not go through an initial ramdisk.
+early-boot-unit = Unit
+early-boot-at = At
+early-boot-took = Took
+early-boot-result = Result
+early-boot-shortcuts = Scroll: ↑/↓ | Fetch again: r | Go back: q
+boot-times-heading = Boot times
+boot-times-title = Time { $units } units took to start during the current boot · { $order }
+boot-times-slowest-first = slowest first
+boot-times-fastest-first = fastest first
+boot-times-by-name = by name
+boot-times-none = systemd did not time any unit of the current boot.
+boot-times-took = Took
+boot-times-unit = Unit
+boot-times-shortcuts = Navigate: ↑/↓ | Properties: Enter | Order: o | Critical chain: Tab | Fetch again: r | Go back: q
+critical-chain-heading = Critical chain
+critical-chain-title = Critical chain of { $target }
+critical-chain-shortcuts = Navigate: ↑/↓ | Properties: Enter | Boot times: Tab | Fetch again: r | Go back: q
+dashboard-title = System overview
+dashboard-state = State
+dashboard-uptime = Uptime
+dashboard-load = Load
+dashboard-units = Units
+dashboard-failed-title = Recently failed · { $units } failed units
+dashboard-no-failed = No failed units.
+dashboard-shortcuts = Navigate: ↑/↓ | Properties: Enter | Fetch again: r | Unit list: q
+journal-search-title = "{ $pattern }" { $window } · { $matching } of { $units } units with matches
+journal-search-searching = Searching the journal of { $units } units for "{ $pattern }" { $window }...
+journal-search-between = from { $since } until { $until }
+journal-search-since = since { $since }
+journal-search-until = until { $until }
+journal-search-whole = in the whole journal
+journal-search-no-pattern = Nothing to search the journal for
+journal-search-no-hits = No entry of the listed units matches.
+journal-search-unit = Unit
+journal-search-hits = Hits
+journal-search-last-match = Last match
+journal-search-shortcuts = Navigate: ↑/↓ | Log of the unit at its matches: Enter or v | Search again: r | Go back: q
+triage-title = Why { $unit } does not start
+triage-shortcuts = Scroll: ↑/↓ | View logs: v | Check again: r | Go back: q
+triage-gathering = Gathering...
+triage-causes = Likely causes
+triage-no-causes = Nothing points to a problem.
+triage-kernel-limits = Kernel limits
+triage-sysctl-now = Now: { $command }
+triage-sysctl-persist = After reboots: "{ $line }" in /etc/sysctl.d/90-{ $stem }.conf, then sysctl --system
+triage-error-lines = Last error lines of this boot
+tmpfiles-title = Directories of { $unit } and tmpfiles.d
+tmpfiles-shortcuts = Scroll: ↑/↓ | Dry run of systemd-tmpfiles: d | Create the directories: a | Check again: r | Go back: q
+tmpfiles-reading = Reading tmpfiles.d...
+tmpfiles-directories = Directories the unit uses
+tmpfiles-no-directories = None set with RuntimeDirectory=, StateDirectory=, WorkingDirectory= or the like.
+tmpfiles-exists = exists
+tmpfiles-missing = missing
+tmpfiles-entries = tmpfiles.d lines about them
+tmpfiles-no-entries = None. systemd creates the RuntimeDirectory= and similar ones itself when the unit starts.
+tmpfiles-dry-running = Asking systemd-tmpfiles what it would do...
+tmpfiles-running = Running systemd-tmpfiles...
+tmpfiles-dry-run-done = What systemd-tmpfiles would do
+tmpfiles-run-done = What systemd-tmpfiles did
+tmpfiles-run-nothing = Nothing; every directory is as configured.
+tmpfiles-run-failed = systemd-tmpfiles failed
+plugin-view-running = Running the plugin...
+plugin-view-failed = The plugin failed: { $error }
+plugin-view-shortcuts = Scroll: ↑/↓ | Run again: r | Go back: q
+
+## Popups
+
+job-conflict-title-start = Could not start { $unit }
+job-conflict-title-restart = Could not restart { $unit }
+job-conflict-looking = Looking for the queued job...
+job-conflict-gone = The job in the way is no longer queued.
+job-conflict-queued = Queued job in the way:
+job-conflict-retry-shortcuts = Retry: y or Enter | Leave: Esc
+job-conflict-cancel-shortcuts = Cancel it and retry: y or Enter | Leave: Esc
+job-failure-title = Could not { $action } { $unit }
+job-failure-shortcuts = Close: Esc or Enter
+job-failure-ended = The { $job } job { $result }.
+job-failure-reading = Reading the journal...
+job-failure-unreadable = The journal could not be read: { $error }
+job-failure-nothing-logged = Nothing was logged by the unit during the job.
+job-failure-logged = Logged during the job:
+job-result-done = done
+job-result-canceled = canceled
+job-result-timeout = timed out
+job-result-failed = failed
+job-result-dependency = a dependency failed
+job-result-skipped = skipped
+stop-impact-title-stop = Stop { $unit }?
+stop-impact-title-restart = Restart { $unit }?
+stop-impact-checking = Checking which units go down with it...
+stop-impact-stopped = These units will be stopped too:
+stop-impact-restarted = These units will be restarted too:
+stop-impact-stop-shortcuts = Stop them all: y or Enter | Cancel: Esc
+stop-impact-restart-shortcuts = Restart them all: y or Enter | Cancel: Esc
+job-mode-title = Job mode for { $unit }
+job-mode-shortcuts = Start: s | Stop: x | Close: Esc
+unit-menu-shortcuts = Do it: Enter or its key | Close: Esc
+unit-menu-new-instance = New instance
+unit-menu-start = Start
+unit-menu-stop = Stop
+unit-menu-restart = Restart
+unit-menu-job-mode = Start or stop with a job mode
+unit-menu-enable = Enable
+unit-menu-disable = Disable
+unit-menu-logs = View logs
+unit-menu-properties = Properties
+unit-menu-triage = Why it does not start
+unit-menu-tmpfiles = Directories and tmpfiles.d
+unit-menu-mark = Mark for a merged log
+unit-menu-star = Star
+unit-menu-unstar = Unstar
+recent-title = Recently visited units
+recent-shortcuts = Select: Enter | Logs: v | Properties: p | Close: Esc
+recent-empty = Units whose logs or properties are opened show up here
+columns-title = Columns
+columns-shortcuts = Show/hide: space | Close: Esc
+filter-title = Input
+filter-title-preset = Input · preset { $preset }
+toast-error = Error
+toast-done = Done
+
+## Prompts
+
+prompt-instance-name = Instance name for { $template }
+prompt-time-range = Time range as SINCE..UNTIL (e.g. 09:00..10:30, -2h, yesterday..today)
+prompt-export-displayed = File to write the displayed log lines to
+prompt-export-full = File to write the full log to
+prompt-forward-log = File to append new log lines to, or | COMMAND to pipe them into
+prompt-journal-search = Search the journal: PATTERN or PATTERN @ SINCE..UNTIL (default the last hour)
+prompt-support-bundle = File to write the support bundle to (empty: a dated .tar.gz in ~)
+
+## Status bar and lock screen
+
+status-state-unknown = state unknown
+status-no-failed = no failed units
+status-failed = { $count } failed
+status-succeeded = succeeded
+status-failed-action = failed
+scope-demo = demo units
+scope-synthetic = synthetic units
+lock-title = Locked
+lock-hint = Type the unlock sequence to continue
+
+## Small windows
+
+too-small = Terminal too small: { $width }×{ $height }, needs at least { $min_width }×{ $min_height }
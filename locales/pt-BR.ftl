@@ -0,0 +1,333 @@
+# Mensagens da interface em português do Brasil. As que faltarem aparecem em
+# inglês. As dicas de atalhos seguem o formato `Rótulo: tecla`, separadas por
+# ` | `, e as teclas não mudam.
+
+## Comuns a várias telas
+
+loading = Carregando...
+unknown = desconhecido
+usage-of = { $current } de { $max }
+usage-no-limit = { $current } sem limite
+
+## Erros do systemd
+
+error-authorization-required = Você não tem permissão para fazer isso. Tente executar o programa com sudo.
+error-service-unknown = O serviço pedido não está disponível ou não está em execução.
+error-no-reply = O serviço não respondeu a tempo. Ele pode estar ocupado ou com problemas.
+error-access-denied = Acesso negado. Você não tem permissão suficiente para esta operação.
+error-no-such-unit = A unidade pedida não existe.
+error-no-reply-within = Sem resposta de { $what } em { $seconds }s (veja [timeouts] no arquivo de configuração)
+error-not-a-template = A unidade selecionada não é um modelo
+error-no-following = Acompanhar não está disponível com { $scope }
+error-no-watching = Observar não está disponível com { $scope }
+app-no-unit-named = Nenhuma unidade chamada { $unit }
+app-config-reloaded = Arquivo de configuração recarregado
+app-invalid-config = Arquivo de configuração inválido: { $error }
+
+## Caixa de atalhos e ajuda
+
+shortcuts-title = Atalhos
+actions-heading = Ações
+global-exit = Sair
+global-lock = Bloquear
+global-reload-config = Recarregar configuração
+global-help = Ajuda
+split-shortcuts = Trocar de painel: Tab | Fechar divisão: s
+help-title = Teclas
+help-shortcuts = Rolar: ↑/↓ | Página: Page Up/Page Down | Fechar: Esc or ?
+help-everywhere = Em todas as telas
+help-split = Propriedades e log lado a lado
+help-unit-list = Lista de unidades
+help-log = Log
+help-properties = Propriedades
+help-security = Segurança
+help-early-boot = Unidades do ramdisk inicial
+help-boot-times = Tempos do boot
+help-dashboard = Painel
+help-triage = Por que não inicia
+help-journal-search = Busca no journal
+help-tmpfiles = Diretórios e tmpfiles.d
+help-plugin-views = Telas de plugins
+
+## Saída
+
+quit-title = Sair?
+quit-still-running = Ainda em andamento:
+quit-shortcuts = Sair mesmo assim: y or Ctrl + c | Ficar: qualquer outra tecla
+
+## Lista de unidades
+
+list-title = Unidades do Systemd
+list-title-failed = Unidades do Systemd com falha
+list-title-grouped = { $title } por { $grouping }
+list-grouping-slice = slice
+list-grouping-type = tipo de unidade
+list-grouping-state = estado ativo
+list-refreshing = atualizando...
+list-actions-heading = Ações no serviço selecionado
+list-shortcuts = Navegar: ↑/↓ | Menu de ações: Enter | Iniciar: s | Parar: x | Reiniciar: r | Iniciar/parar com um modo de job: S | Habilitar: e | Desabilitar: d | Atualizar tudo: u | Ver logs: v | Buscar no journal: J | Por que não inicia: w | Diretórios e tmpfiles.d: T | Log do kernel: K | Unidades do ramdisk inicial: I | Tempos do boot: B | Painel: D | Propriedades: p | Nova instância do template: n | Expandir/recolher família ou seção: space | Unidades recentes: m | Marcar para um log combinado: + | Limpar marcas: - | Favoritar: * | Só as com falha: f | Ordenar: o | Agrupar por slice/tipo/estado: z | Colunas: c | Pacote de suporte: b
+list-go-to-activator = Ir para { $activator }
+list-column-name = Nome
+list-column-active = Ativo
+list-column-active-sub = Ativo (sub)
+list-column-state = Habilitação
+list-column-load = Carga
+list-column-path = Caminho
+list-column-memory = Memória
+list-column-cpu = CPU
+list-column-tasks = Tarefas
+list-column-description = Descrição
+list-section-count = { $units } unidades
+list-section-count-failed = { $units } unidades, { $failed } com falha
+list-sort-name = nome
+list-sort-load = estado de carga
+list-sort-active = estado ativo
+list-sort-sub = subestado
+list-sort-description = descrição
+list-sort-ascending = crescente
+list-sort-descending = decrescente
+reader-heading = Unidades do systemd
+reader-heading-failed = Unidades do systemd com falha
+reader-loading = { $heading }, carregando
+reader-listed = { $heading }, { $units } listadas por { $sort } em ordem { $direction }
+reader-listed-refreshing = { $heading }, { $units } listadas por { $sort } em ordem { $direction }, atualizando
+reader-unit = Unidade { $position } de { $units }: { $unit }
+reader-selected-unit = Unidade selecionada { $position } de { $units }: { $unit }
+reader-section = seção { $section }, { $units } unidades, { $failed } com falha, { $state }
+reader-family = família { $family } de { $types }, { $state }
+reader-family-active = { $state }, { $active } de { $units } ativas
+reader-collapsed = recolhida
+reader-expanded = expandida
+reader-memory = memória { $usage }
+reader-tasks = tarefas { $usage }
+reader-started-by = iniciada por { $activator }
+reader-starred = favorita
+reader-marked = marcada
+reader-job-running = { $job } em andamento
+reader-job-ended = { $job }: { $result }
+list-fetched = buscado há { $seconds }s
+list-hidden-by-filter = { $unit } está oculta pelo filtro
+list-no-longer-loaded = { $unit } não está mais carregada
+list-action-done = { $action } { $unit }: feito
+list-new-instance = nova instância { $instance }
+list-bundle-writing = { $count ->
+    [one] gravando um pacote de suporte de { $count } unidade...
+   *[other] gravando um pacote de suporte de { $count } unidades...
+}
+list-bundle-written = pacote de suporte gravado em { $path }
+list-bundle-failed = Não foi possível gravar o pacote de suporte: { $error }
+bundle-log-unreadable = Não foi possível ler o log: { $error }
+
+## Log
+
+log-title-oldest-first = Logs de { $units } (mais antigos no topo) · prioridade ≤ { $priority } · buscados há { $seconds }s
+log-title-newest-first = Logs de { $units } (mais recentes no topo) · prioridade ≤ { $priority } · buscados há { $seconds }s
+log-title-last = últimas { $lines } entradas
+log-title-whole-history = todo o histórico
+log-title-boot = boot { $boot }
+log-title-since = desde { $time }
+log-title-until = até { $time }
+log-title-verbose = hora, PID, UID, host, identificador
+log-title-following = acompanhando
+log-title-filter = &{ $pattern } ({ $matching } de { $lines } linhas)
+log-title-paused = pausado, { $lines } linhas novas ({ $key } para mostrar)
+log-title-selected = { $lines } linhas selecionadas
+log-title-copied = { $lines } linhas copiadas
+log-title-forwarding = encaminhando para { $target }
+log-title-written = gravado em { $path }
+log-title-clock-skew = relógio defasado: a entrada mais recente, de { $host }, está datada { $ahead } no futuro
+log-title-clock-ahead = relógio defasado: { $host } está { $skew } adiantado em relação a esta máquina
+log-title-clock-behind = relógio defasado: { $host } está { $skew } atrasado em relação a esta máquina
+log-boot-heading = Boot
+log-boot-shortcuts = Navegar: ↑/↓ | Mostrar logs do boot selecionado: Enter | Cancelar: Esc
+log-time-range-heading = Intervalo de tempo
+log-time-range-shortcuts = Navegar: ↑/↓ | Mostrar logs do intervalo selecionado: Enter | Cancelar: Esc
+log-search-heading = Busca
+log-search-shortcuts = Digite para buscar | Confirmar: Enter | Cancelar: Esc
+log-select-heading = Selecionar linhas
+log-select-shortcuts = Estender: ↑/↓/g/G | Copiar para a área de transferência: y or Enter | Cancelar: Esc
+log-filter-heading = Filtro
+log-filter-shortcuts = Digite um texto ou regex para manter as linhas que batem | Confirmar: Enter | Limpar: Esc
+log-follow = Acompanhar novas entradas
+log-stop-following = Parar de acompanhar
+log-forward = Encaminhar novas linhas
+log-stop-forwarding = Parar de encaminhar
+log-navigation = Rolar: ↑/↓/←/→ | Topo/fim: g/G | Trocar de aba: Tab/Shift+Tab
+log-navigation-wrapped = Rolar: ↑/↓ | Topo/fim: g/G | Trocar de aba: ←/→ or Tab
+log-wrap-lines = Quebrar linhas longas
+log-cut-lines = Cortar linhas longas
+log-newest-first = Mais recentes primeiro
+log-oldest-first = Mais antigos primeiro
+log-shortcuts = { $navigation } | { $follow }: a | Buscar novas entradas: r | Salvar linhas mostradas/todas: w/W | { $forward }: F | { $wrap }: z | Horários ({ $timestamps }): T | { $order }: o | Dividir com as propriedades: s | Voltar: q
+log-shortcuts-more = Prioridade: err 1 | warning 2 | info 3 | debug 4 | Boot: b | Intervalo de tempo: t | Entradas buscadas: L | Buscar: / | Próxima/anterior ocorrência: n/N | Filtrar: & | Copiar linhas: V | Colunas PID/UID/host: M
+log-filter-title = Filtro (regex)
+log-filter-title-text = Filtro (regex inválida, buscando como texto)
+log-boots-title = Mostrar logs do boot
+log-time-range-title = Mostrar logs de
+
+## Propriedades
+
+details-properties = propriedades
+details-refreshing = atualizando
+details-fetched = buscadas há { $seconds }s
+details-secrets-shown = segredos à mostra
+details-last-action = { $action } por { $user } aqui
+details-ago = há { $age }
+details-shortcuts = Trocar de aba: ←/→ or Tab | Revelar segredos: R | Dividir com o log: s | Voltar: q
+details-shortcuts-revealed = Trocar de aba: ←/→ or Tab | Esconder segredos: R | Dividir com o log: s | Voltar: q
+
+## Outras telas
+
+security-title = Segurança de { $unit } — exposição
+security-mac-title = SELinux / AppArmor
+security-failed = O systemd-analyze security não conseguiu avaliar a unidade: { $error }
+security-shortcuts = Rolar: ↑/↓ | Trocar de aba: ←/→ or Tab | Voltar: q
+early-boot-title = Unidades do ramdisk inicial
+early-boot-title-switched = Unidades do ramdisk inicial do boot atual · troca de raiz após { $seconds }
+early-boot-none = O boot atual não passou por um ramdisk inicial.
+early-boot-unit = Unidade
+early-boot-at = Em
+early-boot-took = Levou
+early-boot-result = Resultado
+early-boot-shortcuts = Rolar: ↑/↓ | Buscar de novo: r | Voltar: q
+boot-times-heading = Tempos do boot
+boot-times-title = Tempo que { $units } unidades levaram para iniciar no boot atual · { $order }
+boot-times-slowest-first = mais lentas primeiro
+boot-times-fastest-first = mais rápidas primeiro
+boot-times-by-name = por nome
+boot-times-none = O systemd não cronometrou nenhuma unidade do boot atual.
+boot-times-took = Levou
+boot-times-unit = Unidade
+boot-times-shortcuts = Navegar: ↑/↓ | Propriedades: Enter | Ordem: o | Cadeia crítica: Tab | Buscar de novo: r | Voltar: q
+critical-chain-heading = Cadeia crítica
+critical-chain-title = Cadeia crítica de { $target }
+critical-chain-shortcuts = Navegar: ↑/↓ | Propriedades: Enter | Tempos do boot: Tab | Buscar de novo: r | Voltar: q
+dashboard-title = Visão geral do sistema
+dashboard-state = Estado
+dashboard-uptime = Ligado há
+dashboard-load = Carga
+dashboard-units = Unidades
+dashboard-failed-title = Falhas recentes · { $units } unidades com falha
+dashboard-no-failed = Nenhuma unidade com falha.
+dashboard-shortcuts = Navegar: ↑/↓ | Propriedades: Enter | Buscar de novo: r | Lista de unidades: q
+journal-search-title = "{ $pattern }" { $window } · { $matching } de { $units } unidades com ocorrências
+journal-search-searching = Buscando "{ $pattern }" no journal de { $units } unidades { $window }...
+journal-search-between = de { $since } até { $until }
+journal-search-since = desde { $since }
+journal-search-until = até { $until }
+journal-search-whole = no journal inteiro
+journal-search-no-pattern = Nada para buscar no journal
+journal-search-no-hits = Nenhuma entrada das unidades listadas corresponde.
+journal-search-unit = Unidade
+journal-search-hits = Ocorrências
+journal-search-last-match = Última ocorrência
+journal-search-shortcuts = Navegar: ↑/↓ | Log da unidade nas ocorrências: Enter ou v | Buscar de novo: r | Voltar: q
+triage-title = Por que { $unit } não inicia
+triage-shortcuts = Rolar: ↑/↓ | Ver logs: v | Verificar de novo: r | Voltar: q
+triage-gathering = Reunindo informações...
+triage-causes = Causas prováveis
+triage-no-causes = Nada indica um problema.
+triage-kernel-limits = Limites do kernel
+triage-sysctl-now = Agora: { $command }
+triage-sysctl-persist = Após reinicializações: "{ $line }" em /etc/sysctl.d/90-{ $stem }.conf, depois sysctl --system
+triage-error-lines = Últimas linhas de erro deste boot
+tmpfiles-title = Diretórios de { $unit } e tmpfiles.d
+tmpfiles-shortcuts = Rolar: ↑/↓ | Simular o systemd-tmpfiles: d | Criar os diretórios: a | Verificar de novo: r | Voltar: q
+tmpfiles-reading = Lendo o tmpfiles.d...
+tmpfiles-directories = Diretórios que a unidade usa
+tmpfiles-no-directories = Nenhum definido com RuntimeDirectory=, StateDirectory=, WorkingDirectory= ou semelhantes.
+tmpfiles-exists = existe
+tmpfiles-missing = não existe
+tmpfiles-entries = Linhas do tmpfiles.d sobre eles
+tmpfiles-no-entries = Nenhuma. O systemd cria o RuntimeDirectory= e semelhantes quando a unidade inicia.
+tmpfiles-dry-running = Perguntando ao systemd-tmpfiles o que ele faria...
+tmpfiles-running = Executando o systemd-tmpfiles...
+tmpfiles-dry-run-done = O que o systemd-tmpfiles faria
+tmpfiles-run-done = O que o systemd-tmpfiles fez
+tmpfiles-run-nothing = Nada; todos os diretórios estão como configurados.
+tmpfiles-run-failed = O systemd-tmpfiles falhou
+plugin-view-running = Executando o plugin...
+plugin-view-failed = O plugin falhou: { $error }
+plugin-view-shortcuts = Rolar: ↑/↓ | Executar de novo: r | Voltar: q
+
+## Janelas
+
+job-conflict-title-start = Não foi possível iniciar { $unit }
+job-conflict-title-restart = Não foi possível reiniciar { $unit }
+job-conflict-looking = Procurando o job na fila...
+job-conflict-gone = O job que atrapalhava não está mais na fila.
+job-conflict-queued = Job na fila que atrapalha:
+job-conflict-retry-shortcuts = Tentar de novo: y or Enter | Deixar: Esc
+job-conflict-cancel-shortcuts = Cancelá-lo e tentar de novo: y or Enter | Deixar: Esc
+job-failure-title = Não foi possível { $action } { $unit }
+job-failure-shortcuts = Fechar: Esc or Enter
+job-failure-ended = O job { $job } terminou: { $result }.
+job-failure-reading = Lendo o journal...
+job-failure-unreadable = Não foi possível ler o journal: { $error }
+job-failure-nothing-logged = A unidade não registrou nada durante o job.
+job-failure-logged = Registrado durante o job:
+job-result-done = concluído
+job-result-canceled = cancelado
+job-result-timeout = tempo esgotado
+job-result-failed = falhou
+job-result-dependency = uma dependência falhou
+job-result-skipped = ignorado
+stop-impact-title-stop = Parar { $unit }?
+stop-impact-title-restart = Reiniciar { $unit }?
+stop-impact-checking = Verificando quais unidades caem junto...
+stop-impact-stopped = Estas unidades também serão paradas:
+stop-impact-restarted = Estas unidades também serão reiniciadas:
+stop-impact-stop-shortcuts = Parar todas: y or Enter | Cancelar: Esc
+stop-impact-restart-shortcuts = Reiniciar todas: y or Enter | Cancelar: Esc
+job-mode-title = Modo de job para { $unit }
+job-mode-shortcuts = Iniciar: s | Parar: x | Fechar: Esc
+unit-menu-shortcuts = Executar: Enter or sua tecla | Fechar: Esc
+unit-menu-new-instance = Nova instância
+unit-menu-start = Iniciar
+unit-menu-stop = Parar
+unit-menu-restart = Reiniciar
+unit-menu-job-mode = Iniciar ou parar com um modo de job
+unit-menu-enable = Habilitar
+unit-menu-disable = Desabilitar
+unit-menu-logs = Ver logs
+unit-menu-properties = Propriedades
+unit-menu-triage = Por que não inicia
+unit-menu-tmpfiles = Diretórios e tmpfiles.d
+unit-menu-mark = Marcar para um log combinado
+unit-menu-star = Favoritar
+unit-menu-unstar = Desfavoritar
+recent-title = Unidades visitadas recentemente
+recent-shortcuts = Selecionar: Enter | Logs: v | Propriedades: p | Fechar: Esc
+recent-empty = As unidades cujos logs ou propriedades forem abertos aparecem aqui
+columns-title = Colunas
+columns-shortcuts = Mostrar/esconder: space | Fechar: Esc
+filter-title = Filtro
+filter-title-preset = Filtro · predefinição { $preset }
+toast-error = Erro
+toast-done = Feito
+
+## Perguntas
+
+prompt-instance-name = Nome da instância de { $template }
+prompt-time-range = Intervalo de tempo como SINCE..UNTIL (ex.: 09:00..10:30, -2h, yesterday..today)
+prompt-export-displayed = Arquivo onde gravar as linhas mostradas do log
+prompt-export-full = Arquivo onde gravar o log completo
+prompt-forward-log = Arquivo onde acrescentar as novas linhas do log, ou | COMANDO para recebê-las
+prompt-journal-search = Buscar no journal: PADRÃO ou PADRÃO @ SINCE..UNTIL (por padrão a última hora)
+prompt-support-bundle = Arquivo onde gravar o pacote de suporte (vazio: um .tar.gz datado em ~)
+
+## Barra de estado e tela de bloqueio
+
+status-state-unknown = estado desconhecido
+status-no-failed = nenhuma unidade com falha
+status-failed = { $count } com falha
+status-succeeded = concluído
+status-failed-action = falhou
+scope-demo = unidades de demonstração
+scope-synthetic = unidades sintéticas
+lock-title = Bloqueado
+lock-hint = Digite a sequência de desbloqueio para continuar
+
+## Janelas pequenas
+
+too-small = Terminal pequeno demais: { $width }×{ $height }, precisa de pelo menos { $min_width }×{ $min_height }
@@ -0,0 +1,109 @@
+use clap::{Args, Parser, Subcommand};
+
+/// A program for managing systemd services through a TUI.
+#[derive(Parser)]
+#[command(version, about)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Development aid: replace systemd with UNITS synthetic units and megabytes
+    /// of made up logs to check scrolling, filtering and sorting performance
+    #[arg(long, value_name = "UNITS")]
+    pub stress: Option<usize>,
+
+    /// Show the units and logs of a made up server instead of those of
+    /// systemd, whose actions change their state, to try the interface on a
+    /// machine without systemd
+    #[arg(long, conflicts_with = "stress")]
+    pub demo: bool,
+
+    /// Show units and logs as plain labeled lines, without tables, borders or
+    /// color-only cues, for use with a screen reader
+    #[arg(long)]
+    pub screen_reader: bool,
+
+    /// Draw in the normal screen instead of the alternate one, without colors
+    /// or tables, so the output can be captured by scripts or serial consoles
+    #[arg(long)]
+    pub plain: bool,
+
+    /// Manage the units of the calling user rather than those of the system,
+    /// like systemctl --user
+    #[arg(long, conflicts_with_all = ["stress", "demo"])]
+    pub user: bool,
+
+    /// Manage the units of another machine through ssh, which needs
+    /// systemd-stdio-bridge there and a login that does not ask for a password
+    #[arg(long, value_name = "[USER@]HOST", conflicts_with_all = ["stress", "demo"])]
+    pub host: Option<String>,
+
+    /// Start with the list filtered, as if PATTERN had been typed after i
+    #[arg(long, value_name = "PATTERN")]
+    pub filter: Option<String>,
+
+    /// Open the properties of this unit once the list is loaded; a name
+    /// without a type is taken as a service
+    #[arg(long, value_name = "NAME")]
+    pub unit: Option<String>,
+
+    /// With --unit, open its log rather than its properties
+    #[arg(long, requires = "unit")]
+    pub log: bool,
+
+    /// Write what the program does, its D-Bus calls, commands, events and how
+    /// long they took, to debug.log next to the config file
+    #[arg(long)]
+    pub debug: bool,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Report every unknown key and invalid value of the config file with its
+    /// line, exiting with a non-zero status if there is any
+    CheckConfig,
+    /// Print the units, one per line with its name, load, active and sub
+    /// states, enablement and description separated by tabs
+    List(Listing),
+    /// Print the failed units, as list does
+    Failed(Listing),
+    /// Start a unit and wait for its job, exiting with a non-zero status
+    /// unless it succeeds
+    Start(UnitAction),
+    /// Stop a unit and wait for its job, as start does
+    Stop(UnitAction),
+    /// Restart a unit and wait for its job, as start does
+    Restart(UnitAction),
+    /// Enable a unit, then reload systemd
+    Enable(UnitAction),
+    /// Disable a unit, then reload systemd
+    Disable(UnitAction),
+}
+
+#[derive(Args)]
+pub struct Listing {
+    /// Print a JSON array of objects instead
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Args)]
+pub struct UnitAction {
+    /// Name of the unit; one without a type is taken as a service
+    #[arg(value_name = "UNIT")]
+    pub unit: String,
+
+    /// Print the result as a JSON object instead of a line of text
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// `name`, or the service it stands for when it has no type, as systemctl
+/// reads unit names.
+pub fn unit_name(name: String) -> String {
+    if name.contains('.') {
+        name
+    } else {
+        format!("{}.service", name)
+    }
+}
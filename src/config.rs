@@ -0,0 +1,576 @@
+use ratatui::style::{Color, Style};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, RwLock};
+use std::time::Duration;
+use toml::{Table, Value};
+
+use crate::domain::unit_type::UnitType;
+use crate::infrastructure::target::Target;
+use crate::terminal::i18n;
+use crate::terminal::keymap::parse_key;
+use crate::terminal::theme::Theme;
+
+static CONFIG: LazyLock<RwLock<Arc<Config>>> =
+    LazyLock::new(|| RwLock::new(Arc::new(Config::default())));
+/// `--screen-reader`, which a reloaded config file cannot turn off.
+static SCREEN_READER_FLAG: AtomicBool = AtomicBool::new(false);
+
+/// Keys moving through the list, the log and the properties, on top of the
+/// arrows, Page Up/Down and Home/End.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Keymap {
+    #[default]
+    Default,
+    /// `j`/`k`, `Ctrl + d`/`Ctrl + u`, `gg`/`G`, and `/` to filter the list.
+    Vim,
+}
+
+/// Which part of a text is cut when it does not fit its column.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TruncationSide {
+    /// `very long descrip…`
+    #[default]
+    End,
+    /// `…ng description`
+    Start,
+    /// `very lo…cription`
+    Middle,
+}
+
+/// Column of the unit table that can be hidden; the name is always shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Column {
+    Active,
+    /// Enablement state of the unit file (`enabled`, `disabled`, `static`...).
+    State,
+    Load,
+    /// Path of the unit file.
+    Path,
+    Memory,
+    /// CPU time used since the unit started.
+    Cpu,
+    Tasks,
+    Description,
+    /// Column added by a plugin, by its index among those of the plugins.
+    #[serde(skip)]
+    Plugin(usize),
+}
+
+impl Column {
+    /// Every column of the table's own, in the order the table shows them.
+    pub const ALL: [Column; 8] = [
+        Column::Active,
+        Column::State,
+        Column::Load,
+        Column::Path,
+        Column::Memory,
+        Column::Cpu,
+        Column::Tasks,
+        Column::Description,
+    ];
+}
+
+/// How long to wait on systemd before reporting it as unresponsive.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Timeouts {
+    /// Seconds a D-Bus call to systemd may take.
+    pub dbus_seconds: u64,
+    /// Seconds `journalctl` may take to print a log or the list of boots.
+    pub journal_seconds: u64,
+    /// Seconds a plugin may take to describe itself, run an action or fill a
+    /// view or column, and a script to run.
+    pub plugin_seconds: u64,
+    /// What every timeout is multiplied by with `--host`, as each call then
+    /// also crosses ssh and the network.
+    pub remote_factor: f64,
+}
+
+impl Default for Timeouts {
+    fn default() -> Self {
+        // The D-Bus default reply timeout; reading a large journal is slower.
+        Self {
+            dbus_seconds: 25,
+            journal_seconds: 60,
+            plugin_seconds: 60,
+            remote_factor: 2.0,
+        }
+    }
+}
+
+impl Timeouts {
+    /// `seconds` stretched by `remote_factor` when the target is another host.
+    fn scaled(&self, seconds: u64) -> Duration {
+        let timeout = Duration::from_secs(seconds);
+        if Target::get().host.is_some() && self.remote_factor.is_finite() {
+            timeout.mul_f64(self.remote_factor.max(1.0))
+        } else {
+            timeout
+        }
+    }
+
+    pub fn dbus(&self) -> Duration {
+        self.scaled(self.dbus_seconds)
+    }
+
+    pub fn journal(&self) -> Duration {
+        self.scaled(self.journal_seconds)
+    }
+
+    pub fn plugin(&self) -> Duration {
+        self.scaled(self.plugin_seconds)
+    }
+}
+
+/// Questions asked before doing something that cannot be taken back.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Confirmations {
+    /// List the units a stop or restart takes down with it, when there are
+    /// any, before going ahead.
+    pub stop_impact: bool,
+    /// Ask before quitting while operations still run in the background.
+    pub quit_with_pending: bool,
+}
+
+impl Default for Confirmations {
+    fn default() -> Self {
+        Self {
+            stop_impact: true,
+            quit_with_pending: true,
+        }
+    }
+}
+
+/// A filter saved under a name, loaded into the filter of the list with `P`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FilterPreset {
+    pub name: String,
+    pub filter: String,
+}
+
+/// Action written in Rhai, offered in the unit menu and run with `key` from
+/// the list. Read at start only.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Script {
+    pub title: String,
+    /// Key run it with, written like those of `[keys]`; keys the list already
+    /// uses keep doing what they do.
+    pub key: Option<String>,
+    pub code: Option<String>,
+    /// File holding the code instead, relative to the config directory.
+    pub file: Option<String>,
+}
+
+/// Built-in set of colors the interface is drawn with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemeName {
+    /// For dark terminals.
+    #[default]
+    Default,
+    /// For terminals with a white background.
+    Light,
+    /// No colors at all, highlights shown in reverse video, as with `NO_COLOR`.
+    Monochrome,
+}
+
+/// Colors of the active state of units, each a name (`green`), a 256-color
+/// index (`42`) or a hex code (`#87d787`), in place of those of the theme.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StateColors {
+    pub active: Option<String>,
+    /// `activating`, `deactivating`, `reloading` and other passing states.
+    pub transitioning: Option<String>,
+    pub failed: Option<String>,
+    pub inactive: Option<String>,
+}
+
+impl StateColors {
+    /// Keys whose value is not a color.
+    fn invalid_keys(&self) -> Vec<&'static str> {
+        [
+            ("state_colors.active", &self.active),
+            ("state_colors.transitioning", &self.transitioning),
+            ("state_colors.failed", &self.failed),
+            ("state_colors.inactive", &self.inactive),
+        ]
+        .into_iter()
+        .filter(|(_, color)| {
+            color
+                .as_ref()
+                .is_some_and(|color| color.parse::<Color>().is_err())
+        })
+        .map(|(key, _)| key)
+        .collect()
+    }
+}
+
+/// Line of the config file, when it can be told, and what is wrong there.
+pub type Problem = (Option<usize>, String);
+
+fn only(key: &str, value: Value) -> Value {
+    Value::Table(Table::from_iter([(key.to_string(), value)]))
+}
+
+/// 1-based line of the byte at `offset`.
+fn line_of(content: &str, offset: usize) -> usize {
+    content[..offset.min(content.len())].lines().count().max(1)
+}
+
+/// 1-based line where `key` is set, under the `[section]` header if given.
+fn key_line(content: &str, section: Option<&str>, key: &str) -> Option<usize> {
+    let mut current_section = None;
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if let Some(header) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            current_section = Some(header.trim().to_string());
+        } else if current_section.as_deref() == section
+            && line
+                .split_once('=')
+                .is_some_and(|(name, _)| name.trim().trim_matches('"') == key)
+        {
+            return Some(index + 1);
+        }
+    }
+    None
+}
+
+/// Settings read from `~/.config/systemd-manager-tui/config.toml`.
+/// Every key is optional.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub truncation: TruncationSide,
+    /// Text to type to leave the lock screen.
+    pub unlock_sequence: String,
+    /// Minutes without a key press after which the screen locks itself.
+    pub auto_lock_minutes: Option<u64>,
+    /// Seconds between refreshes of the unit list in the background.
+    pub refresh_seconds: Option<u64>,
+    /// Render plain labeled lines instead of tables, borders and colors alone.
+    pub screen_reader: bool,
+    /// Most redraws per second; events arriving in between are drawn together.
+    pub max_fps: u32,
+    pub timeouts: Timeouts,
+    /// Parts of variable names, matched ignoring case, whose values are hidden
+    /// in the details and in copied or exported log lines.
+    pub sensitive_patterns: Vec<String>,
+    /// Most recent log entries fetched when opening a log; 0 fetches the whole
+    /// history.
+    pub log_lines: usize,
+    /// Unit types listed, among services, sockets, timers and mounts.
+    pub unit_types: Vec<UnitType>,
+    /// Columns of the unit table besides the name, shown in their usual order
+    /// whatever the order they are listed in.
+    pub columns: Vec<Column>,
+    /// Filter words match their characters in order, like fzf, rather than as
+    /// a whole, and the best matches are listed first.
+    pub fuzzy_filter: bool,
+    pub keymap: Keymap,
+    /// Keys pressed in place of the default ones, by the default key as the
+    /// shortcuts show it (`"x" = "X"`, `"Tab" = "Ctrl + n"`).
+    pub keys: BTreeMap<String, String>,
+    /// Clicks and the wheel act on the list, the log and the shortcuts, at
+    /// the cost of selecting text without holding Shift. Read at start only.
+    pub mouse: bool,
+    pub filter_presets: Vec<FilterPreset>,
+    pub scripts: Vec<Script>,
+    pub theme: ThemeName,
+    /// Colors of the theme replaced, by the role they play (`error`, `heading`...).
+    pub colors: BTreeMap<String, String>,
+    pub state_colors: StateColors,
+    pub confirm: Confirmations,
+    /// Color of the borders of the unit list, the filter and the shortcuts,
+    /// so that the config of each machine can tell production from staging
+    /// at a glance.
+    pub accent: Option<String>,
+    /// File the actions taken on units are appended to, `actions.log` next to
+    /// the config file when unset. Pointing every operator of a host at the
+    /// same writable file shows each other's actions.
+    pub action_log: Option<String>,
+    /// Print the actions taken, the units still failed and those waiting for a
+    /// daemon-reload on quitting, so the scrollback records the session.
+    pub exit_summary: bool,
+    /// Start on an overview of the system rather than the unit list.
+    pub dashboard: bool,
+    /// Language of the interface, like `pt-BR`, that of the environment when
+    /// unset. Read at start only.
+    pub language: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            truncation: TruncationSide::default(),
+            unlock_sequence: "unlock".to_string(),
+            auto_lock_minutes: None,
+            refresh_seconds: None,
+            screen_reader: false,
+            max_fps: 30,
+            timeouts: Timeouts::default(),
+            sensitive_patterns: ["PASSWORD", "TOKEN", "KEY", "SECRET"]
+                .map(String::from)
+                .to_vec(),
+            log_lines: 1000,
+            unit_types: UnitType::LISTED.to_vec(),
+            columns: vec![
+                Column::Active,
+                Column::State,
+                Column::Load,
+                Column::Memory,
+                Column::Tasks,
+                Column::Description,
+            ],
+            fuzzy_filter: false,
+            keymap: Keymap::default(),
+            keys: BTreeMap::new(),
+            mouse: true,
+            filter_presets: vec![],
+            scripts: vec![],
+            theme: ThemeName::default(),
+            colors: BTreeMap::new(),
+            state_colors: StateColors::default(),
+            confirm: Confirmations::default(),
+            accent: None,
+            action_log: None,
+            exit_summary: false,
+            dashboard: false,
+            language: None,
+        }
+    }
+}
+
+impl Config {
+    /// Time between background refreshes of the unit list, if they are on.
+    pub fn refresh_interval(&self) -> Option<Duration> {
+        self.refresh_seconds
+            .filter(|seconds| *seconds > 0)
+            .map(Duration::from_secs)
+    }
+
+    /// Style of the borders colored with `accent`, plain when it is not set.
+    pub fn accent_style(&self) -> Style {
+        self.accent
+            .as_deref()
+            .and_then(|accent| accent.parse().ok())
+            .map_or(Style::default(), |color: Color| Style::default().fg(color))
+    }
+
+    /// Directory of the config file, which also keeps what is remembered
+    /// between sessions.
+    pub fn dir() -> Option<PathBuf> {
+        let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(config_dir.join("systemd-manager-tui"))
+    }
+
+    pub fn path() -> Option<PathBuf> {
+        Some(Self::dir()?.join("config.toml"))
+    }
+
+    /// [`Config::log_lines`] as understood by [`LogQuery`](crate::domain::log_query::LogQuery).
+    pub fn log_depth(&self) -> Option<usize> {
+        Some(self.log_lines).filter(|lines| *lines > 0)
+    }
+
+    /// Reads the config file, falling back to the defaults when there is none.
+    pub fn load() -> Result<Config, Box<dyn Error>> {
+        let Some(path) = Self::path().filter(|path| path.exists()) else {
+            return Ok(Config::default());
+        };
+        let content = std::fs::read_to_string(&path)?;
+        let config: Config =
+            toml::from_str(&content).map_err(|e| format!("{}: {}", path.display(), e))?;
+        if let Some((key, problem)) = config.invalid_values().first() {
+            return Err(format!("{}: {} {}", path.display(), key, problem).into());
+        }
+        Ok(config)
+    }
+
+    /// Values that parse but cannot be used, as `(key, problem)`.
+    fn invalid_values(&self) -> Vec<(&'static str, &'static str)> {
+        let mut invalid = vec![];
+        if self.unlock_sequence.is_empty() {
+            invalid.push(("unlock_sequence", "cannot be empty"));
+        }
+        if self.timeouts.dbus_seconds == 0 {
+            invalid.push(("timeouts.dbus_seconds", "must be at least 1 second"));
+        }
+        if self.timeouts.journal_seconds == 0 {
+            invalid.push(("timeouts.journal_seconds", "must be at least 1 second"));
+        }
+        if self.timeouts.plugin_seconds == 0 {
+            invalid.push(("timeouts.plugin_seconds", "must be at least 1 second"));
+        }
+        if self.unit_types.is_empty() {
+            invalid.push(("unit_types", "cannot be empty"));
+        }
+        if !self
+            .unit_types
+            .iter()
+            .all(|unit_type| UnitType::LISTED.contains(unit_type))
+        {
+            invalid.push((
+                "unit_types",
+                "can only list service, socket, timer and mount",
+            ));
+        }
+        if !self
+            .keys
+            .iter()
+            .all(|(default, bound)| parse_key(default).is_some() && parse_key(bound).is_some())
+        {
+            invalid.push((
+                "keys",
+                "can only bind keys written like \"x\", \"Ctrl + d\", \"Enter\", \"Page Down\" or \"F5\"",
+            ));
+        }
+        let mut bound: Vec<&String> = self.keys.values().collect();
+        bound.sort();
+        bound.dedup();
+        if bound.len() < self.keys.len() {
+            invalid.push(("keys", "cannot bind the same key to two shortcuts"));
+        }
+        if self
+            .scripts
+            .iter()
+            .any(|script| script.code.is_some() == script.file.is_some())
+        {
+            invalid.push(("scripts", "each need either code or a file"));
+        }
+        let script_keys: Vec<&String> = self
+            .scripts
+            .iter()
+            .filter_map(|script| script.key.as_ref())
+            .collect();
+        if !script_keys.iter().all(|key| parse_key(key).is_some()) {
+            invalid.push((
+                "scripts",
+                "can only be bound to keys written like \"x\", \"Ctrl + d\" or \"F5\"",
+            ));
+        }
+        let mut distinct = script_keys.clone();
+        distinct.sort();
+        distinct.dedup();
+        if distinct.len() < script_keys.len() {
+            invalid.push(("scripts", "cannot bind the same key to two scripts"));
+        }
+        if !self.colors.keys().all(|role| Theme::has_role(role)) {
+            invalid.push(("colors", "can only set the colors listed in the README"));
+        }
+        if self
+            .colors
+            .values()
+            .any(|color| color.parse::<Color>().is_err())
+        {
+            invalid.push(("colors", "can only hold colors"));
+        }
+        for key in self.state_colors.invalid_keys() {
+            invalid.push((key, "is not a color"));
+        }
+        if let Some(accent) = &self.accent
+            && accent.parse::<Color>().is_err()
+        {
+            invalid.push(("accent", "is not a color"));
+        }
+        if let Some(language) = &self.language
+            && !i18n::is_shipped(language)
+        {
+            invalid.push(("language", "is not one of en and pt-BR"));
+        }
+        invalid
+    }
+
+    /// Every problem of the config file at `path`, rather than only the first
+    /// one like [`Config::load`].
+    pub fn check(path: &Path) -> Result<Vec<Problem>, Box<dyn Error>> {
+        let content = std::fs::read_to_string(path)?;
+        let table: Table = match content.parse() {
+            Ok(table) => table,
+            Err(e) => {
+                let e: toml::de::Error = e;
+                let line = e.span().map(|span| line_of(&content, span.start));
+                return Ok(vec![(line, e.message().to_string())]);
+            }
+        };
+
+        // Each key is tried on its own so one bad value does not hide the others.
+        let mut problems = vec![];
+        for (key, value) in &table {
+            let Err(e) = only(key, value.clone()).try_into::<Config>() else {
+                continue;
+            };
+            match value {
+                Value::Table(section) => {
+                    for (nested_key, nested_value) in section {
+                        let nested = only(nested_key, nested_value.clone());
+                        if let Err(e) = only(key, nested).try_into::<Config>() {
+                            let line = key_line(&content, Some(key), nested_key);
+                            problems
+                                .push((line, format!("{}.{}: {}", key, nested_key, e.message())));
+                        }
+                    }
+                }
+                _ => problems.push((
+                    key_line(&content, None, key),
+                    format!("{}: {}", key, e.message()),
+                )),
+            }
+        }
+
+        if problems.is_empty() {
+            let config: Config = Value::Table(table).try_into()?;
+            for (key, problem) in config.invalid_values() {
+                let line = match key.split_once('.') {
+                    Some((section, key)) => key_line(&content, Some(section), key),
+                    None => key_line(&content, None, key),
+                };
+                problems.push((line, format!("{} {}", key, problem)));
+            }
+        }
+        problems.sort_by_key(|(line, _)| *line);
+        Ok(problems)
+    }
+
+    /// Makes `config` the one returned by [`Config::get`], with the command line
+    /// flags applied on top of it.
+    pub fn init(mut config: Config, screen_reader_flag: bool) {
+        SCREEN_READER_FLAG.store(screen_reader_flag, Ordering::Relaxed);
+        config.screen_reader |= screen_reader_flag;
+        *CONFIG.write().unwrap() = Arc::new(config);
+    }
+
+    /// Reads the config file again. The current settings stay in place when it
+    /// is invalid.
+    pub fn reload() -> Result<(), Box<dyn Error>> {
+        let config = Self::load()?;
+        Self::init(config, SCREEN_READER_FLAG.load(Ordering::Relaxed));
+        Ok(())
+    }
+
+    /// The settings in effect; read them again rather than keeping them, as they
+    /// change when the file is reloaded.
+    pub fn get() -> Arc<Config> {
+        CONFIG.read().unwrap().clone()
+    }
+
+    /// Shortest time between two redraws, from [`Config::max_fps`].
+    pub fn frame_interval(&self) -> Duration {
+        Duration::from_secs(1) / self.max_fps.max(1)
+    }
+}
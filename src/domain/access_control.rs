@@ -0,0 +1,48 @@
+use super::log_entry::LogEntry;
+
+/// SELinux and AppArmor state of a unit: the context and profile it is set to
+/// run under, the label its main process actually has, and the denials of the
+/// current boot that concern it.
+#[derive(Debug, Clone, Default)]
+pub struct AccessControl {
+    /// `SELinuxContext=`; empty when not set.
+    selinux_context: String,
+    /// `AppArmorProfile=`; empty when not set.
+    apparmor_profile: String,
+    /// Label of the main process from `/proc/<pid>/attr/current`, when it runs.
+    process_label: Option<String>,
+    /// AVC and AppArmor denials mentioning the unit, oldest first.
+    denials: Vec<LogEntry>,
+}
+
+impl AccessControl {
+    pub fn new(
+        selinux_context: String,
+        apparmor_profile: String,
+        process_label: Option<String>,
+        denials: Vec<LogEntry>,
+    ) -> Self {
+        Self {
+            selinux_context,
+            apparmor_profile,
+            process_label,
+            denials,
+        }
+    }
+
+    pub fn selinux_context(&self) -> &str {
+        &self.selinux_context
+    }
+
+    pub fn apparmor_profile(&self) -> &str {
+        &self.apparmor_profile
+    }
+
+    pub fn process_label(&self) -> Option<&str> {
+        self.process_label.as_deref()
+    }
+
+    pub fn denials(&self) -> &[LogEntry] {
+        &self.denials
+    }
+}
@@ -0,0 +1,49 @@
+use chrono::{DateTime, Local};
+
+/// A boot recorded in the journal, as listed by `journalctl --list-boots`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Boot {
+    /// 0 for the current boot, -1 for the one before, and so on.
+    offset: i32,
+    id: String,
+    first_entry_usec: u64,
+    last_entry_usec: u64,
+}
+
+fn format_usec(usec: u64) -> String {
+    DateTime::from_timestamp_micros(usec as i64)
+        .map(|dt| {
+            dt.with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
+
+impl Boot {
+    pub fn new(offset: i32, id: String, first_entry_usec: u64, last_entry_usec: u64) -> Self {
+        Self {
+            offset,
+            id,
+            first_entry_usec,
+            last_entry_usec,
+        }
+    }
+
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Time span covered by the boot, e.g. `2024-05-02 08:12:40 → 2024-05-02 19:03:11`.
+    pub fn span(&self) -> String {
+        format!(
+            "{} → {}",
+            format_usec(self.first_entry_usec),
+            format_usec(self.last_entry_usec)
+        )
+    }
+}
@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+/// How long a unit took to start during the current boot, as
+/// `systemd-analyze blame` tells it.
+#[derive(Debug, Clone)]
+pub struct BootTime {
+    name: String,
+    took: Duration,
+}
+
+impl BootTime {
+    pub fn new(name: String, took: Duration) -> Self {
+        Self { name, took }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn took(&self) -> Duration {
+        self.took
+    }
+}
+
+/// A unit of the critical chain of the default target: the units it waited
+/// for, each one started once the next one was up.
+#[derive(Debug, Clone)]
+pub struct ChainLink {
+    name: String,
+    /// 0 for the default target, 1 for the unit it waited for, and so on.
+    depth: usize,
+    /// When the unit became active, from the start of the boot.
+    at: Option<Duration>,
+    /// How long it took to start; targets take no time.
+    took: Option<Duration>,
+}
+
+impl ChainLink {
+    pub fn new(name: String, depth: usize, at: Option<Duration>, took: Option<Duration>) -> Self {
+        Self {
+            name,
+            depth,
+            at,
+            took,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn at(&self) -> Option<Duration> {
+        self.at
+    }
+
+    pub fn took(&self) -> Option<Duration> {
+        self.took
+    }
+}
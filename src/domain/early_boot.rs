@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+/// A unit systemd worked on in the initial ramdisk, before it switched to the
+/// real root, with times counted from the start of the boot.
+#[derive(Debug, Clone)]
+pub struct EarlyBootUnit {
+    name: String,
+    started_usec: Option<u64>,
+    finished_usec: Option<u64>,
+    /// Result of the last job, e.g. `done` or `failed`; empty while unknown.
+    result: String,
+}
+
+impl EarlyBootUnit {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            started_usec: None,
+            finished_usec: None,
+            result: String::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn result(&self) -> &str {
+        &self.result
+    }
+
+    pub fn set_started(&mut self, usec: u64) {
+        self.started_usec = Some(usec);
+    }
+
+    pub fn set_finished(&mut self, usec: u64, result: String) {
+        self.finished_usec = Some(usec);
+        self.result = result;
+    }
+
+    /// When the unit started, or finished when its start was not logged.
+    pub fn at(&self) -> Option<Duration> {
+        self.started_usec
+            .or(self.finished_usec)
+            .map(Duration::from_micros)
+    }
+
+    /// Time between the start and the end of the job.
+    pub fn took(&self) -> Option<Duration> {
+        Some(Duration::from_micros(
+            self.finished_usec?.checked_sub(self.started_usec?)?,
+        ))
+    }
+}
+
+/// Units of the initial ramdisk of the current boot, in the order they were
+/// first logged.
+#[derive(Debug, Clone, Default)]
+pub struct EarlyBoot {
+    units: Vec<EarlyBootUnit>,
+    /// `None` when the boot did not go through an initial ramdisk.
+    switch_root_usec: Option<u64>,
+}
+
+impl EarlyBoot {
+    pub fn new(units: Vec<EarlyBootUnit>, switch_root_usec: Option<u64>) -> Self {
+        Self {
+            units,
+            switch_root_usec,
+        }
+    }
+
+    pub fn units(&self) -> &[EarlyBootUnit] {
+        &self.units
+    }
+
+    pub fn switch_root(&self) -> Option<Duration> {
+        self.switch_root_usec.map(Duration::from_micros)
+    }
+}
@@ -0,0 +1,98 @@
+/// Points for each matched character.
+const MATCH: i32 = 16;
+/// Extra points for a character right after the previous matched one.
+const CONSECUTIVE: i32 = 8;
+/// Extra points for a character starting a word (`network-manager`, `foo@bar`).
+const BOUNDARY: i32 = 10;
+/// Extra points for an upper case character after a lower case one (`NetworkManager`).
+const CAMEL: i32 = 7;
+/// Points lost when characters are skipped between two matched ones, and for
+/// each further skipped character.
+const GAP_START: i32 = 3;
+const GAP_EXTENSION: i32 = 1;
+
+/// How much matching a character at `index` says about the text being the one looked for.
+fn bonus(text: &[char], index: usize) -> i32 {
+    let Some(previous) = index.checked_sub(1).map(|previous| text[previous]) else {
+        return BOUNDARY;
+    };
+    let current = text[index];
+    if !previous.is_alphanumeric() {
+        BOUNDARY
+    } else if (previous.is_lowercase() && current.is_uppercase())
+        || (!previous.is_ascii_digit() && current.is_ascii_digit())
+    {
+        CAMEL
+    } else {
+        0
+    }
+}
+
+fn same_letter(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+/// Matches the characters of `pattern`, in order and ignoring case, anywhere in
+/// `text` like fzf or skim do: `nmgr` matches `NetworkManager`. Returns the
+/// score of the best alignment, higher when the characters are close together
+/// or start words, and the char indices of `text` it matched.
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<(i32, Vec<usize>)> {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    if pattern.is_empty() {
+        return Some((0, vec![]));
+    }
+    if pattern.len() > text.len() {
+        return None;
+    }
+
+    // best[i][j]: score of the best alignment of pattern[..=i] with pattern[i]
+    // on text[j], and the index of text where pattern[i - 1] is then.
+    let mut best: Vec<Vec<Option<(i32, usize)>>> = vec![vec![None; text.len()]; pattern.len()];
+    for (i, &wanted) in pattern.iter().enumerate() {
+        // Best alignment of pattern[..i] ending before j - 1, gap included.
+        let mut best_gapped: Option<(i32, usize)> = None;
+        for j in i..text.len() {
+            if i > 0 && j >= 2 {
+                best_gapped = best_gapped.map(|(score, at)| (score - GAP_EXTENSION, at));
+                if let Some((score, _)) = best[i - 1][j - 2]
+                    && best_gapped.is_none_or(|(gapped, _)| score - GAP_START > gapped)
+                {
+                    best_gapped = Some((score - GAP_START, j - 2));
+                }
+            }
+            if !same_letter(wanted, text[j]) {
+                continue;
+            }
+            let gain = MATCH + bonus(&text, j);
+            best[i][j] = if i == 0 {
+                Some((gain, 0))
+            } else {
+                let adjacent = j
+                    .checked_sub(1)
+                    .and_then(|previous| best[i - 1][previous])
+                    .map(|(score, _)| (score + CONSECUTIVE, j - 1));
+                [adjacent, best_gapped]
+                    .into_iter()
+                    .flatten()
+                    .max_by_key(|(score, _)| *score)
+                    .map(|(score, at)| (score + gain, at))
+            };
+        }
+    }
+
+    let last = pattern.len() - 1;
+    let (mut at, (score, _)) = best[last]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, cell)| cell.map(|cell| (j, cell)))
+        .max_by_key(|(j, (score, _))| (*score, std::cmp::Reverse(*j)))?;
+    let mut positions = vec![at];
+    for i in (1..=last).rev() {
+        let (_, previous) = best[i][at]?;
+        positions.push(previous);
+        at = previous;
+    }
+    positions.reverse();
+    Some((score, positions))
+}
@@ -0,0 +1,86 @@
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// `(foo.service has 'stop' job queued, but 'start' is included in transaction)`
+static QUEUED_JOB: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\((\S+) has '([^']+)' job queued").unwrap());
+
+/// A job systemd has queued, as `systemctl list-jobs` shows it.
+#[derive(Debug, Clone)]
+pub struct QueuedJob {
+    id: u32,
+    unit: String,
+    /// `start`, `stop`, `restart`...
+    job_type: String,
+    /// `waiting` or `running`.
+    state: String,
+}
+
+impl QueuedJob {
+    pub fn new(id: u32, unit: String, job_type: String, state: String) -> Self {
+        Self {
+            id,
+            unit,
+            job_type,
+            state,
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+
+    pub fn job_type(&self) -> &str {
+        &self.job_type
+    }
+
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+}
+
+/// A job systemd refused to queue because one already queued goes the other
+/// way, e.g. a start while a stop that cannot be replaced is pending.
+#[derive(Debug, Clone)]
+pub struct JobConflict {
+    /// Unit and type of the job in the way, when the error names them.
+    queued: Option<(String, String)>,
+    message: String,
+}
+
+impl JobConflict {
+    /// Recognizes the error systemd answers with in that case.
+    pub fn from_error(message: &str) -> Option<Self> {
+        if !message.contains("TransactionIsDestructive") && !message.contains("is destructive") {
+            return None;
+        }
+        let queued = QUEUED_JOB
+            .captures(message)
+            .map(|captures| (captures[1].to_string(), captures[2].to_string()));
+        Some(Self {
+            queued,
+            message: message.to_string(),
+        })
+    }
+
+    /// What systemd said, without the name of the D-Bus error.
+    pub fn message(&self) -> &str {
+        self.message
+            .split_once(": ")
+            .filter(|(name, _)| name.starts_with("org.freedesktop."))
+            .map_or(&self.message, |(_, message)| message)
+    }
+
+    /// Whether `job` is the one in the way; every queued job may be when the
+    /// error does not name it.
+    pub fn is_caused_by(&self, job: &QueuedJob) -> bool {
+        match &self.queued {
+            Some((unit, job_type)) => job.unit == *unit && job.job_type == *job_type,
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+/// How systemd queues a start or stop job next to the jobs already queued
+/// (`systemctl --job-mode=`).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum JobMode {
+    #[default]
+    Replace,
+    Fail,
+    ReplaceIrreversibly,
+    Isolate,
+    IgnoreDependencies,
+    IgnoreRequirements,
+}
+
+impl JobMode {
+    pub const ALL: [JobMode; 6] = [
+        JobMode::Replace,
+        JobMode::Fail,
+        JobMode::ReplaceIrreversibly,
+        JobMode::Isolate,
+        JobMode::IgnoreDependencies,
+        JobMode::IgnoreRequirements,
+    ];
+
+    /// As passed to `StartUnit` and `StopUnit`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            JobMode::Replace => "replace",
+            JobMode::Fail => "fail",
+            JobMode::ReplaceIrreversibly => "replace-irreversibly",
+            JobMode::Isolate => "isolate",
+            JobMode::IgnoreDependencies => "ignore-dependencies",
+            JobMode::IgnoreRequirements => "ignore-requirements",
+        }
+    }
+
+    pub fn explanation(&self) -> &'static str {
+        match self {
+            JobMode::Replace => "Cancels queued jobs that conflict with this one. What s and x do.",
+            JobMode::Fail => "Gives up if the job conflicts with a queued one.",
+            JobMode::ReplaceIrreversibly => "Like replace, and later jobs cannot cancel this one.",
+            JobMode::Isolate => {
+                "Start only: stops every other unit, like switching targets. The unit must allow it."
+            }
+            JobMode::IgnoreDependencies => {
+                "Leaves the units it depends on, and that depend on it, alone."
+            }
+            JobMode::IgnoreRequirements => {
+                "Keeps ordering, but does not start or stop the units it requires."
+            }
+        }
+    }
+}
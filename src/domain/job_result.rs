@@ -0,0 +1,43 @@
+/// How a job queued on a unit ended, as reported by systemd's `JobRemoved`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JobResult {
+    Done,
+    Canceled,
+    Timeout,
+    Failed,
+    /// A unit the job required failed.
+    Dependency,
+    /// The job did not apply to the state the unit was in.
+    Skipped,
+    Other(String),
+}
+
+impl JobResult {
+    pub fn parse(result: &str) -> Self {
+        match result {
+            "done" => JobResult::Done,
+            "canceled" => JobResult::Canceled,
+            "timeout" => JobResult::Timeout,
+            "failed" => JobResult::Failed,
+            "dependency" => JobResult::Dependency,
+            "skipped" => JobResult::Skipped,
+            other => JobResult::Other(other.to_string()),
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, JobResult::Done | JobResult::Skipped)
+    }
+
+    pub fn describe(&self) -> &str {
+        match self {
+            JobResult::Done => "done",
+            JobResult::Canceled => "canceled",
+            JobResult::Timeout => "timed out",
+            JobResult::Failed => "failed",
+            JobResult::Dependency => "a dependency failed",
+            JobResult::Skipped => "skipped",
+            JobResult::Other(result) => result,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use super::log_entry::LogEntry;
+
+/// Entries of one unit that matched a search of the whole journal.
+#[derive(Debug, Clone)]
+pub struct UnitHits {
+    name: String,
+    count: usize,
+    /// Most recent matching entry.
+    last: LogEntry,
+}
+
+impl UnitHits {
+    pub fn new(name: String, last: LogEntry) -> Self {
+        Self {
+            name,
+            count: 1,
+            last,
+        }
+    }
+
+    /// Counts `entry`, which is newer than the previous hits.
+    pub fn add(&mut self, entry: LogEntry) {
+        self.count += 1;
+        self.last = entry;
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn last(&self) -> &LogEntry {
+        &self.last
+    }
+}
@@ -0,0 +1,203 @@
+/// A kernel limit, set with sysctl, that programs report running into.
+#[derive(Debug)]
+pub struct KernelLimit {
+    /// Name of the sysctl, e.g. `net.core.somaxconn`.
+    sysctl: &'static str,
+    /// What it limits, in a few words.
+    what: &'static str,
+    /// A value that is enough for most setups.
+    suggested: &'static str,
+    /// Lower case parts of log messages saying it was reached.
+    signs: &'static [&'static str],
+}
+
+/// Limits recognized in logs, the more specific signs first.
+const KERNEL_LIMITS: &[KernelLimit] = &[
+    KernelLimit {
+        sysctl: "fs.inotify.max_user_watches",
+        what: "inotify watches per user",
+        suggested: "524288",
+        signs: &[
+            "max_user_watches",
+            "inotify watch limit",
+            "limit on the total number of inotify watches",
+        ],
+    },
+    KernelLimit {
+        sysctl: "fs.inotify.max_user_instances",
+        what: "inotify instances per user",
+        suggested: "1024",
+        signs: &[
+            "max_user_instances",
+            "inotify instance limit",
+            "too many open inotify",
+            "inotify_init",
+        ],
+    },
+    KernelLimit {
+        sysctl: "net.core.somaxconn",
+        what: "length of the queue of connections waiting to be accepted",
+        suggested: "4096",
+        signs: &["somaxconn", "listen queue overflow", "backlog setting of"],
+    },
+    KernelLimit {
+        sysctl: "fs.file-max",
+        what: "open files in the whole system",
+        suggested: "2097152",
+        signs: &[
+            "file-max",
+            "file table overflow",
+            "too many open files in system",
+        ],
+    },
+    KernelLimit {
+        sysctl: "vm.max_map_count",
+        what: "memory mappings per process",
+        suggested: "262144",
+        signs: &["max_map_count", "max virtual memory areas"],
+    },
+    KernelLimit {
+        sysctl: "kernel.pid_max",
+        what: "process and thread IDs",
+        suggested: "4194304",
+        signs: &["pid_max"],
+    },
+    KernelLimit {
+        sysctl: "kernel.threads-max",
+        what: "threads in the whole system",
+        suggested: "4194304",
+        signs: &["threads-max"],
+    },
+    KernelLimit {
+        sysctl: "net.ipv4.ip_local_port_range",
+        what: "local ports for outgoing connections",
+        suggested: "1024 65535",
+        signs: &["ip_local_port_range"],
+    },
+    KernelLimit {
+        sysctl: "net.core.rmem_max",
+        what: "size of socket receive buffers",
+        suggested: "7500000",
+        signs: &["rmem_max", "receive buffer size"],
+    },
+    KernelLimit {
+        sysctl: "net.core.wmem_max",
+        what: "size of socket send buffers",
+        suggested: "7500000",
+        signs: &["wmem_max", "send buffer size"],
+    },
+    KernelLimit {
+        sysctl: "net.netfilter.nf_conntrack_max",
+        what: "connections tracked by the firewall",
+        suggested: "262144",
+        signs: &["nf_conntrack_max", "nf_conntrack: table full"],
+    },
+    KernelLimit {
+        sysctl: "fs.aio-max-nr",
+        what: "asynchronous I/O requests in the whole system",
+        suggested: "1048576",
+        signs: &["aio-max-nr"],
+    },
+];
+
+impl KernelLimit {
+    pub fn sysctl(&self) -> &'static str {
+        self.sysctl
+    }
+
+    pub fn what(&self) -> &'static str {
+        self.what
+    }
+
+    /// File of `/proc/sys` holding the current value.
+    pub fn proc_path(&self) -> String {
+        format!("/proc/sys/{}", self.sysctl.replace('.', "/"))
+    }
+
+    fn is_reported_by(&self, message: &str) -> bool {
+        self.signs.iter().any(|sign| message.contains(sign))
+    }
+
+    /// The limits `messages` say were reached, each with the last message
+    /// about it.
+    pub fn reported_in<'a>(
+        messages: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<(&'static KernelLimit, String)> {
+        let mut reported: Vec<(&'static KernelLimit, String)> = vec![];
+        for message in messages {
+            let lower = message.to_lowercase();
+            let Some(limit) = KERNEL_LIMITS
+                .iter()
+                .find(|limit| limit.is_reported_by(&lower))
+            else {
+                continue;
+            };
+            match reported
+                .iter_mut()
+                .find(|(known, _)| known.sysctl == limit.sysctl)
+            {
+                Some((_, last)) => *last = message.to_string(),
+                None => reported.push((limit, message.to_string())),
+            }
+        }
+        reported
+    }
+}
+
+/// A kernel limit the log of a unit says it ran into, with its value now.
+#[derive(Debug, Clone)]
+pub struct SysctlHint {
+    limit: &'static KernelLimit,
+    /// Value read from `/proc/sys`, `None` when it could not be read.
+    current: Option<String>,
+    /// Last log message about it.
+    evidence: String,
+}
+
+impl SysctlHint {
+    pub fn new(limit: &'static KernelLimit, current: Option<String>, evidence: String) -> Self {
+        Self {
+            limit,
+            current,
+            evidence,
+        }
+    }
+
+    pub fn limit(&self) -> &'static KernelLimit {
+        self.limit
+    }
+
+    pub fn current(&self) -> Option<&str> {
+        self.current.as_deref()
+    }
+
+    pub fn evidence(&self) -> &str {
+        &self.evidence
+    }
+
+    /// The suggested value, or twice the current one when that is already as high.
+    pub fn raise_to(&self) -> String {
+        let suggested = self.limit.suggested;
+        match (
+            self.current
+                .as_deref()
+                .and_then(|current| current.parse::<u64>().ok()),
+            suggested.parse::<u64>(),
+        ) {
+            (Some(current), Ok(suggested)) if current >= suggested => {
+                current.saturating_mul(2).to_string()
+            }
+            _ => suggested.to_string(),
+        }
+    }
+
+    /// Line of a `sysctl.d` file that keeps the raised value across reboots.
+    pub fn sysctl_d_line(&self) -> String {
+        format!("{} = {}", self.limit.sysctl, self.raise_to())
+    }
+
+    /// Command raising the value until the next reboot.
+    pub fn sysctl_command(&self) -> String {
+        format!("sysctl -w {}=\"{}\"", self.limit.sysctl, self.raise_to())
+    }
+}
@@ -0,0 +1,213 @@
+use chrono::{DateTime, Local, Utc};
+use std::time::Duration;
+
+/// Syslog priority of a journal entry, from most to least severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogPriority {
+    Emergency,
+    Alert,
+    Critical,
+    Error,
+    Warning,
+    Notice,
+    Info,
+    Debug,
+}
+
+impl LogPriority {
+    pub fn from_level(level: u8) -> Option<Self> {
+        match level {
+            0 => Some(LogPriority::Emergency),
+            1 => Some(LogPriority::Alert),
+            2 => Some(LogPriority::Critical),
+            3 => Some(LogPriority::Error),
+            4 => Some(LogPriority::Warning),
+            5 => Some(LogPriority::Notice),
+            6 => Some(LogPriority::Info),
+            7 => Some(LogPriority::Debug),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogPriority::Emergency => "emerg",
+            LogPriority::Alert => "alert",
+            LogPriority::Critical => "crit",
+            LogPriority::Error => "err",
+            LogPriority::Warning => "warning",
+            LogPriority::Notice => "notice",
+            LogPriority::Info => "info",
+            LogPriority::Debug => "debug",
+        }
+    }
+}
+
+/// How the timestamp of a log line is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimestampFormat {
+    /// Local time, like `journalctl --output=short`.
+    #[default]
+    Local,
+    Utc,
+    /// Local time with microseconds and offset, like `--output=short-iso-precise`.
+    Iso8601,
+    /// Time elapsed since the entry, e.g. `5m ago`.
+    Relative,
+}
+
+impl TimestampFormat {
+    pub fn next(self) -> Self {
+        match self {
+            TimestampFormat::Local => TimestampFormat::Utc,
+            TimestampFormat::Utc => TimestampFormat::Iso8601,
+            TimestampFormat::Iso8601 => TimestampFormat::Relative,
+            TimestampFormat::Relative => TimestampFormat::Local,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimestampFormat::Local => "local",
+            TimestampFormat::Utc => "UTC",
+            TimestampFormat::Iso8601 => "ISO 8601",
+            TimestampFormat::Relative => "relative",
+        }
+    }
+
+    fn format(&self, timestamp: DateTime<Utc>) -> String {
+        match self {
+            TimestampFormat::Local => timestamp
+                .with_timezone(&Local)
+                .format("%b %d %H:%M:%S")
+                .to_string(),
+            TimestampFormat::Utc => timestamp.format("%b %d %H:%M:%S UTC").to_string(),
+            TimestampFormat::Iso8601 => timestamp
+                .with_timezone(&Local)
+                .format("%Y-%m-%dT%H:%M:%S%.6f%:z")
+                .to_string(),
+            TimestampFormat::Relative => {
+                let seconds = (Utc::now() - timestamp).num_seconds().max(0);
+                match seconds {
+                    0..60 => format!("{}s ago", seconds),
+                    60..3_600 => format!("{}m ago", seconds / 60),
+                    3_600..86_400 => format!("{}h ago", seconds / 3_600),
+                    _ => format!("{}d ago", seconds / 86_400),
+                }
+            }
+        }
+    }
+}
+
+/// One journal record as exported by `journalctl --output=json`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LogEntry {
+    cursor: String,
+    realtime_usec: u64,
+    priority: Option<LogPriority>,
+    message: String,
+    pid: Option<u32>,
+    uid: Option<u32>,
+    hostname: String,
+    identifier: String,
+}
+
+impl LogEntry {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        cursor: String,
+        realtime_usec: u64,
+        priority: Option<LogPriority>,
+        message: String,
+        pid: Option<u32>,
+        uid: Option<u32>,
+        hostname: String,
+        identifier: String,
+    ) -> Self {
+        Self {
+            cursor,
+            realtime_usec,
+            priority,
+            message,
+            pid,
+            uid,
+            hostname,
+            identifier,
+        }
+    }
+
+    pub fn cursor(&self) -> &str {
+        &self.cursor
+    }
+
+    pub fn realtime_usec(&self) -> u64 {
+        self.realtime_usec
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn hostname(&self) -> &str {
+        &self.hostname
+    }
+
+    /// How far after `now_usec`, the current time of the host whose journal
+    /// it is read from, the entry is dated, if it is: the clock was set back
+    /// since it was written, or the host that wrote it runs ahead.
+    pub fn ahead_of(&self, now_usec: u64) -> Option<Duration> {
+        self.realtime_usec
+            .checked_sub(now_usec)
+            .filter(|ahead| *ahead > 0)
+            .map(Duration::from_micros)
+    }
+
+    pub fn priority(&self) -> Option<LogPriority> {
+        self.priority
+    }
+
+    /// Whether the entry is at least as severe as `max_priority`. Entries
+    /// without a priority are always shown.
+    pub fn is_within(&self, max_priority: LogPriority) -> bool {
+        self.priority
+            .is_none_or(|priority| priority <= max_priority)
+    }
+
+    /// Formats the entry like `journalctl --output=short` does.
+    pub fn short_line(&self) -> String {
+        self.line(TimestampFormat::Local)
+    }
+
+    fn timestamp(&self, timestamp_format: TimestampFormat) -> String {
+        DateTime::from_timestamp_micros(self.realtime_usec as i64)
+            .map(|dt| timestamp_format.format(dt))
+            .unwrap_or_default()
+    }
+
+    /// Formats the entry like `journalctl --output=short` does, with the
+    /// timestamp written as `timestamp_format` says.
+    pub fn line(&self, timestamp_format: TimestampFormat) -> String {
+        let timestamp = self.timestamp(timestamp_format);
+        let pid = self.pid.map(|pid| format!("[{}]", pid)).unwrap_or_default();
+
+        format!(
+            "{} {} {}{}: {}",
+            timestamp, self.hostname, self.identifier, pid, self.message
+        )
+    }
+
+    /// Like [`LogEntry::line`], with the PID, UID, hostname and identifier in
+    /// aligned columns; `-` stands for a field the entry does not have.
+    pub fn verbose_line(&self, timestamp_format: TimestampFormat) -> String {
+        let field = |value: Option<u32>| value.map_or("-".to_string(), |value| value.to_string());
+        format!(
+            "{} {:>7} {:>5} {:<15} {:<20} {}",
+            self.timestamp(timestamp_format),
+            field(self.pid),
+            field(self.uid),
+            self.hostname,
+            self.identifier,
+            self.message
+        )
+    }
+}
@@ -0,0 +1,70 @@
+/// Which part of a unit's journal to fetch.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LogQuery {
+    /// Boot offset as understood by `journalctl -b`; `None` means every boot.
+    boot: Option<i32>,
+    /// Time window in any format `journalctl --since/--until` accepts
+    /// (`09:00`, `-15min`, `yesterday`...).
+    since: Option<String>,
+    until: Option<String>,
+    after_cursor: Option<String>,
+    /// Most recent entries to fetch; `None` means the whole history.
+    lines: Option<usize>,
+}
+
+/// Turns `None` and blank strings into `None`.
+fn non_blank(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+impl LogQuery {
+    pub fn with_boot(mut self, boot: Option<i32>) -> Self {
+        self.boot = boot;
+        self
+    }
+
+    pub fn with_time_range(mut self, since: Option<&str>, until: Option<&str>) -> Self {
+        self.since = non_blank(since);
+        self.until = non_blank(until);
+        self
+    }
+
+    /// Only entries written after `cursor`.
+    pub fn with_after_cursor(mut self, cursor: Option<String>) -> Self {
+        self.after_cursor = cursor;
+        self
+    }
+
+    pub fn with_lines(mut self, lines: Option<usize>) -> Self {
+        self.lines = lines;
+        self
+    }
+
+    pub fn boot(&self) -> Option<i32> {
+        self.boot
+    }
+
+    pub fn since(&self) -> Option<&str> {
+        self.since.as_deref()
+    }
+
+    pub fn until(&self) -> Option<&str> {
+        self.until.as_deref()
+    }
+
+    pub fn after_cursor(&self) -> Option<&str> {
+        self.after_cursor.as_deref()
+    }
+
+    pub fn lines(&self) -> Option<usize> {
+        self.lines
+    }
+
+    /// Whether entries written from now on can still match.
+    pub fn includes_now(&self) -> bool {
+        self.boot.is_none_or(|boot| boot == 0) && self.until.is_none()
+    }
+}
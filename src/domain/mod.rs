@@ -1,4 +1,30 @@
+pub mod access_control;
+pub mod boot;
+pub mod boot_time;
+pub mod early_boot;
+pub mod fuzzy;
+pub mod job_conflict;
+pub mod job_mode;
+pub mod job_result;
+pub mod journal_search;
+pub mod kernel_limit;
+pub mod log_entry;
+pub mod log_query;
+pub mod mount_property;
+pub mod overview;
+pub mod plugin;
+pub mod resource_usage;
+pub mod security_report;
 pub mod service;
 pub mod service_property;
 pub mod service_repository;
 pub mod service_state;
+pub mod socket_property;
+pub mod stop_impact;
+pub mod timer_property;
+pub mod tmpfiles;
+pub mod triage;
+pub mod unit_family;
+pub mod unit_filter;
+pub mod unit_property;
+pub mod unit_type;
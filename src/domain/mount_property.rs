@@ -0,0 +1,48 @@
+#[derive(Debug, Clone)]
+pub struct MountProperty {
+    what: String,
+    r#where: String,
+    fs_type: String,
+    options: String,
+    timeout_usec: u64,
+    result: String,
+}
+
+impl MountProperty {
+    pub fn new(
+        what: String,
+        r#where: String,
+        fs_type: String,
+        options: String,
+        timeout_usec: u64,
+        result: String,
+    ) -> Self {
+        Self {
+            what,
+            r#where,
+            fs_type,
+            options,
+            timeout_usec,
+            result,
+        }
+    }
+
+    pub fn what(&self) -> &str {
+        &self.what
+    }
+    pub fn r#where(&self) -> &str {
+        &self.r#where
+    }
+    pub fn fs_type(&self) -> &str {
+        &self.fs_type
+    }
+    pub fn options(&self) -> &str {
+        &self.options
+    }
+    pub fn timeout_usec(&self) -> u64 {
+        self.timeout_usec
+    }
+    pub fn result(&self) -> &str {
+        &self.result
+    }
+}
@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+/// What the whole system looks like at a glance, for the dashboard.
+#[derive(Debug, Clone, Default)]
+pub struct Overview {
+    /// `SystemState` of the manager, like `running` or `degraded`.
+    state: String,
+    uptime: Option<Duration>,
+    /// Load average over 1, 5 and 15 minutes.
+    load: Option<[f64; 3]>,
+    /// Failed units with when they failed, in microseconds since the epoch,
+    /// the most recent first.
+    failed: Vec<(String, u64)>,
+}
+
+impl Overview {
+    pub fn new(
+        state: String,
+        uptime: Option<Duration>,
+        load: Option<[f64; 3]>,
+        mut failed: Vec<(String, u64)>,
+    ) -> Self {
+        failed.sort_by_key(|(_, usec)| std::cmp::Reverse(*usec));
+        Self {
+            state,
+            uptime,
+            load,
+            failed,
+        }
+    }
+
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    pub fn uptime(&self) -> Option<Duration> {
+        self.uptime
+    }
+
+    pub fn load(&self) -> Option<[f64; 3]> {
+        self.load
+    }
+
+    pub fn failed(&self) -> &[(String, u64)] {
+        &self.failed
+    }
+}
+
+/// Time since boot from the first field of `/proc/uptime`.
+pub fn parse_uptime(text: &str) -> Option<Duration> {
+    let seconds: f64 = text.split_whitespace().next()?.parse().ok()?;
+    Some(Duration::from_secs_f64(seconds))
+}
+
+/// Load averages from the first three fields of `/proc/loadavg`.
+pub fn parse_loadavg(text: &str) -> Option<[f64; 3]> {
+    let mut fields = text.split_whitespace().map(|field| field.parse().ok());
+    Some([fields.next()??, fields.next()??, fields.next()??])
+}
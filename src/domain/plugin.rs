@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use std::error::Error;
+
+/// Something a plugin adds to the interface: an action on a unit, a view of
+/// one, or a column of the list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extension {
+    /// What the plugin calls it when asked to run or fill it.
+    id: String,
+    /// What the interface calls it: the entry of the unit menu, the title of
+    /// the view or the header of the column.
+    title: String,
+    /// Key running the action from the list, written like those of `[keys]`.
+    key: Option<String>,
+}
+
+impl Extension {
+    pub fn new(id: String, title: String) -> Self {
+        Self {
+            id,
+            title,
+            key: None,
+        }
+    }
+
+    pub fn with_key(mut self, key: Option<String>) -> Self {
+        self.key = key;
+        self
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+/// Additions of a site to the interface, like restarting a unit and telling a
+/// chat channel about it, made without changing the program.
+pub trait Plugin: Send + Sync {
+    fn name(&self) -> &str;
+    /// Actions offered in the unit menu.
+    fn actions(&self) -> &[Extension];
+    /// Views of a unit offered in the unit menu.
+    fn views(&self) -> &[Extension];
+    /// Columns added to the list.
+    fn columns(&self) -> &[Extension];
+    /// Runs the action `id` on `unit`, returning what it has to say.
+    fn run_action(&self, id: &str, unit: &str) -> Result<String, Box<dyn Error>>;
+    /// Text of the view `id` of `unit`.
+    fn get_view(&self, id: &str, unit: &str) -> Result<String, Box<dyn Error>>;
+    /// Values of the column `id` for those of `units` that have one, by unit name.
+    fn get_column(
+        &self,
+        id: &str,
+        units: &[String],
+    ) -> Result<HashMap<String, String>, Box<dyn Error>>;
+}
@@ -0,0 +1,53 @@
+/// Memory, CPU and task accounting of a running unit, next to the limits set on it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResourceUsage {
+    memory_current: Option<u64>,
+    memory_max: Option<u64>,
+    tasks_current: Option<u64>,
+    tasks_max: Option<u64>,
+    /// CPU time used since the unit started, in nanoseconds.
+    cpu_usage_nsec: Option<u64>,
+}
+
+/// systemd reports `u64::MAX` for values that are not tracked or limits that are not set.
+fn known(value: u64) -> Option<u64> {
+    (value != u64::MAX).then_some(value)
+}
+
+impl ResourceUsage {
+    pub fn new(
+        memory_current: u64,
+        memory_max: u64,
+        tasks_current: u64,
+        tasks_max: u64,
+        cpu_usage_nsec: u64,
+    ) -> Self {
+        Self {
+            memory_current: known(memory_current),
+            memory_max: known(memory_max),
+            tasks_current: known(tasks_current),
+            tasks_max: known(tasks_max),
+            cpu_usage_nsec: known(cpu_usage_nsec),
+        }
+    }
+
+    pub fn memory_current(&self) -> Option<u64> {
+        self.memory_current
+    }
+
+    pub fn memory_max(&self) -> Option<u64> {
+        self.memory_max
+    }
+
+    pub fn tasks_current(&self) -> Option<u64> {
+        self.tasks_current
+    }
+
+    pub fn tasks_max(&self) -> Option<u64> {
+        self.tasks_max
+    }
+
+    pub fn cpu_usage_nsec(&self) -> Option<u64> {
+        self.cpu_usage_nsec
+    }
+}
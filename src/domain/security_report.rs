@@ -0,0 +1,75 @@
+/// A single line of the `systemd-analyze security` table.
+#[derive(Debug, Clone)]
+pub struct SecurityDirective {
+    /// `Some(true)` when the setting is hardened (✓), `Some(false)` when it is
+    /// exposed (✗) and `None` when systemd does not rate it.
+    passed: Option<bool>,
+    name: String,
+    description: String,
+    exposure: Option<f32>,
+}
+
+impl SecurityDirective {
+    pub fn new(
+        passed: Option<bool>,
+        name: String,
+        description: String,
+        exposure: Option<f32>,
+    ) -> Self {
+        Self {
+            passed,
+            name,
+            description,
+            exposure,
+        }
+    }
+
+    pub fn passed(&self) -> Option<bool> {
+        self.passed
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn exposure(&self) -> Option<f32> {
+        self.exposure
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SecurityReport {
+    directives: Vec<SecurityDirective>,
+    overall_exposure: f32,
+    overall_rating: String,
+}
+
+impl SecurityReport {
+    pub fn new(
+        directives: Vec<SecurityDirective>,
+        overall_exposure: f32,
+        overall_rating: String,
+    ) -> Self {
+        Self {
+            directives,
+            overall_exposure,
+            overall_rating,
+        }
+    }
+
+    pub fn directives(&self) -> &[SecurityDirective] {
+        &self.directives
+    }
+
+    pub fn overall_exposure(&self) -> f32 {
+        self.overall_exposure
+    }
+
+    pub fn overall_rating(&self) -> &str {
+        &self.overall_rating
+    }
+}
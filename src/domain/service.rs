@@ -1,12 +1,16 @@
-use super::service_property::ServiceProperty;
 use super::service_state::ServiceState;
 
-#[derive(Clone)]
+/// Name standing for the kernel messages (`journalctl --dmesg`) wherever a unit
+/// log is expected. Unit names always have a type suffix, so it cannot clash.
+pub const KERNEL_LOG: &str = "kernel";
+
+#[derive(Clone, PartialEq)]
 pub struct Service {
     name: String,
     description: String,
     state: ServiceState,
-    properties: Option<ServiceProperty>,
+    /// Path of the unit file; empty for units generated at runtime.
+    file_path: String,
 }
 
 impl Service {
@@ -15,10 +19,30 @@ impl Service {
             name,
             description,
             state,
-            properties: None,
+            file_path: String::new(),
         }
     }
 
+    pub fn with_file_path(mut self, file_path: String) -> Self {
+        self.file_path = file_path;
+        self
+    }
+
+    /// Pseudo unit whose log is the kernel ring buffer, with hardware and
+    /// OOM-killer messages.
+    pub fn kernel() -> Self {
+        Service::new(
+            KERNEL_LOG.to_string(),
+            "Kernel messages".to_string(),
+            ServiceState::new(
+                "loaded".to_string(),
+                "active".to_string(),
+                "running".to_string(),
+                "static".to_string(),
+            ),
+        )
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -27,6 +51,39 @@ impl Service {
         self.name.strip_suffix(".service").unwrap_or(&self.name)
     }
 
+    /// Name without the unit type suffix (`foo.socket` → `foo`).
+    pub fn stem(&self) -> &str {
+        self.name
+            .rsplit_once('.')
+            .map_or(&self.name, |(stem, _)| stem)
+    }
+
+    /// Whether this is a template unit such as `foo@.service`.
+    pub fn is_template(&self) -> bool {
+        self.name.contains("@.")
+    }
+
+    /// Name of the template an instance was created from
+    /// (`foo@bar.service` → `foo@.service`).
+    pub fn template_name(&self) -> Option<String> {
+        let (prefix, rest) = self.name.split_once('@')?;
+        let (instance, suffix) = rest.rsplit_once('.')?;
+        if instance.is_empty() {
+            return None;
+        }
+        Some(format!("{}@.{}", prefix, suffix))
+    }
+
+    /// Unit name of a new instance of this template, the instance escaped
+    /// (`foo@.service` + `bar baz` → `foo@bar\x20baz.service`).
+    pub fn instantiate(&self, instance: &str) -> Option<String> {
+        if !self.is_template() || instance.is_empty() {
+            return None;
+        }
+        let instance = escape_instance(instance);
+        Some(self.name.replacen("@.", &format!("@{}.", instance), 1))
+    }
+
     pub fn description(&self) -> &str {
         &self.description
     }
@@ -35,11 +92,46 @@ impl Service {
         &self.state
     }
 
-    pub fn properties(&self) -> Option<&ServiceProperty> {
-        self.properties.as_ref()
+    pub fn file_path(&self) -> &str {
+        &self.file_path
     }
+}
 
-    pub fn update_properties(&mut self, properties: ServiceProperty) {
-        self.properties = Some(properties);
+/// `instance` escaped as `systemd-escape` does: `/` becomes `-`, and bytes
+/// other than ASCII letters, digits, `:`, `_` and a `.` that does not come
+/// first become `\xNN`.
+fn escape_instance(instance: &str) -> String {
+    let mut escaped = String::with_capacity(instance.len());
+    for (index, byte) in instance.bytes().enumerate() {
+        match byte {
+            b'/' => escaped.push('-'),
+            b'.' if index > 0 => escaped.push('.'),
+            b':' | b'_' => escaped.push(byte as char),
+            _ if byte.is_ascii_alphanumeric() => escaped.push(byte as char),
+            _ => escaped.push_str(&format!("\\x{:02x}", byte)),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::escape_instance;
+
+    #[test]
+    fn escapes_instances_like_systemd_escape() {
+        // Expected values are the output of `systemd-escape -- NAME`.
+        let cases = [
+            ("web1", "web1"),
+            ("/var/lib/app", "-var-lib-app"),
+            ("blue-green", "blue\\x2dgreen"),
+            (".hidden", "\\x2ehidden"),
+            ("v1.2:main_x", "v1.2:main_x"),
+            ("café", "caf\\xc3\\xa9"),
+            ("a b\\c", "a\\x20b\\x5cc"),
+        ];
+        for (instance, escaped) in cases {
+            assert_eq!(escape_instance(instance), escaped, "{}", instance);
+        }
     }
 }
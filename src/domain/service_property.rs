@@ -48,6 +48,9 @@ pub struct ServiceProperty {
     limit_memlock: u64,
     memory_limit: u64,
     cpu_shares: u64,
+
+    /// `NAME=value` assignments from `Environment=`.
+    environment: Vec<String>,
 }
 
 impl ServiceProperty {
@@ -83,6 +86,8 @@ impl ServiceProperty {
         limit_memlock: u64,
         memory_limit: u64,
         cpu_shares: u64,
+
+        environment: Vec<String>,
     ) -> Self {
         Self {
             exec_start,
@@ -115,6 +120,8 @@ impl ServiceProperty {
             limit_memlock,
             memory_limit,
             cpu_shares,
+
+            environment,
         }
     }
 
@@ -236,4 +243,7 @@ impl ServiceProperty {
     pub fn cpu_shares(&self) -> u64 {
         self.cpu_shares
     }
+    pub fn environment(&self) -> &[String] {
+        &self.environment
+    }
 }
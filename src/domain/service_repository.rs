@@ -1,12 +1,88 @@
+use super::access_control::AccessControl;
+use super::boot::Boot;
+use super::boot_time::{BootTime, ChainLink};
+use super::early_boot::EarlyBoot;
+use super::job_conflict::QueuedJob;
+use super::job_mode::JobMode;
+use super::job_result::JobResult;
+use super::log_entry::LogEntry;
+use super::log_query::LogQuery;
+use super::overview::Overview;
+use super::resource_usage::ResourceUsage;
+use super::security_report::SecurityReport;
 use super::service::Service;
+use super::stop_impact::ImpactedUnit;
+use super::tmpfiles::Tmpfiles;
+use super::triage::TriageFacts;
+use super::unit_property::UnitProperty;
+use async_trait::async_trait;
+use std::collections::HashMap;
 use std::error::Error;
 
+#[async_trait]
 pub trait ServiceRepository {
-    fn list_services(&self) -> Result<Vec<Service>, Box<dyn Error>>;
-    fn get_service_log(&self, name: &str) -> Result<String, Box<dyn Error>>;
-    fn start_service(&self, name: &str) -> Result<(), Box<dyn Error>>;
-    fn stop_service(&self, name: &str) -> Result<(), Box<dyn Error>>;
-    fn restart_service(&self, name: &str) -> Result<(), Box<dyn Error>>;
-    fn enable_service(&self, name: &str) -> Result<(), Box<dyn Error>>;
-    fn disable_service(&self, name: &str) -> Result<(), Box<dyn Error>>;
+    async fn list_services(&self) -> Result<Vec<Service>, Box<dyn Error>>;
+    async fn get_service_log(
+        &self,
+        name: &str,
+        query: &LogQuery,
+    ) -> Result<Vec<LogEntry>, Box<dyn Error>>;
+    /// Entries of every unit matching `query`, oldest first, with the unit each
+    /// is about.
+    async fn get_journal(
+        &self,
+        query: &LogQuery,
+    ) -> Result<Vec<(String, LogEntry)>, Box<dyn Error>>;
+    async fn list_boots(&self) -> Result<Vec<Boot>, Box<dyn Error>>;
+    /// Units of the initial ramdisk of the current boot.
+    async fn get_early_boot(&self) -> Result<EarlyBoot, Box<dyn Error>>;
+    /// How long each unit took to start during the current boot, slowest
+    /// first.
+    async fn get_boot_times(&self) -> Result<Vec<BootTime>, Box<dyn Error>>;
+    /// Critical chain of the default target, from the target down.
+    async fn get_critical_chain(&self) -> Result<Vec<ChainLink>, Box<dyn Error>>;
+    /// Usage of the running services, by unit name.
+    async fn get_resource_usage(&self) -> Result<HashMap<String, ResourceUsage>, Box<dyn Error>>;
+    /// Timers and sockets that activate each unit, by the name of the activated unit.
+    async fn get_activators(&self) -> Result<HashMap<String, Vec<String>>, Box<dyn Error>>;
+    /// Slice of each loaded unit that runs in one, by unit name.
+    async fn get_slices(&self) -> Result<HashMap<String, String>, Box<dyn Error>>;
+    /// Loaded units whose unit file or drop-ins changed since systemd read
+    /// them, waiting for a daemon-reload.
+    async fn get_units_needing_reload(&self) -> Result<Vec<String>, Box<dyn Error>>;
+    /// State of the whole system as systemd sums it up: `running`, `degraded`
+    /// when a unit failed, `starting`, `stopping` or `maintenance`.
+    async fn get_system_state(&self) -> Result<String, Box<dyn Error>>;
+    /// State, uptime and load of the system, and its failed units.
+    async fn get_overview(&self) -> Result<Overview, Box<dyn Error>>;
+    async fn get_unit_property(&self, name: &str) -> Result<UnitProperty, Box<dyn Error>>;
+    async fn get_security_report(&self, name: &str) -> Result<SecurityReport, Box<dyn Error>>;
+    async fn get_access_control(&self, name: &str) -> Result<AccessControl, Box<dyn Error>>;
+    /// What can explain why the unit does not start.
+    async fn get_triage_facts(&self, name: &str) -> Result<TriageFacts, Box<dyn Error>>;
+    /// Directories of the unit and the `tmpfiles.d` lines about them.
+    async fn get_tmpfiles(&self, name: &str) -> Result<Tmpfiles, Box<dyn Error>>;
+    /// Runs `systemd-tmpfiles --create` on `paths`, only telling what it would
+    /// do when `dry_run`, and returns what it printed.
+    async fn run_tmpfiles(&self, paths: &[String], dry_run: bool)
+    -> Result<String, Box<dyn Error>>;
+    /// Path and content of the unit file, then of its drop-ins.
+    async fn get_unit_files(&self, name: &str) -> Result<Vec<(String, String)>, Box<dyn Error>>;
+    /// Active units that go down too when the unit is stopped or restarted,
+    /// through `Requires=`, `BindsTo=` or `PartOf=` on it or on one of them.
+    async fn get_stop_impact(&self, name: &str) -> Result<Vec<ImpactedUnit>, Box<dyn Error>>;
+    /// Queues a start job, returning its object path.
+    async fn start_service(&self, name: &str, mode: JobMode) -> Result<String, Box<dyn Error>>;
+    /// Queues a stop job, returning its object path.
+    async fn stop_service(&self, name: &str, mode: JobMode) -> Result<String, Box<dyn Error>>;
+    /// Queues a restart job, returning its object path.
+    async fn restart_service(&self, name: &str) -> Result<String, Box<dyn Error>>;
+    /// Waits for the job at the object path `job`, queued on the unit, to end.
+    async fn wait_for_job(&self, name: &str, job: &str) -> Result<JobResult, Box<dyn Error>>;
+    /// Jobs queued by the manager, running or waiting.
+    async fn list_jobs(&self) -> Result<Vec<QueuedJob>, Box<dyn Error>>;
+    async fn cancel_job(&self, id: u32) -> Result<(), Box<dyn Error>>;
+    async fn enable_service(&self, name: &str) -> Result<(), Box<dyn Error>>;
+    async fn disable_service(&self, name: &str) -> Result<(), Box<dyn Error>>;
+    async fn reload_daemon(&self) -> Result<(), Box<dyn Error>>;
 }
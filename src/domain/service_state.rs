@@ -1,4 +1,4 @@
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub struct ServiceState {
     load: String,
     active: String,
@@ -31,4 +31,12 @@ impl ServiceState {
     pub fn file(&self) -> &str {
         &self.file
     }
+
+    /// Whether the unit file is enabled, so the unit starts at boot.
+    pub fn is_enabled(&self) -> bool {
+        matches!(
+            self.file.as_str(),
+            "enabled" | "enabled-runtime" | "linked" | "linked-runtime" | "alias"
+        )
+    }
 }
@@ -0,0 +1,56 @@
+/// Listen address of a socket: (type, address), e.g. ("Stream", "/run/foo.sock").
+pub type SS = (String, String);
+
+#[derive(Debug, Clone)]
+pub struct SocketProperty {
+    listen: Vec<SS>,
+    accept: bool,
+    n_connections: u32,
+    n_accepted: u32,
+    n_refused: u32,
+    result: String,
+}
+
+impl SocketProperty {
+    pub fn new(
+        listen: Vec<SS>,
+        accept: bool,
+        n_connections: u32,
+        n_accepted: u32,
+        n_refused: u32,
+        result: String,
+    ) -> Self {
+        Self {
+            listen,
+            accept,
+            n_connections,
+            n_accepted,
+            n_refused,
+            result,
+        }
+    }
+
+    pub fn formatted_listen(&self) -> String {
+        self.listen
+            .iter()
+            .map(|(kind, address)| format!("{} ({})", address, kind))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    pub fn accept(&self) -> bool {
+        self.accept
+    }
+    pub fn n_connections(&self) -> u32 {
+        self.n_connections
+    }
+    pub fn n_accepted(&self) -> u32 {
+        self.n_accepted
+    }
+    pub fn n_refused(&self) -> u32 {
+        self.n_refused
+    }
+    pub fn result(&self) -> &str {
+        &self.result
+    }
+}
@@ -0,0 +1,60 @@
+/// Dependency through which stopping or restarting a unit reaches another one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Propagation {
+    /// The other unit has `Requires=` on it.
+    Requires,
+    /// The other unit has `BindsTo=` on it.
+    BindsTo,
+    /// The other unit has `PartOf=` on it.
+    PartOf,
+}
+
+impl Propagation {
+    /// Name of the property of the stopped unit listing the units reached this way.
+    pub fn reverse_property(&self) -> &'static str {
+        match self {
+            Propagation::Requires => "RequiredBy",
+            Propagation::BindsTo => "BoundBy",
+            Propagation::PartOf => "ConsistsOf",
+        }
+    }
+
+    pub const ALL: [Propagation; 3] = [
+        Propagation::Requires,
+        Propagation::BindsTo,
+        Propagation::PartOf,
+    ];
+}
+
+/// An active unit that systemd stops or restarts along with another one.
+#[derive(Debug, Clone)]
+pub struct ImpactedUnit {
+    name: String,
+    propagation: Propagation,
+    /// The unit it depends on, the one acted on or one already reached.
+    cause: String,
+}
+
+impl ImpactedUnit {
+    pub fn new(name: String, propagation: Propagation, cause: String) -> Self {
+        Self {
+            name,
+            propagation,
+            cause,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Why it goes down, e.g. `requires foo.socket`.
+    pub fn reason(&self) -> String {
+        let relation = match self.propagation {
+            Propagation::Requires => "requires",
+            Propagation::BindsTo => "is bound to",
+            Propagation::PartOf => "is part of",
+        };
+        format!("{} {}", relation, self.cause)
+    }
+}
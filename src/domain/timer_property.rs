@@ -0,0 +1,98 @@
+use chrono::DateTime;
+
+/// Calendar trigger of a timer: (timer base, calendar expression, next elapse in µs).
+#[allow(clippy::upper_case_acronyms)]
+pub type SST = (String, String, u64);
+
+/// Monotonic trigger of a timer: (timer base, offset in µs, next elapse in µs).
+#[allow(clippy::upper_case_acronyms)]
+pub type STT = (String, u64, u64);
+
+#[derive(Debug, Clone)]
+pub struct TimerProperty {
+    unit: String,
+    timers_calendar: Vec<SST>,
+    timers_monotonic: Vec<STT>,
+    next_elapse_realtime: u64,
+    last_trigger: u64,
+    persistent: bool,
+    accuracy_usec: u64,
+    randomized_delay_usec: u64,
+    result: String,
+}
+
+impl TimerProperty {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        unit: String,
+        timers_calendar: Vec<SST>,
+        timers_monotonic: Vec<STT>,
+        next_elapse_realtime: u64,
+        last_trigger: u64,
+        persistent: bool,
+        accuracy_usec: u64,
+        randomized_delay_usec: u64,
+        result: String,
+    ) -> Self {
+        Self {
+            unit,
+            timers_calendar,
+            timers_monotonic,
+            next_elapse_realtime,
+            last_trigger,
+            persistent,
+            accuracy_usec,
+            randomized_delay_usec,
+            result,
+        }
+    }
+
+    /// Formats a realtime timestamp expressed in microseconds.
+    pub fn format_usec_timestamp(&self, usec: u64) -> String {
+        if usec == 0 || usec == u64::MAX {
+            return "n/a".to_string();
+        }
+        match DateTime::from_timestamp((usec / 1_000_000) as i64, 0) {
+            Some(dt) => dt.format("%Y-%m-%d %H:%M:%S").to_string(),
+            None => "".to_string(),
+        }
+    }
+
+    pub fn formatted_timers_calendar(&self) -> String {
+        self.timers_calendar
+            .iter()
+            .map(|(base, expression, _)| format!("{}: {}", base, expression))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    pub fn formatted_timers_monotonic(&self) -> String {
+        self.timers_monotonic
+            .iter()
+            .map(|(base, offset, _)| format!("{}: {}s", base, offset / 1_000_000))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+    pub fn next_elapse_realtime(&self) -> u64 {
+        self.next_elapse_realtime
+    }
+    pub fn last_trigger(&self) -> u64 {
+        self.last_trigger
+    }
+    pub fn persistent(&self) -> bool {
+        self.persistent
+    }
+    pub fn accuracy_usec(&self) -> u64 {
+        self.accuracy_usec
+    }
+    pub fn randomized_delay_usec(&self) -> u64 {
+        self.randomized_delay_usec
+    }
+    pub fn result(&self) -> &str {
+        &self.result
+    }
+}
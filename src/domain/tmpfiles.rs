@@ -0,0 +1,67 @@
+/// A line of a `tmpfiles.d` configuration file.
+#[derive(Debug, Clone)]
+pub struct TmpfilesEntry {
+    /// Configuration file the line comes from.
+    source: String,
+    /// `d`, `D`, `L+`, `z`...; see `tmpfiles.d(5)`.
+    kind: String,
+    path: String,
+    /// Mode, user, group, age and argument as written, `-` when left out.
+    settings: String,
+}
+
+impl TmpfilesEntry {
+    pub fn new(source: String, kind: String, path: String, settings: String) -> Self {
+        Self {
+            source,
+            kind,
+            path,
+            settings,
+        }
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn settings(&self) -> &str {
+        &self.settings
+    }
+
+    /// Whether the line is about `directory` or something in it. Lines about
+    /// its parents, such as `/run`, concern every unit and are left out.
+    pub fn concerns(&self, directory: &str) -> bool {
+        self.path
+            .trim_end_matches('/')
+            .strip_prefix(directory.trim_end_matches('/'))
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    }
+}
+
+/// Directories a unit relies on and the `tmpfiles.d` lines that set them up,
+/// since a missing runtime directory is a common reason for a failed start.
+#[derive(Debug, Clone, Default)]
+pub struct Tmpfiles {
+    /// Directories from `RuntimeDirectory=`, `StateDirectory=`,
+    /// `WorkingDirectory=`, `ReadWritePaths=` and the like, with whether they
+    /// exist.
+    pub directories: Vec<(String, bool)>,
+    pub entries: Vec<TmpfilesEntry>,
+}
+
+impl Tmpfiles {
+    pub fn paths(&self) -> Vec<String> {
+        self.directories
+            .iter()
+            .map(|(directory, _)| directory.clone())
+            .collect()
+    }
+}
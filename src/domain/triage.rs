@@ -0,0 +1,251 @@
+use super::kernel_limit::SysctlHint;
+use super::log_entry::LogEntry;
+
+/// `ExecMainCode` of a main process that was killed by a signal, or dumped core.
+const CLD_KILLED: i32 = 2;
+const CLD_DUMPED: i32 = 3;
+
+/// A `Condition*=` or `Assert*=` that was checked and did not hold.
+#[derive(Debug, Clone)]
+pub struct FailedCheck {
+    /// `ConditionPathExists`, `AssertFileNotEmpty`...
+    kind: String,
+    negated: bool,
+    parameter: String,
+}
+
+impl FailedCheck {
+    pub fn new(kind: String, negated: bool, parameter: String) -> Self {
+        Self {
+            kind,
+            negated,
+            parameter,
+        }
+    }
+
+    /// As written in the unit file, e.g. `ConditionPathExists=!/run/foo`.
+    pub fn directive(&self) -> String {
+        let negation = if self.negated { "!" } else { "" };
+        format!("{}={}{}", self.kind, negation, self.parameter)
+    }
+
+    pub fn is_assert(&self) -> bool {
+        self.kind.starts_with("Assert")
+    }
+}
+
+/// Everything gathered about a unit that does not start, before ranking.
+#[derive(Debug, Clone, Default)]
+pub struct TriageFacts {
+    /// `Result` of the service (`exit-code`, `timeout`...); empty for other units.
+    pub result: String,
+    pub exec_main_code: i32,
+    pub exec_main_status: i32,
+    pub failed_checks: Vec<FailedCheck>,
+    /// Units it requires that are failed, or missing.
+    pub failed_dependencies: Vec<(String, String)>,
+    /// Error lines of its log in the current boot, oldest first.
+    pub error_lines: Vec<LogEntry>,
+    /// Unit file and drop-ins changed in the last day, with how many minutes ago.
+    pub recent_edits: Vec<(String, u64)>,
+    /// The files changed on disk since systemd last read them.
+    pub needs_daemon_reload: bool,
+    /// Kernel limits its log of the current boot says it reached.
+    pub sysctl_hints: Vec<SysctlHint>,
+}
+
+/// A likely cause, with what points to it and what to try.
+#[derive(Debug, Clone)]
+pub struct Finding {
+    /// Higher is more likely to be the cause.
+    score: u8,
+    cause: String,
+    evidence: String,
+    suggestion: String,
+}
+
+impl Finding {
+    fn new(score: u8, cause: &str, evidence: String, suggestion: &str) -> Self {
+        Self {
+            score,
+            cause: cause.to_string(),
+            evidence,
+            suggestion: suggestion.to_string(),
+        }
+    }
+
+    pub fn cause(&self) -> &str {
+        &self.cause
+    }
+
+    pub fn evidence(&self) -> &str {
+        &self.evidence
+    }
+
+    pub fn suggestion(&self) -> &str {
+        &self.suggestion
+    }
+}
+
+/// What the exit statuses systemd itself uses when setting up a process went
+/// wrong mean (see `systemd.exec(5)`, "Process Exit Codes").
+fn setup_failure(status: i32) -> Option<(&'static str, &'static str)> {
+    Some(match status {
+        200 => (
+            "The working directory could not be entered",
+            "Check WorkingDirectory= exists and is readable by the unit's user",
+        ),
+        203 => (
+            "The program could not be run",
+            "Check the path in ExecStart= exists, is executable and has a valid interpreter line",
+        ),
+        209 => (
+            "Standard output could not be set up",
+            "Check StandardOutput=/StandardError= and the files or sockets they name",
+        ),
+        217 => (
+            "The user or group does not exist",
+            "Create the account or fix User=/Group=",
+        ),
+        218 => (
+            "Capabilities could not be set",
+            "Check CapabilityBoundingSet= and AmbientCapabilities=",
+        ),
+        226 => (
+            "The file system namespace could not be set up",
+            "Check that the paths in ReadWritePaths=, BindPaths= and similar exist",
+        ),
+        _ => return None,
+    })
+}
+
+impl TriageFacts {
+    /// Likely causes, most likely first.
+    pub fn diagnose(&self) -> Vec<Finding> {
+        let mut findings = vec![];
+
+        for check in &self.failed_checks {
+            let (score, cause) = if check.is_assert() {
+                (95, "An assertion failed")
+            } else {
+                (90, "A start condition was not met")
+            };
+            findings.push(Finding::new(
+                score,
+                cause,
+                check.directive(),
+                "Make the condition hold, or change it in the unit file if it is wrong",
+            ));
+        }
+
+        for (dependency, state) in &self.failed_dependencies {
+            findings.push(Finding::new(
+                85,
+                "A required unit is not available",
+                format!("{} is {}", dependency, state),
+                "Fix that unit first; this one cannot start without it",
+            ));
+        }
+
+        match self.result.as_str() {
+            "exit-code" => match setup_failure(self.exec_main_status) {
+                Some((cause, suggestion)) => findings.push(Finding::new(
+                    100,
+                    cause,
+                    format!("exited with status {}", self.exec_main_status),
+                    suggestion,
+                )),
+                None => findings.push(Finding::new(
+                    70,
+                    "The program exited with an error",
+                    format!("exited with status {}", self.exec_main_status),
+                    "Read its last error lines below or its full log (v)",
+                )),
+            },
+            "signal" | "core-dump" => {
+                let how = if self.exec_main_code == CLD_DUMPED {
+                    "dumped core on"
+                } else if self.exec_main_code == CLD_KILLED {
+                    "was killed by"
+                } else {
+                    "stopped on"
+                };
+                findings.push(Finding::new(
+                    75,
+                    "The program crashed",
+                    format!("{} signal {}", how, self.exec_main_status),
+                    "Look for a core dump with coredumpctl and check recent upgrades",
+                ));
+            }
+            "timeout" => findings.push(Finding::new(
+                80,
+                "Starting took too long",
+                "timed out".to_string(),
+                "Check what it waits on, or raise TimeoutStartSec=",
+            )),
+            "oom-kill" => findings.push(Finding::new(
+                85,
+                "It ran out of memory",
+                "killed by the OOM killer".to_string(),
+                "Raise MemoryMax= or find what uses the memory",
+            )),
+            "resources" => findings.push(Finding::new(
+                80,
+                "systemd could not set it up",
+                "failed with result resources".to_string(),
+                "Check PIDFile=, sockets and directories the unit sets up",
+            )),
+            "start-limit-hit" => findings.push(Finding::new(
+                60,
+                "It was restarted too often",
+                "start rate limit reached".to_string(),
+                "Fix the earlier failure, then run systemctl reset-failed on it",
+            )),
+            _ => {}
+        }
+
+        for hint in &self.sysctl_hints {
+            findings.push(Finding::new(
+                85,
+                "It reached a kernel limit",
+                format!(
+                    "{} is {}: {}",
+                    hint.limit().sysctl(),
+                    hint.current().unwrap_or("unknown"),
+                    hint.evidence()
+                ),
+                "Raise it as shown under Kernel limits below",
+            ));
+        }
+
+        if self.needs_daemon_reload {
+            findings.push(Finding::new(
+                65,
+                "Its unit file changed but was not reloaded",
+                "systemd still runs the previous version".to_string(),
+                "Run systemctl daemon-reload, then start it again",
+            ));
+        }
+
+        for (path, minutes) in &self.recent_edits {
+            findings.push(Finding::new(
+                50,
+                "Its configuration was edited recently",
+                format!("{} changed {} min ago", path, minutes),
+                "Review the latest change to that file",
+            ));
+        }
+
+        if let Some(last_error) = self.error_lines.last() {
+            findings.push(Finding::new(
+                40,
+                "It logged an error",
+                last_error.message().to_string(),
+                "Read the full log (v) around that time",
+            ));
+        }
+
+        findings.sort_by_key(|finding| std::cmp::Reverse(finding.score));
+        findings
+    }
+}
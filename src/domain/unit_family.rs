@@ -0,0 +1,65 @@
+use super::service::Service;
+use super::unit_type::UnitType;
+
+/// Units of different types sharing a name, such as `foo.service`, `foo.socket`
+/// and `foo.timer`, which the list shows as a single row.
+#[derive(Clone)]
+pub struct UnitFamily {
+    name: String,
+    members: Vec<Service>,
+}
+
+impl UnitFamily {
+    pub fn new(name: String, members: Vec<Service>) -> Self {
+        UnitFamily { name, members }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn members(&self) -> &[Service] {
+        &self.members
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.members.iter().any(|member| member.name() == name)
+    }
+
+    /// Member actions and views apply to: the service if there is one.
+    pub fn main(&self) -> &Service {
+        self.members
+            .iter()
+            .find(|member| UnitType::from_name(member.name()) == UnitType::Service)
+            .unwrap_or(&self.members[0])
+    }
+
+    /// Most notable active state among the members, a failure coming first.
+    pub fn active(&self) -> &str {
+        ["failed", "activating", "deactivating", "active"]
+            .into_iter()
+            .find(|state| {
+                self.members
+                    .iter()
+                    .any(|member| member.state().active() == *state)
+            })
+            .unwrap_or("inactive")
+    }
+
+    pub fn active_count(&self) -> usize {
+        self.members
+            .iter()
+            .filter(|member| member.state().active() == "active")
+            .count()
+    }
+
+    /// Unit types of the members, e.g. `service + socket`.
+    pub fn types(&self) -> String {
+        self.members
+            .iter()
+            .filter_map(|member| member.name().rsplit_once('.'))
+            .map(|(_, suffix)| suffix)
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+}
@@ -0,0 +1,142 @@
+use super::fuzzy::fuzzy_match;
+use super::service::Service;
+
+/// Condition of a `key:value` token; a comma separated value matches any of
+/// its parts (`state:failed,activating`).
+#[derive(Debug, Clone, PartialEq)]
+enum Condition {
+    /// `state:` matches the load, active or sub state.
+    State(Vec<String>),
+    /// `type:` matches the unit type suffix.
+    Type(Vec<String>),
+    /// `enabled:yes` or `enabled:no`.
+    Enabled(bool),
+}
+
+impl Condition {
+    fn parse(token: &str) -> Option<Self> {
+        let (key, value) = token.split_once(':')?;
+        let values = || -> Vec<String> {
+            value
+                .split(',')
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+                .collect()
+        };
+        Some(match key {
+            "state" => Condition::State(values()),
+            "type" => Condition::Type(values()),
+            "enabled" => Condition::Enabled(match value {
+                "yes" | "true" | "1" => true,
+                "no" | "false" | "0" => false,
+                _ => return None,
+            }),
+            _ => return None,
+        })
+    }
+
+    fn matches(&self, service: &Service) -> bool {
+        let state = service.state();
+        match self {
+            Condition::State(values) => values
+                .iter()
+                .any(|value| [state.load(), state.active(), state.sub()].contains(&value.as_str())),
+            Condition::Type(values) => {
+                let suffix = service.name().rsplit_once('.').map(|(_, suffix)| suffix);
+                values.iter().any(|value| Some(value.as_str()) == suffix)
+            }
+            Condition::Enabled(enabled) => state.is_enabled() == *enabled,
+        }
+    }
+}
+
+/// What the filter above the unit list asks for: words that must all appear
+/// in the name, each possibly listing alternatives (`nginx|php`), and tokens
+/// such as `state:failed`, `type:timer` or `enabled:yes` that must all hold.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UnitFilter {
+    /// Each word, as the alternatives separated by `|` it lists.
+    words: Vec<Vec<String>>,
+    conditions: Vec<Condition>,
+    /// Words match their characters in order rather than as a whole, like fzf.
+    fuzzy: bool,
+}
+
+impl UnitFilter {
+    /// Reads the typed text; tokens with an unknown key or value are matched
+    /// against the name like other words.
+    pub fn parse(text: &str, fuzzy: bool) -> Self {
+        let mut filter = Self {
+            fuzzy,
+            ..Self::default()
+        };
+        for token in text.to_lowercase().split_whitespace() {
+            match Condition::parse(token) {
+                Some(condition) => filter.conditions.push(condition),
+                None => {
+                    let alternatives: Vec<String> = token
+                        .split('|')
+                        .filter(|alternative| !alternative.is_empty())
+                        .map(str::to_string)
+                        .collect();
+                    if !alternatives.is_empty() {
+                        filter.words.push(alternatives);
+                    }
+                }
+            }
+        }
+        filter
+    }
+
+    /// Whether units are better ranked by how well their name matches than by
+    /// the sort of the list.
+    pub fn ranks(&self) -> bool {
+        self.fuzzy && !self.words.is_empty()
+    }
+
+    /// Score and matched char indices of the alternative of a word that best
+    /// matches `text`.
+    fn match_word(&self, alternatives: &[String], text: &str) -> Option<(i32, Vec<usize>)> {
+        if self.fuzzy {
+            return alternatives
+                .iter()
+                .filter_map(|alternative| fuzzy_match(alternative, text))
+                .max_by_key(|(score, _)| *score);
+        }
+        let lower = text.to_lowercase();
+        alternatives.iter().find_map(|alternative| {
+            let start = lower.find(alternative.as_str())?;
+            let start = lower[..start].chars().count();
+            Some((0, (start..start + alternative.chars().count()).collect()))
+        })
+    }
+
+    /// How well the unit matches, higher being better, or `None` when it does not.
+    pub fn score(&self, service: &Service) -> Option<i32> {
+        if !self
+            .conditions
+            .iter()
+            .all(|condition| condition.matches(service))
+        {
+            return None;
+        }
+        let name = service.formatted_name();
+        self.words
+            .iter()
+            .map(|alternatives| self.match_word(alternatives, name).map(|(score, _)| score))
+            .sum()
+    }
+
+    pub fn matches(&self, service: &Service) -> bool {
+        self.score(service).is_some()
+    }
+
+    /// Char indices of `text` the words match, to highlight them.
+    pub fn matched_chars(&self, text: &str) -> Vec<usize> {
+        self.words
+            .iter()
+            .filter_map(|alternatives| self.match_word(alternatives, text))
+            .flat_map(|(_, positions)| positions)
+            .collect()
+    }
+}
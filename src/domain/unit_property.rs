@@ -0,0 +1,13 @@
+use super::mount_property::MountProperty;
+use super::service_property::ServiceProperty;
+use super::socket_property::SocketProperty;
+use super::timer_property::TimerProperty;
+
+/// Type specific properties of a unit.
+#[derive(Debug, Clone)]
+pub enum UnitProperty {
+    Service(Box<ServiceProperty>),
+    Socket(SocketProperty),
+    Timer(TimerProperty),
+    Mount(MountProperty),
+}
@@ -0,0 +1,43 @@
+use serde::Deserialize;
+
+/// Kind of systemd unit, derived from the unit name suffix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnitType {
+    Service,
+    Socket,
+    Timer,
+    Mount,
+    Other,
+}
+
+impl UnitType {
+    /// Unit types shown in the list.
+    pub const LISTED: [UnitType; 4] = [
+        UnitType::Service,
+        UnitType::Socket,
+        UnitType::Timer,
+        UnitType::Mount,
+    ];
+
+    pub fn from_name(name: &str) -> Self {
+        match name.rsplit_once('.').map(|(_, suffix)| suffix) {
+            Some("service") => UnitType::Service,
+            Some("socket") => UnitType::Socket,
+            Some("timer") => UnitType::Timer,
+            Some("mount") => UnitType::Mount,
+            _ => UnitType::Other,
+        }
+    }
+
+    /// D-Bus interface exposing the type specific properties.
+    pub fn interface(&self) -> Option<&'static str> {
+        match self {
+            UnitType::Service => Some("org.freedesktop.systemd1.Service"),
+            UnitType::Socket => Some("org.freedesktop.systemd1.Socket"),
+            UnitType::Timer => Some("org.freedesktop.systemd1.Timer"),
+            UnitType::Mount => Some("org.freedesktop.systemd1.Mount"),
+            UnitType::Other => None,
+        }
+    }
+}
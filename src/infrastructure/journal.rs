@@ -0,0 +1,211 @@
+use serde_json::Value;
+use std::io::{BufRead, BufReader, Lines};
+use std::process::{Child, ChildStdout, Command, Stdio};
+
+use super::target::Target;
+use crate::domain::boot::Boot;
+use crate::domain::early_boot::{EarlyBoot, EarlyBootUnit};
+use crate::domain::log_entry::{LogEntry, LogPriority};
+use crate::domain::service::KERNEL_LOG;
+
+/// Restricts a `journalctl` command to the entries of `unit`, or to the kernel
+/// transport for [`KERNEL_LOG`].
+pub fn match_unit(command: &mut Command, unit: &str) {
+    if unit == KERNEL_LOG {
+        command.arg("--dmesg");
+    } else {
+        command.arg("-u").arg(unit);
+    }
+}
+
+/// Journal fields are strings, but binary-safe fields are exported as byte arrays.
+fn field_string(record: &Value, key: &str) -> String {
+    match record.get(key) {
+        Some(Value::String(value)) => value.clone(),
+        Some(Value::Array(bytes)) => {
+            let bytes: Vec<u8> = bytes
+                .iter()
+                .filter_map(|b| b.as_u64().map(|b| b as u8))
+                .collect();
+            String::from_utf8_lossy(&bytes).to_string()
+        }
+        _ => String::new(),
+    }
+}
+
+fn field_number<T: std::str::FromStr>(record: &Value, key: &str) -> Option<T> {
+    field_string(record, key).parse().ok()
+}
+
+/// Parses one line of `journalctl --output=json`.
+pub fn parse_journal_entry(line: &str) -> Option<LogEntry> {
+    let record: Value = serde_json::from_str(line).ok()?;
+
+    let identifier = match field_string(&record, "SYSLOG_IDENTIFIER") {
+        identifier if identifier.is_empty() => field_string(&record, "_COMM"),
+        identifier => identifier,
+    };
+
+    Some(LogEntry::new(
+        field_string(&record, "__CURSOR"),
+        field_number(&record, "__REALTIME_TIMESTAMP").unwrap_or(0),
+        field_number(&record, "PRIORITY").and_then(LogPriority::from_level),
+        field_string(&record, "MESSAGE"),
+        field_number(&record, "_PID"),
+        field_number(&record, "_UID"),
+        field_string(&record, "_HOSTNAME"),
+        identifier,
+    ))
+}
+
+/// Parses one line of `journalctl --output=json` with the unit the entry is
+/// about: the one that wrote it, or the one systemd logs about (`Started...`).
+pub fn parse_unit_journal_entry(line: &str) -> Option<(String, LogEntry)> {
+    let record: Value = serde_json::from_str(line).ok()?;
+    let unit = ["_SYSTEMD_UNIT", "UNIT", "_SYSTEMD_USER_UNIT", "USER_UNIT"]
+        .into_iter()
+        .map(|key| field_string(&record, key))
+        .find(|unit| !unit.is_empty())?;
+    Some((unit, parse_journal_entry(line)?))
+}
+
+/// Parses the JSON array printed by `journalctl --list-boots --output=json`.
+pub fn parse_boots(output: &str) -> Vec<Boot> {
+    let Ok(Value::Array(records)) = serde_json::from_str::<Value>(output) else {
+        return vec![];
+    };
+
+    records
+        .iter()
+        .filter_map(|record| {
+            Some(Boot::new(
+                record.get("index")?.as_i64()? as i32,
+                record.get("boot_id")?.as_str()?.to_string(),
+                record.get("first_entry")?.as_u64()?,
+                record.get("last_entry")?.as_u64()?,
+            ))
+        })
+        .collect()
+}
+
+/// `MESSAGE_ID`s systemd logs when a job of a unit starts and when it ends
+/// (`Started`, `Finished`, `Reached target`...) or fails.
+const JOB_STARTING: &str = "7d4958e842da4a758f6c1cdc7b36dcc5";
+const JOB_DONE: &str = "39f53479d3a045ac8e11786248231fbf";
+const JOB_FAILED: &str = "be02cf6855d2428ba40df7e9d022f03d";
+
+/// Reads the units of the initial ramdisk from the messages of PID 1 in the
+/// current boot, printed by `journalctl --output=json`, up to the switch to
+/// the real root.
+pub fn parse_early_boot(output: &str) -> EarlyBoot {
+    let mut units: Vec<EarlyBootUnit> = vec![];
+    for record in output
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+    {
+        let Some(usec) = field_number(&record, "__MONOTONIC_TIMESTAMP") else {
+            continue;
+        };
+        if field_string(&record, "MESSAGE") == "Switching root." {
+            return EarlyBoot::new(units, Some(usec));
+        }
+        let name = field_string(&record, "UNIT");
+        let message_id = field_string(&record, "MESSAGE_ID");
+        if name.is_empty() || ![JOB_STARTING, JOB_DONE, JOB_FAILED].contains(&message_id.as_str()) {
+            continue;
+        }
+        let index = match units.iter().position(|unit| unit.name() == name) {
+            Some(index) => index,
+            None => {
+                units.push(EarlyBootUnit::new(name));
+                units.len() - 1
+            }
+        };
+        match message_id.as_str() {
+            JOB_STARTING => units[index].set_started(usec),
+            JOB_DONE => units[index].set_finished(usec, field_string(&record, "JOB_RESULT")),
+            _ => units[index].set_finished(usec, "failed".to_string()),
+        }
+    }
+    // Never switched root: everything ran from the real root.
+    EarlyBoot::default()
+}
+
+/// Whether `message` is an SELinux AVC or an AppArmor denial.
+pub fn is_denial(message: &str) -> bool {
+    (message.contains("avc:") && message.contains("denied"))
+        || message.contains("apparmor=\"DENIED\"")
+}
+
+/// A running `journalctl --follow` process.
+pub struct JournalFollower {
+    child: Child,
+}
+
+impl JournalFollower {
+    /// Starts following `unit`, only emitting entries newer than `after_cursor`.
+    pub fn spawn(
+        unit: &str,
+        after_cursor: Option<&str>,
+    ) -> std::io::Result<(Self, JournalEntries)> {
+        let mut command = Command::new("journalctl");
+        match_unit(&mut command, unit);
+        command
+            .arg("--output=json")
+            .arg("--follow")
+            .arg("--no-pager");
+        match after_cursor {
+            Some(cursor) => command
+                .arg(format!("--after-cursor={}", cursor))
+                .arg("--lines=all"),
+            None => command.arg("--lines=0"),
+        };
+
+        let mut child = Target::get()
+            .command(&command)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or("journalctl has no stdout")
+            .map_err(std::io::Error::other)?;
+
+        Ok((
+            Self { child },
+            JournalEntries {
+                lines: BufReader::new(stdout).lines(),
+            },
+        ))
+    }
+
+    pub fn stop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+impl Drop for JournalFollower {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Entries streamed by a [`JournalFollower`]; ends when the follower is stopped.
+pub struct JournalEntries {
+    lines: Lines<BufReader<ChildStdout>>,
+}
+
+impl Iterator for JournalEntries {
+    type Item = LogEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = self.lines.next()?.ok()?;
+            if let Some(entry) = parse_journal_entry(&line) {
+                return Some(entry);
+            }
+        }
+    }
+}
@@ -1 +1,6 @@
+pub mod journal;
+pub mod script_plugin;
+pub mod synthetic_service_adapter;
 pub mod systemd_service_adapter;
+pub mod target;
+pub mod tmpfiles;
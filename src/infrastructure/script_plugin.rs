@@ -0,0 +1,227 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::{Read, Write};
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+use crate::domain::plugin::{Extension, Plugin};
+use crate::infrastructure::target::Target;
+
+/// What a plugin prints when asked to `describe` itself.
+#[derive(Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Description {
+    actions: Vec<DescribedExtension>,
+    views: Vec<DescribedExtension>,
+    columns: Vec<DescribedExtension>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DescribedExtension {
+    id: String,
+    title: String,
+}
+
+impl From<DescribedExtension> for Extension {
+    fn from(described: DescribedExtension) -> Self {
+        Extension::new(described.id, described.title)
+    }
+}
+
+/// Plugin made of an executable of the plugins directory, in any language.
+///
+/// It is run as `PLUGIN describe` once at startup and prints in JSON what it
+/// adds, as `{"actions": [{"id": "...", "title": "..."}], "views": [...],
+/// "columns": [...]}`. It is then run as `PLUGIN action ID UNIT` and
+/// `PLUGIN view ID UNIT`, printing what to show, and as `PLUGIN column ID` with
+/// a unit name per line on its standard input, printing `UNIT<tab>VALUE` lines.
+/// A non-zero exit status is a failure, told with what it printed on stderr.
+pub struct ScriptPlugin {
+    path: PathBuf,
+    name: String,
+    actions: Vec<Extension>,
+    views: Vec<Extension>,
+    columns: Vec<Extension>,
+}
+
+impl ScriptPlugin {
+    /// Every executable of `dir`, by file name, along with why those that
+    /// could not describe themselves within `timeout` are left out.
+    pub fn load_all(dir: &Path, timeout: Duration) -> (Vec<ScriptPlugin>, Vec<String>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return (vec![], vec![]);
+        };
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.metadata().is_ok_and(|metadata| {
+                    metadata.is_file() && metadata.permissions().mode() & 0o111 != 0
+                })
+            })
+            .collect();
+        paths.sort();
+
+        let mut plugins = vec![];
+        let mut problems = vec![];
+        for path in paths {
+            match ScriptPlugin::load(path.clone(), timeout) {
+                Ok(plugin) => plugins.push(plugin),
+                Err(e) => {
+                    warn!(plugin = %path.display(), "left out: {}", e);
+                    problems.push(format!("Plugin {}: {}", path.display(), e));
+                }
+            }
+        }
+        (plugins, problems)
+    }
+
+    fn load(path: PathBuf, timeout: Duration) -> Result<ScriptPlugin, Box<dyn Error>> {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let output = run_within(Command::new(&path).arg("describe"), timeout)?;
+        let description: Description =
+            serde_json::from_str(&output).map_err(|e| format!("invalid description: {}", e))?;
+        Ok(ScriptPlugin {
+            path,
+            name,
+            actions: description.actions.into_iter().map(Into::into).collect(),
+            views: description.views.into_iter().map(Into::into).collect(),
+            columns: description.columns.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    /// The plugin run with `args`, told which manager is shown.
+    fn command(&self, args: &[&str]) -> Command {
+        let target = Target::get();
+        let mut command = Command::new(&self.path);
+        command.args(args).env(
+            "SYSTEMD_MANAGER_TUI_SCOPE",
+            if target.user { "user" } else { "system" },
+        );
+        if let Some(host) = &target.host {
+            command.env("SYSTEMD_MANAGER_TUI_HOST", host);
+        }
+        command
+    }
+}
+
+impl Plugin for ScriptPlugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn actions(&self) -> &[Extension] {
+        &self.actions
+    }
+
+    fn views(&self) -> &[Extension] {
+        &self.views
+    }
+
+    fn columns(&self) -> &[Extension] {
+        &self.columns
+    }
+
+    fn run_action(&self, id: &str, unit: &str) -> Result<String, Box<dyn Error>> {
+        run(&mut self.command(&["action", id, unit]))
+    }
+
+    fn get_view(&self, id: &str, unit: &str) -> Result<String, Box<dyn Error>> {
+        run(&mut self.command(&["view", id, unit]))
+    }
+
+    fn get_column(
+        &self,
+        id: &str,
+        units: &[String],
+    ) -> Result<HashMap<String, String>, Box<dyn Error>> {
+        let mut child = self
+            .command(&["column", id])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        // Written meanwhile, for a plugin answering each line as it reads it
+        // not to wait on a full pipe; one that stops reading early still gets
+        // its say below.
+        let input = units.join("\n");
+        if let Some(mut stdin) = child.stdin.take() {
+            thread::spawn(move || {
+                let _ = stdin.write_all(input.as_bytes());
+            });
+        }
+        let output = output_of(child.wait_with_output()?)?;
+        Ok(output
+            .lines()
+            .filter_map(|line| line.split_once('\t'))
+            .map(|(unit, value)| (unit.to_string(), value.trim().to_string()))
+            .collect())
+    }
+}
+
+/// Standard output of `command`, with nothing on its standard input.
+fn run(command: &mut Command) -> Result<String, Box<dyn Error>> {
+    output_of(command.stdin(Stdio::null()).output()?)
+}
+
+/// Like `run`, killing `command` once it runs past `timeout`, for a plugin
+/// that hangs not to hold up the start.
+fn run_within(command: &mut Command, timeout: Duration) -> Result<String, Box<dyn Error>> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    // Read meanwhile, for a plugin printing more than a pipe holds not to
+    // wait on a full one.
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(format!("no reply within {}s", timeout.as_secs()).into());
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+    output_of(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    })
+}
+
+fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut bytes = vec![];
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        bytes
+    })
+}
+
+fn output_of(output: Output) -> Result<String, Box<dyn Error>> {
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(if stderr.is_empty() {
+            format!("exited with {}", output.status).into()
+        } else {
+            stderr.into()
+        });
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
@@ -0,0 +1,1084 @@
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::time;
+
+use crate::domain::access_control::AccessControl;
+use crate::domain::boot::Boot;
+use crate::domain::boot_time::{BootTime, ChainLink};
+use crate::domain::early_boot::{EarlyBoot, EarlyBootUnit};
+use crate::domain::job_conflict::QueuedJob;
+use crate::domain::job_mode::JobMode;
+use crate::domain::job_result::JobResult;
+use crate::domain::kernel_limit::{KernelLimit, SysctlHint};
+use crate::domain::log_entry::{LogEntry, LogPriority};
+use crate::domain::log_query::LogQuery;
+use crate::domain::mount_property::MountProperty;
+use crate::domain::overview::Overview;
+use crate::domain::resource_usage::ResourceUsage;
+use crate::domain::security_report::{SecurityDirective, SecurityReport};
+use crate::domain::service::Service;
+use crate::domain::service_property::ServiceProperty;
+use crate::domain::service_repository::ServiceRepository;
+use crate::domain::service_state::ServiceState;
+use crate::domain::socket_property::SocketProperty;
+use crate::domain::stop_impact::{ImpactedUnit, Propagation};
+use crate::domain::timer_property::TimerProperty;
+use crate::domain::tmpfiles::{Tmpfiles, TmpfilesEntry};
+use crate::domain::triage::{FailedCheck, TriageFacts};
+use crate::domain::unit_property::UnitProperty;
+use crate::domain::unit_type::UnitType;
+
+/// Journal entries generated per unit, roughly 2 MB of messages.
+const ENTRIES_PER_UNIT: usize = 20_000;
+const DEMO_ENTRIES_PER_UNIT: usize = 1_000;
+const BOOTS: i32 = 3;
+
+/// Units of a typical initial ramdisk, in start order.
+const INITRD_UNITS: [&str; 8] = [
+    "systemd-journald.service",
+    "systemd-udevd.service",
+    "systemd-udev-trigger.service",
+    "initrd-root-device.target",
+    "systemd-fsck-root.service",
+    "sysroot.mount",
+    "initrd-parse-etc.service",
+    "initrd-switch-root.service",
+];
+
+/// Units of a small server with their description and active state, in
+/// batches laid out as the numbered units are: a socket, a timer, a mount, a
+/// template and two of its instances, the services of the socket and of the
+/// timer between two other services.
+const DEMO_UNITS: [(&str, &str, &str); 30] = [
+    ("docker.socket", "Docker Socket for the API", "active"),
+    ("logrotate.timer", "Daily rotation of log files", "active"),
+    ("mnt-data.mount", "/mnt/data", "active"),
+    ("getty@.service", "Getty on %I", "inactive"),
+    ("getty@tty1.service", "Getty on tty1", "active"),
+    ("getty@tty2.service", "Getty on tty2", "inactive"),
+    (
+        "docker.service",
+        "Docker Application Container Engine",
+        "active",
+    ),
+    (
+        "nginx.service",
+        "A high performance web server and a reverse proxy server",
+        "active",
+    ),
+    ("logrotate.service", "Rotate log files", "inactive"),
+    ("postgresql.service", "PostgreSQL RDBMS", "active"),
+    ("cups.socket", "CUPS Scheduler", "active"),
+    (
+        "fstrim.timer",
+        "Discard unused blocks once a week",
+        "active",
+    ),
+    ("mnt-backup.mount", "/mnt/backup", "failed"),
+    ("openvpn@.service", "OpenVPN connection to %i", "inactive"),
+    (
+        "openvpn@office.service",
+        "OpenVPN connection to office",
+        "active",
+    ),
+    (
+        "openvpn@home.service",
+        "OpenVPN connection to home",
+        "inactive",
+    ),
+    ("cups.service", "CUPS Scheduler", "inactive"),
+    ("ssh.service", "OpenBSD Secure Shell server", "active"),
+    (
+        "fstrim.service",
+        "Discard unused blocks on filesystems from /etc/fstab",
+        "inactive",
+    ),
+    ("redis-server.service", "Advanced key-value store", "failed"),
+    (
+        "avahi-daemon.socket",
+        "Avahi mDNS/DNS-SD Stack Activation Socket",
+        "active",
+    ),
+    ("apt-daily.timer", "Daily apt download activities", "active"),
+    ("var-lib-containers.mount", "/var/lib/containers", "active"),
+    ("user@.service", "User Manager for UID %i", "inactive"),
+    ("user@1000.service", "User Manager for UID 1000", "active"),
+    ("user@1001.service", "User Manager for UID 1001", "inactive"),
+    ("avahi-daemon.service", "Avahi mDNS/DNS-SD Stack", "active"),
+    (
+        "cron.service",
+        "Regular background program processing daemon",
+        "active",
+    ),
+    (
+        "apt-daily.service",
+        "Daily apt download activities",
+        "inactive",
+    ),
+    ("NetworkManager.service", "Network Manager", "active"),
+];
+
+const WORDS: [&str; 16] = [
+    "connection",
+    "request",
+    "worker",
+    "timeout",
+    "reloading",
+    "configuration",
+    "accepted",
+    "failed",
+    "cache",
+    "listening",
+    "socket",
+    "upstream",
+    "retrying",
+    "client",
+    "session",
+    "finished",
+];
+
+/// Small deterministic generator so the same unit always gets the same data.
+struct XorShift(u64);
+
+impl XorShift {
+    fn seeded(seed: &str) -> Self {
+        let hash = seed.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        Self(hash.max(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+}
+
+fn now_usec() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|now| now.as_micros() as u64)
+        .unwrap_or_default()
+}
+
+/// Sub state of a unit of the type of `name` once it is active.
+fn active_sub(name: &str) -> &'static str {
+    match UnitType::from_name(name) {
+        UnitType::Socket => "listening",
+        UnitType::Timer => "waiting",
+        UnitType::Mount => "mounted",
+        _ => "running",
+    }
+}
+
+/// A line a unit of the demo could have logged, as bad as `priority`.
+fn demo_message(priority: LogPriority, rng: &mut XorShift) -> String {
+    let number = rng.below(1000);
+    let message = match priority {
+        LogPriority::Error => [
+            "connect() to 10.0.0.12:5432 failed (111: Connection refused)",
+            "worker process exited on signal 9",
+            "could not open file: Permission denied",
+        ][rng.below(3) as usize],
+        LogPriority::Warning => [
+            "upstream response is buffered to a temporary file",
+            "slow request, took more than 500 ms",
+            "configuration uses a deprecated option",
+        ][rng.below(3) as usize],
+        LogPriority::Notice => [
+            "reloading configuration",
+            "signal process started",
+            "received SIGHUP, reopening logs",
+        ][rng.below(3) as usize],
+        _ => [
+            "accepted connection",
+            "request completed",
+            "session opened",
+            "session closed",
+            "checkpoint complete",
+        ][rng.below(5) as usize],
+    };
+    format!("{} (#{})", message, number)
+}
+
+fn unit_name(index: usize) -> String {
+    match index % 10 {
+        0 => format!("synthetic-{:05}.socket", index),
+        1 => format!("synthetic-{:05}.timer", index),
+        2 => format!("mnt-synthetic\\x2d{:05}.mount", index),
+        // A template followed by a few of its instances.
+        3 => format!("synthetic-{:05}@.service", index),
+        4..=5 => format!(
+            "synthetic-{:05}@{}.service",
+            index - index % 10 + 3,
+            index % 10
+        ),
+        // Services started by the socket and the timer of their batch, sharing their name.
+        6 => format!("synthetic-{:05}.service", index - 6),
+        8 => format!("synthetic-{:05}.service", index - 7),
+        _ => format!("synthetic-{:05}.service", index),
+    }
+}
+
+fn cursor(name: &str, index: usize) -> String {
+    format!("synthetic;{};{}", name, index)
+}
+
+fn cursor_index(name: &str, cursor: &str) -> Option<usize> {
+    cursor
+        .strip_prefix(&format!("synthetic;{};", name))?
+        .parse()
+        .ok()
+}
+
+/// Units made up in place of those of systemd.
+#[derive(Clone, Copy)]
+pub enum Dataset {
+    /// This many numbered units with megabytes of logs each, for `--stress`.
+    Stress(usize),
+    /// The units of a small server with short logs, for `--demo`.
+    Demo,
+}
+
+/// Backend that makes up units and their logs instead of talking to systemd:
+/// thousands of them so scrolling, filtering and sorting can be exercised with
+/// `--stress`, or a few that look real so the interface can be shown and
+/// tried with `--demo`, on any machine.
+pub struct SyntheticServiceAdapter {
+    dataset: Dataset,
+    units: usize,
+    /// Ids of the made-up jobs cancelled so far.
+    cancelled_jobs: Mutex<HashSet<u32>>,
+    /// Id of the next job queued by a start, stop or restart.
+    next_job: AtomicU32,
+    /// Whether each job queued starts its unit rather than stops it.
+    jobs: Mutex<HashMap<String, bool>>,
+    /// Active and sub states the jobs that finished left their unit in.
+    states: Mutex<HashMap<String, (&'static str, &'static str)>>,
+    /// Unit file states changed by enabling or disabling.
+    enablements: Mutex<HashMap<String, &'static str>>,
+}
+
+impl SyntheticServiceAdapter {
+    pub fn new(dataset: Dataset) -> Self {
+        let units = match dataset {
+            Dataset::Stress(units) => units,
+            Dataset::Demo => DEMO_UNITS.len(),
+        };
+        Self {
+            dataset,
+            units,
+            cancelled_jobs: Mutex::new(HashSet::new()),
+            // Above the ids of the stop jobs queued on the mounts.
+            next_job: AtomicU32::new(1 << 24),
+            jobs: Mutex::new(HashMap::new()),
+            states: Mutex::new(HashMap::new()),
+            enablements: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn unit_name(&self, index: usize) -> String {
+        match self.dataset {
+            Dataset::Stress(_) => unit_name(index),
+            Dataset::Demo => DEMO_UNITS[index].0.to_string(),
+        }
+    }
+
+    fn entries_per_unit(&self) -> usize {
+        match self.dataset {
+            Dataset::Stress(_) => ENTRIES_PER_UNIT,
+            Dataset::Demo => DEMO_ENTRIES_PER_UNIT,
+        }
+    }
+
+    /// When the first entry of every unit was logged, one second apart.
+    fn started_usec(&self) -> u64 {
+        now_usec() - self.entries_per_unit() as u64 * 1_000_000
+    }
+
+    /// Object path of a new made-up job, which `starts` its unit or stops it.
+    fn queue_job(&self, starts: bool) -> String {
+        let job = format!(
+            "/org/freedesktop/systemd1/job/{}",
+            self.next_job.fetch_add(1, Ordering::Relaxed)
+        );
+        self.jobs.lock().unwrap().insert(job.clone(), starts);
+        job
+    }
+
+    /// A stop job queued on the mount of every batch that has one, until
+    /// cancelled, so that starting the last service of the batch conflicts.
+    fn queued_jobs(&self) -> Vec<QueuedJob> {
+        let cancelled = self.cancelled_jobs.lock().unwrap();
+        (2..self.units)
+            .step_by(10)
+            .map(|index| index as u32 + 1)
+            .filter(|id| !cancelled.contains(id))
+            .map(|id| {
+                QueuedJob::new(
+                    id,
+                    self.unit_name(id as usize - 1),
+                    "stop".to_string(),
+                    "waiting".to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn entry(&self, name: &str, index: usize, started_usec: u64) -> LogEntry {
+        let mut rng = XorShift::seeded(&cursor(name, index));
+        let priority = match rng.below(100) {
+            0..=1 => LogPriority::Error,
+            2..=7 => LogPriority::Warning,
+            8..=19 => LogPriority::Notice,
+            20..=79 => LogPriority::Info,
+            _ => LogPriority::Debug,
+        };
+        let words: Vec<&str> = (0..8 + rng.below(12))
+            .map(|_| WORDS[rng.below(WORDS.len() as u64) as usize])
+            .collect();
+
+        let message = match self.dataset {
+            Dataset::Stress(_) => format!("#{} {}", index, words.join(" ")),
+            Dataset::Demo => demo_message(priority, &mut rng),
+        };
+
+        LogEntry::new(
+            cursor(name, index),
+            started_usec + index as u64 * 1_000_000,
+            Some(priority),
+            message,
+            Some(1000 + rng.below(30_000) as u32),
+            Some(if rng.below(4) == 0 { 0 } else { 1000 }),
+            "synthetic".to_string(),
+            name.split(['@', '.']).next().unwrap_or(name).to_string(),
+        )
+    }
+}
+
+#[async_trait]
+impl ServiceRepository for SyntheticServiceAdapter {
+    async fn list_services(&self) -> Result<Vec<Service>, Box<dyn std::error::Error>> {
+        let states = self.states.lock().unwrap();
+        let enablements = self.enablements.lock().unwrap();
+        Ok((0..self.units)
+            .map(|index| {
+                let name = self.unit_name(index);
+                let mut rng = XorShift::seeded(&name);
+                let (active, sub) = match rng.below(20) {
+                    0 => ("failed", "failed"),
+                    1 => ("activating", "start"),
+                    2..=7 => ("inactive", "dead"),
+                    _ => ("active", "running"),
+                };
+                let file = if rng.below(3) == 0 {
+                    "disabled"
+                } else {
+                    "enabled"
+                };
+                let description = format!(
+                    "Synthetic {} {}",
+                    WORDS[rng.below(WORDS.len() as u64) as usize],
+                    WORDS[rng.below(WORDS.len() as u64) as usize]
+                );
+                let (active, sub, file, description) = match self.dataset {
+                    Dataset::Stress(_) => (active, sub, file, description),
+                    Dataset::Demo => {
+                        let (_, description, active) = DEMO_UNITS[index];
+                        let sub = match active {
+                            "active" => active_sub(&name),
+                            "failed" => "failed",
+                            _ => "dead",
+                        };
+                        (active, sub, "enabled", description.to_string())
+                    }
+                };
+                let (active, sub) = states.get(&name).copied().unwrap_or((active, sub));
+                let file = enablements.get(&name).copied().unwrap_or(file);
+                let state = if name.contains("@.") {
+                    ServiceState::new(
+                        "template".to_string(),
+                        "inactive".to_string(),
+                        "dead".to_string(),
+                        file.to_string(),
+                    )
+                } else {
+                    // Disabled services that never ran are not loaded by systemd.
+                    let load = if active == "inactive" && file == "disabled" && index % 10 == 7 {
+                        "not-loaded"
+                    } else {
+                        "loaded"
+                    };
+                    ServiceState::new(
+                        load.to_string(),
+                        active.to_string(),
+                        sub.to_string(),
+                        file.to_string(),
+                    )
+                };
+                let service = Service::new(name, description, state);
+                let file = service
+                    .template_name()
+                    .unwrap_or_else(|| service.name().to_string());
+                service.with_file_path(format!("/usr/lib/systemd/system/{}", file))
+            })
+            .collect())
+    }
+
+    async fn get_service_log(
+        &self,
+        name: &str,
+        query: &LogQuery,
+    ) -> Result<Vec<LogEntry>, Box<dyn std::error::Error>> {
+        let started_usec = self.started_usec();
+        let entries = self.entries_per_unit();
+        let first = match query.after_cursor() {
+            Some(cursor) => cursor_index(name, cursor).map_or(0, |index| index + 1),
+            None => 0,
+        };
+        let first = match query.lines() {
+            Some(lines) => first.max(entries.saturating_sub(lines)),
+            None => first,
+        };
+        Ok((first..entries)
+            .map(|index| self.entry(name, index, started_usec))
+            .collect())
+    }
+
+    /// The last ten minutes of every unit, whatever the time range asked.
+    async fn get_journal(
+        &self,
+        _query: &LogQuery,
+    ) -> Result<Vec<(String, LogEntry)>, Box<dyn std::error::Error>> {
+        const RECENT_ENTRIES: usize = 600;
+        let started_usec = self.started_usec();
+        let last = self.entries_per_unit();
+        let mut entries: Vec<(String, LogEntry)> = self
+            .list_services()
+            .await?
+            .iter()
+            .flat_map(|service| {
+                (last - RECENT_ENTRIES..last).map(|index| {
+                    (
+                        service.name().to_string(),
+                        self.entry(service.name(), index, started_usec),
+                    )
+                })
+            })
+            .collect();
+        entries.sort_by_key(|(_, entry)| entry.realtime_usec());
+        Ok(entries)
+    }
+
+    async fn get_resource_usage(
+        &self,
+    ) -> Result<HashMap<String, ResourceUsage>, Box<dyn std::error::Error>> {
+        Ok(self
+            .list_services()
+            .await?
+            .into_iter()
+            .filter(|service| {
+                service.name().ends_with(".service") && service.state().active() == "active"
+            })
+            .map(|service| {
+                let mut rng = XorShift::seeded(service.name());
+                let memory_max = match rng.below(3) {
+                    0 => u64::MAX,
+                    _ => (64 + rng.below(960)) << 20,
+                };
+                let tasks_max = 100 + rng.below(4000);
+                let usage = ResourceUsage::new(
+                    rng.below(memory_max.min(1 << 30)),
+                    memory_max,
+                    1 + rng.below(tasks_max),
+                    tasks_max,
+                    rng.below(3_600_000_000_000),
+                );
+                (service.name().to_string(), usage)
+            })
+            .collect())
+    }
+
+    async fn get_slices(&self) -> Result<HashMap<String, String>, Box<dyn std::error::Error>> {
+        const SLICES: [&str; 4] = ["system.slice", "system.slice", "app.slice", "machine.slice"];
+        Ok((0..self.units)
+            .map(|index| self.unit_name(index))
+            .filter(|name| !name.ends_with(".timer") && !name.contains("@."))
+            .map(|name| {
+                let slice = SLICES[XorShift::seeded(&name).below(SLICES.len() as u64) as usize];
+                (name, slice.to_string())
+            })
+            .collect())
+    }
+
+    async fn get_units_needing_reload(&self) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        Ok((0..self.units)
+            .map(|index| self.unit_name(index))
+            .filter(|name| !name.contains("@.") && XorShift::seeded(name).below(50) == 0)
+            .collect())
+    }
+
+    async fn get_system_state(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let degraded = self
+            .list_services()
+            .await?
+            .iter()
+            .any(|service| service.state().active() == "failed");
+        Ok(if degraded { "degraded" } else { "running" }.to_string())
+    }
+
+    async fn get_overview(&self) -> Result<Overview, Box<dyn std::error::Error>> {
+        // Up since the first entries were logged, failed some time after.
+        let started_usec = self.started_usec();
+        let failed = self
+            .list_services()
+            .await?
+            .iter()
+            .filter(|service| service.state().active() == "failed")
+            .map(|service| {
+                let mut rng = XorShift::seeded(service.name());
+                let offset = rng.below(now_usec() - started_usec);
+                (service.name().to_string(), started_usec + offset)
+            })
+            .collect();
+        Ok(Overview::new(
+            self.get_system_state().await?,
+            Some(Duration::from_micros(now_usec() - started_usec)),
+            Some([0.42, 0.37, 0.31]),
+            failed,
+        ))
+    }
+
+    async fn get_activators(
+        &self,
+    ) -> Result<HashMap<String, Vec<String>>, Box<dyn std::error::Error>> {
+        // Every socket and timer activates one of the plain services of its batch.
+        Ok((0..self.units)
+            .filter(|index| index % 10 <= 1)
+            .map(|index| (index, index + 6 + index % 10))
+            .filter(|(_, activated)| *activated < self.units)
+            .map(|(index, activated)| (self.unit_name(activated), vec![self.unit_name(index)]))
+            .collect())
+    }
+
+    async fn list_boots(&self) -> Result<Vec<Boot>, Box<dyn std::error::Error>> {
+        let day = 86_400_000_000;
+        let now = now_usec();
+        Ok((0..BOOTS)
+            .map(|boot| {
+                let last_entry = now - boot as u64 * day;
+                Boot::new(
+                    -boot,
+                    format!("{:032x}", XorShift::seeded(&boot.to_string()).next()),
+                    last_entry - day / 2,
+                    last_entry,
+                )
+            })
+            .collect())
+    }
+
+    async fn get_access_control(
+        &self,
+        name: &str,
+    ) -> Result<AccessControl, Box<dyn std::error::Error>> {
+        let mut rng = XorShift::seeded(name);
+        let stem = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+        let selinux_type = format!("{}_t", stem.replace(['-', '@'], "_"));
+        let pid = 1000 + rng.below(60_000);
+        let denials = (0..rng.below(4))
+            .map(|index| {
+                LogEntry::new(
+                    format!("denial-{}-{}", name, index),
+                    now_usec() - (index + 1) * 600_000_000,
+                    Some(LogPriority::Warning),
+                    format!(
+                        "avc:  denied  {{ write }} for  pid={} comm=\"{}\" name=\"{}\" scontext=system_u:system_r:{}:s0 tcontext=system_u:object_r:var_log_t:s0 tclass=file permissive=0",
+                        pid,
+                        stem.chars().take(15).collect::<String>(),
+                        WORDS[rng.below(WORDS.len() as u64) as usize],
+                        selinux_type
+                    ),
+                    None,
+                    None,
+                    "synthetic".to_string(),
+                    "audit".to_string(),
+                )
+            })
+            .rev()
+            .collect();
+        Ok(AccessControl::new(
+            format!("system_u:system_r:{}:s0", selinux_type),
+            String::new(),
+            Some(format!("system_u:system_r:{}:s0", selinux_type)),
+            denials,
+        ))
+    }
+
+    async fn get_triage_facts(
+        &self,
+        name: &str,
+    ) -> Result<TriageFacts, Box<dyn std::error::Error>> {
+        let mut rng = XorShift::seeded(name);
+        let mut facts = TriageFacts {
+            result: ["success", "exit-code", "timeout", "signal"][rng.below(4) as usize]
+                .to_string(),
+            exec_main_code: 1,
+            exec_main_status: [1, 203, 217][rng.below(3) as usize],
+            ..TriageFacts::default()
+        };
+        if facts.result == "signal" {
+            facts.exec_main_code = 2;
+            facts.exec_main_status = 9;
+        }
+        if rng.below(3) == 0 {
+            facts.failed_checks.push(FailedCheck::new(
+                "ConditionPathExists".to_string(),
+                false,
+                format!(
+                    "/etc/{}.conf",
+                    name.split(['@', '.']).next().unwrap_or(name)
+                ),
+            ));
+        }
+        if rng.below(3) == 0 {
+            facts
+                .failed_dependencies
+                .push(("network-online.target".to_string(), "failed".to_string()));
+        }
+        if rng.below(2) == 0 {
+            facts.recent_edits.push((
+                format!("/etc/systemd/system/{}.d/override.conf", name),
+                rng.below(600),
+            ));
+        }
+        facts.needs_daemon_reload = rng.below(4) == 0;
+        if rng.below(3) == 0 {
+            let warning = [
+                "The TCP backlog setting of 511 cannot be enforced because /proc/sys/net/core/somaxconn is set to the lower value of 128.",
+                "Failed to add a watch: inotify watch limit reached",
+            ][rng.below(2) as usize];
+            let current = if warning.contains("somaxconn") {
+                "128"
+            } else {
+                "8192"
+            };
+            facts.sysctl_hints = KernelLimit::reported_in([warning])
+                .into_iter()
+                .map(|(limit, evidence)| {
+                    SysctlHint::new(limit, Some(current.to_string()), evidence)
+                })
+                .collect();
+        }
+        let started_usec = self.started_usec();
+        facts.error_lines = (0..self.entries_per_unit())
+            .rev()
+            .map(|index| self.entry(name, index, started_usec))
+            .filter(|entry| matches!(entry.priority(), Some(priority) if priority <= LogPriority::Error))
+            .take(10)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .collect();
+        Ok(facts)
+    }
+
+    async fn get_tmpfiles(&self, name: &str) -> Result<Tmpfiles, Box<dyn std::error::Error>> {
+        let mut rng = XorShift::seeded(name);
+        let stem = name.split(['@', '.']).next().unwrap_or(name);
+        let runtime_directory = format!("/run/{}", stem);
+        let mut tmpfiles = Tmpfiles {
+            directories: vec![
+                (runtime_directory.clone(), rng.below(3) != 0),
+                (format!("/var/lib/{}", stem), true),
+            ],
+            entries: vec![],
+        };
+        if rng.below(2) == 0 {
+            tmpfiles.entries.push(TmpfilesEntry::new(
+                format!("/usr/lib/tmpfiles.d/{}.conf", stem),
+                "d".to_string(),
+                runtime_directory,
+                "0755 root root - -".to_string(),
+            ));
+        }
+        Ok(tmpfiles)
+    }
+
+    async fn run_tmpfiles(
+        &self,
+        paths: &[String],
+        dry_run: bool,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let verb = if dry_run { "Would create" } else { "Created" };
+        Ok(paths
+            .iter()
+            .map(|path| format!("{} directory \"{}\".\n", verb, path))
+            .collect())
+    }
+
+    async fn get_unit_files(
+        &self,
+        name: &str,
+    ) -> Result<Vec<(String, String)>, Box<dyn std::error::Error>> {
+        let mut rng = XorShift::seeded(name);
+        let stem = name.split(['@', '.']).next().unwrap_or(name);
+        let mut files = vec![(
+            format!("/usr/lib/systemd/system/{}", name),
+            format!(
+                "[Unit]\nDescription=Synthetic unit {}\n\n[Service]\nExecStart=/usr/bin/{}\n\n[Install]\nWantedBy=multi-user.target\n",
+                name, stem
+            ),
+        )];
+        if rng.below(2) == 0 {
+            files.push((
+                format!("/etc/systemd/system/{}.d/override.conf", name),
+                format!(
+                    "[Service]\nEnvironment=LOG_LEVEL=debug {}_API_TOKEN={:08x}\n",
+                    stem.to_uppercase().replace('-', "_"),
+                    rng.below(u32::MAX as u64)
+                ),
+            ));
+        }
+        Ok(files)
+    }
+
+    async fn get_early_boot(&self) -> Result<EarlyBoot, Box<dyn std::error::Error>> {
+        let mut usec = 300_000;
+        let units = INITRD_UNITS
+            .iter()
+            .map(|name| {
+                let mut rng = XorShift::seeded(name);
+                let mut unit = EarlyBootUnit::new(name.to_string());
+                unit.set_started(usec);
+                usec += 20_000 + rng.below(400_000);
+                let result = match rng.below(10) {
+                    0 => "failed",
+                    _ => "done",
+                };
+                unit.set_finished(usec, result.to_string());
+                unit
+            })
+            .collect();
+        Ok(EarlyBoot::new(units, Some(usec)))
+    }
+
+    async fn get_boot_times(&self) -> Result<Vec<BootTime>, Box<dyn std::error::Error>> {
+        // A few slow units, some taking a second or so, most a few milliseconds.
+        let mut times: Vec<BootTime> = self
+            .list_services()
+            .await?
+            .iter()
+            .filter(|service| service.state().active() != "inactive")
+            .map(|service| {
+                let mut rng = XorShift::seeded(&format!("{} boot", service.name()));
+                let millis = match rng.below(10) {
+                    0 => 2000 + rng.below(15_000),
+                    1..=3 => 200 + rng.below(1800),
+                    _ => 2 + rng.below(200),
+                };
+                BootTime::new(service.name().to_string(), Duration::from_millis(millis))
+            })
+            .collect();
+        times.sort_by_key(|time| std::cmp::Reverse(time.took()));
+        Ok(times)
+    }
+
+    async fn get_critical_chain(&self) -> Result<Vec<ChainLink>, Box<dyn std::error::Error>> {
+        // The slowest services, waited for between the usual targets, from
+        // the bottom of the chain up.
+        let mut slowest = self
+            .get_boot_times()
+            .await?
+            .into_iter()
+            .filter(|time| UnitType::from_name(time.name()) == UnitType::Service);
+        let mut links: Vec<(String, Option<Duration>)> = vec![("sysinit.target".to_string(), None)];
+        for target in ["basic.target", "network-online.target", "multi-user.target"] {
+            if let Some(time) = slowest.next() {
+                links.push((time.name().to_string(), Some(time.took())));
+            }
+            links.push((target.to_string(), None));
+        }
+
+        // Each one active once it took its time after the one below.
+        let mut at = Duration::from_millis(800);
+        let stamped: Vec<(String, Duration, Option<Duration>)> = links
+            .into_iter()
+            .map(|(name, took)| {
+                at += took.unwrap_or_default() + Duration::from_millis(1);
+                (name, at, took)
+            })
+            .collect();
+        Ok(stamped
+            .into_iter()
+            .rev()
+            .enumerate()
+            .map(|(depth, (name, at, took))| ChainLink::new(name, depth, Some(at), took))
+            .collect())
+    }
+
+    async fn get_unit_property(
+        &self,
+        name: &str,
+    ) -> Result<UnitProperty, Box<dyn std::error::Error>> {
+        let mut rng = XorShift::seeded(name);
+        let property = match UnitType::from_name(name) {
+            UnitType::Service => UnitProperty::Service(Box::new(ServiceProperty::new(
+                vec![(
+                    format!("/usr/bin/{}", name.split(['@', '.']).next().unwrap_or(name)),
+                    vec!["--foreground".to_string()],
+                    false,
+                    0,
+                    0,
+                    0,
+                    0,
+                    0,
+                    0,
+                    0,
+                )],
+                vec![],
+                vec![],
+                vec![],
+                vec![],
+                rng.below(30_000) as u32,
+                now_usec() - rng.below(86_400_000_000),
+                0,
+                0,
+                0,
+                rng.below(30_000) as u32,
+                0,
+                "on-failure".to_string(),
+                100_000,
+                String::new(),
+                "success".to_string(),
+                "synthetic".to_string(),
+                "synthetic".to_string(),
+                u64::MAX,
+                1024,
+                rng.below(8192),
+                8_388_608,
+                u64::MAX,
+                u64::MAX,
+                vec![
+                    "LANG=C.UTF-8".to_string(),
+                    format!("API_TOKEN={:016x}", rng.next()),
+                    "DATABASE_PASSWORD=synthetic".to_string(),
+                ],
+            ))),
+            UnitType::Socket => UnitProperty::Socket(SocketProperty::new(
+                vec![(
+                    "Stream".to_string(),
+                    format!("/run/{}", name.trim_end_matches(".socket")),
+                )],
+                false,
+                rng.below(10) as u32,
+                rng.below(10_000) as u32,
+                0,
+                "success".to_string(),
+            )),
+            UnitType::Timer => UnitProperty::Timer(TimerProperty::new(
+                name.replace(".timer", ".service"),
+                vec![(
+                    "OnCalendar".to_string(),
+                    "*-*-* 00:00:00".to_string(),
+                    now_usec() + rng.below(86_400_000_000),
+                )],
+                vec![],
+                now_usec() + rng.below(86_400_000_000),
+                now_usec() - rng.below(86_400_000_000),
+                true,
+                60_000_000,
+                0,
+                "success".to_string(),
+            )),
+            UnitType::Mount => UnitProperty::Mount(MountProperty::new(
+                format!("/dev/synthetic{}", rng.below(100)),
+                format!("/{}", name.trim_end_matches(".mount").replace('-', "/")),
+                "ext4".to_string(),
+                "rw,relatime".to_string(),
+                90_000_000,
+                "success".to_string(),
+            )),
+            UnitType::Other => return Err("Properties are not available for this unit type".into()),
+        };
+        Ok(property)
+    }
+
+    async fn get_security_report(
+        &self,
+        name: &str,
+    ) -> Result<SecurityReport, Box<dyn std::error::Error>> {
+        let mut rng = XorShift::seeded(name);
+        let directives = [
+            "PrivateNetwork=",
+            "ProtectSystem=",
+            "ProtectHome=",
+            "NoNewPrivileges=",
+            "PrivateTmp=",
+            "RestrictNamespaces=",
+        ]
+        .iter()
+        .map(|directive| {
+            let passed = rng.below(2) == 0;
+            SecurityDirective::new(
+                Some(passed),
+                directive.to_string(),
+                "Synthetic directive".to_string(),
+                (!passed).then_some(rng.below(5) as f32 / 10.0),
+            )
+        })
+        .collect();
+        let exposure = rng.below(100) as f32 / 10.0;
+        let rating = match exposure {
+            e if e < 2.0 => "SAFE",
+            e if e < 4.0 => "OK",
+            e if e < 7.0 => "MEDIUM",
+            e if e < 9.0 => "EXPOSED",
+            _ => "UNSAFE",
+        };
+        Ok(SecurityReport::new(
+            directives,
+            exposure,
+            rating.to_string(),
+        ))
+    }
+
+    async fn start_service(
+        &self,
+        name: &str,
+        _mode: JobMode,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let Some(index) = (0..self.units).find(|index| self.unit_name(*index) == name) else {
+            return Ok(self.queue_job(true));
+        };
+        if index % 10 != 9 {
+            return Ok(self.queue_job(true));
+        }
+        let mount = self.unit_name(index - 7);
+        if self.queued_jobs().iter().any(|job| job.unit() == mount) {
+            return Err(format!(
+                "org.freedesktop.systemd1.TransactionIsDestructive: Transaction for {}/start is \
+                 destructive ({} has 'stop' job queued, but 'start' is included in transaction).",
+                name, mount
+            )
+            .into());
+        }
+        Ok(self.queue_job(true))
+    }
+
+    async fn get_stop_impact(
+        &self,
+        name: &str,
+    ) -> Result<Vec<ImpactedUnit>, Box<dyn std::error::Error>> {
+        let Some(index) = (0..self.units).find(|index| self.unit_name(*index) == name) else {
+            return Ok(vec![]);
+        };
+        // The service of a socket requires it, and the instances of a batch
+        // are bound to its mount.
+        let dependents = match index % 10 {
+            0 => vec![(index + 6, Propagation::Requires)],
+            2 => vec![
+                (index + 2, Propagation::BindsTo),
+                (index + 3, Propagation::BindsTo),
+            ],
+            _ => vec![],
+        };
+        Ok(dependents
+            .into_iter()
+            .filter(|(dependent, _)| *dependent < self.units)
+            .map(|(dependent, propagation)| {
+                ImpactedUnit::new(self.unit_name(dependent), propagation, name.to_string())
+            })
+            .collect())
+    }
+
+    async fn stop_service(
+        &self,
+        _name: &str,
+        _mode: JobMode,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(self.queue_job(false))
+    }
+
+    async fn restart_service(&self, _name: &str) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(self.queue_job(true))
+    }
+
+    async fn wait_for_job(
+        &self,
+        name: &str,
+        job: &str,
+    ) -> Result<JobResult, Box<dyn std::error::Error>> {
+        // Jobs take up to a few seconds, and a unit fails the same way every time.
+        time::sleep(Duration::from_millis(
+            500 + XorShift::seeded(job).below(2500),
+        ))
+        .await;
+        let starts = self.jobs.lock().unwrap().remove(job).unwrap_or(true);
+        let result = match self.dataset {
+            Dataset::Stress(_) => match XorShift::seeded(name).below(8) {
+                0 => JobResult::Failed,
+                1 => JobResult::Dependency,
+                2 => JobResult::Timeout,
+                _ => JobResult::Done,
+            },
+            // The units that failed fail again when started.
+            Dataset::Demo
+                if starts
+                    && DEMO_UNITS
+                        .iter()
+                        .any(|(unit, _, active)| *unit == name && *active == "failed") =>
+            {
+                JobResult::Failed
+            }
+            Dataset::Demo => JobResult::Done,
+        };
+        let state = match (&result, starts) {
+            (JobResult::Done, true) => Some(("active", active_sub(name))),
+            (JobResult::Done, false) => Some(("inactive", "dead")),
+            (JobResult::Failed | JobResult::Timeout, true) => Some(("failed", "failed")),
+            _ => None,
+        };
+        if let Some(state) = state {
+            self.states.lock().unwrap().insert(name.to_string(), state);
+        }
+        Ok(result)
+    }
+
+    async fn list_jobs(&self) -> Result<Vec<QueuedJob>, Box<dyn std::error::Error>> {
+        Ok(self.queued_jobs())
+    }
+
+    async fn cancel_job(&self, id: u32) -> Result<(), Box<dyn std::error::Error>> {
+        self.cancelled_jobs.lock().unwrap().insert(id);
+        Ok(())
+    }
+
+    async fn enable_service(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        self.enablements
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), "enabled");
+        Ok(())
+    }
+
+    async fn disable_service(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        self.enablements
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), "disabled");
+        Ok(())
+    }
+
+    async fn reload_daemon(&self) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+}
@@ -1,10 +1,115 @@
-use zbus::blocking::{Connection, Proxy};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use async_trait::async_trait;
+use futures_lite::StreamExt;
+use futures_lite::future::block_on;
+use tokio::task;
 use zbus::zvariant::OwnedObjectPath;
+use zbus::{Connection, MessageStream, Proxy};
 
+use super::journal::{
+    JournalEntries, JournalFollower, is_denial, match_unit, parse_boots, parse_early_boot,
+    parse_journal_entry, parse_unit_journal_entry,
+};
+use super::target::Target;
+use super::tmpfiles::parse_cat_config;
+
+use crate::domain::access_control::AccessControl;
+use crate::domain::boot::Boot;
+use crate::domain::boot_time::{BootTime, ChainLink};
+use crate::domain::early_boot::EarlyBoot;
+use crate::domain::job_conflict::QueuedJob;
+use crate::domain::job_mode::JobMode;
+use crate::domain::job_result::JobResult;
+use crate::domain::kernel_limit::{KernelLimit, SysctlHint};
+use crate::domain::log_entry::LogEntry;
+use crate::domain::log_query::LogQuery;
+use crate::domain::mount_property::MountProperty;
+use crate::domain::overview::{Overview, parse_loadavg, parse_uptime};
+use crate::domain::resource_usage::ResourceUsage;
+use crate::domain::security_report::{SecurityDirective, SecurityReport};
 use crate::domain::service::Service;
-use crate::domain::service_property::{ServiceProperty, SASBTTUII};
+use crate::domain::service_property::{SASBTTUII, ServiceProperty};
 use crate::domain::service_repository::ServiceRepository;
 use crate::domain::service_state::ServiceState;
+use crate::domain::socket_property::{SS, SocketProperty};
+use crate::domain::stop_impact::{ImpactedUnit, Propagation};
+use crate::domain::timer_property::{SST, STT, TimerProperty};
+use crate::domain::tmpfiles::Tmpfiles;
+use crate::domain::triage::{FailedCheck, TriageFacts};
+use crate::domain::unit_property::UnitProperty;
+use crate::domain::unit_type::UnitType;
+
+/// Signals kept while nobody reads them; older ones are dropped, which is
+/// harmless since any change leads to fetching the units again.
+const UNIT_SIGNALS_QUEUED: usize = 256;
+
+/// Names of the units systemd reports as added, removed, changed or done
+/// with a job, one per signal, in the order they were emitted.
+pub struct UnitChanges {
+    messages: MessageStream,
+    /// Kept open for as long as the signals are listened to.
+    _connection: Connection,
+}
+
+impl Iterator for UnitChanges {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let message = block_on(self.messages.next())?.ok()?;
+            let header = message.header();
+            let name = match header.member().map(|member| member.as_str()) {
+                Some("UnitNew" | "UnitRemoved") => message
+                    .body()
+                    .deserialize::<(String, OwnedObjectPath)>()
+                    .ok()
+                    .map(|(name, _)| name),
+                Some("JobRemoved") => message
+                    .body()
+                    .deserialize::<(u32, OwnedObjectPath, String, String)>()
+                    .ok()
+                    .map(|(_, _, name, _)| name),
+                Some("PropertiesChanged") => header
+                    .path()
+                    .and_then(|path| {
+                        path.as_str()
+                            .strip_prefix("/org/freedesktop/systemd1/unit/")
+                    })
+                    .map(unescape_bus_label),
+                _ => None,
+            };
+            if let Some(name) = name {
+                return Some(name);
+            }
+        }
+    }
+}
+
+/// Undoes the escaping of a unit name in its object path, where every
+/// character but letters and digits is written as `_` and two hex digits.
+fn unescape_bus_label(label: &str) -> String {
+    let bytes = label.as_bytes();
+    let mut unescaped = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'_')
+            .then(|| label.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                unescaped.push(byte);
+                i += 3;
+            }
+            None => {
+                unescaped.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&unescaped).into_owned()
+}
 
 /// Represents a systemd unit as returned by the D-Bus ListUnits method.
 /// Each tuple element corresponds to a specific property of the unit:
@@ -32,75 +137,219 @@ type SystemdUnit = (
     OwnedObjectPath,
 );
 
+/// Parses the lines printed by `systemd-analyze blame`, such as
+/// `1min 2.345s foo.service` or `432ms bar.mount`: the time a unit took to
+/// start, in one or more parts, then its name.
+fn parse_blame(output: &str) -> Vec<BootTime> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (took, name) = line.trim().rsplit_once(' ')?;
+            Some(BootTime::new(name.to_string(), parse_timespan(took)?))
+        })
+        .collect()
+}
+
+/// Parses the tree printed by `systemd-analyze critical-chain`, under two
+/// lines telling what `@` and `+` mean:
+///
+/// ```text
+/// graphical.target @1.602s
+/// └─multi-user.target @1.602s
+///   └─systemd-user-sessions.service @1.590s +9ms
+/// ```
+fn parse_critical_chain(output: &str) -> Vec<ChainLink> {
+    output
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with("The time"))
+        .map(|line| {
+            let (depth, link) = match line.split_once("└─") {
+                Some((indent, link)) => (indent.chars().count() / 2 + 1, link),
+                None => (0, line.trim()),
+            };
+            let (name, times) = link.split_once(' ').unwrap_or((link, ""));
+            let (at, took) = match times.split_once('+') {
+                Some((at, took)) => (at, Some(took)),
+                None => (times, None),
+            };
+            ChainLink::new(
+                name.to_string(),
+                depth,
+                at.trim().strip_prefix('@').and_then(parse_timespan),
+                took.and_then(parse_timespan),
+            )
+        })
+        .collect()
+}
+
+/// A time span as systemd prints it, like `1min 2.345s`.
+fn parse_timespan(span: &str) -> Option<Duration> {
+    let mut parts = span.split_whitespace().peekable();
+    parts.peek()?;
+    parts.map(parse_timespan_part).sum()
+}
+
+/// One part of a time span as systemd prints it, like `2.345s` or `1min`.
+fn parse_timespan_part(part: &str) -> Option<Duration> {
+    let split = part.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (value, unit) = part.split_at(split);
+    let value: f64 = value.parse().ok()?;
+    let seconds = match unit {
+        "us" | "µs" => value / 1_000_000.0,
+        "ms" => value / 1000.0,
+        "s" => value,
+        "min" => value * 60.0,
+        "h" => value * 3600.0,
+        "d" => value * 86_400.0,
+        _ => return None,
+    };
+    Some(Duration::from_secs_f64(seconds))
+}
+
+/// Parses the human readable table printed by `systemd-analyze security <unit>`.
+///
+/// Columns are separated by runs of two or more spaces, the first character of a
+/// row is the ✓/✗ marker and the report ends with a line such as
+/// `→ Overall exposure level for foo.service: 9.6 UNSAFE 😨`.
+fn parse_security_report(output: &str) -> SecurityReport {
+    let mut directives = vec![];
+    let mut overall_exposure = 0.0;
+    let mut overall_rating = String::from("UNKNOWN");
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with("NAME") {
+            continue;
+        }
+
+        if let Some(overall) = trimmed.strip_prefix('→') {
+            let mut parts = overall
+                .rsplit(':')
+                .next()
+                .unwrap_or_default()
+                .split_whitespace();
+            overall_exposure = parts.next().and_then(|v| v.parse().ok()).unwrap_or(0.0);
+            overall_rating = parts.next().unwrap_or("UNKNOWN").to_string();
+            continue;
+        }
+
+        let (passed, rest) = if let Some(rest) = trimmed.strip_prefix('✓') {
+            (Some(true), rest)
+        } else if let Some(rest) = trimmed.strip_prefix('✗') {
+            (Some(false), rest)
+        } else {
+            (None, trimmed)
+        };
+
+        let columns: Vec<&str> = rest
+            .trim()
+            .split("  ")
+            .map(str::trim)
+            .filter(|column| !column.is_empty())
+            .collect();
+
+        let Some(name) = columns.first() else {
+            continue;
+        };
+        let exposure = columns.last().and_then(|v| v.parse::<f32>().ok());
+        let description = match (columns.len(), exposure) {
+            (n, Some(_)) if n >= 3 => columns[1..n - 1].join(" "),
+            (n, None) if n >= 2 => columns[1..].join(" "),
+            _ => String::new(),
+        };
+
+        directives.push(SecurityDirective::new(
+            passed,
+            name.to_string(),
+            description,
+            exposure,
+        ));
+    }
+
+    SecurityReport::new(directives, overall_exposure, overall_rating)
+}
+
 pub struct SystemdServiceAdapter;
 
 impl SystemdServiceAdapter {
-    fn manager_proxy(&self) -> Result<(Connection, Proxy<'static>), Box<dyn std::error::Error>> {
-        let connection: Connection = Connection::system()?;
+    async fn manager_proxy(
+        &self,
+    ) -> Result<(Connection, Proxy<'static>), Box<dyn std::error::Error>> {
+        let connection: Connection = Target::get().connection().await?;
         let proxy = Proxy::new(
             &connection,
             "org.freedesktop.systemd1",
             "/org/freedesktop/systemd1",
             "org.freedesktop.systemd1.Manager",
-        )?;
+        )
+        .await?;
         Ok((connection, proxy))
     }
 
-    pub fn reload_daemon(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let (conn, proxy) = self.manager_proxy()?;
-        proxy.call::<&str, (), ()>("Reload", &())?;
-        conn.close()?;
-        Ok(())
-    }
-
-    pub fn get_service_property(
+    pub fn follow_service_log(
         &self,
         name: &str,
-    ) -> Result<ServiceProperty, Box<dyn std::error::Error>> {
-        let (conn, manager) = self.manager_proxy()?;
-
-        let unit_path: OwnedObjectPath = manager.call("GetUnit", &(name))?;
+        after_cursor: Option<&str>,
+    ) -> Result<(JournalFollower, JournalEntries), Box<dyn std::error::Error>> {
+        Ok(JournalFollower::spawn(name, after_cursor)?)
+    }
 
-        let service_proxy = Proxy::new(
+    /// Listens to the signals systemd emits about its units until the
+    /// returned changes are dropped.
+    pub async fn watch_units(&self) -> Result<UnitChanges, Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
+        // systemd only emits unit and job signals once a client subscribed.
+        proxy.call::<_, _, ()>("Subscribe", &()).await?;
+        let messages = MessageStream::for_match_rule(
+            "type='signal',path_namespace='/org/freedesktop/systemd1'",
             &conn,
-            "org.freedesktop.systemd1",
-            unit_path.as_str(),
-            "org.freedesktop.systemd1.Service",
-        )?;
+            Some(UNIT_SIGNALS_QUEUED),
+        )
+        .await?;
+        Ok(UnitChanges {
+            messages,
+            _connection: conn,
+        })
+    }
 
-        let exec_start: Vec<SASBTTUII> = service_proxy.get_property("ExecStart")?;
-        let exec_start_pre: Vec<SASBTTUII> = service_proxy.get_property("ExecStartPre")?;
-        let exec_start_post: Vec<SASBTTUII> = service_proxy.get_property("ExecStartPost")?;
-        let exec_stop: Vec<SASBTTUII> = service_proxy.get_property("ExecStop")?;
-        let exec_stop_post: Vec<SASBTTUII> = service_proxy.get_property("ExecStopPost")?;
+    async fn service_property(
+        &self,
+        service_proxy: &Proxy<'_>,
+    ) -> Result<ServiceProperty, Box<dyn std::error::Error>> {
+        let exec_start: Vec<SASBTTUII> = service_proxy.get_property("ExecStart").await?;
+        let exec_start_pre: Vec<SASBTTUII> = service_proxy.get_property("ExecStartPre").await?;
+        let exec_start_post: Vec<SASBTTUII> = service_proxy.get_property("ExecStartPost").await?;
+        let exec_stop: Vec<SASBTTUII> = service_proxy.get_property("ExecStop").await?;
+        let exec_stop_post: Vec<SASBTTUII> = service_proxy.get_property("ExecStopPost").await?;
 
-        let exec_main_pid: u32 = service_proxy.get_property("ExecMainPID")?;
+        let exec_main_pid: u32 = service_proxy.get_property("ExecMainPID").await?;
         let exec_main_start_timestamp: u64 =
-            service_proxy.get_property("ExecMainStartTimestamp")?;
-        let exec_main_exit_timestamp: u64 = service_proxy.get_property("ExecMainExitTimestamp")?;
-        let exec_main_code: i32 = service_proxy.get_property("ExecMainCode")?;
-        let exec_main_status: i32 = service_proxy.get_property("ExecMainStatus")?;
+            service_proxy.get_property("ExecMainStartTimestamp").await?;
+        let exec_main_exit_timestamp: u64 =
+            service_proxy.get_property("ExecMainExitTimestamp").await?;
+        let exec_main_code: i32 = service_proxy.get_property("ExecMainCode").await?;
+        let exec_main_status: i32 = service_proxy.get_property("ExecMainStatus").await?;
 
-        let main_pid: u32 = service_proxy.get_property("MainPID")?;
-        let control_pid: u32 = service_proxy.get_property("ControlPID")?;
+        let main_pid: u32 = service_proxy.get_property("MainPID").await?;
+        let control_pid: u32 = service_proxy.get_property("ControlPID").await?;
 
-        let restart: String = service_proxy.get_property("Restart")?;
-        let restart_usec: u64 = service_proxy.get_property("RestartUSec")?;
+        let restart: String = service_proxy.get_property("Restart").await?;
+        let restart_usec: u64 = service_proxy.get_property("RestartUSec").await?;
 
-        let status_text: String = service_proxy.get_property("StatusText")?;
-        let result: String = service_proxy.get_property("Result")?;
+        let status_text: String = service_proxy.get_property("StatusText").await?;
+        let result: String = service_proxy.get_property("Result").await?;
 
-        let user: String = service_proxy.get_property("User")?;
-        let group: String = service_proxy.get_property("Group")?;
+        let user: String = service_proxy.get_property("User").await?;
+        let group: String = service_proxy.get_property("Group").await?;
 
-        let limit_cpu: u64 = service_proxy.get_property("LimitCPU")?;
-        let limit_nofile: u64 = service_proxy.get_property("LimitNOFILE")?;
-        let limit_nproc: u64 = service_proxy.get_property("LimitNPROC")?;
-        let limit_memlock: u64 = service_proxy.get_property("LimitMEMLOCK")?;
-        let memory_limit: u64 = service_proxy.get_property("MemoryLimit")?;
-        let cpu_shares: u64 = service_proxy.get_property("CPUShares")?;
+        let limit_cpu: u64 = service_proxy.get_property("LimitCPU").await?;
+        let limit_nofile: u64 = service_proxy.get_property("LimitNOFILE").await?;
+        let limit_nproc: u64 = service_proxy.get_property("LimitNPROC").await?;
+        let limit_memlock: u64 = service_proxy.get_property("LimitMEMLOCK").await?;
+        let memory_limit: u64 = service_proxy.get_property("MemoryLimit").await?;
+        let cpu_shares: u64 = service_proxy.get_property("CPUShares").await?;
 
-        conn.close()?;
+        let environment: Vec<String> = service_proxy.get_property("Environment").await?;
 
         Ok(ServiceProperty::new(
             exec_start,
@@ -127,98 +376,1147 @@ impl SystemdServiceAdapter {
             limit_memlock,
             memory_limit,
             cpu_shares,
+            environment,
+        ))
+    }
+
+    async fn socket_property(
+        &self,
+        socket_proxy: &Proxy<'_>,
+    ) -> Result<SocketProperty, Box<dyn std::error::Error>> {
+        let listen: Vec<SS> = socket_proxy.get_property("Listen").await?;
+        let accept: bool = socket_proxy.get_property("Accept").await?;
+        let n_connections: u32 = socket_proxy.get_property("NConnections").await?;
+        let n_accepted: u32 = socket_proxy.get_property("NAccepted").await?;
+        let n_refused: u32 = socket_proxy.get_property("NRefused").await?;
+        let result: String = socket_proxy.get_property("Result").await?;
+
+        Ok(SocketProperty::new(
+            listen,
+            accept,
+            n_connections,
+            n_accepted,
+            n_refused,
+            result,
+        ))
+    }
+
+    async fn timer_property(
+        &self,
+        timer_proxy: &Proxy<'_>,
+    ) -> Result<TimerProperty, Box<dyn std::error::Error>> {
+        let unit: String = timer_proxy.get_property("Unit").await?;
+        let timers_calendar: Vec<SST> = timer_proxy.get_property("TimersCalendar").await?;
+        let timers_monotonic: Vec<STT> = timer_proxy.get_property("TimersMonotonic").await?;
+        let next_elapse_realtime: u64 = timer_proxy.get_property("NextElapseUSecRealtime").await?;
+        let last_trigger: u64 = timer_proxy.get_property("LastTriggerUSec").await?;
+        let persistent: bool = timer_proxy.get_property("Persistent").await?;
+        let accuracy_usec: u64 = timer_proxy.get_property("AccuracyUSec").await?;
+        let randomized_delay_usec: u64 = timer_proxy.get_property("RandomizedDelayUSec").await?;
+        let result: String = timer_proxy.get_property("Result").await?;
+
+        Ok(TimerProperty::new(
+            unit,
+            timers_calendar,
+            timers_monotonic,
+            next_elapse_realtime,
+            last_trigger,
+            persistent,
+            accuracy_usec,
+            randomized_delay_usec,
+            result,
+        ))
+    }
+
+    async fn mount_property(
+        &self,
+        mount_proxy: &Proxy<'_>,
+    ) -> Result<MountProperty, Box<dyn std::error::Error>> {
+        let what: String = mount_proxy.get_property("What").await?;
+        let r#where: String = mount_proxy.get_property("Where").await?;
+        let fs_type: String = mount_proxy.get_property("Type").await?;
+        let options: String = mount_proxy.get_property("Options").await?;
+        let timeout_usec: u64 = mount_proxy.get_property("TimeoutUSec").await?;
+        let result: String = mount_proxy.get_property("Result").await?;
+
+        Ok(MountProperty::new(
+            what,
+            r#where,
+            fs_type,
+            options,
+            timeout_usec,
+            result,
         ))
     }
 }
+
+/// Runs `call`, which waits on a program or reads files of the target, on a
+/// thread meant for it rather than on one driving the bus calls.
+async fn blocking<T: Send + 'static>(
+    call: impl FnOnce() -> Result<T, Box<dyn std::error::Error>> + Send + 'static,
+) -> Result<T, Box<dyn std::error::Error>> {
+    task::spawn_blocking(move || call().map_err(|e| e.to_string()))
+        .await?
+        .map_err(Into::into)
+}
+
+/// Runs `command`, a `journalctl` already restricted to some units, for the
+/// entries `query` selects, and returns its JSON output.
+fn run_journal_query(
+    command: &mut std::process::Command,
+    query: &LogQuery,
+) -> Result<String, Box<dyn std::error::Error>> {
+    command.arg("--output=json").arg("--no-pager");
+    match query.lines() {
+        Some(lines) => command.arg(format!("--lines={}", lines)),
+        None => command.arg("--lines=all"),
+    };
+    if let Some(boot) = query.boot() {
+        command.arg(format!("--boot={}", boot));
+    }
+    if let Some(since) = query.since() {
+        command.arg(format!("--since={}", since));
+    }
+    if let Some(until) = query.until() {
+        command.arg(format!("--until={}", until));
+    }
+    if let Some(cursor) = query.after_cursor() {
+        command.arg(format!("--after-cursor={}", cursor));
+    }
+    let output = Target::get().command(command).output()?;
+
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().into());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[async_trait]
 impl ServiceRepository for SystemdServiceAdapter {
-    fn list_services(&self) -> Result<Vec<Service>, Box<dyn std::error::Error>> {
-        let (conn, proxy) = self.manager_proxy()?;
+    async fn list_services(&self) -> Result<Vec<Service>, Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
+
+        let units: Vec<SystemdUnit> = proxy.call("ListUnits", &()).await?;
+        let unit_files: Vec<(String, String)> =
+            proxy.call("ListUnitFiles", &()).await.unwrap_or_default();
+        let paths: HashMap<&str, &str> = unit_files
+            .iter()
+            .filter_map(|(path, _)| Some((path.rsplit('/').next()?, path.as_str())))
+            .collect();
+        // Instances are read from the file of their template.
+        let path_of = |service: &Service| {
+            let template = service.template_name();
+            paths
+                .get(service.name())
+                .or_else(|| paths.get(template.as_deref()?))
+                .map_or(String::new(), |path| path.to_string())
+        };
 
-        let units: Vec<SystemdUnit> = proxy.call("ListUnits", &())?;
+        let mut services: Vec<Service> = vec![];
+        for (
+            name,
+            description,
+            load_state,
+            active_state,
+            sub_state,
+            _followed,
+            _object_path,
+            _job_id,
+            _job_type,
+            _job_object,
+        ) in units
+        {
+            if !UnitType::LISTED.contains(&UnitType::from_name(&name)) {
+                continue;
+            }
+            let state: String = proxy
+                .call("GetUnitFileState", &name)
+                .await
+                .unwrap_or_else(|_| "unknown".into());
 
-        let services = units
-            .into_iter()
-            .filter(|(name, ..)| name.ends_with(".service"))
-            .map(
-                |(
-                    name,
-                    description,
-                    load_state,
-                    active_state,
-                    sub_state,
-                    _followed,
-                    _object_path,
-                    _job_id,
-                    _job_type,
-                    _job_object,
-                )| {
-                    let state: String = proxy
-                        .call("GetUnitFileState", &name)
-                        .unwrap_or_else(|_| "unknown".into());
-
-                    let service_state =
-                        ServiceState::new(load_state, active_state, sub_state, state);
-
-                    Service::new(name, description, service_state)
-                },
+            let service_state = ServiceState::new(load_state, active_state, sub_state, state);
+
+            let service = Service::new(name, description, service_state);
+            let path = path_of(&service);
+            services.push(service.with_file_path(path));
+        }
+
+        // Units systemd has no reason to load, such as disabled ones that never
+        // ran, are only known by their file until started or asked about.
+        let loaded: HashSet<String> = services.iter().map(|s| s.name().to_string()).collect();
+        services.extend(unit_files.iter().filter_map(|(path, file_state)| {
+            let name = path.rsplit('/').next()?;
+            // Aliases are listed under the name of the unit they point to.
+            if loaded.contains(name)
+                || file_state == "alias"
+                || name.contains("@.")
+                || !UnitType::LISTED.contains(&UnitType::from_name(name))
+            {
+                return None;
+            }
+            let state = ServiceState::new(
+                "not-loaded".into(),
+                "inactive".into(),
+                "dead".into(),
+                file_state.clone(),
+            );
+            Some(Service::new(name.to_string(), String::new(), state).with_file_path(path.clone()))
+        }));
+
+        // Templates are never loaded, so they only show up as unit files.
+        let templates: Vec<(String, String)> = proxy
+            .call(
+                "ListUnitFilesByPatterns",
+                &(Vec::<&str>::new(), vec!["*@.service"]),
             )
-            .collect();
+            .await
+            .unwrap_or_default();
+        services.extend(templates.into_iter().filter_map(|(path, file_state)| {
+            let name = path.rsplit('/').next()?.to_string();
+            let state = ServiceState::new(
+                "template".into(),
+                "inactive".into(),
+                "dead".into(),
+                file_state,
+            );
+            Some(Service::new(name, "Template unit".into(), state).with_file_path(path))
+        }));
 
-        conn.close()?;
+        conn.close().await?;
 
         Ok(services)
     }
 
-    fn get_service_log(&self, name: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let output = std::process::Command::new("journalctl")
-            .arg("-eu")
+    async fn get_service_log(
+        &self,
+        name: &str,
+        query: &LogQuery,
+    ) -> Result<Vec<LogEntry>, Box<dyn std::error::Error>> {
+        let mut command = std::process::Command::new("journalctl");
+        match_unit(&mut command, name);
+        let query = query.clone();
+        let output = blocking(move || run_journal_query(&mut command, &query)).await?;
+        Ok(output.lines().filter_map(parse_journal_entry).collect())
+    }
+
+    async fn get_journal(
+        &self,
+        query: &LogQuery,
+    ) -> Result<Vec<(String, LogEntry)>, Box<dyn std::error::Error>> {
+        let mut command = std::process::Command::new("journalctl");
+        let query = query.clone();
+        let output = blocking(move || run_journal_query(&mut command, &query)).await?;
+        Ok(output
+            .lines()
+            .filter_map(parse_unit_journal_entry)
+            .collect())
+    }
+
+    async fn get_resource_usage(
+        &self,
+    ) -> Result<HashMap<String, ResourceUsage>, Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
+
+        let units: Vec<SystemdUnit> = proxy.call("ListUnits", &()).await?;
+
+        let mut usage = HashMap::new();
+        for (name, _, _, active_state, _, _, object_path, ..) in units {
+            if UnitType::from_name(&name) != UnitType::Service || active_state != "active" {
+                continue;
+            }
+            let service_usage = async {
+                let service_proxy = Proxy::new(
+                    &conn,
+                    "org.freedesktop.systemd1",
+                    object_path.as_str(),
+                    "org.freedesktop.systemd1.Service",
+                )
+                .await
+                .ok()?;
+                Some(ResourceUsage::new(
+                    service_proxy.get_property("MemoryCurrent").await.ok()?,
+                    service_proxy.get_property("MemoryMax").await.ok()?,
+                    service_proxy.get_property("TasksCurrent").await.ok()?,
+                    service_proxy.get_property("TasksMax").await.ok()?,
+                    service_proxy.get_property("CPUUsageNSec").await.ok()?,
+                ))
+            };
+            if let Some(service_usage) = service_usage.await {
+                usage.insert(name, service_usage);
+            }
+        }
+
+        conn.close().await?;
+
+        Ok(usage)
+    }
+
+    async fn get_slices(&self) -> Result<HashMap<String, String>, Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
+
+        let units: Vec<SystemdUnit> = proxy.call("ListUnits", &()).await?;
+
+        // Timers run nothing, so they have no slice.
+        let mut slices = HashMap::new();
+        for (name, _, _, _, _, _, object_path, ..) in units {
+            let unit_type = UnitType::from_name(&name);
+            if !matches!(
+                unit_type,
+                UnitType::Service | UnitType::Socket | UnitType::Mount
+            ) {
+                continue;
+            }
+            let slice = async {
+                let unit_proxy = Proxy::new(
+                    &conn,
+                    "org.freedesktop.systemd1",
+                    object_path.as_str(),
+                    unit_type.interface()?,
+                )
+                .await
+                .ok()?;
+                unit_proxy.get_property::<String>("Slice").await.ok()
+            };
+            if let Some(slice) = slice.await.filter(|slice| !slice.is_empty()) {
+                slices.insert(name, slice);
+            }
+        }
+
+        conn.close().await?;
+
+        Ok(slices)
+    }
+
+    async fn get_system_state(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
+        let state: String = proxy.get_property("SystemState").await?;
+        conn.close().await?;
+        Ok(state)
+    }
+
+    async fn get_overview(&self) -> Result<Overview, Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
+        let state: String = proxy.get_property("SystemState").await?;
+        let units: Vec<SystemdUnit> = proxy.call("ListUnits", &()).await?;
+        let mut failed = vec![];
+        for (name, _, _, active, _, _, object_path, ..) in units {
+            if active != "failed" {
+                continue;
+            }
+            let failed_at = match Proxy::new(
+                &conn,
+                "org.freedesktop.systemd1",
+                object_path.as_str(),
+                "org.freedesktop.systemd1.Unit",
+            )
+            .await
+            {
+                Ok(unit_proxy) => unit_proxy
+                    .get_property("StateChangeTimestamp")
+                    .await
+                    .unwrap_or(0),
+                Err(_) => 0,
+            };
+            failed.push((name, failed_at));
+        }
+        conn.close().await?;
+
+        let (uptime, load) = blocking(|| {
+            let target = Target::get();
+            let uptime = target
+                .read_to_string("/proc/uptime")
+                .ok()
+                .and_then(|text| parse_uptime(&text));
+            let load = target
+                .read_to_string("/proc/loadavg")
+                .ok()
+                .and_then(|text| parse_loadavg(&text));
+            Ok((uptime, load))
+        })
+        .await?;
+        Ok(Overview::new(state, uptime, load, failed))
+    }
+
+    async fn get_units_needing_reload(&self) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
+
+        let units: Vec<SystemdUnit> = proxy.call("ListUnits", &()).await?;
+
+        let mut changed = vec![];
+        for (name, _, _, _, _, _, object_path, ..) in units {
+            let Ok(unit_proxy) = Proxy::new(
+                &conn,
+                "org.freedesktop.systemd1",
+                object_path.as_str(),
+                "org.freedesktop.systemd1.Unit",
+            )
+            .await
+            else {
+                continue;
+            };
+            if unit_proxy
+                .get_property("NeedDaemonReload")
+                .await
+                .unwrap_or(false)
+            {
+                changed.push(name);
+            }
+        }
+
+        conn.close().await?;
+
+        Ok(changed)
+    }
+
+    async fn get_activators(
+        &self,
+    ) -> Result<HashMap<String, Vec<String>>, Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
+
+        let units: Vec<SystemdUnit> = proxy.call("ListUnits", &()).await?;
+
+        let mut activators: HashMap<String, Vec<String>> = HashMap::new();
+        for (name, _, _, _, _, _, object_path, ..) in units {
+            if !matches!(
+                UnitType::from_name(&name),
+                UnitType::Timer | UnitType::Socket
+            ) {
+                continue;
+            }
+            let Ok(unit_proxy) = Proxy::new(
+                &conn,
+                "org.freedesktop.systemd1",
+                object_path.as_str(),
+                "org.freedesktop.systemd1.Unit",
+            )
+            .await
+            else {
+                continue;
+            };
+            let triggers: Vec<String> = unit_proxy
+                .get_property("Triggers")
+                .await
+                .unwrap_or_default();
+            for triggered in triggers {
+                activators.entry(triggered).or_default().push(name.clone());
+            }
+        }
+
+        conn.close().await?;
+
+        Ok(activators)
+    }
+
+    async fn list_boots(&self) -> Result<Vec<Boot>, Box<dyn std::error::Error>> {
+        let output = blocking(|| {
+            Ok(Target::get()
+                .command(
+                    std::process::Command::new("journalctl")
+                        .arg("--list-boots")
+                        .arg("--output=json")
+                        .arg("--no-pager"),
+                )
+                .output()?)
+        })
+        .await?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().into());
+        }
+
+        let mut boots = parse_boots(&String::from_utf8_lossy(&output.stdout));
+        boots.sort_by_key(|boot| -boot.offset());
+        Ok(boots)
+    }
+
+    async fn get_early_boot(&self) -> Result<EarlyBoot, Box<dyn std::error::Error>> {
+        let output = blocking(|| {
+            Ok(Target::get()
+                .command(
+                    std::process::Command::new("journalctl")
+                        .arg("--boot=0")
+                        .arg("_PID=1")
+                        .arg("--output=json")
+                        .arg("--no-pager"),
+                )
+                .output()?)
+        })
+        .await?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().into());
+        }
+
+        Ok(parse_early_boot(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    async fn get_boot_times(&self) -> Result<Vec<BootTime>, Box<dyn std::error::Error>> {
+        let output = blocking(|| {
+            Ok(Target::get()
+                .command(
+                    std::process::Command::new("systemd-analyze")
+                        .arg("blame")
+                        .arg("--no-pager")
+                        .env("SYSTEMD_COLORS", "0"),
+                )
+                .output()?)
+        })
+        .await?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().into());
+        }
+
+        Ok(parse_blame(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    async fn get_critical_chain(&self) -> Result<Vec<ChainLink>, Box<dyn std::error::Error>> {
+        let output = blocking(|| {
+            Ok(Target::get()
+                .command(
+                    std::process::Command::new("systemd-analyze")
+                        .arg("critical-chain")
+                        .arg("--no-pager")
+                        .env("SYSTEMD_COLORS", "0"),
+                )
+                .output()?)
+        })
+        .await?;
+
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().into());
+        }
+
+        Ok(parse_critical_chain(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
+    }
+
+    async fn reload_daemon(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
+        proxy.call::<&str, (), ()>("Reload", &()).await?;
+        conn.close().await?;
+        Ok(())
+    }
+
+    async fn get_unit_property(
+        &self,
+        name: &str,
+    ) -> Result<UnitProperty, Box<dyn std::error::Error>> {
+        let (conn, manager) = self.manager_proxy().await?;
+
+        // Loads units only known by their file, which GetUnit does not find.
+        let unit_path: OwnedObjectPath = manager.call("LoadUnit", &(name)).await?;
+
+        let unit_type = UnitType::from_name(name);
+        let interface = unit_type
+            .interface()
+            .ok_or("Properties are not available for this unit type")?;
+
+        let unit_proxy = Proxy::new(
+            &conn,
+            "org.freedesktop.systemd1",
+            unit_path.as_str(),
+            interface,
+        )
+        .await?;
+
+        let property = match unit_type {
+            UnitType::Service => {
+                UnitProperty::Service(Box::new(self.service_property(&unit_proxy).await?))
+            }
+            UnitType::Socket => UnitProperty::Socket(self.socket_property(&unit_proxy).await?),
+            UnitType::Timer => UnitProperty::Timer(self.timer_property(&unit_proxy).await?),
+            UnitType::Mount => UnitProperty::Mount(self.mount_property(&unit_proxy).await?),
+            UnitType::Other => unreachable!("units without an interface are rejected above"),
+        };
+
+        conn.close().await?;
+
+        Ok(property)
+    }
+
+    async fn get_security_report(
+        &self,
+        name: &str,
+    ) -> Result<SecurityReport, Box<dyn std::error::Error>> {
+        let mut command = std::process::Command::new("systemd-analyze");
+        command
+            .arg("security")
             .arg(name)
             .arg("--no-pager")
-            .output()?;
+            .env("SYSTEMD_COLORS", "0");
+        let output = blocking(move || Ok(Target::get().command(&command).output()?)).await?;
 
-        let log = if output.status.success() {
-            String::from_utf8_lossy(&output.stdout).to_string()
-        } else {
-            String::from_utf8_lossy(&output.stderr).to_string()
-        };
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().into());
+        }
 
-        Ok(log)
+        Ok(parse_security_report(&String::from_utf8_lossy(
+            &output.stdout,
+        )))
     }
 
-    fn start_service(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let (conn, proxy) = self.manager_proxy()?;
-        let _job: OwnedObjectPath = proxy.call("StartUnit", &(name, "replace"))?;
-        conn.close()?;
-        Ok(())
+    async fn get_access_control(
+        &self,
+        name: &str,
+    ) -> Result<AccessControl, Box<dyn std::error::Error>> {
+        let (conn, manager) = self.manager_proxy().await?;
+        let unit_path: OwnedObjectPath = manager.call("LoadUnit", &(name)).await?;
+        let interface = UnitType::from_name(name)
+            .interface()
+            .ok_or("Access control is not available for this unit type")?;
+        let unit_proxy = Proxy::new(
+            &conn,
+            "org.freedesktop.systemd1",
+            unit_path.as_str(),
+            interface,
+        )
+        .await?;
+        // Both are (ignore errors, value); only units running commands have them.
+        let (_, selinux_context): (bool, String) = unit_proxy
+            .get_property("SELinuxContext")
+            .await
+            .unwrap_or_default();
+        let (_, apparmor_profile): (bool, String) = unit_proxy
+            .get_property("AppArmorProfile")
+            .await
+            .unwrap_or_default();
+        let main_pid: u32 = unit_proxy.get_property("MainPID").await.unwrap_or(0);
+        conn.close().await?;
+
+        let ((process_label, comm), output) = blocking(move || {
+            let read_proc = |file: &str| {
+                Target::get()
+                    .read_to_string(&format!("/proc/{}/{}", main_pid, file))
+                    .ok()
+                    .map(|value| value.trim_end_matches(['\0', '\n']).to_string())
+            };
+            let process = if main_pid > 0 {
+                (read_proc("attr/current"), read_proc("comm"))
+            } else {
+                (None, None)
+            };
+            let output = Target::get()
+                .command(
+                    std::process::Command::new("journalctl")
+                        .arg("--boot=0")
+                        .arg("_TRANSPORT=audit")
+                        .arg("_TRANSPORT=kernel")
+                        .arg("--output=json")
+                        .arg("--lines=5000")
+                        .arg("--no-pager"),
+                )
+                .output()?;
+            Ok((process, output))
+        })
+        .await?;
+
+        // Denials are logged by the kernel or the audit subsystem, without the
+        // unit; they are matched on what they say about the process instead.
+        let comm = comm.unwrap_or_else(|| {
+            let stem = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+            stem.chars().take(15).collect()
+        });
+        let mut needles = vec![format!("comm=\"{}\"", comm)];
+        if main_pid > 0 {
+            needles.push(format!("pid={} ", main_pid));
+        }
+        if !apparmor_profile.is_empty() {
+            needles.push(format!("profile=\"{}\"", apparmor_profile));
+        }
+        if let Some(selinux_type) = selinux_context.split(':').nth(2) {
+            needles.push(format!(":{}:", selinux_type));
+        }
+
+        let denials = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(parse_journal_entry)
+            .filter(|entry| {
+                is_denial(entry.message())
+                    && needles
+                        .iter()
+                        .any(|needle| entry.message().contains(needle))
+            })
+            .collect();
+
+        Ok(AccessControl::new(
+            selinux_context,
+            apparmor_profile,
+            process_label,
+            denials,
+        ))
     }
 
-    fn stop_service(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let (conn, proxy) = self.manager_proxy()?;
-        let _job: OwnedObjectPath = proxy.call("StopUnit", &(name, "replace"))?;
-        conn.close()?;
-        Ok(())
+    async fn get_triage_facts(
+        &self,
+        name: &str,
+    ) -> Result<TriageFacts, Box<dyn std::error::Error>> {
+        let (conn, manager) = self.manager_proxy().await?;
+        let unit_path: OwnedObjectPath = manager.call("LoadUnit", &(name)).await?;
+        let unit_proxy = Proxy::new(
+            &conn,
+            "org.freedesktop.systemd1",
+            unit_path.as_str(),
+            "org.freedesktop.systemd1.Unit",
+        )
+        .await?;
+        let mut facts = TriageFacts::default();
+
+        if UnitType::from_name(name) == UnitType::Service {
+            let service_proxy = Proxy::new(
+                &conn,
+                "org.freedesktop.systemd1",
+                unit_path.as_str(),
+                "org.freedesktop.systemd1.Service",
+            )
+            .await?;
+            facts.result = service_proxy.get_property("Result").await?;
+            facts.exec_main_code = service_proxy.get_property("ExecMainCode").await?;
+            facts.exec_main_status = service_proxy.get_property("ExecMainStatus").await?;
+        }
+
+        // (type, triggering, negated, parameter, state), a negative state
+        // meaning it was checked and failed.
+        for property in ["Conditions", "Asserts"] {
+            let checks: Vec<(String, bool, bool, String, i32)> =
+                unit_proxy.get_property(property).await.unwrap_or_default();
+            facts
+                .failed_checks
+                .extend(checks.into_iter().filter(|(.., state)| *state < 0).map(
+                    |(kind, _, negated, parameter, _)| FailedCheck::new(kind, negated, parameter),
+                ));
+        }
+
+        for property in ["Requires", "Requisite", "BindsTo"] {
+            let dependencies: Vec<String> =
+                unit_proxy.get_property(property).await.unwrap_or_default();
+            for dependency in dependencies {
+                let Ok(path) = manager
+                    .call::<_, _, OwnedObjectPath>("LoadUnit", &(&dependency))
+                    .await
+                else {
+                    continue;
+                };
+                let Ok(dependency_proxy) = Proxy::new(
+                    &conn,
+                    "org.freedesktop.systemd1",
+                    path.as_str(),
+                    "org.freedesktop.systemd1.Unit",
+                )
+                .await
+                else {
+                    continue;
+                };
+                let load_state: String = dependency_proxy.get_property("LoadState").await?;
+                let active_state: String = dependency_proxy.get_property("ActiveState").await?;
+                if load_state != "loaded" {
+                    facts.failed_dependencies.push((dependency, load_state));
+                } else if active_state == "failed" {
+                    facts.failed_dependencies.push((dependency, active_state));
+                }
+            }
+        }
+
+        facts.needs_daemon_reload = unit_proxy
+            .get_property("NeedDaemonReload")
+            .await
+            .unwrap_or(false);
+        let fragment_path: String = unit_proxy
+            .get_property("FragmentPath")
+            .await
+            .unwrap_or_default();
+        let drop_in_paths: Vec<String> = unit_proxy
+            .get_property("DropInPaths")
+            .await
+            .unwrap_or_default();
+        conn.close().await?;
+
+        let name = name.to_string();
+        blocking(move || {
+            const DAY_MINUTES: u64 = 24 * 60;
+            facts.recent_edits = std::iter::once(fragment_path)
+                .chain(drop_in_paths)
+                .filter_map(|path| {
+                    let modified = Target::get().modified(&path)?;
+                    let minutes = modified.elapsed().ok()?.as_secs() / 60;
+                    (minutes < DAY_MINUTES).then_some((path, minutes))
+                })
+                .collect();
+
+            let mut command = std::process::Command::new("journalctl");
+            match_unit(&mut command, &name);
+            command
+                .arg("--boot=0")
+                .arg("--priority=err")
+                .arg("--lines=10")
+                .arg("--output=json")
+                .arg("--no-pager");
+            let output = Target::get().command(&command).output()?;
+            facts.error_lines = String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(parse_journal_entry)
+                .collect();
+
+            // Programs often report kernel limits as warnings and go on degraded.
+            let mut command = std::process::Command::new("journalctl");
+            match_unit(&mut command, &name);
+            command
+                .arg("--boot=0")
+                .arg("--priority=warning")
+                .arg("--lines=500")
+                .arg("--output=json")
+                .arg("--no-pager");
+            let output = Target::get().command(&command).output()?;
+            let warnings: Vec<LogEntry> = String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .filter_map(parse_journal_entry)
+                .collect();
+            facts.sysctl_hints = KernelLimit::reported_in(warnings.iter().map(LogEntry::message))
+                .into_iter()
+                .map(|(limit, evidence)| {
+                    let current = Target::get()
+                        .read_to_string(&limit.proc_path())
+                        .ok()
+                        .map(|value| value.split_whitespace().collect::<Vec<_>>().join(" "));
+                    SysctlHint::new(limit, current, evidence)
+                })
+                .collect();
+            Ok(facts)
+        })
+        .await
+    }
+
+    async fn get_tmpfiles(&self, name: &str) -> Result<Tmpfiles, Box<dyn std::error::Error>> {
+        let mut directories: Vec<String> = vec![];
+        if UnitType::from_name(name) == UnitType::Service {
+            let (conn, manager) = self.manager_proxy().await?;
+            let unit_path: OwnedObjectPath = manager.call("LoadUnit", &(name)).await?;
+            let service_proxy = Proxy::new(
+                &conn,
+                "org.freedesktop.systemd1",
+                unit_path.as_str(),
+                "org.freedesktop.systemd1.Service",
+            )
+            .await?;
+            // Names relative to the directory each setting is for.
+            for (property, root) in [
+                ("RuntimeDirectory", "/run"),
+                ("StateDirectory", "/var/lib"),
+                ("CacheDirectory", "/var/cache"),
+                ("LogsDirectory", "/var/log"),
+                ("ConfigurationDirectory", "/etc"),
+            ] {
+                let names: Vec<String> = service_proxy
+                    .get_property(property)
+                    .await
+                    .unwrap_or_default();
+                directories.extend(names.iter().map(|name| format!("{}/{}", root, name)));
+            }
+            let working_directory: String = service_proxy
+                .get_property("WorkingDirectory")
+                .await
+                .unwrap_or_default();
+            let read_write_paths: Vec<String> = service_proxy
+                .get_property("ReadWritePaths")
+                .await
+                .unwrap_or_default();
+            let pid_file: String = service_proxy
+                .get_property("PIDFile")
+                .await
+                .unwrap_or_default();
+            conn.close().await?;
+
+            // A leading `-` makes a path optional and `+` skips the namespace.
+            directories.extend(
+                std::iter::once(working_directory)
+                    .chain(read_write_paths)
+                    .map(|path| path.trim_start_matches(['-', '+']).to_string())
+                    .filter(|path| path.starts_with('/')),
+            );
+            if let Some(parent) = std::path::Path::new(&pid_file).parent()
+                && parent.is_absolute()
+            {
+                directories.push(parent.display().to_string());
+            }
+        }
+        let mut seen = std::collections::HashSet::new();
+        directories.retain(|directory| seen.insert(directory.clone()));
+
+        let output = blocking(|| {
+            Ok(Target::get()
+                .command(
+                    std::process::Command::new("systemd-tmpfiles")
+                        .arg("--cat-config")
+                        .arg("--no-pager"),
+                )
+                .output()?)
+        })
+        .await?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().into());
+        }
+        let entries = parse_cat_config(&String::from_utf8_lossy(&output.stdout))
+            .into_iter()
+            .filter(|entry| {
+                directories
+                    .iter()
+                    .any(|directory| entry.concerns(directory))
+            })
+            .collect();
+        let directories = blocking(move || {
+            Ok(directories
+                .into_iter()
+                .map(|directory| {
+                    let exists = Target::get().exists(&directory);
+                    (directory, exists)
+                })
+                .collect())
+        })
+        .await?;
+
+        Ok(Tmpfiles {
+            directories,
+            entries,
+        })
+    }
+
+    async fn run_tmpfiles(
+        &self,
+        paths: &[String],
+        dry_run: bool,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        if paths.is_empty() {
+            return Err("The unit has no directory for systemd-tmpfiles to create".into());
+        }
+        let mut command = std::process::Command::new("systemd-tmpfiles");
+        command.arg("--create");
+        if dry_run {
+            command.arg("--dry-run");
+        }
+        for path in paths {
+            command.arg(format!("--prefix={}", path));
+        }
+        let output = blocking(move || Ok(Target::get().command(&command).output()?)).await?;
+        let printed = format!(
+            "{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        if !output.status.success() {
+            return Err(printed.trim().into());
+        }
+        Ok(printed)
+    }
+
+    async fn get_unit_files(
+        &self,
+        name: &str,
+    ) -> Result<Vec<(String, String)>, Box<dyn std::error::Error>> {
+        let (conn, manager) = self.manager_proxy().await?;
+        let unit_path: OwnedObjectPath = manager.call("LoadUnit", &(name)).await?;
+        let unit_proxy = Proxy::new(
+            &conn,
+            "org.freedesktop.systemd1",
+            unit_path.as_str(),
+            "org.freedesktop.systemd1.Unit",
+        )
+        .await?;
+        let fragment_path: String = unit_proxy
+            .get_property("FragmentPath")
+            .await
+            .unwrap_or_default();
+        let drop_in_paths: Vec<String> = unit_proxy
+            .get_property("DropInPaths")
+            .await
+            .unwrap_or_default();
+        conn.close().await?;
+
+        // Generated and transient units have no fragment.
+        blocking(move || {
+            Ok(std::iter::once(fragment_path)
+                .chain(drop_in_paths)
+                .filter(|path| !path.is_empty())
+                .map(|path| {
+                    let content = Target::get()
+                        .read_to_string(&path)
+                        .unwrap_or_else(|e| format!("# Could not be read: {}\n", e));
+                    (path, content)
+                })
+                .collect())
+        })
+        .await
+    }
+
+    async fn start_service(
+        &self,
+        name: &str,
+        mode: JobMode,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
+        let job: OwnedObjectPath = proxy.call("StartUnit", &(name, mode.name())).await?;
+        conn.close().await?;
+        Ok(job.to_string())
+    }
+
+    async fn get_stop_impact(
+        &self,
+        name: &str,
+    ) -> Result<Vec<ImpactedUnit>, Box<dyn std::error::Error>> {
+        let (conn, manager) = self.manager_proxy().await?;
+        let mut impacted: Vec<ImpactedUnit> = vec![];
+        let mut pending = vec![name.to_string()];
+        while let Some(cause) = pending.pop() {
+            let unit_path: OwnedObjectPath = manager.call("LoadUnit", &(&cause)).await?;
+            let unit_proxy = Proxy::new(
+                &conn,
+                "org.freedesktop.systemd1",
+                unit_path.as_str(),
+                "org.freedesktop.systemd1.Unit",
+            )
+            .await?;
+            for propagation in Propagation::ALL {
+                let dependents: Vec<String> = unit_proxy
+                    .get_property(propagation.reverse_property())
+                    .await
+                    .unwrap_or_default();
+                for dependent in dependents {
+                    if dependent == name || impacted.iter().any(|unit| unit.name() == dependent) {
+                        continue;
+                    }
+                    let path: OwnedObjectPath = manager.call("LoadUnit", &(&dependent)).await?;
+                    let dependent_proxy = Proxy::new(
+                        &conn,
+                        "org.freedesktop.systemd1",
+                        path.as_str(),
+                        "org.freedesktop.systemd1.Unit",
+                    )
+                    .await?;
+                    // Units already down are left as they are.
+                    let active_state: String = dependent_proxy.get_property("ActiveState").await?;
+                    if matches!(active_state.as_str(), "inactive" | "failed") {
+                        continue;
+                    }
+                    pending.push(dependent.clone());
+                    impacted.push(ImpactedUnit::new(dependent, propagation, cause.clone()));
+                }
+            }
+        }
+        conn.close().await?;
+        Ok(impacted)
+    }
+
+    async fn stop_service(
+        &self,
+        name: &str,
+        mode: JobMode,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
+        let job: OwnedObjectPath = proxy.call("StopUnit", &(name, mode.name())).await?;
+        conn.close().await?;
+        Ok(job.to_string())
+    }
+
+    async fn restart_service(&self, name: &str) -> Result<String, Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
+        let job: OwnedObjectPath = proxy.call("RestartUnit", &(name, "replace")).await?;
+        conn.close().await?;
+        Ok(job.to_string())
+    }
+
+    async fn wait_for_job(
+        &self,
+        name: &str,
+        job: &str,
+    ) -> Result<JobResult, Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
+        // systemd only emits job signals once a client subscribed.
+        proxy.call::<_, _, ()>("Subscribe", &()).await?;
+        let mut removed = proxy.receive_signal("JobRemoved").await?;
+
+        // A job that ended before the signal was listened to is gone with its
+        // result; the state the unit is left in tells how it went instead.
+        let job_state: Option<String> = match Proxy::new(
+            &conn,
+            "org.freedesktop.systemd1",
+            job,
+            "org.freedesktop.systemd1.Job",
+        )
+        .await
+        {
+            Ok(job_proxy) => job_proxy.get_property("State").await.ok(),
+            Err(_) => None,
+        };
+        let result = if job_state.is_none() {
+            let unit_path: OwnedObjectPath = proxy.call("LoadUnit", &(name)).await?;
+            let unit_proxy = Proxy::new(
+                &conn,
+                "org.freedesktop.systemd1",
+                unit_path.as_str(),
+                "org.freedesktop.systemd1.Unit",
+            )
+            .await?;
+            let active_state: String = unit_proxy.get_property("ActiveState").await?;
+            if active_state == "failed" {
+                JobResult::Failed
+            } else {
+                JobResult::Done
+            }
+        } else {
+            removed
+                .find_map(|message| {
+                    let (_id, path, _unit, result): (u32, OwnedObjectPath, String, String) =
+                        message.body().deserialize().ok()?;
+                    (path.as_str() == job).then(|| JobResult::parse(&result))
+                })
+                .await
+                .ok_or("The connection to systemd closed before the job ended")?
+        };
+
+        drop(removed);
+        let _ = proxy.call::<_, _, ()>("Unsubscribe", &()).await;
+        conn.close().await?;
+        Ok(result)
+    }
+
+    async fn list_jobs(&self) -> Result<Vec<QueuedJob>, Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
+        let jobs: Vec<(
+            u32,
+            String,
+            String,
+            String,
+            OwnedObjectPath,
+            OwnedObjectPath,
+        )> = proxy.call("ListJobs", &()).await?;
+        conn.close().await?;
+        Ok(jobs
+            .into_iter()
+            .map(|(id, unit, job_type, state, _, _)| QueuedJob::new(id, unit, job_type, state))
+            .collect())
     }
 
-    fn restart_service(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let (conn, proxy) = self.manager_proxy()?;
-        let _job: OwnedObjectPath = proxy.call("RestartUnit", &(name, "replace"))?;
-        conn.close()?;
+    async fn cancel_job(&self, id: u32) -> Result<(), Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
+        proxy.call::<&str, (u32,), ()>("CancelJob", &(id,)).await?;
+        conn.close().await?;
         Ok(())
     }
 
-    fn enable_service(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let (conn, proxy) = self.manager_proxy()?;
-        let (_carries_install_info, _changes): (bool, Vec<(String, String, String)>) =
-            proxy.call("EnableUnitFiles", &(vec![name], false, true))?;
-        conn.close()?;
+    async fn enable_service(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
+        let (_carries_install_info, _changes): (bool, Vec<(String, String, String)>) = proxy
+            .call("EnableUnitFiles", &(vec![name], false, true))
+            .await?;
+        conn.close().await?;
         Ok(())
     }
 
-    fn disable_service(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let (conn, proxy) = self.manager_proxy()?;
+    async fn disable_service(&self, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let (conn, proxy) = self.manager_proxy().await?;
         let _changes: Vec<(String, String, String)> =
-            proxy.call("DisableUnitFiles", &(vec![name], false))?;
-        conn.close()?;
+            proxy.call("DisableUnitFiles", &(vec![name], false)).await?;
+        conn.close().await?;
         Ok(())
     }
 }
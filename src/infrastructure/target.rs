@@ -0,0 +1,207 @@
+use std::ffi::{OsStr, OsString};
+use std::io;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime};
+
+use tracing::debug;
+use zbus::address::transport::{Transport, Unixexec};
+use zbus::connection::Builder;
+use zbus::{Address, Connection};
+
+/// Programs that act on the system by default and on the calling user's units
+/// and journal with `--user`.
+const USER_AWARE: [&str; 3] = ["journalctl", "systemd-analyze", "systemd-tmpfiles"];
+
+static TARGET: OnceLock<Target> = OnceLock::new();
+
+/// How far the clock of the target is ahead of this one, once read.
+static CLOCK_OFFSET: OnceLock<Option<i64>> = OnceLock::new();
+
+/// The manager whose units are shown: that of the system or of the user, on
+/// this machine or on a host reached with ssh, as `systemctl --user --host`.
+#[derive(Debug, Default)]
+pub struct Target {
+    pub user: bool,
+    pub host: Option<String>,
+}
+
+impl Target {
+    /// Must be called before anything is fetched; later calls are ignored.
+    pub fn set(target: Target) {
+        let _ = TARGET.set(target);
+    }
+
+    pub fn get() -> &'static Target {
+        TARGET.get_or_init(Target::default)
+    }
+
+    /// Bus the manager answers on. On another host it is the one
+    /// `systemd-stdio-bridge` relays over ssh, as `systemctl --host` does.
+    pub async fn connection(&self) -> zbus::Result<Connection> {
+        match &self.host {
+            Some(host) => {
+                debug!(host, user = self.user, "connecting through ssh");
+                let mut args: Vec<OsString> = [
+                    "-xT",
+                    "-oBatchMode=yes",
+                    // The bridge gets the terminal's stderr, where ssh would
+                    // write over the interface.
+                    "-oLogLevel=QUIET",
+                    "--",
+                    host,
+                    "systemd-stdio-bridge",
+                ]
+                .into_iter()
+                .map(OsString::from)
+                .collect();
+                if self.user {
+                    args.push("--user".into());
+                }
+                let ssh = Unixexec::new(PathBuf::from("ssh"), None, args);
+                Builder::address(Address::from(Transport::Unixexec(ssh)))?
+                    .build()
+                    .await
+            }
+            None if self.user => Connection::session().await,
+            None => Connection::system().await,
+        }
+    }
+
+    /// `command` as it has to run to act on the target: with `--user` for the
+    /// user's units, and through ssh for another host.
+    pub fn command(&self, command: &Command) -> Command {
+        let program = command.get_program();
+        let mut args: Vec<&OsStr> = command.get_args().collect();
+        if self.user && USER_AWARE.iter().any(|name| program == *name) {
+            args.insert(0, OsStr::new("--user"));
+        }
+        let Some(host) = &self.host else {
+            let mut local = Command::new(program);
+            local.args(args);
+            for (key, value) in command.get_envs() {
+                match value {
+                    Some(value) => local.env(key, value),
+                    None => local.env_remove(key),
+                };
+            }
+            debug!("running {:?}", local);
+            return local;
+        };
+
+        // ssh hands the remote shell a single line, so every word is quoted.
+        let mut line: Vec<String> = vec![];
+        let envs: Vec<String> = command
+            .get_envs()
+            .filter_map(|(key, value)| {
+                Some(format!(
+                    "{}={}",
+                    key.to_string_lossy(),
+                    value?.to_string_lossy()
+                ))
+            })
+            .collect();
+        if !envs.is_empty() {
+            line.push("env".to_string());
+            line.extend(envs.iter().map(|env| quote(env)));
+        }
+        line.push(quote(&program.to_string_lossy()));
+        line.extend(args.iter().map(|arg| quote(&arg.to_string_lossy())));
+
+        let mut remote = Command::new("ssh");
+        remote
+            .arg("-xT")
+            .arg("-oBatchMode=yes")
+            .arg("--")
+            .arg(host)
+            .arg(line.join(" "));
+        debug!("running {:?}", remote);
+        remote
+    }
+
+    /// Contents of the file at `path` on the target.
+    pub fn read_to_string(&self, path: &str) -> io::Result<String> {
+        if self.host.is_none() {
+            return std::fs::read_to_string(path);
+        }
+        let output = self
+            .command(Command::new("cat").arg("--").arg(path))
+            .output()?;
+        if !output.status.success() {
+            return Err(io::Error::other(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// When the file at `path` on the target was last modified.
+    pub fn modified(&self, path: &str) -> Option<SystemTime> {
+        if self.host.is_none() {
+            return std::fs::metadata(path).ok()?.modified().ok();
+        }
+        let output = self
+            .command(Command::new("stat").arg("-c").arg("%Y").arg("--").arg(path))
+            .output()
+            .ok()?;
+        let seconds: u64 = String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .parse()
+            .ok()?;
+        Some(SystemTime::UNIX_EPOCH + Duration::from_secs(seconds))
+    }
+
+    /// How far the clock of the target is ahead of this one, in microseconds,
+    /// negative when it is behind. Read once, with `date` on another host, and
+    /// `None` when it could not be.
+    pub fn clock_offset(&self) -> Option<i64> {
+        *CLOCK_OFFSET.get_or_init(|| self.read_clock_offset())
+    }
+
+    /// The clock offset if it was already read, without reading it.
+    pub fn known_clock_offset(&self) -> Option<i64> {
+        CLOCK_OFFSET.get().copied().flatten()
+    }
+
+    fn read_clock_offset(&self) -> Option<i64> {
+        if self.host.is_none() {
+            return Some(0);
+        }
+        let sent = now_usec();
+        let output = self
+            .command(Command::new("date").arg("+%s%6N"))
+            .output()
+            .ok()?;
+        let received = now_usec();
+        let remote: i64 = String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .parse()
+            .ok()?;
+        // Taken about halfway through the round trip, connecting included.
+        let offset = remote - (sent + received) / 2;
+        debug!(offset_usec = offset, "read the clock of the target");
+        Some(offset)
+    }
+
+    /// Whether `path` exists on the target.
+    pub fn exists(&self, path: &str) -> bool {
+        if self.host.is_none() {
+            return std::path::Path::new(path).exists();
+        }
+        self.command(Command::new("test").arg("-e").arg(path))
+            .output()
+            .is_ok_and(|output| output.status.success())
+    }
+}
+
+fn now_usec() -> i64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_micros() as i64)
+}
+
+/// `word` in single quotes for a POSIX shell.
+fn quote(word: &str) -> String {
+    format!("'{}'", word.replace('\'', r"'\''"))
+}
@@ -0,0 +1,53 @@
+use crate::domain::tmpfiles::TmpfilesEntry;
+
+/// Directory specifiers of `tmpfiles.d(5)` as they expand for the system
+/// instance; other specifiers are left as written.
+const SPECIFIERS: [(&str, &str); 8] = [
+    ("%t", "/run"),
+    ("%S", "/var/lib"),
+    ("%C", "/var/cache"),
+    ("%L", "/var/log"),
+    ("%E", "/etc"),
+    ("%T", "/tmp"),
+    ("%V", "/var/tmp"),
+    ("%%", "%"),
+];
+
+fn expand_specifiers(path: &str) -> String {
+    SPECIFIERS
+        .iter()
+        .fold(path.to_string(), |path, (specifier, value)| {
+            path.replace(specifier, value)
+        })
+}
+
+/// Parses the output of `systemd-tmpfiles --cat-config`, where each file is
+/// introduced by a `# /path/of/file.conf` comment.
+pub fn parse_cat_config(output: &str) -> Vec<TmpfilesEntry> {
+    let mut source = String::new();
+    let mut entries = vec![];
+    for line in output.lines() {
+        let line = line.trim();
+        if let Some(path) = line.strip_prefix("# /")
+            && path.ends_with(".conf")
+            && !path.contains(' ')
+        {
+            source = format!("/{}", path);
+            continue;
+        }
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        let (Some(kind), Some(path)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        entries.push(TmpfilesEntry::new(
+            source.clone(),
+            kind.to_string(),
+            expand_specifiers(path.trim_matches('"')),
+            fields.collect::<Vec<_>>().join(" "),
+        ));
+    }
+    entries
+}
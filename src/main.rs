@@ -1,19 +1,188 @@
+mod cli;
+mod config;
 mod domain;
 mod infrastructure;
+mod script;
 mod terminal;
 mod usecases;
-use terminal::app::App;
+use clap::Parser;
+use cli::{Cli, Command};
+use color_eyre::eyre::eyre;
+use config::Config;
+use infrastructure::synthetic_service_adapter::Dataset;
+use infrastructure::target::Target;
+use ratatui::layout::Rect;
+use ratatui::{TerminalOptions, Viewport};
+use signal_hook::consts::{SIGINT, SIGQUIT, SIGTERM};
+use signal_hook::iterator::Signals;
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::thread;
+use terminal::app::{Actions, App};
+use terminal::color::ColorSupport;
+use tracing::{Level, error, info};
+use usecases::plugins::Plugins;
+use usecases::services_manager::ServicesManager;
 
 fn main() -> color_eyre::Result<()> {
+    let cli = Cli::parse();
     // if unsafe { libc::geteuid() } != 0 {
     //     eprintln!("❌ This application must be run with sudo (as root).");
     //     std::process::exit(1);
     // }
     color_eyre::install()?;
-    let terminal = ratatui::init();
+    if let Some(Command::CheckConfig) = cli.command {
+        check_config();
+    }
+    let config = Config::load().map_err(|e| eyre!("Invalid config file: {}", e))?;
+    Config::init(config, cli.screen_reader || cli.plain);
+    if let Some(units) = cli.stress {
+        ServicesManager::use_synthetic_units(Dataset::Stress(units));
+    } else if cli.demo {
+        ServicesManager::use_synthetic_units(Dataset::Demo);
+    }
+    Target::set(Target {
+        user: cli.user,
+        host: cli.host.clone(),
+    });
+    let debug_log = if cli.debug {
+        Some(init_debug_log()?)
+    } else {
+        None
+    };
+    if let Some(command) = cli.command {
+        script::run(command);
+    }
+    // Before the terminal is taken over, for a plugin that hangs to be
+    // interruptible, and before the list is made, for it to have their columns.
+    let plugin_problems = Plugins::load();
+    let terminal = if cli.plain {
+        ColorSupport::set(ColorSupport::None);
+        // Unlike an inline viewport, a fixed one does not query the cursor
+        // position, which a captured session cannot answer.
+        let (columns, rows) = crossterm::terminal::size()?;
+        ratatui::try_init_with_options(TerminalOptions {
+            viewport: Viewport::Fixed(Rect::new(0, 0, columns, rows)),
+        })?
+    } else {
+        ratatui::init()
+    };
+    // A captured session has no mouse to report.
+    let mouse = Config::get().mouse && !cli.plain;
+    if mouse {
+        crossterm::execute!(std::io::stdout(), crossterm::event::EnableMouseCapture)?;
+    }
+    restore_terminal_on_exit(mouse);
     let mut app = App::new();
+    for problem in plugin_problems {
+        app.report(problem);
+    }
+    if let Some(filter) = &cli.filter {
+        app.filter_on_start(filter);
+    }
+    if let Some(unit) = cli.unit {
+        let name = cli::unit_name(unit);
+        let screen = if cli.log {
+            Actions::GoLog
+        } else {
+            Actions::GoDetails
+        };
+        app.open_on_start(name, screen);
+    } else if Config::get().dashboard {
+        app.dashboard_on_start();
+    }
     app.init();
     let result = app.run(terminal);
+    restore_terminal(mouse);
+    if let Some(path) = debug_log {
+        eprintln!("Debug log written to {}", path.display());
+    }
+    if let Some(summary) = result? {
+        print!("{}", summary);
+    }
+    Ok(())
+}
+
+/// Gives the shell its terminal back: the normal screen, line editing and no
+/// mouse reports.
+fn restore_terminal(mouse: bool) {
+    if mouse {
+        let _ = crossterm::execute!(std::io::stdout(), crossterm::event::DisableMouseCapture);
+    }
     ratatui::restore();
-    result
+}
+
+/// Restores the terminal before the process ends on a panic, in any thread, or
+/// on SIGTERM, SIGINT or SIGQUIT, which would otherwise leave the shell in raw
+/// mode on the alternate screen. A panic ends the process even in a worker
+/// thread, as the interface cannot be drawn once the terminal is restored.
+fn restore_terminal_on_exit(mouse: bool) {
+    let report = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        error!("{}", info);
+        restore_terminal(mouse);
+        report(info);
+        std::process::exit(101);
+    }));
+
+    if let Ok(mut signals) = Signals::new([SIGTERM, SIGINT, SIGQUIT]) {
+        thread::spawn(move || {
+            if let Some(signal) = signals.forever().next() {
+                info!(signal, "terminated by a signal");
+                restore_terminal(mouse);
+                std::process::exit(128 + signal);
+            }
+        });
+    }
+}
+
+/// Sends the tracing output to `debug.log` next to the config file, emptied on
+/// each start so that it only tells about the session being diagnosed.
+fn init_debug_log() -> color_eyre::Result<PathBuf> {
+    let dir = Config::dir().ok_or_else(|| eyre!("No config directory for the debug log"))?;
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join("debug.log");
+    let file = File::create(&path)?;
+    tracing_subscriber::fmt()
+        .with_writer(Mutex::new(file))
+        .with_max_level(Level::TRACE)
+        .with_thread_names(true)
+        .with_ansi(false)
+        .init();
+    info!(
+        version = env!("CARGO_PKG_VERSION"),
+        target = ?Target::get(),
+        scope = ServicesManager::scope(),
+        args = ?std::env::args().skip(1).collect::<Vec<_>>(),
+        "session started"
+    );
+    Ok(path)
+}
+
+/// Prints the problems of the config file and exits, with 1 if there are any.
+fn check_config() -> ! {
+    let Some(path) = Config::path().filter(|path| path.exists()) else {
+        println!("No config file, the defaults are used");
+        std::process::exit(0);
+    };
+    match Config::check(&path) {
+        Ok(problems) if problems.is_empty() => {
+            println!("{}: OK", path.display());
+            std::process::exit(0);
+        }
+        Ok(problems) => {
+            for (line, message) in problems {
+                match line {
+                    Some(line) => eprintln!("{}:{}: {}", path.display(), line, message),
+                    None => eprintln!("{}: {}", path.display(), message),
+                }
+            }
+            std::process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{}: {}", path.display(), e);
+            std::process::exit(1);
+        }
+    }
 }
@@ -0,0 +1,114 @@
+use serde_json::{Value, json};
+use std::error::Error;
+
+use crate::cli::{self, Command, Listing, UnitAction};
+use crate::domain::job_mode::JobMode;
+use crate::domain::service::Service;
+use crate::terminal::action_log::ActionLog;
+use crate::terminal::components::list::ServiceAction;
+use crate::usecases::runtime;
+use crate::usecases::services_manager::ServicesManager;
+
+/// Runs a subcommand without the interface, through the same use cases, and
+/// exits with 0 if it succeeded or 1 otherwise.
+pub fn run(command: Command) -> ! {
+    let result = runtime::block_on(async {
+        match command {
+            Command::CheckConfig => unreachable!("checked before the config is loaded"),
+            Command::List(listing) => list(listing, false).await,
+            Command::Failed(listing) => list(listing, true).await,
+            Command::Start(unit) => act(unit, ServiceAction::Start(JobMode::default())).await,
+            Command::Stop(unit) => act(unit, ServiceAction::Stop(JobMode::default())).await,
+            Command::Restart(unit) => act(unit, ServiceAction::Restart).await,
+            Command::Enable(unit) => act(unit, ServiceAction::Enable).await,
+            Command::Disable(unit) => act(unit, ServiceAction::Disable).await,
+        }
+    });
+    match result {
+        Ok(success) => std::process::exit(if success { 0 } else { 1 }),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+async fn list(listing: Listing, failed_only: bool) -> Result<bool, Box<dyn Error>> {
+    let services: Vec<Service> = ServicesManager::list_services()
+        .await?
+        .into_iter()
+        .filter(|service| !failed_only || service.state().active() == "failed")
+        .collect();
+    if listing.json {
+        let units: Vec<Value> = services.iter().map(to_json).collect();
+        println!("{}", serde_json::to_string_pretty(&units)?);
+    } else {
+        for service in &services {
+            let state = service.state();
+            println!(
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                service.name(),
+                state.load(),
+                state.active(),
+                state.sub(),
+                state.file(),
+                service.description()
+            );
+        }
+    }
+    Ok(true)
+}
+
+fn to_json(service: &Service) -> Value {
+    let state = service.state();
+    json!({
+        "name": service.name(),
+        "load": state.load(),
+        "active": state.active(),
+        "sub": state.sub(),
+        "enablement": state.file(),
+        "description": service.description(),
+    })
+}
+
+/// Takes `action` on the unit, waiting for the job it queues, and prints how
+/// it went.
+async fn act(unit: UnitAction, action: ServiceAction) -> Result<bool, Box<dyn Error>> {
+    let name = cli::unit_name(unit.unit);
+    let service = ServicesManager::list_services()
+        .await?
+        .into_iter()
+        .find(|service| service.name() == name)
+        .ok_or_else(|| format!("No unit named {}", name))?;
+
+    let label = action.log_label().unwrap_or_default();
+    let (result, success) = match action.run(&service).await {
+        Ok(job) => {
+            // As the list records it, once systemd took it.
+            ActionLog::load().record(&name, &label);
+            match job {
+                Some(job) => match ServicesManager::wait_for_job(&name, &job).await {
+                    Ok(result) => (result.describe().to_string(), result.is_success()),
+                    Err(e) => (e.to_string(), false),
+                },
+                None => ("done".to_string(), true),
+            }
+        }
+        Err(e) => (e.to_string(), false),
+    };
+
+    if unit.json {
+        let outcome = json!({
+            "unit": name,
+            "action": label,
+            "result": result,
+            "success": success,
+        });
+        println!("{}", outcome);
+    } else if success {
+        println!("{} {}: {}", label, name, result);
+    } else {
+        eprintln!("{} {}: {}", label, name, result);
+    }
+    Ok(success)
+}
@@ -0,0 +1,145 @@
+use chrono::{DateTime, Local};
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use crate::config::Config;
+use crate::terminal::components::log::expand_home;
+
+const FILE_NAME: &str = "actions.log";
+
+/// An action taken on a unit through this tool.
+#[derive(Clone)]
+pub struct LoggedAction {
+    at: DateTime<Local>,
+    user: String,
+    action: String,
+}
+
+impl LoggedAction {
+    pub fn at(&self) -> DateTime<Local> {
+        self.at
+    }
+
+    pub fn user(&self) -> &str {
+        &self.user
+    }
+
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    /// How long ago the action was taken, none if it is dated ahead.
+    pub fn age(&self) -> Option<Duration> {
+        (Local::now() - self.at).to_std().ok()
+    }
+}
+
+/// Actions taken on units from the list, one per line as
+/// `TIME<tab>USER<tab>UNIT<tab>ACTION`, in the file set with `action_log`.
+/// Only the last action of each unit is kept in memory.
+pub struct ActionLog {
+    last: HashMap<String, LoggedAction>,
+    /// Actions recorded by this session, oldest first, with their unit.
+    session: Vec<(String, LoggedAction)>,
+    path: Option<PathBuf>,
+    /// When the file was last read, to read it again only when someone else
+    /// wrote to it.
+    modified: Option<SystemTime>,
+}
+
+impl ActionLog {
+    pub fn load() -> Self {
+        let path = match &Config::get().action_log {
+            Some(path) => Some(expand_home(path)),
+            None => Config::dir().map(|dir| dir.join(FILE_NAME)),
+        };
+        let mut log = Self {
+            last: HashMap::new(),
+            session: vec![],
+            path,
+            modified: None,
+        };
+        log.reload();
+        log
+    }
+
+    /// Reads the file again if it changed since it was last read.
+    pub fn reload(&mut self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let modified = fs::metadata(path).and_then(|meta| meta.modified()).ok();
+        if modified.is_none() || modified == self.modified {
+            return;
+        }
+        let Ok(content) = fs::read_to_string(path) else {
+            return;
+        };
+        self.modified = modified;
+        self.last = content
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(4, '\t');
+                let at = DateTime::parse_from_rfc3339(fields.next()?).ok()?;
+                let user = fields.next()?.to_string();
+                let unit = fields.next()?.to_string();
+                let action = fields.next()?.to_string();
+                Some((
+                    unit,
+                    LoggedAction {
+                        at: at.with_timezone(&Local),
+                        user,
+                        action,
+                    },
+                ))
+            })
+            .collect();
+    }
+
+    pub fn last(&self, unit: &str) -> Option<&LoggedAction> {
+        self.last.get(unit)
+    }
+
+    /// Actions taken since the program started, oldest first, with their unit.
+    pub fn session(&self) -> &[(String, LoggedAction)] {
+        &self.session
+    }
+
+    /// Appends `action` on `unit` by the current user. Failing to write the
+    /// file only loses the entry for other sessions.
+    pub fn record(&mut self, unit: &str, action: &str) {
+        // Who ran sudo rather than root.
+        let user = std::env::var("SUDO_USER")
+            .or_else(|_| std::env::var("USER"))
+            .unwrap_or_else(|_| "unknown".to_string());
+        let logged = LoggedAction {
+            at: Local::now(),
+            user,
+            action: action.to_string(),
+        };
+        if let Some(path) = &self.path {
+            let line = format!(
+                "{}\t{}\t{}\t{}\n",
+                logged.at.to_rfc3339(),
+                logged.user,
+                unit,
+                logged.action
+            );
+            let _ = path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| {
+                    OpenOptions::new()
+                        .create(true)
+                        .append(true)
+                        .open(path)?
+                        .write_all(line.as_bytes())
+                });
+        }
+        self.session.push((unit.to_string(), logged.clone()));
+        self.last.insert(unit.to_string(), logged);
+    }
+}
@@ -1,100 +1,593 @@
+use chrono::{DateTime, Local};
 use color_eyre::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
-use ratatui::layout::{Alignment, Constraint, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
+    MouseEventKind,
+};
+use ratatui::layout::{Alignment, Constraint, Flex, Layout, Rect};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 use ratatui::DefaultTerminal;
 use ratatui::Frame;
-use std::sync::mpsc::{self, Receiver, Sender};
+use signal_hook::consts::SIGHUP;
+use signal_hook::iterator::Signals;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use tracing::{debug, trace, warn};
 
 use std::cell::RefCell;
 use std::rc::Rc;
 
-use super::components::details::ServiceDetails;
+use super::color;
+use super::components::boot_times::BootTimes;
+use super::components::columns::ColumnChooser;
+use super::components::component::Component;
+use super::components::dashboard::Dashboard;
+use super::components::details::UnitDetails;
+use super::components::early_boot::EarlyBootUnits;
 use super::components::filter::Filter;
-use super::components::list::TableServices;
+use super::components::help::Help;
+use super::components::job_conflict::JobConflictPopup;
+use super::components::job_failure::JobFailurePopup;
+use super::components::job_mode::JobModeMenu;
+use super::components::journal_search::JournalSearch;
+use super::components::list::{ServiceAction, TableServices};
+use super::components::lock::LockScreen;
 use super::components::log::ServiceLog;
-
-#[derive(PartialEq)]
-enum Status {
-    List,
-    Log,
-    Details,
-}
+use super::components::plugin_view::PluginView;
+use super::components::prompt::{Prompt, PromptKind};
+use super::components::recent::RecentUnits;
+use super::components::security::ServiceSecurity;
+use super::components::status_bar::StatusBar;
+use super::components::stop_impact::StopImpact;
+use super::components::tmpfiles::UnitTmpfiles;
+use super::components::toasts::Toasts;
+use super::components::triage::Triage;
+use super::components::unit_menu::UnitMenu;
+use super::i18n::{tr, tr_args};
+use super::keymap;
+use super::mouse;
+use super::reducer::{Effect, Status, reduce};
+use super::store::{DataKey, Fetched, Store};
+use super::summary::SessionSummary;
+use super::theme::Theme;
+use crate::config::{Column, Config};
+use crate::domain::access_control::AccessControl;
+use crate::domain::boot_time::{BootTime, ChainLink};
+use crate::domain::early_boot::EarlyBoot;
+use crate::domain::job_conflict::{JobConflict, QueuedJob};
+use crate::domain::job_result::JobResult;
+use crate::domain::journal_search::UnitHits;
+use crate::domain::log_entry::LogEntry;
+use crate::domain::overview::Overview;
+use crate::domain::security_report::SecurityReport;
+use crate::domain::service::Service;
+use crate::domain::stop_impact::ImpactedUnit;
+use crate::domain::tmpfiles::Tmpfiles;
+use crate::domain::triage::TriageFacts;
+use crate::usecases::plugins::PluginExtension;
+use crate::usecases::runtime;
+use crate::usecases::services_manager::ServicesManager;
 
 pub enum Actions {
     RefreshLog,
-    RefreshDetails,
+    /// Systemd reported a change to the units, added, removed or changed.
+    UnitsChanged(Vec<String>),
+    /// `SystemState` of the manager, or why it could not be read.
+    UpdateSystemState(Result<String, String>),
     GoList,
     GoLog,
+    GoKernelLog,
     GoDetails,
-    Updatelog((String, String)),
-    UpdateDetails,
+    /// Shows the properties and the log of the selected unit together.
+    GoSplit,
+    GoSecurity,
+    GoEarlyBoot,
+    GoBootTimes,
+    GoDashboard,
+    GoTriage,
+    GoTmpfiles,
+    GoPluginView(PluginExtension),
+    Fetched(Fetched),
+    Invalidated(DataKey),
+    UpdateSecurity(Result<SecurityReport, String>),
+    UpdateAccessControl(AccessControl),
+    UpdateEarlyBoot(EarlyBoot),
+    UpdateBootTimes(Vec<BootTime>),
+    UpdateCriticalChain(Vec<ChainLink>),
+    UpdateOverview(Overview),
+    /// Opens the properties of a unit found by another view, like the boot
+    /// times.
+    OpenDetailsOf(String),
+    UpdateTriage(TriageFacts),
+    UpdateTmpfiles(Tmpfiles),
+    /// Output of `systemd-tmpfiles`, or why it failed.
+    UpdateTmpfilesRun(Result<String, String>),
+    UpdatePluginView(Result<String, String>),
+    UpdateJournalSearch(Vec<UnitHits>),
+    /// Opens the log of a unit found by the journal search.
+    OpenSearchHit(String),
     Filter(String),
     UpdateIgnoreListKeys(bool),
+    OpenPrompt(PromptKind),
+    SubmitPrompt(PromptKind, String),
+    OpenRecentUnits,
+    OpenColumnChooser,
+    ToggleColumn(Column),
+    SelectUnit(String),
+    /// Stops or restarts the selected unit once the units going down with it
+    /// are confirmed.
+    OpenStopImpact(ServiceAction),
+    /// Units going down with the given one, or why they could not be listed.
+    UpdateStopImpact(String, Result<Vec<ImpactedUnit>, String>),
+    RunServiceAction(String, ServiceAction),
+    RunPluginAction(String, PluginExtension),
+    /// Starts or stops the selected unit with a chosen job mode.
+    OpenJobModeMenu,
+    /// Lists what can be done to the selected unit.
+    OpenUnitMenu,
+    /// The action was refused on the unit because of a queued job.
+    OpenJobConflict(String, ServiceAction, JobConflict),
+    /// Jobs queued when the action was refused on the unit, or why they could
+    /// not be listed.
+    UpdateJobConflict(String, Result<Vec<QueuedJob>, String>),
+    ReloadConfig,
+    /// Fetches the unit list again unless a fetch is already running.
+    RefreshUnits,
+    /// A support bundle is written, at the given path if it succeeded.
+    SupportBundleWritten(Option<String>),
+    /// Systemd took the action on the unit, queuing the job at the given
+    /// object path if it queues one, or refused it.
+    UnitActionDone(String, ServiceAction, Result<Option<String>, String>),
+    PluginActionDone(String, PluginExtension, Result<String, String>),
+    /// An instance of the template was started, or could not be.
+    TemplateInstantiated(String, String, Result<(), String>),
+    /// A job queued from the list is still running.
+    AnimateJobs,
+    /// The job at the given object path, queued on the unit, ended with the
+    /// result, or could not be waited for.
+    JobFinished(String, String, Result<JobResult, String>),
+    /// A job on the unit, queued at the given time for the action, did not
+    /// succeed.
+    OpenJobFailure(Box<Service>, String, JobResult, DateTime<Local>),
+    /// Entries the unit logged during its failed job, or why they could not
+    /// be read.
+    UpdateJobFailure(String, Result<Vec<LogEntry>, String>),
+}
+
+impl Actions {
+    /// Name of the action, for the debug log; what it carries may be the whole
+    /// unit list.
+    fn name(&self) -> &'static str {
+        match self {
+            Actions::RefreshLog => "RefreshLog",
+            Actions::UnitsChanged(..) => "UnitsChanged",
+            Actions::UpdateSystemState(..) => "UpdateSystemState",
+            Actions::GoList => "GoList",
+            Actions::GoLog => "GoLog",
+            Actions::GoKernelLog => "GoKernelLog",
+            Actions::GoDetails => "GoDetails",
+            Actions::GoSplit => "GoSplit",
+            Actions::GoSecurity => "GoSecurity",
+            Actions::GoEarlyBoot => "GoEarlyBoot",
+            Actions::GoBootTimes => "GoBootTimes",
+            Actions::GoDashboard => "GoDashboard",
+            Actions::GoTriage => "GoTriage",
+            Actions::GoTmpfiles => "GoTmpfiles",
+            Actions::GoPluginView(..) => "GoPluginView",
+            Actions::Fetched(..) => "Fetched",
+            Actions::Invalidated(..) => "Invalidated",
+            Actions::UpdateSecurity(..) => "UpdateSecurity",
+            Actions::UpdateAccessControl(..) => "UpdateAccessControl",
+            Actions::UpdateEarlyBoot(..) => "UpdateEarlyBoot",
+            Actions::UpdateBootTimes(..) => "UpdateBootTimes",
+            Actions::UpdateCriticalChain(..) => "UpdateCriticalChain",
+            Actions::UpdateOverview(..) => "UpdateOverview",
+            Actions::OpenDetailsOf(..) => "OpenDetailsOf",
+            Actions::UpdateTriage(..) => "UpdateTriage",
+            Actions::UpdateTmpfiles(..) => "UpdateTmpfiles",
+            Actions::UpdateTmpfilesRun(..) => "UpdateTmpfilesRun",
+            Actions::UpdatePluginView(..) => "UpdatePluginView",
+            Actions::UpdateJournalSearch(..) => "UpdateJournalSearch",
+            Actions::OpenSearchHit(..) => "OpenSearchHit",
+            Actions::Filter(..) => "Filter",
+            Actions::UpdateIgnoreListKeys(..) => "UpdateIgnoreListKeys",
+            Actions::OpenPrompt(..) => "OpenPrompt",
+            Actions::SubmitPrompt(..) => "SubmitPrompt",
+            Actions::OpenRecentUnits => "OpenRecentUnits",
+            Actions::OpenColumnChooser => "OpenColumnChooser",
+            Actions::ToggleColumn(..) => "ToggleColumn",
+            Actions::SelectUnit(..) => "SelectUnit",
+            Actions::OpenStopImpact(..) => "OpenStopImpact",
+            Actions::UpdateStopImpact(..) => "UpdateStopImpact",
+            Actions::RunServiceAction(..) => "RunServiceAction",
+            Actions::RunPluginAction(..) => "RunPluginAction",
+            Actions::OpenJobModeMenu => "OpenJobModeMenu",
+            Actions::OpenUnitMenu => "OpenUnitMenu",
+            Actions::OpenJobConflict(..) => "OpenJobConflict",
+            Actions::UpdateJobConflict(..) => "UpdateJobConflict",
+            Actions::ReloadConfig => "ReloadConfig",
+            Actions::RefreshUnits => "RefreshUnits",
+            Actions::SupportBundleWritten(..) => "SupportBundleWritten",
+            Actions::UnitActionDone(..) => "UnitActionDone",
+            Actions::PluginActionDone(..) => "PluginActionDone",
+            Actions::TemplateInstantiated(..) => "TemplateInstantiated",
+            Actions::AnimateJobs => "AnimateJobs",
+            Actions::JobFinished(..) => "JobFinished",
+            Actions::OpenJobFailure(..) => "OpenJobFailure",
+            Actions::UpdateJobFailure(..) => "UpdateJobFailure",
+        }
+    }
 }
 
 pub enum AppEvent {
     Key(KeyEvent),
+    /// A click or a turn of the wheel; moves are not reported.
+    Mouse(MouseEvent),
     Action(Actions),
     Error(String),
+    /// Something that went well, told in passing.
+    Notice(String),
+    /// The terminal window is now this many columns and rows.
+    Resize(u16, u16),
+}
+
+impl AppEvent {
+    /// What happened, in a few words for the debug log.
+    fn describe(&self) -> String {
+        match self {
+            AppEvent::Key(key) => format!("key {:?} {:?}", key.code, key.modifiers),
+            AppEvent::Mouse(mouse) => format!("mouse {:?}", mouse.kind),
+            AppEvent::Action(action) => format!("action {}", action.name()),
+            AppEvent::Error(e) => format!("error {}", e),
+            AppEvent::Notice(notice) => format!("notice {}", notice),
+            AppEvent::Resize(columns, rows) => format!("resize {}x{}", columns, rows),
+        }
+    }
 }
 
-fn get_user_friendly_error(error: &str) -> &str {
+pub fn get_user_friendly_error(error: &str) -> String {
     if error.contains("org.freedesktop.DBus.Error.InteractiveAuthorizationRequired") {
-        "You do not have the permission to do that. Try running the program with sudo."
+        tr("error-authorization-required")
     } else if error.contains("org.freedesktop.DBus.Error.ServiceUnknown") {
-        "The requested service is not available or not running."
+        tr("error-service-unknown")
     } else if error.contains("org.freedesktop.DBus.Error.NoReply") {
-        "The service did not respond in time. It might be busy or not functioning properly."
+        tr("error-no-reply")
     } else if error.contains("org.freedesktop.DBus.Error.AccessDenied") {
-        "Access denied. You don't have sufficient permissions for this operation."
+        tr("error-access-denied")
     } else if error.contains("org.freedesktop.systemd1.NoSuchUnit") {
-        "The requested service unit doesn't exist."
+        tr("error-no-such-unit")
     } else {
         // Default fallback for unknown errors
-        error
+        error.to_string()
     }
 }
+
+/// Keys of the split view itself, on top of those of the pane in focus.
+fn split_shortcuts() -> Line<'static> {
+    Line::from(tr("split-shortcuts"))
+}
+
+/// Keys working on every screen, shown under the shortcuts of the screen.
+fn global_shortcuts() -> Line<'static> {
+    Line::from(vec![
+        Span::styled(
+            tr("global-exit"),
+            Style::default()
+                .fg(Theme::current().error)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(": Ctrl + c | "),
+        Span::styled(
+            tr("global-lock"),
+            Style::default()
+                .fg(Theme::current().warning)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(": Ctrl + l | "),
+        Span::styled(
+            tr("global-reload-config"),
+            Style::default()
+                .fg(Theme::current().warning)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(": Ctrl + r | "),
+        Span::styled(
+            tr("global-help"),
+            Style::default()
+                .fg(Theme::current().warning)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(": ?"),
+    ])
+}
+
+fn is_quit_key(key: KeyEvent) -> bool {
+    key.modifiers == KeyModifiers::CONTROL
+        && matches!(key.code, KeyCode::Char('c') | KeyCode::Char('C'))
+}
+
+/// Popup listing what is still in flight, asking whether to quit anyway.
+fn draw_quit_confirmation(frame: &mut Frame, area: Rect, pending: &[String]) {
+    let width = area.width.saturating_sub(4).min(70);
+    let height = (pending.len() as u16 + 6).min(area.height);
+    let popup_area = Rect::new(
+        area.x + (area.width.saturating_sub(width)) / 2,
+        area.y + (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    );
+
+    let mut text = vec![Line::from(tr("quit-still-running")), Line::from("")];
+    text.extend(
+        pending
+            .iter()
+            .map(|operation| Line::from(format!("• {}", operation))),
+    );
+    text.push(Line::from(""));
+    text.push(Line::from(vec![Span::styled(
+        tr("quit-shortcuts"),
+        Style::default().fg(Theme::current().muted),
+    )]));
+
+    let popup = Paragraph::new(text).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::current().popup_border))
+            .title(format!(" {} ", tr("quit-title"))),
+    );
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup, popup_area);
+}
+
+/// Tells how small the window is, and how large it has to get.
+fn draw_too_small(frame: &mut Frame, area: Rect) {
+    let text = tr_args(
+        "too-small",
+        &[
+            ("width", &area.width.to_string()),
+            ("height", &area.height.to_string()),
+            ("min_width", &MIN_WIDTH.to_string()),
+            ("min_height", &MIN_HEIGHT.to_string()),
+        ],
+    );
+    let [centered] = Layout::vertical([Constraint::Length(2)])
+        .flex(Flex::Center)
+        .areas(area);
+    frame.render_widget(
+        Paragraph::new(text)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        centered,
+    );
+}
+
 fn spawn_key_event_listener(event_tx: Sender<AppEvent>) {
     thread::spawn(move || {
         loop {
-            if event::poll(Duration::from_millis(100)).unwrap_or(false) {
-                if let Ok(Event::Key(key_event)) = event::read() {
-                    if key_event.kind == KeyEventKind::Press
-                        && event_tx.send(AppEvent::Key(key_event)).is_err()
-                    {
-                        break;
-                    }
+            if !event::poll(Duration::from_millis(100)).unwrap_or(false) {
+                continue;
+            }
+            let app_event = match event::read() {
+                Ok(Event::Key(key_event)) if key_event.kind == KeyEventKind::Press => {
+                    AppEvent::Key(key_event)
                 }
+                Ok(Event::Mouse(mouse_event))
+                    if matches!(
+                        mouse_event.kind,
+                        MouseEventKind::Down(_)
+                            | MouseEventKind::ScrollDown
+                            | MouseEventKind::ScrollUp
+                    ) =>
+                {
+                    AppEvent::Mouse(mouse_event)
+                }
+                Ok(Event::Resize(columns, rows)) => AppEvent::Resize(columns, rows),
+                _ => continue,
+            };
+            if event_tx.send(app_event).is_err() {
+                break;
             }
         }
     });
 }
+/// Reloads the config file on SIGHUP, like daemons do.
+fn spawn_reload_signal_listener(event_tx: Sender<AppEvent>) {
+    let Ok(mut signals) = Signals::new([SIGHUP]) else {
+        return;
+    };
+    thread::spawn(move || {
+        for _ in signals.forever() {
+            if event_tx
+                .send(AppEvent::Action(Actions::ReloadConfig))
+                .is_err()
+            {
+                break;
+            }
+        }
+    });
+}
+
+/// Signals arriving this long after the first of a burst are still fetched
+/// with it, as one action makes systemd emit several.
+const UNIT_CHANGES_SETTLE: Duration = Duration::from_millis(200);
+
+/// Fetches the units again as soon as systemd reports a change to them.
+fn spawn_unit_watcher(event_tx: Sender<AppEvent>) {
+    thread::spawn(move || {
+        let changes = match runtime::block_on(ServicesManager::watch_units()) {
+            Ok(changes) => changes,
+            Err(e) => {
+                warn!("units are not watched, only refreshed: {}", e);
+                return;
+            }
+        };
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            for name in changes {
+                if tx.send(name).is_err() {
+                    break;
+                }
+            }
+        });
+        while let Ok(first) = rx.recv() {
+            let deadline = Instant::now() + UNIT_CHANGES_SETTLE;
+            let mut names = vec![first];
+            while let Some(left) = deadline.checked_duration_since(Instant::now())
+                && let Ok(name) = rx.recv_timeout(left)
+            {
+                names.push(name);
+            }
+            names.sort();
+            names.dedup();
+            debug!(units = names.len(), "units changed");
+            if event_tx
+                .send(AppEvent::Action(Actions::UnitsChanged(names)))
+                .is_err()
+            {
+                break;
+            }
+        }
+    });
+}
+
+/// Asks for the unit list again every `refresh_seconds`, read anew each time
+/// so that reloading the config turns it on or off.
+fn spawn_refresh_timer(event_tx: Sender<AppEvent>) {
+    thread::spawn(move || {
+        loop {
+            let interval = Config::get().refresh_interval();
+            thread::sleep(interval.unwrap_or(Duration::from_secs(1)));
+            if interval.is_some()
+                && event_tx
+                    .send(AppEvent::Action(Actions::RefreshUnits))
+                    .is_err()
+            {
+                break;
+            }
+        }
+    });
+}
+
+/// Width from which the split view shows its panes side by side rather than
+/// stacked.
+const SPLIT_MIN_WIDTH: u16 = 160;
+
+/// Size below which the screens would overlap, the shortcuts and status bar
+/// taking most of the rows; a placeholder is drawn instead.
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 12;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Pane {
+    Details,
+    Log,
+}
+
 pub struct App<'a> {
     running: bool,
     status: Status,
     table_service: Rc<RefCell<TableServices<'a>>>,
     filter: Rc<RefCell<Filter>>,
-    service_log: Rc<RefCell<ServiceLog<'a>>>,
-    details: Rc<RefCell<ServiceDetails>>,
+    service_log: Rc<RefCell<ServiceLog>>,
+    details: Rc<RefCell<UnitDetails>>,
+    security: Rc<RefCell<ServiceSecurity>>,
+    early_boot: Rc<RefCell<EarlyBootUnits>>,
+    boot_times: Rc<RefCell<BootTimes>>,
+    dashboard: Rc<RefCell<Dashboard>>,
+    triage: Rc<RefCell<Triage>>,
+    journal_search: Rc<RefCell<JournalSearch>>,
+    tmpfiles: Rc<RefCell<UnitTmpfiles>>,
+    plugin_view: Rc<RefCell<PluginView>>,
+    prompt: Rc<RefCell<Prompt>>,
+    recent_units: Rc<RefCell<RecentUnits>>,
+    column_chooser: Rc<RefCell<ColumnChooser>>,
+    stop_impact: Rc<RefCell<StopImpact>>,
+    job_mode_menu: Rc<RefCell<JobModeMenu>>,
+    job_conflict: Rc<RefCell<JobConflictPopup>>,
+    job_failure: Rc<RefCell<JobFailurePopup>>,
+    unit_menu: Rc<RefCell<UnitMenu>>,
+    help: Rc<RefCell<Help>>,
+    toasts: Rc<RefCell<Toasts>>,
+    status_bar: Rc<RefCell<StatusBar>>,
+    lock_screen: Rc<RefCell<LockScreen>>,
+    /// When a key was last pressed, to lock the screen after `auto_lock_minutes`.
+    last_input: Instant,
+    /// Where the shortcuts box was last drawn, and the symbol of each of its
+    /// cells by row, for clicks on its hints.
+    shortcuts_area: Rect,
+    shortcut_cells: Vec<Vec<String>>,
+    /// Size of the window once resized, applied at the next draw.
+    resized: Option<Rect>,
+    store: Rc<RefCell<Store>>,
+    /// Operations still in flight when Ctrl + c was pressed, waiting for the
+    /// user to confirm quitting anyway.
+    quit_confirmation: Option<Vec<String>>,
+    /// Pane of the split view that gets the keys.
+    split_focus: Pane,
+    /// Unit to select once the list is loaded, and the screen to open it in.
+    opening: Option<(String, Actions)>,
     event_rx: Receiver<AppEvent>,
     event_tx: Sender<AppEvent>,
 }
 
-impl App<'_> {
+impl<'a> App<'a> {
     pub fn new() -> Self {
         let (event_tx, event_rx) = mpsc::channel::<AppEvent>();
+        let store = Rc::new(RefCell::new(Store::new(event_tx.clone())));
         Self {
             running: true,
             status: Status::List,
-            table_service: Rc::new(RefCell::new(TableServices::new(event_tx.clone()))),
+            table_service: Rc::new(RefCell::new(TableServices::new(
+                event_tx.clone(),
+                store.clone(),
+            ))),
             filter: Rc::new(RefCell::new(Filter::new(event_tx.clone()))),
-            service_log: Rc::new(RefCell::new(ServiceLog::new(event_tx.clone()))),
-            details: Rc::new(RefCell::new(ServiceDetails::new(event_tx.clone()))),
+            service_log: Rc::new(RefCell::new(ServiceLog::new(
+                event_tx.clone(),
+                store.clone(),
+            ))),
+            details: Rc::new(RefCell::new(UnitDetails::new(
+                event_tx.clone(),
+                store.clone(),
+            ))),
+            security: Rc::new(RefCell::new(ServiceSecurity::new(event_tx.clone()))),
+            early_boot: Rc::new(RefCell::new(EarlyBootUnits::new(event_tx.clone()))),
+            boot_times: Rc::new(RefCell::new(BootTimes::new(event_tx.clone()))),
+            dashboard: Rc::new(RefCell::new(Dashboard::new(
+                event_tx.clone(),
+                store.clone(),
+            ))),
+            triage: Rc::new(RefCell::new(Triage::new(event_tx.clone()))),
+            journal_search: Rc::new(RefCell::new(JournalSearch::new(event_tx.clone()))),
+            tmpfiles: Rc::new(RefCell::new(UnitTmpfiles::new(event_tx.clone()))),
+            plugin_view: Rc::new(RefCell::new(PluginView::new(event_tx.clone()))),
+            prompt: Rc::new(RefCell::new(Prompt::new(event_tx.clone()))),
+            recent_units: Rc::new(RefCell::new(RecentUnits::new(event_tx.clone()))),
+            column_chooser: Rc::new(RefCell::new(ColumnChooser::new(event_tx.clone()))),
+            stop_impact: Rc::new(RefCell::new(StopImpact::new(event_tx.clone()))),
+            job_mode_menu: Rc::new(RefCell::new(JobModeMenu::new(event_tx.clone()))),
+            job_conflict: Rc::new(RefCell::new(JobConflictPopup::new(event_tx.clone()))),
+            job_failure: Rc::new(RefCell::new(JobFailurePopup::new(event_tx.clone()))),
+            unit_menu: Rc::new(RefCell::new(UnitMenu::new(event_tx.clone()))),
+            help: Rc::new(RefCell::new(Help::default())),
+            toasts: Rc::new(RefCell::new(Toasts::default())),
+            status_bar: Rc::new(RefCell::new(StatusBar::new(
+                event_tx.clone(),
+                store.clone(),
+            ))),
+            lock_screen: Rc::new(RefCell::new(LockScreen::default())),
+            last_input: Instant::now(),
+            shortcuts_area: Rect::default(),
+            shortcut_cells: vec![],
+            resized: None,
+            store,
+            quit_confirmation: None,
+            split_focus: Pane::Details,
+            opening: None,
             event_rx,
             event_tx,
         }
@@ -102,213 +595,649 @@ impl App<'_> {
 
     pub fn init(&mut self) {
         spawn_key_event_listener(self.event_tx.clone());
+        spawn_reload_signal_listener(self.event_tx.clone());
+        spawn_refresh_timer(self.event_tx.clone());
+        spawn_unit_watcher(self.event_tx.clone());
+        self.store.borrow_mut().fetch_units();
     }
 
-    pub fn run(mut self, mut terminal: DefaultTerminal) -> Result<()> {
-        self.running = true;
+    /// Shows `problem` as an error once the interface starts.
+    pub fn report(&mut self, problem: String) {
+        self.toasts.borrow_mut().error(problem);
+    }
 
-        let table_service = Rc::clone(&self.table_service);
-        let filter = Rc::clone(&self.filter);
-        let log = Rc::clone(&self.service_log);
-        let details = Rc::clone(&self.details);
+    /// Starts with the list filtered by `filter`, as if it had been typed.
+    pub fn filter_on_start(&mut self, filter: &str) {
+        self.filter.borrow_mut().set_input(filter);
+    }
+
+    /// Opens the unit called `name` in `screen` (`GoDetails`, `GoLog`...) as
+    /// soon as the list is loaded.
+    pub fn open_on_start(&mut self, name: String, screen: Actions) {
+        self.opening = Some((name, screen));
+    }
+
+    /// Starts on the dashboard rather than the unit list.
+    pub fn dashboard_on_start(&mut self) {
+        self.status = Status::Dashboard;
+        self.dashboard.borrow_mut().fetch_and_dispatch();
+    }
+
+    /// Runs until quit, returning what the session changed when `exit_summary`
+    /// is set.
+    pub fn run(mut self, mut terminal: DefaultTerminal) -> Result<Option<SessionSummary>> {
+        self.running = true;
+        let mut last_draw: Option<Instant> = None;
+        let mut dirty = true;
 
         while self.running {
-            match self.status {
-                Status::Log => self.draw_log_status(&mut terminal, &log)?,
-                Status::List => self.draw_list_status(&mut terminal, &filter, &table_service)?,
-                Status::Details => self.draw_details_status(&mut terminal, &details)?,
+            if self.idle_time_left() == Some(Duration::ZERO) {
+                self.lock_screen.borrow_mut().lock();
+                dirty = true;
             }
 
-            match self.event_rx.recv()? {
-                AppEvent::Key(key) => match self.status {
-                    Status::Log => {
-                        self.on_key_event(key);
-                        self.service_log.borrow_mut().on_key_event(key)
-                    }
-                    Status::List => {
-                        self.on_key_event(key);
-                        self.table_service.borrow_mut().on_key_event(key);
-                        self.filter.borrow_mut().on_key_event(key);
-                    }
-                    Status::Details => {
-                        self.on_key_event(key);
-                        self.details.borrow_mut().on_key_event(key);
-                    }
-                },
-                AppEvent::Action(Actions::UpdateIgnoreListKeys(bool)) => {
-                    self.table_service.borrow_mut().set_ignore_key_events(bool);
-                }
-                AppEvent::Action(Actions::Filter(input)) => {
-                    self.table_service.borrow_mut().set_selected_index(0);
-                    self.table_service.borrow_mut().refresh(input);
+            // Events arriving faster than the frame rate are all handled before the
+            // next draw, so a burst of updates only costs one redraw.
+            let until_next_frame = last_draw
+                .map(|drawn| {
+                    Config::get()
+                        .frame_interval()
+                        .saturating_sub(drawn.elapsed())
+                })
+                .unwrap_or_default();
+            if dirty && until_next_frame.is_zero() {
+                let started = Instant::now();
+                self.draw(&mut terminal)?;
+                trace!(elapsed = ?started.elapsed(), "drawn");
+                last_draw = Some(Instant::now());
+                dirty = false;
+            }
+
+            let event = if dirty {
+                match self.event_rx.recv_timeout(until_next_frame) {
+                    Ok(event) => event,
+                    Err(RecvTimeoutError::Timeout) => continue,
+                    Err(RecvTimeoutError::Disconnected) => break,
                 }
-                AppEvent::Action(Actions::Updatelog(log)) => {
-                    self.service_log.borrow_mut().update(log.0, log.1);
+            } else if let Some(wake_up) = self.time_to_wake_up() {
+                match self.event_rx.recv_timeout(wake_up) {
+                    Ok(event) => event,
+                    // A toast is due to go away, or the screen to lock.
+                    Err(RecvTimeoutError::Timeout) => {
+                        dirty = true;
+                        continue;
+                    }
+                    Err(RecvTimeoutError::Disconnected) => break,
                 }
-                AppEvent::Action(Actions::RefreshLog) => {
-                    if self.status == Status::Log {
-                        if let Some(service) =
-                            self.table_service.borrow_mut().get_selected_service()
-                        {
-                            self.service_log
-                                .borrow_mut()
-                                .fetch_log_and_dispatch(service.clone());
-                        }
+            } else {
+                self.event_rx.recv()?
+            };
+            let started = Instant::now();
+            let described = event.describe();
+            let changed = self.handle_event(event)?;
+            debug!(elapsed = ?started.elapsed(), changed, "{}", described);
+            dirty |= changed;
+        }
+
+        Ok(Config::get().exit_summary.then(|| self.summary()))
+    }
+
+    fn summary(&self) -> SessionSummary {
+        let actions = self.table_service.borrow().session_actions().to_vec();
+        let failed = self.store.borrow().units().map_or(vec![], |units| {
+            units
+                .value()
+                .iter()
+                .filter(|service| service.state().active() == "failed")
+                .map(|service| service.name().to_string())
+                .collect()
+        });
+        // Left out when systemd cannot be asked.
+        let pending_reloads =
+            runtime::block_on(ServicesManager::get_units_needing_reload()).unwrap_or_default();
+        SessionSummary::new(actions, failed, pending_reloads)
+    }
+
+    /// Handles `event`, returning whether what is shown may have changed, for
+    /// the screen not to be drawn again after a refresh bringing nothing new.
+    fn handle_event(&mut self, event: AppEvent) -> Result<bool> {
+        if let AppEvent::Key(_) | AppEvent::Mouse(_) = event {
+            self.last_input = Instant::now();
+        }
+
+        match event {
+            AppEvent::Key(key) if self.lock_screen.borrow().is_locked() => {
+                self.lock_screen.borrow_mut().on_key_event(key);
+            }
+            AppEvent::Key(key) if self.quit_confirmation.is_some() => {
+                self.on_quit_confirmation_key_event(key);
+            }
+            AppEvent::Key(key) if self.prompt.borrow().is_active() => {
+                self.on_key_event(key);
+                self.prompt.borrow_mut().on_key_event(key);
+            }
+            AppEvent::Key(key) if self.help.borrow().is_open() => {
+                self.on_key_event(key);
+                self.help.borrow_mut().on_key_event(key);
+            }
+            AppEvent::Key(key) if self.recent_units.borrow().is_open() => {
+                self.on_key_event(key);
+                self.recent_units.borrow_mut().on_key_event(key);
+            }
+            AppEvent::Key(key) if self.column_chooser.borrow().is_open() => {
+                self.on_key_event(key);
+                self.column_chooser.borrow_mut().on_key_event(key);
+            }
+            AppEvent::Key(key) if self.stop_impact.borrow().is_open() => {
+                self.on_key_event(key);
+                self.stop_impact.borrow_mut().on_key_event(key);
+            }
+            AppEvent::Key(key) if self.job_mode_menu.borrow().is_open() => {
+                self.on_key_event(key);
+                self.job_mode_menu.borrow_mut().on_key_event(key);
+            }
+            AppEvent::Key(key) if self.job_conflict.borrow().is_open() => {
+                self.on_key_event(key);
+                self.job_conflict.borrow_mut().on_key_event(key);
+            }
+            AppEvent::Key(key) if self.job_failure.borrow().is_open() => {
+                self.on_key_event(key);
+                self.job_failure.borrow_mut().on_key_event(key);
+            }
+            AppEvent::Key(key) if self.unit_menu.borrow().is_open() => {
+                self.on_key_event(key);
+                self.unit_menu.borrow_mut().on_key_event(key);
+            }
+            AppEvent::Key(key) => {
+                // Popups and text fields keep their keys, so typing is unaffected.
+                let typing = self
+                    .focused_screen()
+                    .iter()
+                    .any(|(component, _)| component.borrow().is_typing());
+                let key = if typing {
+                    Some(key)
+                } else {
+                    keymap::remap(key)
+                };
+                if let Some(key) = key
+                    && !typing
+                    && key.code == KeyCode::Char('?')
+                {
+                    self.open_help();
+                } else if let Some(key) = key
+                    && !typing
+                    && self.status == Status::Split
+                    && matches!(key.code, KeyCode::Tab | KeyCode::Char('s'))
+                {
+                    self.on_split_key_event(key);
+                } else if let Some(key) = key {
+                    self.on_key_event(key);
+                    for (component, _) in self.focused_screen() {
+                        component.borrow_mut().on_key_event(key);
                     }
                 }
-                AppEvent::Action(Actions::GoLog) => {
-                    self.status = Status::Log;
-                    self.event_tx.send(AppEvent::Action(Actions::RefreshLog))?;
-                    self.service_log.borrow_mut().start_auto_refresh();
+            }
+            AppEvent::Mouse(_) if self.has_popup() => return Ok(false),
+            AppEvent::Mouse(mouse) if mouse::is_within(&mouse, self.shortcuts_area) => {
+                self.on_shortcuts_click(mouse);
+            }
+            AppEvent::Mouse(mouse) => {
+                for (component, _) in self.screen() {
+                    component.borrow_mut().on_mouse_event(mouse);
                 }
-                AppEvent::Action(Actions::GoList) => self.status = Status::List,
-                AppEvent::Action(Actions::UpdateDetails) => {}
-                AppEvent::Action(Actions::RefreshDetails) => {
-                    if self.status == Status::Details {
-                        self.details.borrow_mut().fetch_log_and_dispatch();
-                    }
+            }
+            AppEvent::Action(action) => {
+                let (status, effects) = reduce(self.status, action);
+                if status == Status::Split && self.status != Status::Split {
+                    // Keys keep going to the view the split was opened from.
+                    self.split_focus = if self.status == Status::Log {
+                        Pane::Log
+                    } else {
+                        Pane::Details
+                    };
                 }
-                AppEvent::Action(Actions::GoDetails) => {
-                    if let Some(service) = self.table_service.borrow_mut().get_selected_service() {
-                        self.details.borrow_mut().update(service.clone());
+                if status != self.status {
+                    // What the screen left was still fetching would be thrown away.
+                    for (component, _) in self.screen() {
+                        component.borrow_mut().cancel_fetches();
                     }
-                    self.event_tx
-                        .send(AppEvent::Action(Actions::RefreshDetails))?;
-                    self.status = Status::Details;
-                    self.details.borrow_mut().start_auto_refresh();
                 }
-                AppEvent::Error(error_msg) => {
-                    // Get a user-friendly message based on the error
-                    let user_friendly_message = get_user_friendly_error(&error_msg);
-
-                    // Draw an error popup immediately
-                    terminal.draw(|frame| {
-                        let area = frame.area();
-
-                        // Calculate popup dimensions and position
-                        let popup_width = std::cmp::min(70, area.width.saturating_sub(4));
-                        let popup_height = std::cmp::min(12, area.height.saturating_sub(4));
-
-                        let popup_x = (area.width.saturating_sub(popup_width)) / 2;
-                        let popup_y = (area.height.saturating_sub(popup_height)) / 2;
-
-                        let popup_area = Rect::new(
-                            area.x + popup_x,
-                            area.y + popup_y,
-                            popup_width,
-                            popup_height,
-                        );
-
-                        // Draw a clear background for the popup
-                        frame.render_widget(Clear, popup_area);
-
-                        // Create the error message paragraph
-                        let text = vec![
-                            Line::from(vec![Span::styled(
-                                "ERROR",
-                                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-                            )]),
-                            Line::from(""),
-                            Line::from(user_friendly_message),
-                            Line::from(""),
-                            Line::from(vec![Span::styled(
-                                "Press any key to dismiss",
-                                Style::default().fg(Color::Gray),
-                            )]),
-                        ];
-
-                        let error_block = Paragraph::new(text)
-                            .block(
-                                Block::default()
-                                    .borders(Borders::ALL)
-                                    .border_style(Style::default().fg(Color::Red))
-                                    .title("Error"),
-                            )
-                            .alignment(Alignment::Center)
-                            .wrap(ratatui::widgets::Wrap { trim: true });
-
-                        frame.render_widget(error_block, popup_area);
-                    })?;
-
-                    // Wait for any key press to dismiss
-                    if let Ok(Event::Key(_)) = event::read() {
-                        // Continue after key press
-                    }
+                let mut changed = status != self.status;
+                self.status = status;
+                for effect in effects {
+                    changed |= self.apply(effect);
                 }
+                return Ok(changed);
+            }
+            AppEvent::Error(error_msg) => {
+                let message = get_user_friendly_error(&error_msg);
+                self.toasts.borrow_mut().error(message);
+            }
+            AppEvent::Notice(message) => self.toasts.borrow_mut().success(message),
+            AppEvent::Resize(columns, rows) => {
+                self.resized = Some(Rect::new(0, 0, columns, rows));
             }
         }
 
-        Ok(())
+        Ok(true)
     }
-    fn draw_details_status(
-        &mut self,
-        terminal: &mut DefaultTerminal,
-        service_details: &Rc<RefCell<ServiceDetails>>,
-    ) -> Result<()> {
-        let mut service_details = service_details.borrow_mut();
-        terminal.draw(|frame| {
-            let area = frame.area();
 
-            let [list_box, help_area_box] =
-                Layout::vertical([Constraint::Min(0), Constraint::Max(7)]).areas(area);
+    /// Selects the unit called `name` and opens it in `screen`, if it is listed.
+    fn open_unit(&mut self, name: &str, screen: Actions) {
+        let mut table_service = self.table_service.borrow_mut();
+        if table_service.find_service(name).is_none() {
+            self.event_tx
+                .send(AppEvent::Error(tr_args(
+                    "app-no-unit-named",
+                    &[("unit", name)],
+                )))
+                .unwrap();
+            return;
+        }
+        table_service.select_unit(name);
+        if table_service
+            .get_selected_service()
+            .is_some_and(|service| service.name() == name)
+        {
+            self.event_tx.send(AppEvent::Action(screen)).unwrap();
+        }
+    }
 
-            service_details.render(frame, list_box);
-            self.draw_shortcuts(frame, help_area_box, service_details.shortcuts());
-        })?;
+    /// Carries out `effect`, returning whether what is shown may have changed.
+    fn apply(&mut self, effect: Effect) -> bool {
+        let selected_service = self.table_service.borrow().get_selected_service().cloned();
 
-        Ok(())
+        match effect {
+            Effect::FetchLog => {
+                let services = self.table_service.borrow().log_targets();
+                self.service_log.borrow_mut().fetch(services);
+            }
+            Effect::FetchKernelLog => self.service_log.borrow_mut().fetch(vec![Service::kernel()]),
+            Effect::RefetchLog => self.service_log.borrow_mut().refetch(),
+            Effect::FollowLog => self.service_log.borrow_mut().start_follow(),
+            Effect::Store(fetched) => return self.store.borrow_mut().store(fetched),
+            Effect::ReloadList => {
+                self.table_service.borrow_mut().reload();
+                self.status_bar.borrow_mut().fetch();
+                let listed = self.store.borrow().units().is_some();
+                if let Some((name, screen)) = self.opening.take_if(|_| listed) {
+                    self.open_unit(&name, screen);
+                }
+            }
+            Effect::UpdateSystemState(state) => return self.status_bar.borrow_mut().update(state),
+            Effect::LogStored(service_name) => {
+                self.service_log.borrow_mut().on_log_stored(&service_name);
+            }
+            Effect::LoadDetails => {
+                if let Some(service) = selected_service {
+                    self.details.borrow_mut().update(service);
+                }
+            }
+            Effect::FetchDetails => self.details.borrow().fetch(),
+            Effect::RefetchDetails(changed) => self.details.borrow().fetch_if_changed(&changed),
+            Effect::UnitsChanged => self.table_service.borrow_mut().on_units_changed(),
+            Effect::FetchSecurity => {
+                if let Some(service) = selected_service {
+                    self.security
+                        .borrow_mut()
+                        .fetch_report_and_dispatch(service);
+                }
+            }
+            Effect::UpdateSecurity(report) => self.security.borrow_mut().update(report),
+            Effect::UpdateAccessControl(access_control) => self
+                .security
+                .borrow_mut()
+                .update_access_control(access_control),
+            Effect::FetchEarlyBoot => self.early_boot.borrow_mut().fetch_and_dispatch(),
+            Effect::UpdateEarlyBoot(early_boot) => self.early_boot.borrow_mut().update(early_boot),
+            Effect::FetchBootTimes => self.boot_times.borrow_mut().fetch_and_dispatch(),
+            Effect::UpdateBootTimes(times) => self.boot_times.borrow_mut().update(times),
+            Effect::UpdateCriticalChain(chain) => self.boot_times.borrow_mut().update_chain(chain),
+            Effect::FetchOverview => self.dashboard.borrow_mut().fetch_and_dispatch(),
+            Effect::UpdateOverview(overview) => self.dashboard.borrow_mut().update(overview),
+            Effect::OpenDetailsOf(name) => self.open_unit(&name, Actions::GoDetails),
+            Effect::FetchTriage => {
+                if let Some(service) = selected_service {
+                    self.triage.borrow_mut().fetch_and_dispatch(service);
+                }
+            }
+            Effect::UpdateTriage(facts) => self.triage.borrow_mut().update(facts),
+            Effect::FetchTmpfiles => {
+                if let Some(service) = selected_service {
+                    self.tmpfiles.borrow_mut().fetch_and_dispatch(service);
+                }
+            }
+            Effect::UpdateTmpfiles(tmpfiles) => self.tmpfiles.borrow_mut().update(tmpfiles),
+            Effect::UpdateTmpfilesRun(result) => self.tmpfiles.borrow_mut().on_run_done(result),
+            Effect::FetchPluginView(view) => {
+                if let Some(service) = selected_service {
+                    self.plugin_view
+                        .borrow_mut()
+                        .fetch_and_dispatch(view, service);
+                }
+            }
+            Effect::UpdatePluginView(text) => self.plugin_view.borrow_mut().update(text),
+            Effect::SearchJournal(input) => {
+                let units = self.table_service.borrow().listed_unit_names();
+                self.journal_search.borrow_mut().search(&input, units);
+            }
+            Effect::UpdateJournalSearch(hits) => self.journal_search.borrow_mut().update(hits),
+            Effect::FetchSearchHit(name) => {
+                let service = self.table_service.borrow().find_service(&name).cloned();
+                if let Some(service) = service {
+                    let journal_search = self.journal_search.borrow();
+                    let (pattern, since, until) = journal_search.window();
+                    self.service_log
+                        .borrow_mut()
+                        .fetch_matches(service, pattern, since, until);
+                }
+            }
+            Effect::FilterList(input) => self.table_service.borrow_mut().apply_filter(input),
+            Effect::SetIgnoreListKeys(ignore) => {
+                self.table_service
+                    .borrow_mut()
+                    .set_ignore_key_events(ignore);
+            }
+            Effect::OpenPrompt(kind) => self.prompt.borrow_mut().open(kind),
+            Effect::InstantiateTemplate(template, instance) => {
+                self.table_service
+                    .borrow_mut()
+                    .instantiate_template(&template, &instance);
+            }
+            Effect::SetLogTimeRange(input) => self.service_log.borrow_mut().set_time_range(&input),
+            Effect::ExportLog(scope, path) => self.service_log.borrow_mut().export(scope, &path),
+            Effect::ForwardLog(target) => self.service_log.borrow_mut().forward_to(&target),
+            Effect::ForwardLogEntries(name, entries) => {
+                self.service_log.borrow_mut().forward(&name, &entries)
+            }
+            Effect::WriteSupportBundle(path) => {
+                self.table_service.borrow_mut().write_support_bundle(&path);
+            }
+            Effect::ShowSupportBundle(path) => {
+                self.table_service
+                    .borrow_mut()
+                    .on_support_bundle_written(path);
+            }
+            Effect::RememberVisit => {
+                if let Some(service) = selected_service {
+                    self.recent_units.borrow_mut().visit(service.name());
+                }
+            }
+            Effect::OpenRecentUnits => self.recent_units.borrow_mut().open(),
+            Effect::OpenColumnChooser => {
+                let columns = self.table_service.borrow().columns().to_vec();
+                self.column_chooser.borrow_mut().open(columns);
+            }
+            Effect::ToggleColumn(column) => self.table_service.borrow_mut().toggle_column(column),
+            Effect::SelectUnit(name) => self.table_service.borrow_mut().select_unit(&name),
+            Effect::OpenStopImpact(action) => {
+                if let Some(service) = selected_service {
+                    self.stop_impact.borrow_mut().open(service, action);
+                }
+            }
+            Effect::UpdateStopImpact(name, impacted) => {
+                self.stop_impact.borrow_mut().update(&name, impacted)
+            }
+            Effect::OpenJobModeMenu => {
+                if let Some(service) = selected_service {
+                    self.job_mode_menu.borrow_mut().open(service);
+                }
+            }
+            Effect::OpenUnitMenu => {
+                if let Some(service) = selected_service {
+                    let starred = self.table_service.borrow().is_favorite(service.name());
+                    self.unit_menu.borrow_mut().open(service, starred);
+                }
+            }
+            Effect::OpenJobConflict(name, action, conflict) => {
+                self.job_conflict.borrow_mut().open(name, action, conflict)
+            }
+            Effect::UpdateJobConflict(name, jobs) => {
+                self.job_conflict.borrow_mut().update(&name, jobs)
+            }
+            Effect::RunServiceAction(name, action) => {
+                self.table_service.borrow_mut().act_on_unit(&name, action)
+            }
+            Effect::RunPluginAction(name, action) => self
+                .table_service
+                .borrow_mut()
+                .run_plugin_action(&name, action),
+            Effect::FinishPluginAction(name, action, result) => {
+                self.status_bar
+                    .borrow_mut()
+                    .on_plugin_action_done(&name, &action, &result);
+                self.table_service
+                    .borrow_mut()
+                    .on_plugin_action_done(&name, &action, result);
+            }
+            Effect::RefreshUnits => return self.table_service.borrow().refresh_units(),
+            Effect::OpenJobFailure(service, label, result, since) => self
+                .job_failure
+                .borrow_mut()
+                .open(*service, label, result, since),
+            Effect::UpdateJobFailure(name, entries) => {
+                self.job_failure.borrow_mut().update(&name, entries)
+            }
+            Effect::FinishUnitAction(name, action, result) => {
+                self.status_bar
+                    .borrow_mut()
+                    .on_unit_action_done(&name, action, &result);
+                self.table_service
+                    .borrow_mut()
+                    .on_unit_action_done(&name, action, result);
+            }
+            Effect::FinishInstantiation(template, instance, result) => {
+                self.status_bar
+                    .borrow_mut()
+                    .on_template_instantiated(&instance, &result);
+                self.table_service
+                    .borrow_mut()
+                    .on_template_instantiated(&template, &instance, result);
+            }
+            Effect::AnimateJobs => self.table_service.borrow_mut().animate_jobs(),
+            Effect::FinishJob(name, job, result) => {
+                let label = self.table_service.borrow().job_label(&name, &job);
+                if let Some(label) = label {
+                    self.status_bar
+                        .borrow_mut()
+                        .on_job_finished(&name, &label, &result);
+                }
+                self.table_service
+                    .borrow_mut()
+                    .on_job_finished(&name, &job, result);
+            }
+            Effect::ReloadConfig => match Config::reload() {
+                // Rows hold text truncated with the previous settings.
+                Ok(()) => {
+                    let mut table_service = self.table_service.borrow_mut();
+                    table_service.reset_columns();
+                    table_service.reload();
+                    self.toasts.borrow_mut().success(tr("app-config-reloaded"));
+                }
+                Err(e) => self
+                    .event_tx
+                    .send(AppEvent::Error(tr_args(
+                        "app-invalid-config",
+                        &[("error", &e.to_string())],
+                    )))
+                    .unwrap(),
+            },
+        }
+        true
     }
 
-    fn draw_log_status(
-        &mut self,
-        terminal: &mut DefaultTerminal,
-        service_log: &Rc<RefCell<ServiceLog>>,
-    ) -> Result<()> {
-        let mut service_log = service_log.borrow_mut();
-        terminal.draw(|frame| {
-            let area = frame.area();
-
-            let [list_box, help_area_box] =
-                Layout::vertical([Constraint::Min(0), Constraint::Max(7)]).areas(area);
+    /// Components making up the current screen, top to bottom, with their heights.
+    fn screen(&self) -> Vec<(Rc<RefCell<dyn Component + 'a>>, Constraint)> {
+        match self.status {
+            Status::List => vec![
+                (self.filter.clone(), Constraint::Length(4)),
+                (self.table_service.clone(), Constraint::Min(10)),
+            ],
+            Status::Log => vec![(self.service_log.clone(), Constraint::Min(0))],
+            Status::Details => vec![(self.details.clone(), Constraint::Min(0))],
+            Status::Security => vec![(self.security.clone(), Constraint::Min(0))],
+            Status::EarlyBoot => vec![(self.early_boot.clone(), Constraint::Min(0))],
+            Status::BootTimes => vec![(self.boot_times.clone(), Constraint::Min(0))],
+            Status::Dashboard => vec![(self.dashboard.clone(), Constraint::Min(0))],
+            Status::Triage => vec![(self.triage.clone(), Constraint::Min(0))],
+            Status::Tmpfiles => vec![(self.tmpfiles.clone(), Constraint::Min(0))],
+            Status::PluginView => vec![(self.plugin_view.clone(), Constraint::Min(0))],
+            Status::JournalSearch => vec![(self.journal_search.clone(), Constraint::Min(0))],
+            Status::Split => vec![
+                (self.details.clone(), Constraint::Ratio(1, 2)),
+                (self.service_log.clone(), Constraint::Ratio(1, 2)),
+            ],
+        }
+    }
 
-            service_log.render(frame, list_box);
-            self.draw_shortcuts(frame, help_area_box, service_log.shortcuts());
-        })?;
+    /// Components of the screen that get the keys: in the split view, only the
+    /// pane in focus.
+    fn focused_screen(&self) -> Vec<(Rc<RefCell<dyn Component + 'a>>, Constraint)> {
+        let mut screen = self.screen();
+        if self.status == Status::Split {
+            screen.remove(match self.split_focus {
+                Pane::Details => 1,
+                Pane::Log => 0,
+            });
+        }
+        screen
+    }
 
-        Ok(())
+    /// Tab moves the focus to the other pane, and `s` leaves the split for the
+    /// view in focus.
+    fn on_split_key_event(&mut self, key: KeyEvent) {
+        match (key.code, self.split_focus) {
+            (KeyCode::Tab, Pane::Details) => self.split_focus = Pane::Log,
+            (KeyCode::Tab, Pane::Log) => self.split_focus = Pane::Details,
+            (_, Pane::Details) => self
+                .event_tx
+                .send(AppEvent::Action(Actions::GoDetails))
+                .unwrap(),
+            (_, Pane::Log) => self
+                .event_tx
+                .send(AppEvent::Action(Actions::GoLog))
+                .unwrap(),
+        }
     }
 
-    fn draw_list_status(
-        &mut self,
-        terminal: &mut DefaultTerminal,
-        filter: &Rc<RefCell<Filter>>,
-        table_service: &Rc<RefCell<TableServices>>,
-    ) -> Result<()> {
-        let filter = filter.borrow_mut();
-        let mut table = table_service.borrow_mut();
+    fn draw(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        let screen = self.screen();
+        for (component, _) in &screen {
+            component.borrow_mut().tick();
+        }
+        let focused = self.focused_screen();
+        // The plain viewport has a fixed size, which only changes here.
+        if let Some(area) = self.resized.take() {
+            terminal.resize(area)?;
+        }
+
         terminal.draw(|frame| {
             let area = frame.area();
+            if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+                // Nothing is left to click on.
+                self.shortcuts_area = Rect::default();
+                self.shortcut_cells.clear();
+                draw_too_small(frame, area);
+                return;
+            }
 
-            let [filter_box, list_box, help_area_box] = Layout::vertical([
-                Constraint::Length(4),
-                Constraint::Min(10),
+            let [screen_area, shortcuts_area, status_area] = Layout::vertical([
+                Constraint::Min(0),
                 Constraint::Max(7),
+                Constraint::Length(1),
             ])
             .areas(area);
+            let constraints = screen.iter().map(|(_, constraint)| *constraint);
+            // Panes side by side only when each still gets a full line of log.
+            let areas = if self.status == Status::Split && screen_area.width >= SPLIT_MIN_WIDTH {
+                Layout::horizontal(constraints).split(screen_area)
+            } else {
+                Layout::vertical(constraints).split(screen_area)
+            };
 
-            filter.draw(frame, filter_box);
-            table.render(frame, list_box);
-            self.draw_shortcuts(frame, help_area_box, table.shortcuts());
+            let mut shortcuts = vec![];
+            for ((component, _), component_area) in screen.iter().zip(areas.iter()) {
+                let has_focus = focused
+                    .iter()
+                    .any(|(other, _)| Rc::ptr_eq(component, other));
+                let mut component = component.borrow_mut();
+                component.render(frame, *component_area);
+                if has_focus {
+                    shortcuts.extend(component.shortcuts());
+                } else {
+                    frame.buffer_mut().set_style(
+                        *component_area,
+                        Style::default().add_modifier(Modifier::DIM),
+                    );
+                }
+            }
+            if self.status == Status::Split {
+                shortcuts.push(split_shortcuts());
+            }
+
+            self.draw_shortcuts(frame, shortcuts_area, shortcuts);
+            self.status_bar.borrow_mut().render(frame, status_area);
+            self.recent_units.borrow_mut().render(frame, area);
+            self.column_chooser.borrow_mut().render(frame, area);
+            self.stop_impact.borrow_mut().render(frame, area);
+            self.job_mode_menu.borrow_mut().render(frame, area);
+            self.job_conflict.borrow_mut().render(frame, area);
+            self.job_failure.borrow_mut().render(frame, area);
+            self.unit_menu.borrow_mut().render(frame, area);
+            self.prompt.borrow_mut().render(frame, area);
+            self.help.borrow_mut().render(frame, area);
+            self.toasts.borrow_mut().render(frame, area);
+            if let Some(pending) = &self.quit_confirmation {
+                draw_quit_confirmation(frame, area, pending);
+            }
+            self.lock_screen.borrow_mut().render(frame, area);
+            color::adapt_to_terminal(frame.buffer_mut());
         })?;
 
         Ok(())
     }
 
-    fn draw_shortcuts(&mut self, frame: &mut Frame, help_area: Rect, shortcuts: Vec<Line<'_>>) {
-        let mut help_text: Vec<Line<'_>> = Vec::new();
+    /// Whether something drawn over the screen takes the keys.
+    fn has_popup(&self) -> bool {
+        self.lock_screen.borrow().is_locked()
+            || self.quit_confirmation.is_some()
+            || self.prompt.borrow().is_active()
+            || self.recent_units.borrow().is_open()
+            || self.column_chooser.borrow().is_open()
+            || self.stop_impact.borrow().is_open()
+            || self.job_mode_menu.borrow().is_open()
+            || self.job_conflict.borrow().is_open()
+            || self.job_failure.borrow().is_open()
+            || self.unit_menu.borrow().is_open()
+            || self.help.borrow().is_open()
+    }
+
+    /// Presses the key of the shortcut hint clicked.
+    fn on_shortcuts_click(&mut self, mouse: MouseEvent) {
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return;
+        }
+        let row = usize::from(mouse.row - self.shortcuts_area.y);
+        let column = usize::from(mouse.column - self.shortcuts_area.x);
+        if let Some(key) = self
+            .shortcut_cells
+            .get(row)
+            .and_then(|cells| mouse::shortcut_at(cells, column))
+        {
+            self.event_tx.send(AppEvent::Key(key)).unwrap();
+        }
+    }
+
+    fn draw_shortcuts(
+        &mut self,
+        frame: &mut Frame,
+        help_area: Rect,
+        shortcuts: Vec<Line<'static>>,
+    ) {
+        let mut help_text: Vec<Line<'static>> = Vec::new();
         let shortcuts_lens = shortcuts.len();
 
-        help_text.extend(shortcuts);
+        help_text.extend(shortcuts.into_iter().map(keymap::bound_keys));
 
         if shortcuts_lens > 0 {
             help_text.push(Line::raw(""));
@@ -317,27 +1246,150 @@ impl App<'_> {
             }
         }
 
-        help_text.push(Line::from(vec![
-            Span::styled(
-                "Exit",
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-            ),
-            Span::raw(": Ctrl + c"),
-        ]));
+        help_text.push(keymap::bound_keys(global_shortcuts()));
 
+        let block = if Config::get().screen_reader {
+            Block::default().title(format!("{}:", tr("shortcuts-title")))
+        } else {
+            Block::default()
+                .title(tr("shortcuts-title"))
+                .borders(Borders::ALL)
+                .border_style(Config::get().accent_style())
+        };
         let help_block = Paragraph::new(help_text)
-            .block(Block::default().title("Shortcuts").borders(Borders::ALL))
+            .block(block)
             .wrap(ratatui::widgets::Wrap { trim: true });
 
         frame.render_widget(help_block, help_area);
+
+        // Kept to tell which hint a click lands on.
+        let buffer = frame.buffer_mut();
+        self.shortcuts_area = help_area;
+        self.shortcut_cells = help_area
+            .rows()
+            .map(|row| {
+                row.columns()
+                    .map(|cell| {
+                        buffer
+                            .cell(cell)
+                            .map_or(String::new(), |cell| cell.symbol().to_string())
+                    })
+                    .collect()
+            })
+            .collect();
     }
 
     fn on_key_event(&mut self, key: KeyEvent) {
-        if let (KeyModifiers::CONTROL, KeyCode::Char('c') | KeyCode::Char('C')) =
-            (key.modifiers, key.code)
+        if is_quit_key(key) {
+            self.request_quit()
+        } else if key.modifiers == KeyModifiers::CONTROL
+            && matches!(key.code, KeyCode::Char('l') | KeyCode::Char('L'))
+        {
+            self.lock_screen.borrow_mut().lock();
+        } else if key.modifiers == KeyModifiers::CONTROL
+            && matches!(key.code, KeyCode::Char('r') | KeyCode::Char('R'))
         {
-            self.quit()
+            self.event_tx
+                .send(AppEvent::Action(Actions::ReloadConfig))
+                .unwrap();
+        }
+    }
+
+    /// Opens the keys of every screen, from the hints each one shows.
+    fn open_help(&mut self) {
+        let screens: [(&str, Rc<RefCell<dyn Component + 'a>>); 11] = [
+            ("help-dashboard", self.dashboard.clone()),
+            ("help-unit-list", self.table_service.clone()),
+            ("help-log", self.service_log.clone()),
+            ("help-properties", self.details.clone()),
+            ("help-security", self.security.clone()),
+            ("help-early-boot", self.early_boot.clone()),
+            ("help-boot-times", self.boot_times.clone()),
+            ("help-triage", self.triage.clone()),
+            ("help-journal-search", self.journal_search.clone()),
+            ("help-tmpfiles", self.tmpfiles.clone()),
+            ("help-plugin-views", self.plugin_view.clone()),
+        ];
+        let mut views = vec![
+            (tr("help-everywhere"), vec![global_shortcuts()]),
+            (tr("help-split"), vec![split_shortcuts()]),
+        ];
+        for (title, component) in screens {
+            views.push((tr(title), component.borrow().shortcuts()));
+        }
+        self.help.borrow_mut().open(views);
+    }
+
+    /// Time before something changes on screen without an event: a toast
+    /// going away or the screen locking itself.
+    fn time_to_wake_up(&self) -> Option<Duration> {
+        [self.idle_time_left(), self.toasts.borrow().time_left()]
+            .into_iter()
+            .flatten()
+            .min()
+    }
+
+    /// Time before the screen locks itself, if `auto_lock_minutes` is set and
+    /// it is not locked already.
+    fn idle_time_left(&self) -> Option<Duration> {
+        let minutes = Config::get()
+            .auto_lock_minutes
+            .filter(|minutes| *minutes > 0)?;
+        if self.lock_screen.borrow().is_locked() {
+            return None;
+        }
+        Some(Duration::from_secs(minutes * 60).saturating_sub(self.last_input.elapsed()))
+    }
+
+    /// Every component, whether on screen or not.
+    fn components(&self) -> Vec<Rc<RefCell<dyn Component + 'a>>> {
+        vec![
+            self.filter.clone(),
+            self.table_service.clone(),
+            self.service_log.clone(),
+            self.details.clone(),
+            self.security.clone(),
+            self.early_boot.clone(),
+            self.boot_times.clone(),
+            self.dashboard.clone(),
+            self.triage.clone(),
+            self.journal_search.clone(),
+            self.tmpfiles.clone(),
+            self.plugin_view.clone(),
+            self.recent_units.clone(),
+            self.column_chooser.clone(),
+            self.stop_impact.clone(),
+            self.job_mode_menu.clone(),
+            self.job_conflict.clone(),
+            self.job_failure.clone(),
+            self.unit_menu.clone(),
+            self.help.clone(),
+            self.toasts.clone(),
+            self.status_bar.clone(),
+            self.prompt.clone(),
+        ]
+    }
+
+    /// Quits right away unless something is still running in the background,
+    /// in which case the user is asked first if `confirm.quit_with_pending`.
+    fn request_quit(&mut self) {
+        let pending: Vec<String> = self
+            .components()
+            .iter()
+            .flat_map(|component| component.borrow().pending_operations())
+            .collect();
+        if pending.is_empty() || !Config::get().confirm.quit_with_pending {
+            self.quit();
+        } else {
+            self.quit_confirmation = Some(pending);
+        }
+    }
+
+    fn on_quit_confirmation_key_event(&mut self, key: KeyEvent) {
+        if is_quit_key(key) || key.code == KeyCode::Char('y') {
+            self.quit();
         }
+        self.quit_confirmation = None;
     }
 
     fn quit(&mut self) {
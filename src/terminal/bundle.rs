@@ -0,0 +1,108 @@
+use chrono::Local;
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use std::error::Error;
+use std::fs::File;
+use std::path::PathBuf;
+use tar::{Builder, Header};
+
+use crate::domain::log_query::LogQuery;
+use crate::domain::service::Service;
+use crate::infrastructure::target::Target;
+use crate::terminal::components::log::expand_home;
+use crate::terminal::i18n::tr_args;
+use crate::terminal::mask::SecretMask;
+use crate::usecases::services_manager::ServicesManager;
+
+/// Most recent log entries of each unit put in a bundle.
+const BUNDLE_LOG_LINES: usize = 1000;
+
+/// Writes the recent log, unit file, drop-ins and state of `services` to a
+/// `.tar.gz` at `path` to attach to a support ticket, or to a dated file in the
+/// home directory when `path` is empty. Sensitive values are masked.
+pub async fn write_support_bundle(
+    path: &str,
+    services: &[Service],
+) -> Result<PathBuf, Box<dyn Error>> {
+    let stamp = Local::now().format("%Y%m%d-%H%M%S");
+    let root = format!("systemd-support-{}", stamp);
+    let path = match path.trim() {
+        "" => expand_home(&format!("~/{}.tar.gz", root)),
+        path => expand_home(path),
+    };
+
+    let mask = SecretMask::from_config();
+    let mut files = vec![(format!("{}/summary.txt", root), summary(services))];
+    for service in services {
+        let query = LogQuery::default().with_lines(Some(BUNDLE_LOG_LINES));
+        let log = match ServicesManager::get_log(service, &query).await {
+            Ok(entries) => entries
+                .iter()
+                .map(|entry| entry.short_line() + "\n")
+                .collect(),
+            Err(e) => tr_args("bundle-log-unreadable", &[("error", &e.to_string())]) + "\n",
+        };
+        files.push((format!("{}/{}/journal.txt", root, service.name()), log));
+
+        let unit_files = ServicesManager::get_unit_files(service)
+            .await
+            .map_err(|e| format!("{}: {}", service.name(), e))?;
+        for (unit_file, content) in unit_files {
+            files.push((
+                format!(
+                    "{}/{}/files/{}",
+                    root,
+                    service.name(),
+                    unit_file.trim_start_matches('/')
+                ),
+                content,
+            ));
+        }
+    }
+
+    let file = File::create(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut archive = Builder::new(GzEncoder::new(file, Compression::default()));
+    let mtime = Local::now().timestamp().max(0) as u64;
+    for (name, content) in files {
+        let content = mask.apply(&content);
+        let mut header = Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(mtime);
+        archive.append_data(&mut header, name, content.as_bytes())?;
+    }
+    archive.into_inner()?.finish()?;
+    Ok(path)
+}
+
+/// When and where the bundle was made, and the state of each unit.
+fn summary(services: &[Service]) -> String {
+    let read = |path: &str| {
+        Target::get()
+            .read_to_string(path)
+            .map(|text| text.trim().to_string())
+            .unwrap_or_default()
+    };
+    let mut summary = format!(
+        "Created: {}\nHost: {}\nKernel: {}\nBy: {} {}\n",
+        Local::now().to_rfc2822(),
+        read("/proc/sys/kernel/hostname"),
+        read("/proc/sys/kernel/osrelease"),
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+    );
+    for service in services {
+        let state = service.state();
+        summary.push_str(&format!(
+            "\n{}\n  Description: {}\n  Load: {}\n  Active: {} ({})\n  Enablement: {}\n  Unit file: {}\n",
+            service.name(),
+            service.description(),
+            state.load(),
+            state.active(),
+            state.sub(),
+            state.file(),
+            service.file_path(),
+        ));
+    }
+    summary
+}
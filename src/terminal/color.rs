@@ -0,0 +1,135 @@
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+use std::sync::OnceLock;
+
+use crate::config::{Config, ThemeName};
+
+static SUPPORT: OnceLock<ColorSupport> = OnceLock::new();
+
+/// Colors the terminal can show, from the most to the least capable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSupport {
+    TrueColor,
+    Ansi256,
+    /// The 16 named colors.
+    Basic,
+    None,
+}
+
+impl ColorSupport {
+    /// Guesses the support from `NO_COLOR` (https://no-color.org), `COLORTERM`
+    /// and `TERM`, the same hints other terminal programs go by.
+    fn detect() -> Self {
+        let var = |name| std::env::var(name).unwrap_or_default();
+        if !var("NO_COLOR").is_empty() || var("TERM") == "dumb" {
+            ColorSupport::None
+        } else if matches!(var("COLORTERM").as_str(), "truecolor" | "24bit") {
+            ColorSupport::TrueColor
+        } else if var("TERM").contains("256color") {
+            ColorSupport::Ansi256
+        } else {
+            ColorSupport::Basic
+        }
+    }
+
+    /// Overrides the detection; only effective before the first draw.
+    pub fn set(support: ColorSupport) {
+        let _ = SUPPORT.set(support);
+    }
+
+    pub fn get() -> Self {
+        *SUPPORT.get_or_init(Self::detect)
+    }
+}
+
+const BASIC_COLORS: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn rgb(color: Color) -> Option<(u8, u8, u8)> {
+    match color {
+        Color::Rgb(r, g, b) => Some((r, g, b)),
+        Color::Indexed(index @ 0..16) => Some(BASIC_COLORS[usize::from(index)].1),
+        Color::Indexed(index @ 16..232) => {
+            let index = usize::from(index - 16);
+            Some((
+                CUBE_LEVELS[index / 36],
+                CUBE_LEVELS[index / 6 % 6],
+                CUBE_LEVELS[index % 6],
+            ))
+        }
+        Color::Indexed(index) => {
+            let level = 8 + (index - 232) * 10;
+            Some((level, level, level))
+        }
+        _ => None,
+    }
+}
+
+fn nearest_cube_level(value: u8) -> u8 {
+    (0..6u8)
+        .min_by_key(|level| CUBE_LEVELS[usize::from(*level)].abs_diff(value))
+        .unwrap_or(0)
+}
+
+fn nearest_basic(r: u8, g: u8, b: u8) -> Color {
+    let distance = |(cr, cg, cb): (u8, u8, u8)| {
+        let d = |a: u8, b: u8| u32::from(a.abs_diff(b)).pow(2);
+        d(r, cr) + d(g, cg) + d(b, cb)
+    };
+    BASIC_COLORS
+        .iter()
+        .min_by_key(|(_, value)| distance(*value))
+        .map_or(Color::Reset, |(color, _)| *color)
+}
+
+fn degrade(color: Color, support: ColorSupport) -> Color {
+    match (support, rgb(color)) {
+        (ColorSupport::None, _) => Color::Reset,
+        (ColorSupport::Ansi256, Some((r, g, b))) if matches!(color, Color::Rgb(..)) => {
+            Color::Indexed(
+                16 + 36 * nearest_cube_level(r) + 6 * nearest_cube_level(g) + nearest_cube_level(b),
+            )
+        }
+        (ColorSupport::Basic, Some((r, g, b))) => nearest_basic(r, g, b),
+        _ => color,
+    }
+}
+
+/// Rewrites the colors of a drawn frame into ones the terminal can show. Without
+/// colors at all, or with the monochrome theme, cells that had a background are
+/// reversed so selections and highlights stay visible.
+pub fn adapt_to_terminal(buffer: &mut Buffer) {
+    let support = if Config::get().theme == ThemeName::Monochrome {
+        ColorSupport::None
+    } else {
+        ColorSupport::get()
+    };
+    if support == ColorSupport::TrueColor {
+        return;
+    }
+    for cell in &mut buffer.content {
+        if support == ColorSupport::None && !matches!(cell.bg, Color::Reset) {
+            cell.modifier.insert(Modifier::REVERSED);
+        }
+        cell.fg = degrade(cell.fg, support);
+        cell.bg = degrade(cell.bg, support);
+    }
+}
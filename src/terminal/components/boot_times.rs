@@ -0,0 +1,380 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+
+use crate::domain::boot_time::{BootTime, ChainLink};
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::theme::Theme;
+use crate::usecases::runtime::{self, Task};
+use crate::usecases::services_manager::ServicesManager;
+
+/// Width of the bar of the slowest unit, the others scaled to it.
+const BAR_WIDTH: usize = 20;
+
+/// Time from which a unit of the critical chain is shown as slow.
+const SLOW_LINK: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Copy, PartialEq)]
+enum Tab {
+    Blame,
+    CriticalChain,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Order {
+    Slowest,
+    Fastest,
+    Name,
+}
+
+impl Order {
+    fn next(self) -> Self {
+        match self {
+            Order::Slowest => Order::Fastest,
+            Order::Fastest => Order::Name,
+            Order::Name => Order::Slowest,
+        }
+    }
+
+    /// Message id of the order, for the title.
+    fn label(self) -> &'static str {
+        match self {
+            Order::Slowest => "boot-times-slowest-first",
+            Order::Fastest => "boot-times-fastest-first",
+            Order::Name => "boot-times-by-name",
+        }
+    }
+}
+
+fn seconds(duration: Duration) -> String {
+    if duration < Duration::from_secs(1) {
+        format!("{}ms", duration.as_millis())
+    } else {
+        format!("{:.3}s", duration.as_secs_f64())
+    }
+}
+
+/// Units by the time they took to start during the current boot, opened with
+/// `B` from the list, to find what slows the boot down, and the critical chain
+/// of the default target, the units it waited for one after the other.
+pub struct BootTimes {
+    times: Option<Vec<BootTime>>,
+    chain: Option<Vec<ChainLink>>,
+    tab: Tab,
+    order: Order,
+    table_state: TableState,
+    chain_state: TableState,
+    fetch: Option<Task>,
+    sender: Sender<AppEvent>,
+}
+
+impl BootTimes {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            times: None,
+            chain: None,
+            tab: Tab::Blame,
+            order: Order::Slowest,
+            table_state: TableState::default(),
+            chain_state: TableState::default(),
+            fetch: None,
+            sender,
+        }
+    }
+
+    fn exit(&self) {
+        self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+    }
+
+    pub fn fetch_and_dispatch(&mut self) {
+        self.times = None;
+        self.chain = None;
+        self.table_state.select(Some(0));
+        self.chain_state.select(Some(0));
+        let event_tx = self.sender.clone();
+        self.cancel_fetches();
+        self.fetch = Some(runtime::spawn(async move {
+            match ServicesManager::get_boot_times().await {
+                Ok(times) => event_tx
+                    .send(AppEvent::Action(Actions::UpdateBootTimes(times)))
+                    .expect("Failed to send UpdateBootTimes event"),
+                Err(e) => event_tx
+                    .send(AppEvent::Error(e.to_string()))
+                    .expect("Failed to send Error event"),
+            }
+            match ServicesManager::get_critical_chain().await {
+                Ok(chain) => event_tx
+                    .send(AppEvent::Action(Actions::UpdateCriticalChain(chain)))
+                    .expect("Failed to send UpdateCriticalChain event"),
+                Err(e) => event_tx
+                    .send(AppEvent::Error(e.to_string()))
+                    .expect("Failed to send Error event"),
+            }
+        }));
+    }
+
+    pub fn update(&mut self, times: Vec<BootTime>) {
+        self.times = Some(times);
+        self.sort();
+    }
+
+    pub fn update_chain(&mut self, chain: Vec<ChainLink>) {
+        self.chain = Some(chain);
+    }
+
+    fn switch_tab(&mut self) {
+        self.tab = match self.tab {
+            Tab::Blame => Tab::CriticalChain,
+            Tab::CriticalChain => Tab::Blame,
+        };
+    }
+
+    /// Selection and number of rows of the tab shown.
+    fn selection(&mut self) -> (&mut TableState, usize) {
+        match self.tab {
+            Tab::Blame => (
+                &mut self.table_state,
+                self.times.as_ref().map_or(0, Vec::len),
+            ),
+            Tab::CriticalChain => (
+                &mut self.chain_state,
+                self.chain.as_ref().map_or(0, Vec::len),
+            ),
+        }
+    }
+
+    fn sort(&mut self) {
+        let Some(times) = &mut self.times else {
+            return;
+        };
+        match self.order {
+            Order::Slowest => times.sort_by_key(|time| std::cmp::Reverse(time.took())),
+            Order::Fastest => times.sort_by_key(BootTime::took),
+            Order::Name => times.sort_by(|a, b| a.name().cmp(b.name())),
+        }
+    }
+
+    fn cycle_order(&mut self) {
+        self.order = self.order.next();
+        self.sort();
+        self.table_state.select(Some(0));
+    }
+
+    fn select_next(&mut self, step: usize) {
+        let (state, count) = self.selection();
+        let selected = state.selected().unwrap_or(0);
+        state.select(Some((selected + step).min(count.saturating_sub(1))));
+    }
+
+    fn select_previous(&mut self, step: usize) {
+        let (state, _) = self.selection();
+        let selected = state.selected().unwrap_or(0);
+        state.select(Some(selected.saturating_sub(step)));
+    }
+
+    fn open_selected(&self) {
+        let name = match self.tab {
+            Tab::Blame => self
+                .times
+                .as_ref()
+                .zip(self.table_state.selected())
+                .and_then(|(times, selected)| times.get(selected))
+                .map(BootTime::name),
+            Tab::CriticalChain => self
+                .chain
+                .as_ref()
+                .zip(self.chain_state.selected())
+                .and_then(|(chain, selected)| chain.get(selected))
+                .map(ChainLink::name),
+        };
+        let Some(name) = name else {
+            return;
+        };
+        self.sender
+            .send(AppEvent::Action(Actions::OpenDetailsOf(name.to_string())))
+            .unwrap();
+    }
+
+    fn render_chain(&mut self, frame: &mut Frame, area: Rect, block: Block) {
+        let Some(chain) = &self.chain else {
+            let loading = Paragraph::new(tr("loading"))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(loading, area);
+            return;
+        };
+        let title = match chain.first() {
+            Some(target) => tr_args("critical-chain-title", &[("target", target.name())]),
+            None => tr("critical-chain-heading"),
+        };
+        let rows: Vec<Row> = chain
+            .iter()
+            .map(|link| {
+                let branch = match link.depth() {
+                    0 => String::new(),
+                    depth => format!("{}└─", "  ".repeat(depth - 1)),
+                };
+                let mut spans = vec![
+                    Span::styled(branch, Style::default().fg(Theme::current().muted)),
+                    Span::styled(
+                        link.name().to_string(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                ];
+                if let Some(at) = link.at() {
+                    spans.push(Span::styled(
+                        format!(" @{}", seconds(at)),
+                        Style::default().fg(Theme::current().muted),
+                    ));
+                }
+                if let Some(took) = link.took() {
+                    let style = if took >= SLOW_LINK {
+                        Style::default()
+                            .fg(Theme::current().warning)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default()
+                    };
+                    spans.push(Span::styled(format!(" +{}", seconds(took)), style));
+                }
+                Row::new(vec![Cell::from(Line::from(spans))])
+            })
+            .collect();
+        let table = Table::new(rows, [Constraint::Min(0)])
+            .row_highlight_style(
+                Style::default()
+                    .bg(Theme::current().selection_bg)
+                    .fg(Theme::current().selection_fg)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .block(block.title(format!(" {} ", title)));
+        frame.render_stateful_widget(table, area, &mut self.chain_state);
+    }
+}
+
+impl Component for BootTimes {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title_alignment(Alignment::Center);
+        if self.tab == Tab::CriticalChain {
+            self.render_chain(frame, area, block);
+            return;
+        }
+        let Some(times) = &self.times else {
+            let loading = Paragraph::new(tr("loading"))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(loading, area);
+            return;
+        };
+        if times.is_empty() {
+            let message = Paragraph::new(tr("boot-times-none"))
+                .alignment(Alignment::Center)
+                .block(block.title(format!(" {} ", tr("boot-times-heading"))));
+            frame.render_widget(message, area);
+            return;
+        }
+
+        let slowest = times
+            .iter()
+            .map(BootTime::took)
+            .max()
+            .unwrap_or_default()
+            .max(Duration::from_millis(1));
+        let rows: Vec<Row> = times
+            .iter()
+            .map(|time| {
+                let length = (time.took().as_secs_f64() / slowest.as_secs_f64() * BAR_WIDTH as f64)
+                    .ceil() as usize;
+                Row::new(vec![
+                    Cell::from(seconds(time.took())),
+                    Cell::from("█".repeat(length))
+                        .style(Style::default().fg(Theme::current().warning)),
+                    Cell::from(time.name().to_string())
+                        .style(Style::default().add_modifier(Modifier::BOLD)),
+                ])
+            })
+            .collect();
+
+        let title = format!(
+            " {} ",
+            tr_args(
+                "boot-times-title",
+                &[
+                    ("units", &times.len().to_string()),
+                    ("order", &tr(self.order.label())),
+                ],
+            )
+        );
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(10),
+                Constraint::Length(BAR_WIDTH as u16),
+                Constraint::Min(30),
+            ],
+        )
+        .header(
+            Row::new([tr("boot-times-took"), String::new(), tr("boot-times-unit")]).style(
+                Style::default()
+                    .fg(Theme::current().header)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        )
+        .row_highlight_style(
+            Style::default()
+                .bg(Theme::current().selection_bg)
+                .fg(Theme::current().selection_fg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .block(block.title(title));
+
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Down => self.select_next(1),
+            KeyCode::Up => self.select_previous(1),
+            KeyCode::PageDown => self.select_next(10),
+            KeyCode::PageUp => self.select_previous(10),
+            KeyCode::Enter => self.open_selected(),
+            KeyCode::Tab | KeyCode::Left | KeyCode::Right => self.switch_tab(),
+            KeyCode::Char('o') if self.tab == Tab::Blame => self.cycle_order(),
+            KeyCode::Char('r') => self.fetch_and_dispatch(),
+            KeyCode::Char('q') | KeyCode::Esc => self.exit(),
+            _ => {}
+        }
+    }
+
+    fn shortcuts(&self) -> Vec<Line<'static>> {
+        vec![
+            Line::from(vec![Span::styled(
+                tr("actions-heading"),
+                Style::default()
+                    .fg(Theme::current().heading)
+                    .add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(tr(match self.tab {
+                Tab::Blame => "boot-times-shortcuts",
+                Tab::CriticalChain => "critical-chain-shortcuts",
+            })),
+        ]
+    }
+
+    fn cancel_fetches(&mut self) {
+        if let Some(fetch) = self.fetch.take() {
+            fetch.cancel();
+        }
+    }
+}
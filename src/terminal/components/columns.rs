@@ -0,0 +1,144 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+};
+use std::sync::mpsc::Sender;
+
+use crate::config::Column;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::components::list::table_columns;
+use crate::terminal::i18n::tr;
+use crate::terminal::theme::Theme;
+use crate::usecases::plugins::{PluginExtension, Plugins};
+
+fn label(column: Column) -> &'static str {
+    match column {
+        Column::Active => "Active state",
+        Column::State => "Enablement state",
+        Column::Load => "Load state",
+        Column::Path => "Unit file path",
+        Column::Memory => "Memory",
+        Column::Cpu => "CPU time",
+        Column::Tasks => "Tasks",
+        Column::Description => "Description",
+        Column::Plugin(index) => Plugins::columns()
+            .get(index)
+            .map_or("", PluginExtension::title),
+    }
+}
+
+/// Checklist of the unit table columns, opened with `c` from the list.
+pub struct ColumnChooser {
+    /// Every column that can be shown, the plugins' included.
+    columns: Vec<Column>,
+    /// Columns shown when the chooser was opened, updated as they are toggled.
+    shown: Vec<Column>,
+    open: bool,
+    selected: usize,
+    sender: Sender<AppEvent>,
+}
+
+impl ColumnChooser {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            columns: vec![],
+            shown: vec![],
+            open: false,
+            selected: 0,
+            sender,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn open(&mut self, shown: Vec<Column>) {
+        self.columns = table_columns();
+        self.shown = shown;
+        self.open = true;
+        self.selected = 0;
+    }
+
+    fn close(&mut self) {
+        self.open = false;
+    }
+
+    fn toggle(&mut self) {
+        let column = self.columns[self.selected];
+        if self.shown.contains(&column) {
+            self.shown.retain(|shown| *shown != column);
+        } else {
+            self.shown.push(column);
+        }
+        self.sender
+            .send(AppEvent::Action(Actions::ToggleColumn(column)))
+            .unwrap();
+    }
+}
+
+impl Component for ColumnChooser {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.open {
+            return;
+        }
+
+        let width = area.width.saturating_sub(4).min(40);
+        let height = (self.columns.len() as u16 + 2).min(area.height);
+        let popup_area = Rect::new(
+            area.x + (area.width.saturating_sub(width)) / 2,
+            area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        );
+
+        let items: Vec<ListItem> = self
+            .columns
+            .iter()
+            .map(|column| {
+                let check = if self.shown.contains(column) {
+                    "[x]"
+                } else {
+                    "[ ]"
+                };
+                ListItem::new(format!("{} {}", check, label(*column)))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Theme::current().popup_border))
+                    .title(format!(" {} ", tr("columns-title")))
+                    .title_bottom(
+                        Line::from(format!(" {} ", tr("columns-shortcuts"))).right_aligned(),
+                    ),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Theme::current().selection_bg)
+                    .fg(Theme::current().selection_fg)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol(">> ");
+        let mut state = ListState::default().with_selected(Some(self.selected));
+
+        frame.render_widget(Clear, popup_area);
+        frame.render_stateful_widget(list, popup_area, &mut state);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => self.selected = (self.selected + 1).min(self.columns.len() - 1),
+            KeyCode::Char(' ') | KeyCode::Enter => self.toggle(),
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('c') => self.close(),
+            _ => {}
+        }
+    }
+}
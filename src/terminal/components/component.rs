@@ -0,0 +1,40 @@
+use crossterm::event::{KeyEvent, MouseEvent};
+use ratatui::{Frame, layout::Rect, text::Line};
+
+/// Common interface of everything the app can put on screen.
+///
+/// `App` only talks to screens through this trait, so a new screen just needs
+/// to implement it and be listed in `App::screen`.
+pub trait Component {
+    fn render(&mut self, frame: &mut Frame, area: Rect);
+
+    fn on_key_event(&mut self, key: KeyEvent);
+
+    /// Lines describing the key bindings, shown in the shortcuts box.
+    fn shortcuts(&self) -> Vec<Line<'static>> {
+        vec![]
+    }
+
+    /// Clicks and wheel turns anywhere on screen, when no popup is open; each
+    /// component checks whether they fall in the area it was last drawn in.
+    fn on_mouse_event(&mut self, _event: MouseEvent) {}
+
+    /// Called once per event loop iteration, before drawing.
+    fn tick(&mut self) {}
+
+    /// Operations still running in the background, listed for confirmation
+    /// before quitting so they are not abandoned by accident.
+    fn pending_operations(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Whether keys go into a text the component is editing, so they are
+    /// taken as typed rather than remapped by the `[keys]` of the config file.
+    fn is_typing(&self) -> bool {
+        false
+    }
+
+    /// Stops waiting for what the component was fetching, once its screen is
+    /// left and the result would only be thrown away.
+    fn cancel_fetches(&mut self) {}
+}
@@ -0,0 +1,298 @@
+use chrono::{DateTime, Local};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+
+use crate::domain::overview::Overview;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::store::Store;
+use crate::terminal::theme::Theme;
+use crate::usecases::runtime::{self, Task};
+use crate::usecases::services_manager::ServicesManager;
+
+/// Most failed units listed, the most recent ones.
+const RECENT_FAILED: usize = 10;
+
+/// Uptime in days, hours and minutes (`3d 4h 12m`, `25m`).
+fn uptime(duration: Duration) -> String {
+    let minutes = duration.as_secs() / 60;
+    let (days, hours, minutes) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+    match (days, hours) {
+        (0, 0) => format!("{}m", minutes),
+        (0, _) => format!("{}h {}m", hours, minutes),
+        _ => format!("{}d {}h {}m", days, hours, minutes),
+    }
+}
+
+fn format_usec(usec: u64) -> String {
+    DateTime::from_timestamp_micros(usec as i64)
+        .filter(|_| usec > 0)
+        .map(|dt| {
+            dt.with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// The system at a glance, opened with `D` from the list or on start with
+/// `dashboard`: its state, uptime and load, how many units are in each state
+/// and the units that failed most recently.
+pub struct Dashboard {
+    overview: Option<Overview>,
+    table_state: TableState,
+    fetch: Option<Task>,
+    store: Rc<RefCell<Store>>,
+    sender: Sender<AppEvent>,
+}
+
+impl Dashboard {
+    pub fn new(sender: Sender<AppEvent>, store: Rc<RefCell<Store>>) -> Self {
+        Self {
+            overview: None,
+            table_state: TableState::default(),
+            fetch: None,
+            store,
+            sender,
+        }
+    }
+
+    fn exit(&self) {
+        self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+    }
+
+    pub fn fetch_and_dispatch(&mut self) {
+        let event_tx = self.sender.clone();
+        self.cancel_fetches();
+        self.fetch = Some(runtime::spawn(async move {
+            match ServicesManager::get_overview().await {
+                Ok(overview) => event_tx
+                    .send(AppEvent::Action(Actions::UpdateOverview(overview)))
+                    .expect("Failed to send UpdateOverview event"),
+                Err(e) => event_tx
+                    .send(AppEvent::Error(e.to_string()))
+                    .expect("Failed to send Error event"),
+            }
+        }));
+    }
+
+    pub fn update(&mut self, overview: Overview) {
+        let count = overview.failed().len().min(RECENT_FAILED);
+        let selected = self.table_state.selected().unwrap_or(0);
+        self.table_state
+            .select(Some(selected.min(count.saturating_sub(1))));
+        self.overview = Some(overview);
+    }
+
+    fn failed(&self) -> &[(String, u64)] {
+        self.overview.as_ref().map_or(&[], |overview| {
+            &overview.failed()[..overview.failed().len().min(RECENT_FAILED)]
+        })
+    }
+
+    fn select_next(&mut self) {
+        let count = self.failed().len();
+        let selected = self.table_state.selected().unwrap_or(0);
+        self.table_state
+            .select(Some((selected + 1).min(count.saturating_sub(1))));
+    }
+
+    fn select_previous(&mut self) {
+        let selected = self.table_state.selected().unwrap_or(0);
+        self.table_state.select(Some(selected.saturating_sub(1)));
+    }
+
+    fn open_selected(&self) {
+        let Some((name, _)) = self
+            .table_state
+            .selected()
+            .and_then(|selected| self.failed().get(selected))
+        else {
+            return;
+        };
+        self.sender
+            .send(AppEvent::Action(Actions::OpenDetailsOf(name.clone())))
+            .unwrap();
+    }
+
+    /// Units listed by their active state, `failed` and `active` first.
+    fn units_line(&self) -> Line<'static> {
+        let theme = Theme::current();
+        let store = self.store.borrow();
+        let Some(units) = store.units() else {
+            return Line::from("...");
+        };
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for service in units.value() {
+            *counts
+                .entry(service.state().active().to_string())
+                .or_default() += 1;
+        }
+        let mut spans = vec![Span::styled(
+            format!("{}:", units.value().len()),
+            Style::default().add_modifier(Modifier::BOLD),
+        )];
+        let first = ["failed", "active"];
+        let mut states: Vec<(&String, &usize)> = counts.iter().collect();
+        states.sort_by_key(|(state, _)| {
+            first
+                .iter()
+                .position(|first| first == state)
+                .unwrap_or(first.len())
+        });
+        for (state, count) in states {
+            let color = match state.as_str() {
+                "failed" => theme.error,
+                "active" => theme.ok,
+                "activating" | "deactivating" | "reloading" => theme.warning,
+                _ => theme.muted,
+            };
+            spans.push(Span::styled(
+                format!(" {} {}", count, state),
+                Style::default().fg(color),
+            ));
+        }
+        Line::from(spans)
+    }
+
+    fn summary(&self, overview: &Overview) -> Vec<Line<'static>> {
+        let theme = Theme::current();
+        let label = |id: &str| {
+            Span::styled(
+                format!("{:<12}", tr(id)),
+                Style::default()
+                    .fg(theme.header)
+                    .add_modifier(Modifier::BOLD),
+            )
+        };
+        let state_color = match overview.state() {
+            "running" => theme.ok,
+            "degraded" => theme.error,
+            _ => theme.warning,
+        };
+        let load = overview
+            .load()
+            .map_or("-".to_string(), |[one, five, fifteen]| {
+                format!("{:.2} {:.2} {:.2}", one, five, fifteen)
+            });
+        vec![
+            Line::from(vec![
+                label("dashboard-state"),
+                Span::styled(
+                    overview.state().to_string(),
+                    Style::default()
+                        .fg(state_color)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::from(vec![
+                label("dashboard-uptime"),
+                Span::raw(overview.uptime().map_or("-".to_string(), uptime)),
+            ]),
+            Line::from(vec![label("dashboard-load"), Span::raw(load)]),
+            Line::from([vec![label("dashboard-units")], self.units_line().spans].concat()),
+        ]
+    }
+}
+
+impl Component for Dashboard {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title_alignment(Alignment::Center)
+            .title(format!(" {} ", tr("dashboard-title")));
+        let Some(overview) = &self.overview else {
+            let loading = Paragraph::new(tr("loading"))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(loading, area);
+            return;
+        };
+
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        let [summary_area, failed_area] =
+            Layout::vertical([Constraint::Length(5), Constraint::Min(0)]).areas(inner);
+        frame.render_widget(Paragraph::new(self.summary(overview)), summary_area);
+
+        let failed_block = Block::default().borders(Borders::TOP).title(format!(
+            " {} ",
+            tr_args(
+                "dashboard-failed-title",
+                &[("units", &overview.failed().len().to_string())],
+            )
+        ));
+        if overview.failed().is_empty() {
+            let message = Paragraph::new(tr("dashboard-no-failed"))
+                .style(Style::default().fg(Theme::current().ok))
+                .block(failed_block);
+            frame.render_widget(message, failed_area);
+            return;
+        }
+        let rows: Vec<Row> = self
+            .failed()
+            .iter()
+            .map(|(name, failed_at)| {
+                Row::new(vec![
+                    Cell::from(format_usec(*failed_at))
+                        .style(Style::default().fg(Theme::current().muted)),
+                    Cell::from(name.clone()).style(
+                        Style::default()
+                            .fg(Theme::current().error)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                ])
+            })
+            .collect();
+        let table = Table::new(rows, [Constraint::Length(20), Constraint::Min(30)])
+            .row_highlight_style(
+                Style::default()
+                    .bg(Theme::current().selection_bg)
+                    .fg(Theme::current().selection_fg)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .block(failed_block);
+        frame.render_stateful_widget(table, failed_area, &mut self.table_state);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Down => self.select_next(),
+            KeyCode::Up => self.select_previous(),
+            KeyCode::Enter => self.open_selected(),
+            KeyCode::Char('r') => self.fetch_and_dispatch(),
+            KeyCode::Char('q') | KeyCode::Esc => self.exit(),
+            _ => {}
+        }
+    }
+
+    fn shortcuts(&self) -> Vec<Line<'static>> {
+        vec![
+            Line::from(vec![Span::styled(
+                tr("actions-heading"),
+                Style::default()
+                    .fg(Theme::current().heading)
+                    .add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(tr("dashboard-shortcuts")),
+        ]
+    }
+
+    fn cancel_fetches(&mut self) {
+        if let Some(fetch) = self.fetch.take() {
+            fetch.cancel();
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use ratatui::style::Stylize;
+use ratatui::text::Text;
+use ratatui::widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+
+use crossterm::event::{KeyCode, KeyEvent, MouseEvent, MouseEventKind};
+
+use crate::config::Config;
+use crate::domain::service::Service;
+use crate::domain::unit_property::UnitProperty;
+use crate::terminal::action_log::{ActionLog, LoggedAction};
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::components::gauge::compact_duration;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::keymap::VimKeys;
+use crate::terminal::mask::SecretMask;
+use crate::terminal::mouse;
+use crate::terminal::store::{DataKey, Store};
+use crate::terminal::theme::Theme;
+use crate::terminal::truncate::truncate_line;
+
+mod mount;
+mod service;
+mod socket;
+mod timer;
+
+use mount::MountDetails;
+use service::ServiceDetails;
+use socket::SocketDetails;
+use timer::TimerDetails;
+
+/// Turns the properties of one unit type into the lines shown in the details tab.
+pub trait DetailsRenderer {
+    fn lines(&self) -> Vec<Line<'static>>;
+}
+
+fn renderer(properties: &UnitProperty) -> Box<dyn DetailsRenderer + '_> {
+    match properties {
+        UnitProperty::Service(properties) => Box::new(ServiceDetails(properties)),
+        UnitProperty::Socket(properties) => Box::new(SocketDetails(properties)),
+        UnitProperty::Timer(properties) => Box::new(TimerDetails(properties)),
+        UnitProperty::Mount(properties) => Box::new(MountDetails(properties)),
+    }
+}
+
+fn property_line(key: &'static str, value: String) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(key, Style::new().bold()),
+        Span::raw("="),
+        Span::raw(value),
+    ])
+}
+
+pub struct UnitDetails {
+    service: Option<Service>,
+    store: Rc<RefCell<Store>>,
+    sender: Sender<AppEvent>,
+    scroll: u16,
+    /// Whether values matching `sensitive_patterns` are shown, until leaving.
+    secrets_revealed: bool,
+    vim_keys: VimKeys,
+    /// Last action taken on the unit through this tool, by anyone sharing the
+    /// action log.
+    last_action: Option<LoggedAction>,
+    /// Where the properties were last drawn, for the wheel.
+    area: Rect,
+}
+
+impl UnitDetails {
+    pub fn new(sender: Sender<AppEvent>, store: Rc<RefCell<Store>>) -> Self {
+        Self {
+            service: None,
+            store,
+            sender,
+            scroll: 0,
+            secrets_revealed: false,
+            vim_keys: VimKeys::default(),
+            last_action: None,
+            area: Rect::default(),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.service = None;
+        self.scroll = 0;
+        self.secrets_revealed = false;
+    }
+
+    fn exit(&self) {
+        self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+    }
+
+    pub fn fetch(&self) {
+        if let Some(service) = &self.service {
+            self.store.borrow_mut().fetch_properties(service.clone());
+        }
+    }
+
+    /// Fetches the properties again when systemd changed those of the unit
+    /// shown, among the units `changed`.
+    pub fn fetch_if_changed(&self, changed: &[String]) {
+        if self
+            .service
+            .as_ref()
+            .is_some_and(|service| changed.iter().any(|name| name == service.name()))
+        {
+            self.fetch();
+        }
+    }
+
+    pub fn update(&mut self, service: Service) {
+        self.last_action = ActionLog::load().last(service.name()).cloned();
+        self.service = Some(service);
+    }
+}
+
+impl Component for UnitDetails {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        self.area = area;
+        let store = self.store.borrow();
+        if let Some(service) = &self.service
+            && let Some(properties) = store.properties(service.name())
+        {
+            let width = usize::from(area.width.saturating_sub(2));
+            let side = Config::get().truncation;
+            let mask = SecretMask::from_config();
+            let lines: Vec<Line> = renderer(properties.value())
+                .lines()
+                .into_iter()
+                .map(|mut line| {
+                    if !self.secrets_revealed {
+                        for span in &mut line.spans {
+                            span.content = mask.apply(&span.content).into_owned().into();
+                        }
+                    }
+                    truncate_line(line, width, side)
+                })
+                .collect();
+            let freshness = if properties.is_stale() {
+                tr("details-refreshing")
+            } else {
+                tr_args(
+                    "details-fetched",
+                    &[("seconds", &properties.age().as_secs().to_string())],
+                )
+            };
+            let revealed = if self.secrets_revealed {
+                format!("· {} ", tr("details-secrets-shown"))
+            } else {
+                String::new()
+            };
+            // The list keeps the state current while the properties are open.
+            let state = store
+                .units()
+                .and_then(|units| {
+                    units
+                        .value()
+                        .iter()
+                        .find(|unit| unit.name() == service.name())
+                })
+                .unwrap_or(service)
+                .state();
+            let mut title = Line::from(vec![
+                Span::raw(format!(" {} ", service.name())),
+                Span::styled(
+                    format!("{} ({})", state.active(), state.sub()),
+                    Style::default().fg(Theme::current().color_of(state.active())),
+                ),
+                Span::raw(format!(
+                    " · {} · {} {}",
+                    tr("details-properties"),
+                    freshness,
+                    revealed
+                )),
+            ]);
+            if let Some(action) = &self.last_action {
+                let ago = action.age().map_or(String::new(), |age| {
+                    let age = compact_duration(u64::try_from(age.as_nanos()).unwrap_or(u64::MAX));
+                    format!("{}, ", tr_args("details-ago", &[("age", &age)]))
+                });
+                title.push_span(Span::styled(
+                    format!(
+                        "· {} {}{} ",
+                        tr_args(
+                            "details-last-action",
+                            &[("action", action.action()), ("user", action.user())]
+                        ),
+                        ago,
+                        action.at().format("%Y-%m-%d %H:%M")
+                    ),
+                    Style::default().fg(Theme::current().muted),
+                ));
+            }
+
+            // Down and End stop at the last page.
+            let last_page = lines
+                .len()
+                .saturating_sub(usize::from(area.height.saturating_sub(2)));
+            self.scroll = self.scroll.min(last_page.try_into().unwrap_or(u16::MAX));
+            let mut scroll_state = ScrollbarState::new(lines.len()).position(self.scroll as usize);
+            let paragraph = Paragraph::new(Text::from(lines))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(title)
+                        .title_alignment(Alignment::Center),
+                )
+                .scroll((self.scroll, 0));
+
+            frame.render_widget(paragraph, area);
+            frame.render_stateful_widget(
+                Scrollbar::default().orientation(ScrollbarOrientation::VerticalRight),
+                area,
+                &mut scroll_state,
+            );
+        }
+    }
+
+    fn on_mouse_event(&mut self, event: MouseEvent) {
+        if !mouse::is_within(&event, self.area) {
+            return;
+        }
+        let rows = mouse::WHEEL_ROWS as u16;
+        match event.kind {
+            MouseEventKind::ScrollDown => self.scroll = self.scroll.saturating_add(rows),
+            MouseEventKind::ScrollUp => self.scroll = self.scroll.saturating_sub(rows),
+            _ => {}
+        }
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        let Some(key) = self.vim_keys.translate(key) else {
+            return;
+        };
+        match key.code {
+            KeyCode::Right | KeyCode::Tab => {
+                self.reset();
+                self.sender
+                    .send(AppEvent::Action(Actions::GoSecurity))
+                    .unwrap();
+            }
+            KeyCode::Left | KeyCode::BackTab => {
+                self.reset();
+                self.sender.send(AppEvent::Action(Actions::GoLog)).unwrap();
+            }
+            KeyCode::Up => {
+                self.scroll = self.scroll.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                self.scroll = self.scroll.saturating_add(1);
+            }
+            KeyCode::PageUp => {
+                self.scroll = self.scroll.saturating_sub(10);
+            }
+            KeyCode::PageDown => {
+                self.scroll = self.scroll.saturating_add(10);
+            }
+            KeyCode::Home => self.scroll = 0,
+            KeyCode::End => self.scroll = u16::MAX,
+
+            KeyCode::Char('R') => self.secrets_revealed = !self.secrets_revealed,
+            KeyCode::Char('s') => self
+                .sender
+                .send(AppEvent::Action(Actions::GoSplit))
+                .unwrap(),
+            KeyCode::Char('q') => {
+                self.reset();
+                self.exit();
+            }
+            _ => {}
+        }
+    }
+
+    fn shortcuts(&self) -> Vec<Line<'static>> {
+        let help_text = vec![
+            Line::from(vec![Span::styled(
+                tr("actions-heading"),
+                Style::default()
+                    .fg(Theme::current().heading)
+                    .add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(tr(if self.secrets_revealed {
+                "details-shortcuts-revealed"
+            } else {
+                "details-shortcuts"
+            })),
+        ];
+
+        help_text
+    }
+
+    fn cancel_fetches(&mut self) {
+        if let Some(service) = &self.service {
+            let key = DataKey::Properties(service.name().to_string());
+            self.store.borrow_mut().cancel(&key);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use ratatui::text::Line;
+
+use super::{DetailsRenderer, property_line};
+use crate::domain::mount_property::MountProperty;
+
+pub struct MountDetails<'a>(pub &'a MountProperty);
+
+impl DetailsRenderer for MountDetails<'_> {
+    fn lines(&self) -> Vec<Line<'static>> {
+        let properties = self.0;
+
+        vec![
+            property_line("What", properties.what().to_string()),
+            property_line("Where", properties.r#where().to_string()),
+            property_line("Type", properties.fs_type().to_string()),
+            property_line("Options", properties.options().to_string()),
+            Line::from(""),
+            property_line(
+                "TimeoutSec",
+                format!("{}s", properties.timeout_usec() / 1_000_000),
+            ),
+            property_line("Result", properties.result().to_string()),
+        ]
+    }
+}
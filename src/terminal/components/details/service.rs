@@ -0,0 +1,149 @@
+use ratatui::text::Line;
+
+use super::{DetailsRenderer, property_line};
+use crate::domain::service_property::ServiceProperty;
+
+fn format_bytes(bytes: u64) -> String {
+    if bytes >= 1_000_000_000_000 {
+        format!("{:.2} TB", bytes as f64 / 1_000_000_000_000.0)
+    } else if bytes >= 1_000_000_000 {
+        format!("{:.2} GB", bytes as f64 / 1_000_000_000.0)
+    } else if bytes >= 1_000_000 {
+        format!("{:.2} MB", bytes as f64 / 1_000_000.0)
+    } else if bytes >= 1_000 {
+        format!("{:.2} KB", bytes as f64 / 1_000.0)
+    } else {
+        format!("{} bytes", bytes)
+    }
+}
+
+fn format_units(value: u64) -> String {
+    if value >= 1_000_000_000_000 {
+        format!("{:.2} TB", value as f64 / 1_000_000_000_000.0)
+    } else if value >= 1_000_000_000 {
+        format!("{:.2} GB", value as f64 / 1_000_000_000.0)
+    } else if value >= 1_000_000 {
+        format!("{:.2} MB", value as f64 / 1_000_000.0)
+    } else if value >= 1_000 {
+        format!("{:.2} KB", value as f64 / 1_000.0)
+    } else {
+        value.to_string()
+    }
+}
+
+pub struct ServiceDetails<'a>(pub &'a ServiceProperty);
+
+impl DetailsRenderer for ServiceDetails<'_> {
+    fn lines(&self) -> Vec<Line<'static>> {
+        let properties = self.0;
+        let mut lines: Vec<Line> = vec![];
+
+        lines.push(property_line(
+            "ExecStart",
+            properties.formatted_exec_start(),
+        ));
+        lines.push(property_line(
+            "ExecStartPre",
+            properties.formatted_exec_start_pre(),
+        ));
+        lines.push(property_line(
+            "ExecStartPost",
+            properties.formatted_exec_start_post(),
+        ));
+        lines.push(property_line("ExecStop", properties.formatted_exec_stop()));
+        lines.push(property_line(
+            "ExecStopPost",
+            properties.formatted_exec_stop_post(),
+        ));
+
+        lines.push(Line::from(""));
+
+        lines.push(property_line(
+            "ExecMainPID",
+            properties.exec_main_pid().to_string(),
+        ));
+        lines.push(property_line(
+            "ExecMainStartTimestamp",
+            properties.format_timestamp(properties.exec_main_start_timestamp()),
+        ));
+        lines.push(property_line(
+            "ExecMainExitTimestamp",
+            properties.format_timestamp(properties.exec_main_exit_timestamp()),
+        ));
+        lines.push(property_line(
+            "ExecMainCode",
+            properties.exec_main_code().to_string(),
+        ));
+        lines.push(property_line(
+            "ExecMainStatus",
+            properties.exec_main_status().to_string(),
+        ));
+
+        lines.push(Line::from(""));
+
+        lines.push(property_line("MainPID", properties.main_pid().to_string()));
+        lines.push(property_line(
+            "ControlPID",
+            properties.control_pid().to_string(),
+        ));
+
+        lines.push(Line::from(""));
+
+        lines.push(property_line("Restart", properties.restart().to_string()));
+        lines.push(property_line(
+            "RestartUSec",
+            format!("{}s", (properties.restart_usec() as f64 / 1000.0)),
+        ));
+
+        lines.push(Line::from(""));
+
+        lines.push(property_line(
+            "StatusText",
+            properties.status_text().to_string(),
+        ));
+        lines.push(property_line("Result", properties.result().to_string()));
+
+        lines.push(Line::from(""));
+
+        lines.push(property_line("User", properties.user().to_string()));
+        lines.push(property_line("Group", properties.group().to_string()));
+
+        lines.push(Line::from(""));
+
+        if properties.environment().is_empty() {
+            lines.push(property_line("Environment", String::new()));
+        }
+        for assignment in properties.environment() {
+            lines.push(property_line("Environment", assignment.clone()));
+        }
+
+        lines.push(Line::from(""));
+
+        lines.push(property_line(
+            "CPU Limit",
+            format_units(properties.limit_cpu()),
+        ));
+        lines.push(property_line(
+            "Open Files Limit",
+            format_units(properties.limit_nofile()),
+        ));
+        lines.push(property_line(
+            "Process Limit",
+            properties.limit_nproc().to_string(),
+        ));
+        lines.push(property_line(
+            "Memory Lock Limit",
+            format_bytes(properties.limit_memlock()),
+        ));
+        lines.push(property_line(
+            "Memory Limit",
+            format_bytes(properties.memory_limit()),
+        ));
+        lines.push(property_line(
+            "CPU Shares",
+            format_units(properties.cpu_shares()),
+        ));
+
+        lines
+    }
+}
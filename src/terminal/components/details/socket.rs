@@ -0,0 +1,23 @@
+use ratatui::text::Line;
+
+use super::{DetailsRenderer, property_line};
+use crate::domain::socket_property::SocketProperty;
+
+pub struct SocketDetails<'a>(pub &'a SocketProperty);
+
+impl DetailsRenderer for SocketDetails<'_> {
+    fn lines(&self) -> Vec<Line<'static>> {
+        let properties = self.0;
+
+        vec![
+            property_line("Listen", properties.formatted_listen()),
+            property_line("Accept", properties.accept().to_string()),
+            Line::from(""),
+            property_line("NConnections", properties.n_connections().to_string()),
+            property_line("NAccepted", properties.n_accepted().to_string()),
+            property_line("NRefused", properties.n_refused().to_string()),
+            Line::from(""),
+            property_line("Result", properties.result().to_string()),
+        ]
+    }
+}
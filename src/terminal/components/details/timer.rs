@@ -0,0 +1,40 @@
+use ratatui::text::Line;
+
+use super::{DetailsRenderer, property_line};
+use crate::domain::timer_property::TimerProperty;
+
+pub struct TimerDetails<'a>(pub &'a TimerProperty);
+
+impl DetailsRenderer for TimerDetails<'_> {
+    fn lines(&self) -> Vec<Line<'static>> {
+        let properties = self.0;
+
+        vec![
+            property_line("Unit", properties.unit().to_string()),
+            Line::from(""),
+            property_line("TimersCalendar", properties.formatted_timers_calendar()),
+            property_line("TimersMonotonic", properties.formatted_timers_monotonic()),
+            Line::from(""),
+            property_line(
+                "NextElapse",
+                properties.format_usec_timestamp(properties.next_elapse_realtime()),
+            ),
+            property_line(
+                "LastTrigger",
+                properties.format_usec_timestamp(properties.last_trigger()),
+            ),
+            Line::from(""),
+            property_line("Persistent", properties.persistent().to_string()),
+            property_line(
+                "AccuracySec",
+                format!("{}s", properties.accuracy_usec() / 1_000_000),
+            ),
+            property_line(
+                "RandomizedDelaySec",
+                format!("{}s", properties.randomized_delay_usec() / 1_000_000),
+            ),
+            Line::from(""),
+            property_line("Result", properties.result().to_string()),
+        ]
+    }
+}
@@ -0,0 +1,194 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+
+use crate::domain::early_boot::EarlyBoot;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::theme::Theme;
+use crate::usecases::runtime::{self, Task};
+use crate::usecases::services_manager::ServicesManager;
+
+fn seconds(duration: Option<Duration>) -> String {
+    duration
+        .map(|duration| format!("{:.3}s", duration.as_secs_f64()))
+        .unwrap_or_default()
+}
+
+/// Units of the initial ramdisk, which are gone from the unit list once the
+/// real root is mounted.
+pub struct EarlyBootUnits {
+    early_boot: Option<EarlyBoot>,
+    scroll: usize,
+    fetch: Option<Task>,
+    sender: Sender<AppEvent>,
+}
+
+impl EarlyBootUnits {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            early_boot: None,
+            scroll: 0,
+            fetch: None,
+            sender,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.early_boot = None;
+        self.scroll = 0;
+    }
+
+    fn exit(&self) {
+        self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+    }
+
+    pub fn fetch_and_dispatch(&mut self) {
+        let event_tx = self.sender.clone();
+        self.cancel_fetches();
+        self.fetch = Some(runtime::spawn(async move {
+            match ServicesManager::get_early_boot().await {
+                Ok(early_boot) => event_tx
+                    .send(AppEvent::Action(Actions::UpdateEarlyBoot(early_boot)))
+                    .expect("Failed to send UpdateEarlyBoot event"),
+                Err(e) => event_tx
+                    .send(AppEvent::Error(e.to_string()))
+                    .expect("Failed to send Error event"),
+            }
+        }));
+    }
+
+    pub fn update(&mut self, early_boot: EarlyBoot) {
+        self.early_boot = Some(early_boot);
+    }
+}
+
+impl Component for EarlyBootUnits {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title_alignment(Alignment::Center);
+        let Some(early_boot) = &self.early_boot else {
+            let loading = Paragraph::new(tr("loading"))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(loading, area);
+            return;
+        };
+        let Some(switch_root) = early_boot.switch_root() else {
+            let message = Paragraph::new(tr("early-boot-none"))
+                .alignment(Alignment::Center)
+                .block(block.title(format!(" {} ", tr("early-boot-title"))));
+            frame.render_widget(message, area);
+            return;
+        };
+
+        let rows: Vec<Row> = early_boot
+            .units()
+            .iter()
+            .map(|unit| {
+                let result_style = match unit.result() {
+                    "done" => Style::default().fg(Theme::current().ok),
+                    "" => Style::default(),
+                    _ => Style::default().fg(Theme::current().error),
+                };
+                Row::new(vec![
+                    Cell::from(unit.name().to_string())
+                        .style(Style::default().add_modifier(Modifier::BOLD)),
+                    Cell::from(seconds(unit.at())),
+                    Cell::from(seconds(unit.took())),
+                    Cell::from(unit.result().to_string()).style(result_style),
+                ])
+            })
+            .collect();
+
+        self.scroll = self.scroll.min(rows.len().saturating_sub(1));
+        let mut table_state = TableState::default().with_offset(self.scroll);
+
+        let title = Line::from(format!(
+            " {} ",
+            tr_args(
+                "early-boot-title-switched",
+                &[("seconds", &seconds(Some(switch_root)))]
+            )
+        ));
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Min(30),
+                Constraint::Length(12),
+                Constraint::Length(12),
+                Constraint::Length(10),
+            ],
+        )
+        .header(
+            Row::new([
+                tr("early-boot-unit"),
+                tr("early-boot-at"),
+                tr("early-boot-took"),
+                tr("early-boot-result"),
+            ])
+            .style(
+                Style::default()
+                    .fg(Theme::current().header)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        )
+        .block(block.title(title));
+
+        frame.render_stateful_widget(table, area, &mut table_state);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Up => {
+                self.scroll = self.scroll.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                self.scroll += 1;
+            }
+            KeyCode::PageUp => {
+                self.scroll = self.scroll.saturating_sub(10);
+            }
+            KeyCode::PageDown => {
+                self.scroll += 10;
+            }
+            KeyCode::Char('r') => {
+                self.reset();
+                self.fetch_and_dispatch();
+            }
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.reset();
+                self.exit();
+            }
+            _ => {}
+        }
+    }
+
+    fn shortcuts(&self) -> Vec<Line<'static>> {
+        vec![
+            Line::from(vec![Span::styled(
+                tr("actions-heading"),
+                Style::default()
+                    .fg(Theme::current().heading)
+                    .add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(tr("early-boot-shortcuts")),
+        ]
+    }
+
+    fn cancel_fetches(&mut self) {
+        if let Some(fetch) = self.fetch.take() {
+            fetch.cancel();
+        }
+    }
+}
@@ -1,19 +1,31 @@
 use ratatui::{
     crossterm::event::{KeyCode, KeyEvent, KeyEventKind},
     layout::{Constraint, Layout, Position, Rect},
-    style::{Color, Style, Stylize},
+    style::{Style, Stylize},
     text::{Line, Text},
     widgets::{Block, Paragraph},
     Frame,
 };
 use std::sync::mpsc::Sender;
 
+use crate::config::{Config, Keymap};
 use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::history::History;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::theme::Theme;
+
+/// File of the config directory keeping the submitted filters.
+const HISTORY_FILE: &str = "filter_history";
 
 pub struct Filter {
     pub input: String,
     character_index: usize,
     pub input_mode: InputMode,
+    /// Filters submitted before, recalled with ↑/↓ while editing.
+    history: History,
+    /// Index of the preset of the config file last loaded with `P`.
+    preset: Option<usize>,
     sender: Sender<AppEvent>,
 }
 
@@ -24,12 +36,69 @@ pub enum InputMode {
 }
 
 impl Filter {
-    pub const fn new(sender: Sender<AppEvent>) -> Self {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
         Self {
             sender,
             input: String::new(),
             input_mode: InputMode::Normal,
             character_index: 0,
+            history: History::load(HISTORY_FILE),
+            preset: None,
+        }
+    }
+
+    /// Filters the list with `input` as if it had been typed.
+    pub fn set_input(&mut self, input: &str) {
+        self.input = input.to_string();
+        self.character_index = self.input.chars().count();
+        self.sender
+            .send(AppEvent::Action(Actions::Filter(self.input.clone())))
+            .unwrap();
+    }
+
+    /// Loads the next preset of the config file, or clears the filter after
+    /// the last one.
+    fn cycle_preset(&mut self) {
+        let presets = &Config::get().filter_presets;
+        if presets.is_empty() {
+            self.sender
+                .send(AppEvent::Error(
+                    "No filter presets yet: add [[filter_presets]] entries with a name and a filter to the config file".to_string(),
+                ))
+                .unwrap();
+            return;
+        }
+        let next = self.preset.map_or(0, |preset| preset + 1);
+        match presets.get(next) {
+            Some(preset) => {
+                self.preset = Some(next);
+                self.input = preset.filter.clone();
+            }
+            None => {
+                self.preset = None;
+                self.input.clear();
+            }
+        }
+        self.character_index = self.input.chars().count();
+        self.sender
+            .send(AppEvent::Action(Actions::Filter(self.input.clone())))
+            .unwrap();
+    }
+
+    /// Name of the loaded preset, while the filter is still the one it set.
+    fn preset_name(&self) -> Option<String> {
+        Config::get()
+            .filter_presets
+            .get(self.preset?)
+            .filter(|preset| preset.filter == self.input)
+            .map(|preset| preset.name.clone())
+    }
+
+    /// Replaces the input with a filter recalled from the history.
+    fn recall(&mut self, filter: Option<String>) {
+        if let Some(filter) = filter {
+            self.character_index = filter.chars().count();
+            self.input = filter;
         }
     }
 
@@ -76,6 +145,7 @@ impl Filter {
     }
 
     fn submit_message(&mut self) {
+        self.history.push(&self.input);
         self.sender
             .send(AppEvent::Action(Actions::Filter(self.input.clone())))
             .unwrap();
@@ -84,16 +154,20 @@ impl Filter {
             .unwrap();
         self.input_mode = InputMode::Normal
     }
+}
 
-    pub fn on_key_event(&mut self, key: KeyEvent) {
+impl Component for Filter {
+    fn on_key_event(&mut self, key: KeyEvent) {
         match self.input_mode {
             InputMode::Normal => match key.code {
-                KeyCode::Char('i') => {
+                KeyCode::Char('/') if Config::get().keymap != Keymap::Vim => {}
+                KeyCode::Char('i') | KeyCode::Char('/') => {
                     self.sender
                         .send(AppEvent::Action(Actions::UpdateIgnoreListKeys(true)))
                         .unwrap();
                     self.input_mode = InputMode::Editing;
                 }
+                KeyCode::Char('P') => self.cycle_preset(),
                 KeyCode::Esc => {
                     self.input = String::new();
                     self.sender
@@ -108,11 +182,26 @@ impl Filter {
             InputMode::Editing if key.kind == KeyEventKind::Press => {
                 match key.code {
                     KeyCode::Enter => self.submit_message(),
-                    KeyCode::Char(to_insert) => self.enter_char(to_insert),
-                    KeyCode::Backspace => self.delete_char(),
+                    KeyCode::Char(to_insert) => {
+                        self.history.stop_browsing();
+                        self.enter_char(to_insert)
+                    }
+                    KeyCode::Backspace => {
+                        self.history.stop_browsing();
+                        self.delete_char()
+                    }
+                    KeyCode::Up => {
+                        let previous = self.history.previous(&self.input).map(str::to_string);
+                        self.recall(previous);
+                    }
+                    KeyCode::Down => {
+                        let next = self.history.next().map(str::to_string);
+                        self.recall(next);
+                    }
                     KeyCode::Left => self.move_cursor_left(),
                     KeyCode::Right => self.move_cursor_right(),
                     KeyCode::Esc => {
+                        self.history.stop_browsing();
                         self.sender
                             .send(AppEvent::Action(Actions::UpdateIgnoreListKeys(false)))
                             .unwrap();
@@ -128,13 +217,19 @@ impl Filter {
         }
     }
 
-    pub fn draw(&self, frame: &mut Frame, area: Rect) {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
         let vertical = Layout::vertical([Constraint::Length(1), Constraint::Length(3)]);
         let [help_area, input_area] = vertical.areas(area);
 
         let (msg, style) = match self.input_mode {
             InputMode::Normal => (
-                vec!["Press ".into(), "i".bold(), " to start filtering.".into()],
+                vec![
+                    "Press ".into(),
+                    "i".bold(),
+                    " to start filtering, ".into(),
+                    "P".bold(),
+                    " for the next preset.".into(),
+                ],
                 Style::default(),
             ),
             InputMode::Editing => (
@@ -143,7 +238,11 @@ impl Filter {
                     "Esc".bold(),
                     " to stop filtering, ".into(),
                     "Enter".bold(),
-                    " to submit filter".into(),
+                    " to submit filter, ".into(),
+                    "↑/↓".bold(),
+                    " for earlier ones. Besides names, ".into(),
+                    "state:failed type:timer enabled:yes".bold(),
+                    " narrow the list.".into(),
                 ],
                 Style::default(),
             ),
@@ -155,9 +254,16 @@ impl Filter {
         let input = Paragraph::new(self.input.as_str())
             .style(match self.input_mode {
                 InputMode::Normal => Style::default(),
-                InputMode::Editing => Style::default().fg(Color::Yellow),
+                InputMode::Editing => Style::default().fg(Theme::current().input),
             })
-            .block(Block::bordered().title("Input"));
+            .block(
+                Block::bordered()
+                    .border_style(Config::get().accent_style())
+                    .title(match self.preset_name() {
+                        Some(name) => tr_args("filter-title-preset", &[("preset", &name)]),
+                        None => tr("filter-title"),
+                    }),
+            );
         frame.render_widget(input, input_area);
         match self.input_mode {
             InputMode::Normal => {}
@@ -168,4 +274,8 @@ impl Filter {
             )),
         }
     }
+
+    fn is_typing(&self) -> bool {
+        self.input_mode == InputMode::Editing
+    }
 }
@@ -0,0 +1,99 @@
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::theme::Theme;
+
+const EIGHTHS: [char; 9] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+fn saturation_color(ratio: f64) -> Color {
+    if ratio < 0.7 {
+        Theme::current().ok
+    } else if ratio < 0.9 {
+        Theme::current().warning
+    } else {
+        Theme::current().error
+    }
+}
+
+/// Compact byte count for narrow columns (`512K`, `45M`, `1.2G`).
+pub fn compact_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "K", "M", "G", "T"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if value < 10.0 && unit > 0 {
+        format!("{:.1}{}", value, UNITS[unit])
+    } else {
+        format!("{:.0}{}", value, UNITS[unit])
+    }
+}
+
+/// Compact CPU time for narrow columns (`850ms`, `12.3s`, `4m12s`, `3h05m`).
+pub fn compact_duration(nsec: u64) -> String {
+    let millis = nsec / 1_000_000;
+    let seconds = millis / 1000;
+    if millis < 1000 {
+        format!("{}ms", millis)
+    } else if seconds < 60 {
+        format!("{:.1}s", millis as f64 / 1000.0)
+    } else if seconds < 3600 {
+        format!("{}m{:02}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}h{:02}m", seconds / 3600, seconds / 60 % 60)
+    }
+}
+
+/// `current` out of `max` in words, for the screen reader mode
+/// (`45M of 512M`, `12 of no limit`).
+pub fn usage_text(current: Option<u64>, max: Option<u64>, format: fn(u64) -> String) -> String {
+    match (current, max) {
+        (None, _) => tr("unknown"),
+        (Some(current), Some(max)) => tr_args(
+            "usage-of",
+            &[("current", &format(current)), ("max", &format(max))],
+        ),
+        (Some(current), None) => tr_args("usage-no-limit", &[("current", &format(current))]),
+    }
+}
+
+/// A bar `cells` characters wide showing `current` out of `max` with eighth
+/// blocks, followed by `label`. Without a limit only the label is shown, and
+/// without a value a dash.
+pub fn mini_gauge(
+    current: Option<u64>,
+    max: Option<u64>,
+    label: String,
+    cells: usize,
+) -> Line<'static> {
+    let Some(current) = current else {
+        return Line::styled(
+            format!("{:>width$}", "-", width = cells + 2),
+            Style::default().fg(Theme::current().faint),
+        );
+    };
+    let Some(max) = max.filter(|max| *max > 0) else {
+        return Line::from(format!("{} {}", " ".repeat(cells), label));
+    };
+
+    let ratio = (current as f64 / max as f64).min(1.0);
+    let eighths = (ratio * (cells * 8) as f64).round() as usize;
+    let bar: String = (0..cells)
+        .map(|cell| EIGHTHS[eighths.saturating_sub(cell * 8).min(8)])
+        .collect();
+
+    Line::from(vec![
+        Span::styled(
+            bar,
+            Style::default()
+                .fg(saturation_color(ratio))
+                .bg(Theme::current().faint),
+        ),
+        Span::raw(format!(" {}", label)),
+    ])
+}
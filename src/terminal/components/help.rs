@@ -0,0 +1,127 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+use crate::terminal::components::component::Component;
+use crate::terminal::i18n::tr;
+use crate::terminal::keymap;
+use crate::terminal::theme::Theme;
+
+/// Width of the key column, enough for `Ctrl + d/Ctrl + u`.
+const KEY_WIDTH: usize = 20;
+
+/// Full-screen list of the keys of every view, opened with `?`. It is made of
+/// the hints each view shows in the shortcuts box, with the keys bound in the
+/// config file, so it says what pressing them does.
+#[derive(Default)]
+pub struct Help {
+    lines: Vec<Line<'static>>,
+    open: bool,
+    scroll: usize,
+    /// Lines shown at once when last drawn, to scroll by a page.
+    height: usize,
+}
+
+impl Help {
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Opens the help on `views`, each a title and the shortcut lines of its
+    /// components.
+    pub fn open(&mut self, views: Vec<(String, Vec<Line<'static>>)>) {
+        let mut lines = vec![];
+        for (title, shortcuts) in views {
+            let hints: Vec<String> = shortcuts
+                .into_iter()
+                .map(keymap::bound_keys)
+                .map(|line| line.to_string())
+                // The titles of the shortcuts box are replaced by the view.
+                .filter(|line| line.contains(": "))
+                .flat_map(|line| {
+                    line.split(" | ")
+                        .map(|hint| hint.trim().to_string())
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+            if hints.is_empty() {
+                continue;
+            }
+            if !lines.is_empty() {
+                lines.push(Line::raw(""));
+            }
+            lines.push(Line::styled(
+                title,
+                Style::default()
+                    .fg(Theme::current().heading)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            for hint in hints {
+                lines.push(match hint.rsplit_once(": ") {
+                    Some((label, keys)) => Line::from(vec![
+                        Span::styled(
+                            format!("  {:<width$}", keys, width = KEY_WIDTH),
+                            Style::default().add_modifier(Modifier::BOLD),
+                        ),
+                        Span::raw(label.to_string()),
+                    ]),
+                    None => Line::raw(format!("  {}", hint)),
+                });
+            }
+        }
+        self.lines = lines;
+        self.scroll = 0;
+        self.open = true;
+    }
+
+    fn close(&mut self) {
+        self.open = false;
+    }
+
+    fn scroll_by(&mut self, rows: isize) {
+        let max = self.lines.len().saturating_sub(self.height);
+        self.scroll = self.scroll.saturating_add_signed(rows).min(max);
+    }
+}
+
+impl Component for Help {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.open {
+            return;
+        }
+
+        self.height = usize::from(area.height.saturating_sub(2));
+        self.scroll_by(0);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::current().popup_border))
+            .title(format!(" {} ", tr("help-title")))
+            .title_bottom(Line::from(format!(" {} ", tr("help-shortcuts"))).right_aligned());
+        #[allow(clippy::cast_possible_truncation)]
+        let help = Paragraph::new(self.lines.clone())
+            .block(block)
+            .scroll((self.scroll as u16, 0));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(help, area);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        let page = self.height.max(1) as isize;
+        match key.code {
+            KeyCode::Up => self.scroll_by(-1),
+            KeyCode::Down => self.scroll_by(1),
+            KeyCode::PageUp => self.scroll_by(-page),
+            KeyCode::PageDown => self.scroll_by(page),
+            KeyCode::Home => self.scroll = 0,
+            KeyCode::End => self.scroll_by(isize::MAX),
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('?') => self.close(),
+            _ => {}
+        }
+    }
+}
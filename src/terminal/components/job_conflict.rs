@@ -0,0 +1,190 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+use std::sync::mpsc::Sender;
+
+use crate::domain::job_conflict::{JobConflict, QueuedJob};
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::components::list::ServiceAction;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::theme::Theme;
+use crate::usecases::runtime::{self, Task};
+use crate::usecases::services_manager::ServicesManager;
+
+/// Popup shown when a start or restart is refused because of a job already
+/// queued, listing that job and offering to cancel it and try again.
+pub struct JobConflictPopup {
+    /// Unit and action that were refused, with why.
+    pending: Option<(String, ServiceAction, JobConflict)>,
+    /// Queued jobs in the way, once listed.
+    jobs: Option<Vec<QueuedJob>>,
+    fetch: Option<Task>,
+    sender: Sender<AppEvent>,
+}
+
+impl JobConflictPopup {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            pending: None,
+            jobs: None,
+            fetch: None,
+            sender,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    pub fn open(&mut self, name: String, action: ServiceAction, conflict: JobConflict) {
+        self.pending = Some((name.clone(), action, conflict));
+        self.jobs = None;
+        let event_tx = self.sender.clone();
+        self.fetch = Some(runtime::spawn(async move {
+            let jobs = ServicesManager::list_jobs()
+                .await
+                .map_err(|e| e.to_string());
+            event_tx
+                .send(AppEvent::Action(Actions::UpdateJobConflict(name, jobs)))
+                .expect("Failed to send UpdateJobConflict event");
+        }));
+    }
+
+    fn close(&mut self) {
+        self.pending = None;
+        self.jobs = None;
+        if let Some(fetch) = self.fetch.take() {
+            fetch.cancel();
+        }
+    }
+
+    /// Keeps the queued jobs that are in the way of what was refused on `name`.
+    pub fn update(&mut self, name: &str, jobs: Result<Vec<QueuedJob>, String>) {
+        let Some((unit, _, conflict)) = &self.pending else {
+            return;
+        };
+        if unit != name {
+            return;
+        }
+        match jobs {
+            Ok(jobs) => {
+                self.jobs = Some(
+                    jobs.into_iter()
+                        .filter(|job| conflict.is_caused_by(job))
+                        .collect(),
+                )
+            }
+            Err(error) => {
+                self.close();
+                self.sender.send(AppEvent::Error(error)).unwrap();
+            }
+        }
+    }
+
+    /// Cancels the jobs in the way, then runs the action again.
+    fn cancel_and_retry(&mut self) {
+        let (Some((name, action, _)), Some(jobs)) = (self.pending.take(), self.jobs.take()) else {
+            return;
+        };
+        let event_tx = self.sender.clone();
+        runtime::spawn(async move {
+            for job in &jobs {
+                if let Err(e) = ServicesManager::cancel_job(job.id()).await {
+                    event_tx
+                        .send(AppEvent::Error(format!(
+                            "Could not cancel job {} ({} {}): {}",
+                            job.id(),
+                            job.unit(),
+                            job.job_type(),
+                            e
+                        )))
+                        .unwrap();
+                    return;
+                }
+            }
+            event_tx
+                .send(AppEvent::Action(Actions::RunServiceAction(name, action)))
+                .unwrap();
+        });
+    }
+}
+
+impl Component for JobConflictPopup {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let Some((name, action, conflict)) = &self.pending else {
+            return;
+        };
+        let title = match action {
+            ServiceAction::Restart => "job-conflict-title-restart",
+            _ => "job-conflict-title-start",
+        };
+
+        let mut text = vec![
+            Line::styled(
+                conflict.message(),
+                Style::default().fg(Theme::current().muted),
+            ),
+            Line::default(),
+        ];
+        let hint = match &self.jobs {
+            None => {
+                text.push(Line::from(tr("job-conflict-looking")));
+                String::new()
+            }
+            Some(jobs) if jobs.is_empty() => {
+                text.push(Line::from(tr("job-conflict-gone")));
+                format!(" {} ", tr("job-conflict-retry-shortcuts"))
+            }
+            Some(jobs) => {
+                text.push(Line::from(tr("job-conflict-queued")));
+                text.push(Line::default());
+                text.extend(jobs.iter().map(|job| {
+                    Line::from(vec![
+                        Span::styled(
+                            format!("• {} {}", job.unit(), job.job_type()),
+                            Style::default().add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(
+                            format!(" — job {}, {}", job.id(), job.state()),
+                            Style::default().fg(Theme::current().muted),
+                        ),
+                    ])
+                }));
+                format!(" {} ", tr("job-conflict-cancel-shortcuts"))
+            }
+        };
+
+        let width = area.width.saturating_sub(4).min(90);
+        let height = (text.len() as u16 + 4).min(area.height);
+        let popup_area = Rect::new(
+            area.x + (area.width.saturating_sub(width)) / 2,
+            area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        );
+        let popup = Paragraph::new(text).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Theme::current().error))
+                .title(format!(" {} ", tr_args(title, &[("unit", name)])))
+                .title_bottom(Line::from(hint).right_aligned()),
+        );
+
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(popup, popup_area);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Enter | KeyCode::Char('y') if self.jobs.is_some() => self.cancel_and_retry(),
+            KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('q') => self.close(),
+            _ => {}
+        }
+    }
+}
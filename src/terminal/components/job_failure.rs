@@ -0,0 +1,183 @@
+use chrono::{DateTime, Local};
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+use std::sync::mpsc::Sender;
+
+use crate::domain::job_result::JobResult;
+use crate::domain::log_entry::LogEntry;
+use crate::domain::log_query::LogQuery;
+use crate::domain::service::Service;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::components::job_progress::result_text;
+use crate::terminal::components::log::priority_style;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::theme::Theme;
+use crate::usecases::runtime::{self, Task};
+use crate::usecases::services_manager::ServicesManager;
+
+/// Most recent entries of the unit shown under a failed job.
+const SNIPPET_LINES: usize = 15;
+
+/// A job queued from the list that did not go well.
+struct FailedJob {
+    service: Service,
+    /// The action, as written in the action log.
+    label: String,
+    result: JobResult,
+}
+
+/// Popup shown when a start, stop or restart ends in failure, with what
+/// systemd reported and what the unit logged since the job was queued.
+pub struct JobFailurePopup {
+    failed: Option<FailedJob>,
+    /// Entries of the unit logged during the job, or why they could not be read.
+    snippet: Option<Result<Vec<LogEntry>, String>>,
+    fetch: Option<Task>,
+    sender: Sender<AppEvent>,
+}
+
+impl JobFailurePopup {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            failed: None,
+            snippet: None,
+            fetch: None,
+            sender,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.failed.is_some()
+    }
+
+    /// Shows how the job on `service` ended and fetches what the unit logged
+    /// since it was queued at `since`.
+    pub fn open(
+        &mut self,
+        service: Service,
+        label: String,
+        result: JobResult,
+        since: DateTime<Local>,
+    ) {
+        let query = LogQuery::default()
+            .with_time_range(Some(&since.format("%Y-%m-%d %H:%M:%S").to_string()), None)
+            .with_lines(Some(SNIPPET_LINES));
+        let event_tx = self.sender.clone();
+        let fetched = service.clone();
+        self.fetch = Some(runtime::spawn(async move {
+            let entries = ServicesManager::get_log(&fetched, &query)
+                .await
+                .map_err(|e| e.to_string());
+            event_tx
+                .send(AppEvent::Action(Actions::UpdateJobFailure(
+                    fetched.name().to_string(),
+                    entries,
+                )))
+                .expect("Failed to send UpdateJobFailure event");
+        }));
+        self.failed = Some(FailedJob {
+            service,
+            label,
+            result,
+        });
+        self.snippet = None;
+    }
+
+    pub fn update(&mut self, name: &str, entries: Result<Vec<LogEntry>, String>) {
+        if self
+            .failed
+            .as_ref()
+            .is_some_and(|failed| failed.service.name() == name)
+        {
+            self.snippet = Some(entries);
+        }
+    }
+
+    fn close(&mut self) {
+        self.failed = None;
+        self.snippet = None;
+        if let Some(fetch) = self.fetch.take() {
+            fetch.cancel();
+        }
+    }
+}
+
+impl Component for JobFailurePopup {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let Some(failed) = &self.failed else {
+            return;
+        };
+
+        let mut text = vec![
+            Line::styled(
+                tr_args(
+                    "job-failure-ended",
+                    &[
+                        ("job", &failed.label),
+                        ("result", &result_text(&failed.result)),
+                    ],
+                ),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Line::default(),
+        ];
+        match &self.snippet {
+            None => text.push(Line::from(tr("job-failure-reading"))),
+            Some(Err(error)) => text.push(Line::styled(
+                tr_args("job-failure-unreadable", &[("error", error)]),
+                Style::default().fg(Theme::current().muted),
+            )),
+            Some(Ok(entries)) if entries.is_empty() => text.push(Line::styled(
+                tr("job-failure-nothing-logged"),
+                Style::default().fg(Theme::current().muted),
+            )),
+            Some(Ok(entries)) => {
+                text.push(Line::from(tr("job-failure-logged")));
+                text.extend(entries.iter().map(|entry| {
+                    Line::styled(entry.short_line(), priority_style(entry.priority()))
+                }));
+            }
+        }
+
+        let width = area.width.saturating_sub(4).min(120);
+        let height = (text.len() as u16 + 4).min(area.height);
+        let popup_area = Rect::new(
+            area.x + (area.width.saturating_sub(width)) / 2,
+            area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        );
+        let popup = Paragraph::new(text).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Theme::current().error))
+                .title(format!(
+                    " {} ",
+                    tr_args(
+                        "job-failure-title",
+                        &[("action", &failed.label), ("unit", failed.service.name())]
+                    )
+                ))
+                .title_bottom(
+                    Line::from(format!(" {} ", tr("job-failure-shortcuts"))).right_aligned(),
+                ),
+        );
+
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(popup, popup_area);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => self.close(),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+};
+use std::sync::mpsc::Sender;
+
+use crate::domain::job_mode::JobMode;
+use crate::domain::service::Service;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::components::list::ServiceAction;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::theme::Theme;
+
+/// Menu opened with `S` from the list to start or stop the selected unit with
+/// a job mode other than `replace`, as `systemctl --job-mode=` does.
+pub struct JobModeMenu {
+    service: Option<Service>,
+    selected: usize,
+    sender: Sender<AppEvent>,
+}
+
+impl JobModeMenu {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            service: None,
+            selected: 0,
+            sender,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.service.is_some()
+    }
+
+    pub fn open(&mut self, service: Service) {
+        self.service = Some(service);
+        self.selected = 0;
+    }
+
+    fn close(&mut self) {
+        self.service = None;
+    }
+
+    /// Runs the action with the chosen mode on the unit.
+    fn choose(&mut self, action: fn(JobMode) -> ServiceAction) {
+        let Some(service) = self.service.take() else {
+            return;
+        };
+        let mode = JobMode::ALL[self.selected];
+        self.sender
+            .send(AppEvent::Action(Actions::RunServiceAction(
+                service.name().to_string(),
+                action(mode),
+            )))
+            .unwrap();
+    }
+}
+
+impl Component for JobModeMenu {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let Some(service) = &self.service else {
+            return;
+        };
+
+        let width = area.width.saturating_sub(4).min(100);
+        let height = (JobMode::ALL.len() as u16 * 2 + 2).min(area.height);
+        let popup_area = Rect::new(
+            area.x + (area.width.saturating_sub(width)) / 2,
+            area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        );
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::current().popup_border))
+            .title(format!(
+                " {} ",
+                tr_args("job-mode-title", &[("unit", service.name())])
+            ))
+            .title_bottom(Line::from(format!(" {} ", tr("job-mode-shortcuts"))).right_aligned());
+
+        let items: Vec<ListItem> = JobMode::ALL
+            .iter()
+            .map(|mode| {
+                ListItem::new(vec![
+                    Line::from(Span::styled(
+                        mode.name(),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    )),
+                    Line::styled(
+                        format!("   {}", mode.explanation()),
+                        Style::default().fg(Theme::current().muted),
+                    ),
+                ])
+            })
+            .collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(
+                Style::default()
+                    .bg(Theme::current().selection_bg)
+                    .fg(Theme::current().selection_fg)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol(">> ");
+        let mut state = ListState::default().with_selected(Some(self.selected));
+
+        frame.render_widget(Clear, popup_area);
+        frame.render_stateful_widget(list, popup_area, &mut state);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => self.selected = (self.selected + 1).min(JobMode::ALL.len() - 1),
+            KeyCode::Char('s') => self.choose(ServiceAction::Start),
+            KeyCode::Char('x') => self.choose(ServiceAction::Stop),
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('S') => self.close(),
+            _ => {}
+        }
+    }
+}
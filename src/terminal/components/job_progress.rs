@@ -0,0 +1,121 @@
+use chrono::{DateTime, Local};
+use ratatui::style::Style;
+use std::time::{Duration, Instant};
+
+use crate::domain::job_result::JobResult;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::theme::Theme;
+
+const SPINNER: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+/// Time each frame of the spinner is shown.
+pub const SPINNER_INTERVAL: Duration = Duration::from_millis(100);
+/// How long a job that went well is still shown on its row; failures stay
+/// until the next action on the unit.
+const SUCCESS_SHOWN: Duration = Duration::from_secs(10);
+
+/// How a job ended, in words; results systemd may add later are shown as it
+/// names them.
+pub fn result_text(result: &JobResult) -> String {
+    match result {
+        JobResult::Done => tr("job-result-done"),
+        JobResult::Canceled => tr("job-result-canceled"),
+        JobResult::Timeout => tr("job-result-timeout"),
+        JobResult::Failed => tr("job-result-failed"),
+        JobResult::Dependency => tr("job-result-dependency"),
+        JobResult::Skipped => tr("job-result-skipped"),
+        JobResult::Other(result) => result.clone(),
+    }
+}
+
+/// Job queued on a unit from the list, shown in the active column of its row.
+pub struct JobProgress {
+    /// Object path of the job.
+    job: String,
+    /// The action, as written in the action log.
+    label: String,
+    started: Instant,
+    /// When the job was queued, from which its failure is looked up in the journal.
+    queued_at: DateTime<Local>,
+    /// How the job ended and when, once it did.
+    result: Option<(JobResult, Instant)>,
+}
+
+impl JobProgress {
+    pub fn new(job: String, label: String) -> Self {
+        Self {
+            job,
+            label,
+            started: Instant::now(),
+            queued_at: Local::now(),
+            result: None,
+        }
+    }
+
+    pub fn job(&self) -> &str {
+        &self.job
+    }
+
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn queued_at(&self) -> DateTime<Local> {
+        self.queued_at
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.result.is_none()
+    }
+
+    pub fn finish(&mut self, result: JobResult) {
+        self.result = Some((result, Instant::now()));
+    }
+
+    /// Whether the row still has something to show about the job.
+    pub fn is_shown(&self) -> bool {
+        match &self.result {
+            Some((result, at)) => !result.is_success() || at.elapsed() < SUCCESS_SHOWN,
+            None => true,
+        }
+    }
+
+    /// Text of the active column, such as `⠹ restart…` or `✗ start: timed out`.
+    pub fn text(&self) -> String {
+        match &self.result {
+            None => {
+                let frame = self.started.elapsed().as_millis() / SPINNER_INTERVAL.as_millis();
+                format!(
+                    "{} {}…",
+                    SPINNER[frame as usize % SPINNER.len()],
+                    self.label
+                )
+            }
+            Some((result, _)) if result.is_success() => {
+                format!("✓ {}: {}", self.label, result_text(result))
+            }
+            Some((result, _)) => format!("✗ {}: {}", self.label, result_text(result)),
+        }
+    }
+
+    /// The job in words, for the screen reader mode.
+    pub fn describe(&self) -> String {
+        match &self.result {
+            None => tr_args("reader-job-running", &[("job", &self.label)]),
+            Some((result, _)) => tr_args(
+                "reader-job-ended",
+                &[("job", &self.label), ("result", &result_text(result))],
+            ),
+        }
+    }
+
+    /// Colored like a unit passing between states while running, then like
+    /// an active or a failed one.
+    pub fn style(&self, theme: &Theme) -> Style {
+        let state = match &self.result {
+            None => "activating",
+            Some((result, _)) if result.is_success() => "active",
+            Some(_) => "failed",
+        };
+        Style::default().fg(theme.color_of(state))
+    }
+}
@@ -0,0 +1,271 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+};
+use std::sync::mpsc::Sender;
+
+use crate::domain::journal_search::UnitHits;
+use crate::domain::log_query::LogQuery;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::components::log::{compile_pattern, parse_time_range};
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::theme::Theme;
+use crate::usecases::runtime::{self, Task};
+use crate::usecases::services_manager::ServicesManager;
+
+/// Window searched when none is typed after the pattern.
+const DEFAULT_SINCE: &str = "-1h";
+
+/// Units whose journal entries match a pattern within a time window, opened
+/// with `J` from the list and searched among the units it lists.
+pub struct JournalSearch {
+    pattern: String,
+    since: Option<String>,
+    until: Option<String>,
+    /// Units searched, so `r` searches the same ones again.
+    units: Vec<String>,
+    hits: Option<Vec<UnitHits>>,
+    table_state: TableState,
+    fetch: Option<Task>,
+    sender: Sender<AppEvent>,
+}
+
+impl JournalSearch {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            pattern: String::new(),
+            since: None,
+            until: None,
+            units: vec![],
+            hits: None,
+            table_state: TableState::default(),
+            fetch: None,
+            sender,
+        }
+    }
+
+    /// Searches `units` for `input`, a pattern optionally followed by
+    /// ` @ SINCE..UNTIL`.
+    pub fn search(&mut self, input: &str, units: Vec<String>) {
+        let (pattern, window) = match input.rsplit_once(" @ ") {
+            Some((pattern, window)) => (pattern, window.trim()),
+            None => (input, ""),
+        };
+        let (since, until) = if window.is_empty() {
+            (Some(DEFAULT_SINCE), None)
+        } else {
+            parse_time_range(window)
+        };
+        let non_blank = |value: Option<&str>| {
+            value
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+        self.pattern = pattern.trim().to_string();
+        self.since = non_blank(since);
+        self.until = non_blank(until);
+        self.units = units;
+        self.fetch_and_dispatch();
+    }
+
+    fn fetch_and_dispatch(&mut self) {
+        self.hits = None;
+        self.table_state.select(Some(0));
+        let Some((pattern, _)) = compile_pattern(&self.pattern) else {
+            self.sender
+                .send(AppEvent::Error(tr("journal-search-no-pattern")))
+                .unwrap();
+            self.exit();
+            return;
+        };
+        let query =
+            LogQuery::default().with_time_range(self.since.as_deref(), self.until.as_deref());
+        let units = self.units.clone();
+        let event_tx = self.sender.clone();
+        self.cancel_fetches();
+        self.fetch = Some(runtime::spawn(async move {
+            match ServicesManager::search_journal(units, pattern, &query).await {
+                Ok(hits) => event_tx
+                    .send(AppEvent::Action(Actions::UpdateJournalSearch(hits)))
+                    .expect("Failed to send UpdateJournalSearch event"),
+                Err(e) => event_tx
+                    .send(AppEvent::Error(e.to_string()))
+                    .expect("Failed to send Error event"),
+            }
+        }));
+    }
+
+    pub fn update(&mut self, hits: Vec<UnitHits>) {
+        self.hits = Some(hits);
+    }
+
+    /// Pattern and window of the search, for the log of a matching unit.
+    pub fn window(&self) -> (&str, Option<&str>, Option<&str>) {
+        (&self.pattern, self.since.as_deref(), self.until.as_deref())
+    }
+
+    fn exit(&self) {
+        self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+    }
+
+    fn select_next(&mut self, step: usize) {
+        let count = self.hits.as_ref().map_or(0, Vec::len);
+        let selected = self.table_state.selected().unwrap_or(0);
+        self.table_state
+            .select(Some((selected + step).min(count.saturating_sub(1))));
+    }
+
+    fn select_previous(&mut self, step: usize) {
+        let selected = self.table_state.selected().unwrap_or(0);
+        self.table_state.select(Some(selected.saturating_sub(step)));
+    }
+
+    fn open_selected(&self) {
+        let Some(unit_hits) = self
+            .hits
+            .as_ref()
+            .zip(self.table_state.selected())
+            .and_then(|(hits, selected)| hits.get(selected))
+        else {
+            return;
+        };
+        self.sender
+            .send(AppEvent::Action(Actions::OpenSearchHit(
+                unit_hits.name().to_string(),
+            )))
+            .unwrap();
+    }
+
+    fn describe_window(&self) -> String {
+        match (&self.since, &self.until) {
+            (Some(since), Some(until)) => tr_args(
+                "journal-search-between",
+                &[("since", since), ("until", until)],
+            ),
+            (Some(since), None) => tr_args("journal-search-since", &[("since", since)]),
+            (None, Some(until)) => tr_args("journal-search-until", &[("until", until)]),
+            (None, None) => tr("journal-search-whole"),
+        }
+    }
+}
+
+impl Component for JournalSearch {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title_alignment(Alignment::Center);
+        let Some(hits) = &self.hits else {
+            let searching = Paragraph::new(tr_args(
+                "journal-search-searching",
+                &[
+                    ("units", &self.units.len().to_string()),
+                    ("pattern", &self.pattern),
+                    ("window", &self.describe_window()),
+                ],
+            ))
+            .alignment(Alignment::Center)
+            .block(block);
+            frame.render_widget(searching, area);
+            return;
+        };
+
+        let title = format!(
+            " {} ",
+            tr_args(
+                "journal-search-title",
+                &[
+                    ("pattern", &self.pattern),
+                    ("window", &self.describe_window()),
+                    ("matching", &hits.len().to_string()),
+                    ("units", &self.units.len().to_string()),
+                ],
+            )
+        );
+        if hits.is_empty() {
+            let message = Paragraph::new(tr("journal-search-no-hits"))
+                .alignment(Alignment::Center)
+                .block(block.title(title));
+            frame.render_widget(message, area);
+            return;
+        }
+
+        let rows: Vec<Row> = hits
+            .iter()
+            .map(|unit_hits| {
+                Row::new(vec![
+                    Cell::from(unit_hits.name().to_string())
+                        .style(Style::default().add_modifier(Modifier::BOLD)),
+                    Cell::from(unit_hits.count().to_string()),
+                    Cell::from(unit_hits.last().short_line())
+                        .style(Style::default().fg(Theme::current().muted)),
+                ])
+            })
+            .collect();
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(25),
+                Constraint::Length(8),
+                Constraint::Min(0),
+            ],
+        )
+        .header(
+            Row::new([
+                tr("journal-search-unit"),
+                tr("journal-search-hits"),
+                tr("journal-search-last-match"),
+            ])
+            .style(
+                Style::default()
+                    .fg(Theme::current().header)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        )
+        .row_highlight_style(
+            Style::default()
+                .bg(Theme::current().selection_bg)
+                .fg(Theme::current().selection_fg)
+                .add_modifier(Modifier::BOLD),
+        )
+        .block(block.title(title));
+
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Down => self.select_next(1),
+            KeyCode::Up => self.select_previous(1),
+            KeyCode::PageDown => self.select_next(10),
+            KeyCode::PageUp => self.select_previous(10),
+            KeyCode::Enter | KeyCode::Char('v') => self.open_selected(),
+            KeyCode::Char('r') => self.fetch_and_dispatch(),
+            KeyCode::Char('q') | KeyCode::Esc => self.exit(),
+            _ => {}
+        }
+    }
+
+    fn shortcuts(&self) -> Vec<Line<'static>> {
+        vec![
+            Line::from(vec![Span::styled(
+                tr("actions-heading"),
+                Style::default()
+                    .fg(Theme::current().heading)
+                    .add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(tr("journal-search-shortcuts")),
+        ]
+    }
+
+    fn cancel_fetches(&mut self) {
+        if let Some(fetch) = self.fetch.take() {
+            fetch.cancel();
+        }
+    }
+}
@@ -1,224 +1,1612 @@
+use crate::terminal::theme::Theme;
+use crate::usecases::plugins::{PluginExtension, Plugins};
+use crate::usecases::runtime;
 use crate::usecases::services_manager::ServicesManager;
-use crossterm::event::{KeyCode, KeyEvent};
-use ratatui::layout::Rect;
-use ratatui::style::{Color, Modifier, Style};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Flex, Layout, Rect};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::{
     layout::Constraint,
-    widgets::{Block, Borders, Cell, Row, Table, TableState},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap},
     Frame,
 };
-use std::error::Error;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
 use std::sync::mpsc::Sender;
+use std::time::Duration;
+use tokio::time;
 
+use crate::config::{Column, Config, TruncationSide};
+use crate::domain::job_conflict::JobConflict;
+use crate::domain::job_mode::JobMode;
+use crate::domain::job_result::JobResult;
+use crate::domain::resource_usage::ResourceUsage;
 use crate::domain::service::Service;
+use crate::domain::unit_family::UnitFamily;
+use crate::domain::unit_filter::UnitFilter;
+use crate::domain::unit_type::UnitType;
+use crate::terminal::action_log::{ActionLog, LoggedAction};
 use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::bundle;
+use crate::terminal::components::component::Component;
+use crate::terminal::components::gauge::{compact_bytes, compact_duration, mini_gauge, usage_text};
+use crate::terminal::components::job_progress::{JobProgress, SPINNER_INTERVAL, result_text};
+use crate::terminal::components::prompt::PromptKind;
+use crate::terminal::favorites::Favorites;
+use crate::terminal::i18n::{tr, tr_args, tr_count};
+use crate::terminal::keymap::{self, VimKeys};
+use crate::terminal::mouse::{self, DoubleClick};
+use crate::terminal::store::{DataKey, Store, Stored};
+use crate::terminal::truncate::{truncate, truncate_line};
 
-fn generate_rows(services: &[Service]) -> Vec<Row<'static>> {
-    services
+const NAME_WIDTH: Constraint = Constraint::Percentage(15);
+const GAUGE_CELLS: usize = 5;
+const HIGHLIGHT_SYMBOL: &str = ">> ";
+/// How long a unit acted on through this tool is marked with ✎ in the list.
+const RECENT_ACTION: Duration = Duration::from_secs(24 * 60 * 60);
+
+fn column_title(column: Column) -> String {
+    match column {
+        Column::Active => tr("list-column-active"),
+        Column::State => tr("list-column-state"),
+        Column::Load => tr("list-column-load"),
+        Column::Path => tr("list-column-path"),
+        Column::Memory => tr("list-column-memory"),
+        Column::Cpu => tr("list-column-cpu"),
+        Column::Tasks => tr("list-column-tasks"),
+        Column::Description => tr("list-column-description"),
+        Column::Plugin(index) => Plugins::columns()
+            .get(index)
+            .map_or("", PluginExtension::title)
+            .to_string(),
+    }
+}
+
+fn column_width(column: Column) -> Constraint {
+    match column {
+        Column::Active => Constraint::Length(20),
+        Column::State | Column::Load | Column::Tasks => Constraint::Length(10),
+        Column::Path => Constraint::Percentage(20),
+        Column::Memory => Constraint::Length(11),
+        Column::Cpu => Constraint::Length(7),
+        Column::Description => Constraint::Percentage(30),
+        Column::Plugin(_) => Constraint::Length(15),
+    }
+}
+
+/// Widths of the name column followed by `columns`.
+fn constraints(columns: &[Column]) -> Vec<Constraint> {
+    std::iter::once(NAME_WIDTH)
+        .chain(columns.iter().map(|column| column_width(*column)))
+        .collect()
+}
+
+/// Width of every column once the table is drawn in `area`, laid out the way
+/// `Table` does it.
+fn column_widths(area: Rect, columns: &[Column]) -> Vec<usize> {
+    let inner_width = area
+        .width
+        .saturating_sub(2)
+        .saturating_sub(HIGHLIGHT_SYMBOL.len() as u16);
+    Layout::horizontal(constraints(columns))
+        .flex(Flex::Start)
+        .spacing(1)
+        .split(Rect::new(0, 0, inner_width, 1))
         .iter()
-        .map(|service| {
-            let highlight_style = Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD);
-            let normal_style = Style::default().fg(Color::Gray);
-
-            let state_style = match service.state().active() {
-                "active" => Style::default().fg(Color::Green),
-                "activating" => Style::default().fg(Color::Yellow),
-                _ => Style::default().fg(Color::Red),
+        .map(|column| usize::from(column.width))
+        .collect()
+}
+
+/// Chip put in front of units started by a timer or a socket.
+fn activator_chip(activator: &str) -> Option<Span<'static>> {
+    let symbol = match UnitType::from_name(activator) {
+        UnitType::Timer => "⏲ ",
+        UnitType::Socket => "⇄ ",
+        _ => return None,
+    };
+    Some(Span::styled(
+        symbol,
+        Style::default().fg(Theme::current().link),
+    ))
+}
+
+/// Column the table is sorted by.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SortColumn {
+    Name,
+    Load,
+    Active,
+    Sub,
+    Description,
+}
+
+/// Order of the table, cycled with `o` through every column, ascending then
+/// descending.
+#[derive(Debug, Clone, Copy)]
+struct Sort {
+    column: SortColumn,
+    descending: bool,
+}
+
+impl Default for Sort {
+    /// The order units come in, by name with instances after their template.
+    fn default() -> Self {
+        Self {
+            column: SortColumn::Name,
+            descending: false,
+        }
+    }
+}
+
+impl Sort {
+    fn next(self) -> Self {
+        if !self.descending {
+            return Self {
+                descending: true,
+                ..self
             };
+        }
+        let column = match self.column {
+            SortColumn::Name => SortColumn::Load,
+            SortColumn::Load => SortColumn::Active,
+            SortColumn::Active => SortColumn::Sub,
+            SortColumn::Sub => SortColumn::Description,
+            SortColumn::Description => SortColumn::Name,
+        };
+        Self {
+            column,
+            descending: false,
+        }
+    }
 
-            Row::new(vec![
-                Cell::from(service.formatted_name().to_string()).style(highlight_style),
-                Cell::from(format!(
-                    "{} ({})",
-                    service.state().active(),
-                    service.state().sub()
-                ))
-                .style(state_style),
-                Cell::from(service.state().file().to_string()).style(normal_style),
-                Cell::from(service.state().load().to_string()).style(normal_style),
-                Cell::from(service.description().to_string()).style(normal_style),
-            ])
+    fn label(&self) -> String {
+        tr(match self.column {
+            SortColumn::Name => "list-sort-name",
+            SortColumn::Load => "list-sort-load",
+            SortColumn::Active => "list-sort-active",
+            SortColumn::Sub => "list-sort-sub",
+            SortColumn::Description => "list-sort-description",
+        })
+    }
+
+    fn direction(&self) -> String {
+        tr(if self.descending {
+            "list-sort-descending"
+        } else {
+            "list-sort-ascending"
+        })
+    }
+
+    /// Sorts `services`, which come sorted by name, keeping that order between
+    /// units with the same value.
+    fn apply(&self, services: &mut [Service]) {
+        let key = |service: &Service| match self.column {
+            SortColumn::Name => String::new(),
+            SortColumn::Load => service.state().load().to_string(),
+            SortColumn::Active => service.state().active().to_string(),
+            SortColumn::Sub => service.state().sub().to_string(),
+            SortColumn::Description => service.description().to_lowercase(),
+        };
+        services.sort_by_cached_key(key);
+        if self.descending {
+            services.reverse();
+        }
+    }
+}
+
+/// Column titles, with an arrow on the one the table is sorted by.
+fn header_row(sort: Sort, columns: &[Column]) -> Row<'static> {
+    let arrow = if sort.descending { " ▼" } else { " ▲" };
+    let sorted = match sort.column {
+        SortColumn::Name => None,
+        SortColumn::Active | SortColumn::Sub => Some(Column::Active),
+        SortColumn::Load => Some(Column::Load),
+        SortColumn::Description => Some(Column::Description),
+    };
+    let mut titles = vec![tr("list-column-name")];
+    titles.extend(columns.iter().map(|column| match column {
+        Column::Active if sort.column == SortColumn::Sub => tr("list-column-active-sub"),
+        _ => column_title(*column),
+    }));
+    let sorted = match sorted {
+        Some(sorted) => columns
+            .iter()
+            .position(|column| *column == sorted)
+            .map(|index| index + 1),
+        None => Some(0),
+    };
+    if let Some(sorted) = sorted {
+        titles[sorted].push_str(arrow);
+    }
+    Row::new(titles).style(
+        Style::default()
+            .fg(Theme::current().header)
+            .add_modifier(Modifier::BOLD),
+    )
+}
+
+/// What the table is divided into sections by, cycled with `z`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum Grouping {
+    #[default]
+    None,
+    Slice,
+    Type,
+    State,
+}
+
+impl Grouping {
+    fn next(self) -> Self {
+        match self {
+            Grouping::None => Grouping::Slice,
+            Grouping::Slice => Grouping::Type,
+            Grouping::Type => Grouping::State,
+            Grouping::State => Grouping::None,
+        }
+    }
+
+    /// Message naming what the units are grouped by.
+    fn label(&self) -> &'static str {
+        match self {
+            Grouping::None => "",
+            Grouping::Slice => "list-grouping-slice",
+            Grouping::Type => "list-grouping-type",
+            Grouping::State => "list-grouping-state",
+        }
+    }
+
+    /// Name of the section `service` is listed in.
+    fn section_of(&self, service: &Service, slices: &HashMap<String, String>) -> String {
+        match self {
+            Grouping::None => String::new(),
+            Grouping::Slice => slices
+                .get(service.name())
+                .cloned()
+                .unwrap_or_else(|| NO_SLICE.to_string()),
+            Grouping::Type => service
+                .name()
+                .rsplit_once('.')
+                .map_or(String::new(), |(_, suffix)| suffix.to_string()),
+            Grouping::State => service.state().active().to_string(),
+        }
+    }
+
+    /// Where the section called `name` comes: failures and services first,
+    /// slices by name with the units outside any last.
+    fn rank(&self, name: &str) -> (usize, String) {
+        let order: &[&str] = match self {
+            Grouping::Type => &["service", "socket", "timer", "mount"],
+            Grouping::State => &[
+                "failed",
+                "activating",
+                "deactivating",
+                "reloading",
+                "active",
+                "inactive",
+            ],
+            Grouping::Slice if name == NO_SLICE => return (1, String::new()),
+            _ => &[],
+        };
+        let position = order
+            .iter()
+            .position(|known| *known == name)
+            .unwrap_or(order.len());
+        (position, name.to_string())
+    }
+}
+
+/// Section of the units without a slice when grouping by slice, such as
+/// timers and units that are not running.
+const NO_SLICE: &str = "no slice";
+
+/// Header of a section of the table when it is grouped.
+struct Section {
+    name: String,
+    units: usize,
+    failed: usize,
+    collapsed: bool,
+}
+
+/// One row of the table.
+enum ListEntry {
+    /// Header of the units of a slice, type or state, followed by them unless collapsed.
+    Section(Section),
+    Unit(Service),
+    /// Header standing for the whole family, followed by its members when expanded.
+    Family(UnitFamily),
+    /// Unit listed under its expanded family.
+    Member(Service),
+}
+
+impl ListEntry {
+    /// Unit actions and views apply to, none for a section header.
+    fn service(&self) -> Option<&Service> {
+        match self {
+            ListEntry::Unit(service) | ListEntry::Member(service) => Some(service),
+            ListEntry::Family(family) => Some(family.main()),
+            ListEntry::Section(_) => None,
+        }
+    }
+}
+
+/// Divides the units into the sections of `grouping`, each header followed by
+/// its units with families gathered, unless it is collapsed.
+fn group_sections(
+    services: &[Service],
+    grouping: Grouping,
+    slices: &HashMap<String, String>,
+    expanded: &HashSet<String>,
+    collapsed: &HashSet<String>,
+) -> Vec<ListEntry> {
+    if grouping == Grouping::None {
+        return group_families(services, expanded);
+    }
+    let mut sections: Vec<(String, Vec<Service>)> = vec![];
+    for service in services {
+        let name = grouping.section_of(service, slices);
+        match sections.iter_mut().find(|(section, _)| *section == name) {
+            Some((_, members)) => members.push(service.clone()),
+            None => sections.push((name, vec![service.clone()])),
+        }
+    }
+    sections.sort_by_cached_key(|(name, _)| grouping.rank(name));
+
+    let mut entries = vec![];
+    for (name, members) in sections {
+        let is_collapsed = collapsed.contains(&name);
+        entries.push(ListEntry::Section(Section {
+            units: members.len(),
+            failed: members
+                .iter()
+                .filter(|member| member.state().active() == "failed")
+                .count(),
+            collapsed: is_collapsed,
+            name,
+        }));
+        if !is_collapsed {
+            entries.extend(group_families(&members, expanded));
+        }
+    }
+    entries
+}
+
+/// Row of a section header: its name, then how many units it has in the
+/// active column.
+fn section_row(section: &Section, columns: &[Column]) -> Row<'static> {
+    let arrow = if section.collapsed { "▸ " } else { "▾ " };
+    let units = section.units.to_string();
+    let count = if section.failed > 0 {
+        let failed = section.failed.to_string();
+        tr_args(
+            "list-section-count-failed",
+            &[("units", &units), ("failed", &failed)],
+        )
+    } else {
+        tr_args("list-section-count", &[("units", &units)])
+    };
+    let count_style = if section.failed > 0 {
+        Style::default().fg(Theme::current().error)
+    } else {
+        Style::default().fg(Theme::current().muted)
+    };
+    let mut cells = vec![
+        Cell::from(format!("{}{}", arrow, section.name)).style(
+            Style::default()
+                .fg(Theme::current().heading)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ];
+    cells.extend(columns.iter().map(|column| match column {
+        Column::Active => Cell::from(count.clone()).style(count_style),
+        _ => Cell::default(),
+    }));
+    Row::new(cells)
+}
+
+/// Name of the unit of a row, or of its section.
+fn row_key(entry: &ListEntry) -> &str {
+    match entry {
+        ListEntry::Section(section) => &section.name,
+        _ => entry.service().map_or("", Service::name),
+    }
+}
+
+/// Gathers units sharing a name into families, which take the place of their
+/// first member. Templates and their instances are already grouped and are left
+/// as they are.
+fn group_families(services: &[Service], expanded: &HashSet<String>) -> Vec<ListEntry> {
+    let groupable = |service: &Service| !service.is_template() && service.template_name().is_none();
+
+    let mut families: HashMap<&str, Vec<Service>> = HashMap::new();
+    for service in services.iter().filter(|service| groupable(service)) {
+        families
+            .entry(service.stem())
+            .or_default()
+            .push(service.clone());
+    }
+
+    let mut entries = vec![];
+    for service in services {
+        let members = families
+            .get(service.stem())
+            .filter(|members| groupable(service) && members.len() > 1);
+        let Some(members) = members else {
+            entries.push(ListEntry::Unit(service.clone()));
+            continue;
+        };
+        if members[0].name() != service.name() {
+            continue;
+        }
+        let family = UnitFamily::new(service.stem().to_string(), members.clone());
+        let is_expanded = expanded.contains(family.name());
+        entries.push(ListEntry::Family(family));
+        if is_expanded {
+            entries.extend(members.iter().cloned().map(ListEntry::Member));
+        }
+    }
+    entries
+}
+
+fn state_style(theme: &Theme, active: &str) -> Style {
+    Style::default().fg(theme.color_of(active))
+}
+
+/// Colors whether a unit starts at boot: green when it does, dim when it
+/// does not, blue when something else pulls it in and red when it cannot start.
+fn file_state_style(file_state: &str) -> Style {
+    match file_state {
+        "enabled" | "enabled-runtime" | "linked" | "linked-runtime" | "alias" => {
+            Style::default().fg(Theme::current().ok)
+        }
+        "static" | "indirect" | "generated" | "transient" => {
+            Style::default().fg(Theme::current().indirect)
+        }
+        "masked" | "masked-runtime" | "bad" => Style::default().fg(Theme::current().error),
+        _ => Style::default().fg(Theme::current().faint),
+    }
+}
+
+/// Spans of `text` with the characters at the `matched` char indices stand out.
+fn highlight_chars(text: &str, matched: &[usize]) -> Vec<Span<'static>> {
+    let highlight = Style::default()
+        .fg(Theme::current().input)
+        .add_modifier(Modifier::UNDERLINED);
+    let mut spans: Vec<Span<'static>> = vec![];
+    for (index, c) in text.chars().enumerate() {
+        let style = if matched.contains(&index) {
+            highlight
+        } else {
+            Style::default()
+        };
+        match spans.last_mut() {
+            Some(span) if span.style == style => span.content.to_mut().push(c),
+            _ => spans.push(Span::styled(c.to_string(), style)),
+        }
+    }
+    spans
+}
+
+/// Builds one row per entry, cutting every cell to the width of its column.
+#[allow(clippy::too_many_arguments)]
+fn generate_rows(
+    entries: &[ListEntry],
+    expanded: &HashSet<String>,
+    marked: &[String],
+    favorites: &Favorites,
+    action_log: &ActionLog,
+    jobs: &HashMap<String, JobProgress>,
+    resources: &HashMap<String, ResourceUsage>,
+    activators: &HashMap<String, Vec<String>>,
+    plugin_values: &[HashMap<String, String>],
+    columns: &[Column],
+    widths: &[usize],
+    filter: &UnitFilter,
+) -> Vec<Row<'static>> {
+    let config = Config::get();
+    let theme = Theme::current();
+    let side = config.truncation;
+    let fit = |column: usize, text: String| match widths.get(column) {
+        Some(width) => truncate(&text, *width, side),
+        None => text,
+    };
+
+    entries
+        .iter()
+        .map(|entry| {
+            let highlight_style = Style::default().fg(theme.info).add_modifier(Modifier::BOLD);
+            let normal_style = Style::default().fg(theme.muted);
+            let service = match entry {
+                ListEntry::Section(section) => return section_row(section, columns),
+                ListEntry::Unit(service) | ListEntry::Member(service) => service,
+                ListEntry::Family(family) => family.main(),
+            };
+
+            let mut name = Line::default();
+            if favorites.contains(service.name()) {
+                name.push_span(Span::styled("★ ", Style::default().fg(theme.mark)));
+            }
+            if marked.iter().any(|marked| marked == service.name()) {
+                name.push_span(Span::styled("● ", Style::default().fg(theme.mark)));
+            }
+            if recent_action(action_log, service).is_some() {
+                name.push_span(Span::styled("✎ ", Style::default().fg(theme.faint)));
+            }
+            match entry {
+                ListEntry::Unit(service) if service.template_name().is_some() => {
+                    name.push_span("  └ ");
+                }
+                ListEntry::Family(family) if expanded.contains(family.name()) => {
+                    name.push_span("▾ ");
+                }
+                ListEntry::Family(_) => name.push_span("▸ "),
+                ListEntry::Member(_) => name.push_span("  ├ "),
+                ListEntry::Unit(_) | ListEntry::Section(_) => {}
+            }
+            if let Some(chip) = activators
+                .get(service.name())
+                .and_then(|activators| activators.first())
+                .and_then(|activator| activator_chip(activator))
+            {
+                name.push_span(chip);
+            }
+            let shown_name = match entry {
+                ListEntry::Unit(service) => service.formatted_name(),
+                ListEntry::Family(family) => family.name(),
+                ListEntry::Member(service) => service.name(),
+                ListEntry::Section(section) => &section.name,
+            };
+            name.spans.extend(highlight_chars(
+                shown_name,
+                &filter.matched_chars(shown_name),
+            ));
+            let name = match widths.first() {
+                Some(width) => truncate_line(name, *width, side),
+                None => name,
+            };
+
+            let (active, description) = match entry {
+                ListEntry::Family(family) => (
+                    format!(
+                        "{} ({}/{})",
+                        family.active(),
+                        family.active_count(),
+                        family.members().len()
+                    ),
+                    format!("{} · {}", family.types(), service.description()),
+                ),
+                _ => (
+                    format!("{} ({})", service.state().active(), service.state().sub()),
+                    service.description().to_string(),
+                ),
+            };
+            let state_style = match entry {
+                ListEntry::Family(family) => state_style(&theme, family.active()),
+                _ => state_style(&theme, service.state().active()),
+            };
+            // A job queued from here takes the place of the state until it is over.
+            let (active, state_style) = match jobs.get(service.name()) {
+                Some(job) => (job.text(), job.style(&theme)),
+                None => (active, state_style),
+            };
+
+            let usage = resources.get(service.name());
+            let memory = usage.map_or(Line::default(), |usage| {
+                mini_gauge(
+                    usage.memory_current(),
+                    usage.memory_max(),
+                    usage
+                        .memory_current()
+                        .map(compact_bytes)
+                        .unwrap_or_default(),
+                    GAUGE_CELLS,
+                )
+            });
+            let tasks = usage.map_or(Line::default(), |usage| {
+                mini_gauge(
+                    usage.tasks_current(),
+                    usage.tasks_max(),
+                    usage
+                        .tasks_current()
+                        .map(|tasks| tasks.to_string())
+                        .unwrap_or_default(),
+                    GAUGE_CELLS,
+                )
+            });
+            let cpu = usage
+                .and_then(ResourceUsage::cpu_usage_nsec)
+                .map(compact_duration)
+                .unwrap_or_default();
+
+            let mut cells = vec![Cell::from(name).style(highlight_style)];
+            for (index, column) in columns.iter().enumerate() {
+                let index = index + 1;
+                cells.push(match column {
+                    Column::Active => Cell::from(fit(index, active.clone())).style(state_style),
+                    Column::State => Cell::from(fit(index, service.state().file().to_string()))
+                        .style(file_state_style(service.state().file())),
+                    Column::Load => Cell::from(fit(index, service.state().load().to_string()))
+                        .style(normal_style),
+                    // The end of a path says the most.
+                    Column::Path => Cell::from(match widths.get(index) {
+                        Some(width) => truncate(service.file_path(), *width, TruncationSide::Start),
+                        None => service.file_path().to_string(),
+                    })
+                    .style(normal_style),
+                    Column::Memory => Cell::from(memory.clone()),
+                    Column::Cpu => Cell::from(cpu.clone()).style(normal_style),
+                    Column::Tasks => Cell::from(tasks.clone()),
+                    Column::Description => {
+                        Cell::from(fit(index, description.clone())).style(normal_style)
+                    }
+                    Column::Plugin(plugin) => {
+                        let value = plugin_values
+                            .get(*plugin)
+                            .and_then(|values| values.get(service.name()))
+                            .cloned()
+                            .unwrap_or_default();
+                        Cell::from(fit(index, value)).style(normal_style)
+                    }
+                });
+            }
+            Row::new(cells)
         })
         .collect()
 }
 
+#[derive(Clone, Copy)]
 pub enum ServiceAction {
-    Start,
-    Stop,
+    Start(JobMode),
+    Stop(JobMode),
     Restart,
     Enable,
     Disable,
     RefreshAll,
 }
 
+impl ServiceAction {
+    /// How the action is written in the action log, none for those that do
+    /// not change the unit.
+    pub fn log_label(&self) -> Option<String> {
+        let with_mode = |verb: &str, mode: JobMode| {
+            if mode == JobMode::default() {
+                verb.to_string()
+            } else {
+                format!("{} ({})", verb, mode.name())
+            }
+        };
+        match self {
+            ServiceAction::Start(mode) => Some(with_mode("start", *mode)),
+            ServiceAction::Stop(mode) => Some(with_mode("stop", *mode)),
+            ServiceAction::Restart => Some("restart".to_string()),
+            ServiceAction::Enable => Some("enable".to_string()),
+            ServiceAction::Disable => Some("disable".to_string()),
+            ServiceAction::RefreshAll => None,
+        }
+    }
+
+    /// Sends the action on `service` to systemd, returning the object path of
+    /// the job it queued, if it queues one.
+    pub async fn run(
+        self,
+        service: &Service,
+    ) -> Result<Option<String>, Box<dyn std::error::Error>> {
+        match self {
+            ServiceAction::Start(mode) => ServicesManager::start_service(service, mode)
+                .await
+                .map(Some),
+            ServiceAction::Stop(mode) => {
+                ServicesManager::stop_service(service, mode).await.map(Some)
+            }
+            ServiceAction::Restart => ServicesManager::restart_service(service).await.map(Some),
+            ServiceAction::Enable => ServicesManager::enable_service(service).await.map(|_| None),
+            ServiceAction::Disable => ServicesManager::disable_service(service)
+                .await
+                .map(|_| None),
+            ServiceAction::RefreshAll => Ok(None),
+        }
+    }
+}
+
 pub struct TableServices<'a> {
     table: Table<'a>,
     pub table_state: TableState,
-    pub rows: Vec<Row<'static>>,
+    /// Rows built so far, by entry index. Only the rows drawn are built, and
+    /// they are kept until the entries change.
+    rows: HashMap<usize, Row<'static>>,
     pub services: Vec<Service>,
     filtered_services: Vec<Service>,
+    /// Rows of the table: the filtered units with families grouped.
+    entries: Vec<ListEntry>,
+    /// Names of the families whose members are listed.
+    expanded: HashSet<String>,
+    grouping: Grouping,
+    /// Names of the sections whose units are hidden.
+    collapsed: HashSet<String>,
+    /// Slice of each unit, while grouping by slice.
+    slices: HashMap<String, String>,
+    /// Units marked with `+`, in marking order, whose logs `v` shows merged.
+    marked: Vec<String>,
+    favorites: Favorites,
+    action_log: ActionLog,
+    /// Jobs queued from here, by unit name, until their result is no longer shown.
+    jobs: HashMap<String, JobProgress>,
+    /// Systemd reported a change while the units were being fetched.
+    changed_while_fetching: bool,
+    vim_keys: VimKeys,
+    /// Where the table was last drawn, for clicks.
+    area: Rect,
+    double_click: DoubleClick,
+    sort: Sort,
+    /// Columns shown besides the name, in table order.
+    columns: Vec<Column>,
+    resources: HashMap<String, ResourceUsage>,
+    activators: HashMap<String, Vec<String>>,
+    /// Values of the plugin columns, by column then by unit name.
+    plugin_values: Vec<HashMap<String, String>>,
+    /// Only failed units are listed, like `systemctl --failed`.
+    failed_only: bool,
+    /// Progress or outcome of the last support bundle, shown in the title.
+    bundle_notice: Option<String>,
     old_filter_text: String,
+    /// The filter read from `old_filter_text`, whose matches are highlighted.
+    unit_filter: UnitFilter,
     pub ignore_key_events: bool,
+    /// Column widths the rows were cut to, recomputed when the table is resized.
+    widths: Vec<usize>,
+    store: Rc<RefCell<Store>>,
     sender: Sender<AppEvent>,
 }
 
-impl TableServices<'_> {
-    pub fn new(sender: Sender<AppEvent>) -> Self {
-        let (services, rows) = match ServicesManager::list_services() {
-            Ok(svcs) => {
-                let rows = generate_rows(&svcs);
-                (svcs, rows)
-            }
-            Err(_) => {
-                let error_row = Row::new(vec!["Error loading services", "", "", "", ""]);
-                (vec![], vec![error_row])
-            }
-        };
+/// Last action taken on `service` through this tool, if it was within a day.
+fn recent_action<'a>(action_log: &'a ActionLog, service: &Service) -> Option<&'a LoggedAction> {
+    action_log
+        .last(service.name())
+        .filter(|action| action.age().is_some_and(|age| age < RECENT_ACTION))
+}
+
+/// Everything a row shows, in words, for the screen reader mode.
+#[allow(clippy::too_many_arguments)]
+fn describe_entry(
+    entry: &ListEntry,
+    expanded: &HashSet<String>,
+    marked: &[String],
+    favorites: &Favorites,
+    action_log: &ActionLog,
+    jobs: &HashMap<String, JobProgress>,
+    resources: &HashMap<String, ResourceUsage>,
+    activators: &HashMap<String, Vec<String>>,
+) -> String {
+    let service = match entry {
+        ListEntry::Section(section) => {
+            let state = tr(if section.collapsed {
+                "reader-collapsed"
+            } else {
+                "reader-expanded"
+            });
+            return tr_args(
+                "reader-section",
+                &[
+                    ("section", &section.name),
+                    ("units", &section.units.to_string()),
+                    ("failed", &section.failed.to_string()),
+                    ("state", &state),
+                ],
+            );
+        }
+        ListEntry::Unit(service) | ListEntry::Member(service) => service,
+        ListEntry::Family(family) => family.main(),
+    };
+    let mut parts = vec![];
+    match entry {
+        ListEntry::Family(family) => {
+            let state = tr(if expanded.contains(family.name()) {
+                "reader-expanded"
+            } else {
+                "reader-collapsed"
+            });
+            parts.push(tr_args(
+                "reader-family",
+                &[
+                    ("family", family.name()),
+                    ("types", &family.types()),
+                    ("state", &state),
+                ],
+            ));
+            parts.push(tr_args(
+                "reader-family-active",
+                &[
+                    ("state", family.active()),
+                    ("active", &family.active_count().to_string()),
+                    ("units", &family.members().len().to_string()),
+                ],
+            ));
+        }
+        ListEntry::Unit(service) | ListEntry::Member(service) => {
+            parts.push(service.name().to_string());
+            parts.push(format!(
+                "{} ({})",
+                service.state().active(),
+                service.state().sub()
+            ));
+        }
+        ListEntry::Section(_) => {}
+    }
+    if let Some(job) = jobs.get(service.name()) {
+        parts.push(job.describe());
+    }
+    parts.push(service.state().file().to_string());
+    parts.push(service.state().load().to_string());
+    if let Some(usage) = resources.get(service.name()) {
+        let memory = usage_text(usage.memory_current(), usage.memory_max(), compact_bytes);
+        parts.push(tr_args("reader-memory", &[("usage", &memory)]));
+        let tasks = usage_text(usage.tasks_current(), usage.tasks_max(), |tasks| {
+            tasks.to_string()
+        });
+        parts.push(tr_args("reader-tasks", &[("usage", &tasks)]));
+    }
+    if let Some(activator) = activators
+        .get(service.name())
+        .and_then(|activators| activators.first())
+    {
+        parts.push(tr_args("reader-started-by", &[("activator", activator)]));
+    }
+    if favorites.contains(service.name()) {
+        parts.push(tr("reader-starred"));
+    }
+    if marked.iter().any(|marked| marked == service.name()) {
+        parts.push(tr("reader-marked"));
+    }
+    if let Some(action) = recent_action(action_log, service) {
+        parts.push(tr_args(
+            "details-last-action",
+            &[("action", action.action()), ("user", action.user())],
+        ));
+    }
+    format!("{}. {}", parts.join(", "), service.description())
+}
+
+/// Every column the table can show, in table order: its own, then those of
+/// the plugins.
+pub fn table_columns() -> Vec<Column> {
+    Column::ALL
+        .into_iter()
+        .chain((0..Plugins::columns().len()).map(Column::Plugin))
+        .collect()
+}
+
+/// Columns listed in the config file, in table order, and those of the plugins.
+fn configured_columns() -> Vec<Column> {
+    let configured = Config::get().columns.clone();
+    table_columns()
+        .into_iter()
+        .filter(|column| matches!(column, Column::Plugin(_)) || configured.contains(column))
+        .collect()
+}
 
+fn table_block(
+    stale: bool,
+    failed_only: bool,
+    grouping: Grouping,
+    notice: Option<&str>,
+) -> Block<'static> {
+    let mut title = tr(if failed_only {
+        "list-title-failed"
+    } else {
+        "list-title"
+    });
+    if grouping != Grouping::None {
+        title = tr_args(
+            "list-title-grouped",
+            &[("title", &title), ("grouping", &tr(grouping.label()))],
+        );
+    }
+    if stale {
+        title.push_str(&format!(" ({})", tr("list-refreshing")));
+    }
+    if let Some(notice) = notice {
+        title.push_str(&format!(" · {}", notice));
+    }
+    Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Config::get().accent_style())
+}
+
+impl TableServices<'_> {
+    /// Units are filled in from the store once the first fetch lands.
+    pub fn new(sender: Sender<AppEvent>, store: Rc<RefCell<Store>>) -> Self {
         let mut table_state = TableState::default();
         table_state.select(Some(0));
-        let table = Table::new(
-            rows.clone(),
-            [
-                Constraint::Percentage(15),
-                Constraint::Length(20),
-                Constraint::Length(10),
-                Constraint::Length(10),
-                Constraint::Percentage(30),
-            ],
-        )
-        .header(
-            Row::new(["Name", "Active", "State", "Load", "Description"]).style(
-                Style::default()
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        )
-        .block(
-            Block::default()
-                .title("Systemd Services")
-                .borders(Borders::ALL),
-        )
-        .row_highlight_style(
-            Style::default()
-                .bg(Color::Blue)
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
-        )
-        .highlight_symbol(">> ");
+        let columns = configured_columns();
+        let table = Table::default()
+            .widths(constraints(&columns))
+            .header(header_row(Sort::default(), &columns))
+            .block(table_block(false, false, Grouping::None, None))
+            .highlight_symbol(HIGHLIGHT_SYMBOL);
         Self {
             table,
             table_state,
-            rows,
-            filtered_services: services.clone(),
-            services,
+            rows: HashMap::new(),
+            services: vec![],
+            filtered_services: vec![],
+            entries: vec![],
+            expanded: HashSet::new(),
+            grouping: Grouping::default(),
+            collapsed: HashSet::new(),
+            slices: HashMap::new(),
+            marked: vec![],
+            favorites: Favorites::load(),
+            action_log: ActionLog::load(),
+            jobs: HashMap::new(),
+            changed_while_fetching: false,
+            vim_keys: VimKeys::default(),
+            area: Rect::default(),
+            double_click: DoubleClick::default(),
+            sort: Sort::default(),
+            columns,
+            resources: HashMap::new(),
+            activators: HashMap::new(),
+            plugin_values: vec![],
+            failed_only: false,
+            bundle_notice: None,
+            widths: vec![],
+            store,
             sender,
             old_filter_text: String::new(),
+            unit_filter: UnitFilter::default(),
             ignore_key_events: false,
         }
     }
 
-    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
-        frame.render_stateful_widget(&self.table, area, &mut self.table_state);
+    pub fn set_ignore_key_events(&mut self, has_ignore_key_events: bool) {
+        self.ignore_key_events = has_ignore_key_events
     }
 
-    pub fn set_ignore_key_events(&mut self, has_ignore_key_events: bool) {
-        if has_ignore_key_events {
-            self.table = self.table.clone().row_highlight_style(
-                Style::default()
-                    .bg(Color::DarkGray)
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD),
-            );
+    /// Style of the selected row, dimmed while the filter is being typed.
+    fn highlight_style(&self) -> Style {
+        let theme = Theme::current();
+        let background = if self.ignore_key_events {
+            theme.faint
         } else {
-            self.table = self.table.clone().row_highlight_style(
-                Style::default()
-                    .bg(Color::Blue)
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD),
-            );
-        }
-        self.ignore_key_events = has_ignore_key_events
+            theme.selection_bg
+        };
+        Style::default()
+            .bg(background)
+            .fg(theme.selection_fg)
+            .add_modifier(Modifier::BOLD)
     }
 
     pub fn get_selected_service(&self) -> Option<&Service> {
-        if let Some(selected_index) = self.table_state.selected() {
-            if let Some(service) = self.filtered_services.get(selected_index) {
-                return Some(service);
-            }
+        if let Some(selected_index) = self.table_state.selected()
+            && let Some(entry) = self.entries.get(selected_index)
+        {
+            return entry.service();
         }
         None
     }
 
-    pub fn set_selected_index(&mut self, index: usize) {
-        self.table_state.select(Some(index));
+    fn refresh(&mut self, filter_text: String) {
+        let filter = UnitFilter::parse(&filter_text, Config::get().fuzzy_filter);
+        self.old_filter_text = filter_text;
+        self.filtered_services = self.filter(&filter, self.services.clone());
+        self.sort.apply(&mut self.filtered_services);
+        if filter.ranks() {
+            // Best matches first, in the order of the sort when they tie.
+            self.filtered_services
+                .sort_by_cached_key(|service| std::cmp::Reverse(filter.score(service)));
+        }
+        // Starred units first, keeping the order above among them.
+        let favorites = &self.favorites;
+        self.filtered_services
+            .sort_by_key(|service| !favorites.contains(service.name()));
+        self.unit_filter = filter;
+        self.generate_rows();
+    }
+
+    /// Sorts by the next column or direction, keeping the same unit selected.
+    fn cycle_sort(&mut self) {
+        self.sort = self.sort.next();
+        self.table = self
+            .table
+            .clone()
+            .header(header_row(self.sort, &self.columns));
+        self.refresh_keeping_selection();
+    }
+
+    /// Lists only the failed units, or all of them again, on top of the text filter.
+    fn toggle_failed_only(&mut self) {
+        self.failed_only = !self.failed_only;
+        self.update_title();
+        self.refresh_keeping_selection();
+    }
+
+    fn refresh_keeping_selection(&mut self) {
+        self.refresh_selecting(self.old_filter_text.clone(), None);
+    }
+
+    /// Lists the units `filter_text` lets through, keeping the cursor on the
+    /// same unit, or moving it to the first one when it is filtered out.
+    pub fn apply_filter(&mut self, filter_text: String) {
+        self.refresh_selecting(filter_text, Some(0));
+    }
+
+    /// Refreshes with `filter_text`, keeping the cursor on the same row. When
+    /// that row is gone, the cursor goes to `fallback`, or stays where it was.
+    fn refresh_selecting(&mut self, filter_text: String, fallback: Option<usize>) {
+        let selected = self.selected_row_key();
+        let current = self.table_state.selected().unwrap_or(0);
+        self.refresh(filter_text);
+        let index = self
+            .entries
+            .iter()
+            .position(|entry| Some(row_key(entry)) == selected.as_deref())
+            .or(fallback)
+            .unwrap_or(current)
+            .min(self.entries.len().saturating_sub(1));
+        // Nothing to select once the filters leave no row, as with `f` on a
+        // healthy machine.
+        self.table_state
+            .select((!self.entries.is_empty()).then_some(index));
+    }
+
+    /// What tells the selected row apart from the others, whether it is a
+    /// unit or a section header.
+    fn selected_row_key(&self) -> Option<String> {
+        let entry = self.entries.get(self.table_state.selected()?)?;
+        Some(row_key(entry).to_string())
+    }
+
+    /// Divides the list by slice, unit type, active state, or not at all.
+    fn cycle_grouping(&mut self) {
+        self.grouping = self.grouping.next();
+        if self.grouping == Grouping::Slice {
+            self.store.borrow_mut().fetch_slices();
+        }
+        self.update_title();
+        self.refresh_keeping_selection();
+    }
+
+    /// Collapses the section called `name`, or expands it, keeping it selected.
+    fn toggle_section(&mut self, name: String) {
+        if !self.collapsed.remove(&name) {
+            self.collapsed.insert(name.clone());
+        }
+        self.generate_rows();
+        let header = self
+            .entries
+            .iter()
+            .position(|entry| matches!(entry, ListEntry::Section(s) if s.name == name));
+        self.table_state.select(header);
+    }
+
+    /// Groups the filtered units into entries again, whose rows are built
+    /// anew when drawn.
+    fn generate_rows(&mut self) {
+        self.jobs.retain(|_, job| job.is_shown());
+        self.entries = group_sections(
+            &self.filtered_services,
+            self.grouping,
+            &self.slices,
+            &self.expanded,
+            &self.collapsed,
+        );
+        self.rows.clear();
+    }
+
+    /// Moves the first row shown so that the selected one is among the
+    /// `height` rows that fit.
+    fn scroll_to_selection(&mut self, height: usize) {
+        let last = self.entries.len().saturating_sub(1);
+        let selected = self.table_state.selected().unwrap_or(0).min(last);
+        let mut offset = self
+            .table_state
+            .offset()
+            .min(self.entries.len().saturating_sub(height));
+        if selected < offset {
+            offset = selected;
+        } else if height > 0 && selected >= offset + height {
+            offset = selected + 1 - height;
+        }
+        *self.table_state.offset_mut() = offset;
+    }
+
+    /// Rows of the `height` entries from the first one shown, building those
+    /// not built yet.
+    fn visible_rows(&mut self, height: usize) -> Vec<Row<'static>> {
+        let offset = self.table_state.offset();
+        let shown = offset..(offset + height).min(self.entries.len());
+        let missing: Vec<usize> = shown
+            .clone()
+            .filter(|index| !self.rows.contains_key(index))
+            .collect();
+        if let (Some(&first), Some(&last)) = (missing.first(), missing.last()) {
+            let built = generate_rows(
+                &self.entries[first..=last],
+                &self.expanded,
+                &self.marked,
+                &self.favorites,
+                &self.action_log,
+                &self.jobs,
+                &self.resources,
+                &self.activators,
+                &self.plugin_values,
+                &self.columns,
+                &self.widths,
+                &self.unit_filter,
+            );
+            for (index, row) in (first..).zip(built) {
+                self.rows.entry(index).or_insert(row);
+            }
+        }
+        shown.map(|index| self.rows[&index].clone()).collect()
     }
 
-    pub fn refresh(&mut self, filter_text: String) {
-        self.old_filter_text = filter_text.clone();
-        self.filtered_services = self.filter(filter_text, self.services.clone());
-        self.rows = generate_rows(&self.filtered_services.clone());
-        self.table = self.table.clone().rows(self.rows.clone());
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
     }
 
-    fn fetch_services(&mut self) {
-        if let Ok(services) = ServicesManager::list_services() {
-            self.services = services
+    /// Shows `column` if it is hidden and hides it otherwise.
+    pub fn toggle_column(&mut self, column: Column) {
+        let mut columns = self.columns.clone();
+        if columns.contains(&column) {
+            columns.retain(|shown| *shown != column);
         } else {
-            self.services = vec![]
+            columns.push(column);
         }
+        self.set_columns(
+            table_columns()
+                .into_iter()
+                .filter(|c| columns.contains(c))
+                .collect(),
+        );
     }
 
-    fn fetch_and_refresh(&mut self, filter_text: String) {
-        self.fetch_services();
-        self.refresh(filter_text);
+    /// Goes back to the columns of the config file.
+    pub fn reset_columns(&mut self) {
+        self.set_columns(configured_columns());
+    }
+
+    fn set_columns(&mut self, columns: Vec<Column>) {
+        self.table = self
+            .table
+            .clone()
+            .widths(constraints(&columns))
+            .header(header_row(self.sort, &columns));
+        self.columns = columns;
+        // Cut again to the new widths on the next draw.
+        self.widths = vec![];
+    }
+
+    /// Picks up the units currently held by the store.
+    pub fn reload(&mut self) {
+        // Picks up the actions of other sessions sharing the file.
+        self.action_log.reload();
+        let store = self.store.borrow();
+        let Some(units) = store.units() else {
+            return;
+        };
+        self.services = units.value().clone();
+        self.table = self.table.clone().block(table_block(
+            units.is_stale(),
+            self.failed_only,
+            self.grouping,
+            self.bundle_notice.as_deref(),
+        ));
+        if let Some(resources) = store.resources() {
+            self.resources = resources.value().clone();
+        }
+        if let Some(activators) = store.activators() {
+            self.activators = activators.value().clone();
+        }
+        if let Some(plugin_values) = store.plugin_columns() {
+            self.plugin_values = plugin_values.value().clone();
+        }
+        if let Some(slices) = store.slices() {
+            self.slices = slices.value().clone();
+        }
+        let fetched = !units.is_stale();
+        drop(store);
+        self.refresh_keeping_selection();
+        if fetched && std::mem::take(&mut self.changed_while_fetching) {
+            self.fetch_services();
+        }
+    }
+
+    /// One labeled line per unit starting at the selected one, without a table.
+    fn render_linear(&self, frame: &mut Frame, area: Rect) {
+        let stale = self.store.borrow().units().is_some_and(Stored::is_stale);
+        let total = self.entries.len();
+        let heading = tr(if self.failed_only {
+            "reader-heading-failed"
+        } else {
+            "reader-heading"
+        });
+        let listed = |id| {
+            tr_args(
+                id,
+                &[
+                    ("heading", &heading),
+                    ("units", &total.to_string()),
+                    ("sort", &self.sort.label()),
+                    ("direction", &self.sort.direction()),
+                ],
+            )
+        };
+        let mut lines = vec![Line::from(match (self.store.borrow().units(), stale) {
+            (None, _) => tr_args("reader-loading", &[("heading", &heading)]),
+            (Some(_), true) => listed("reader-listed-refreshing"),
+            (Some(_), false) => listed("reader-listed"),
+        })];
+
+        let selected = self.table_state.selected().unwrap_or(0);
+        lines.extend(
+            self.entries
+                .iter()
+                .enumerate()
+                .skip(selected)
+                .take(usize::from(area.height))
+                .map(|(index, entry)| {
+                    let position = if index == selected {
+                        "reader-selected-unit"
+                    } else {
+                        "reader-unit"
+                    };
+                    let unit = describe_entry(
+                        entry,
+                        &self.expanded,
+                        &self.marked,
+                        &self.favorites,
+                        &self.action_log,
+                        &self.jobs,
+                        &self.resources,
+                        &self.activators,
+                    );
+                    Line::from(tr_args(
+                        position,
+                        &[
+                            ("position", &(index + 1).to_string()),
+                            ("units", &total.to_string()),
+                            ("unit", &unit),
+                        ],
+                    ))
+                }),
+        );
+
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), area);
+    }
+
+    /// Names of the units the filter lets through, which the journal search covers.
+    pub fn listed_unit_names(&self) -> Vec<String> {
+        self.filtered_services
+            .iter()
+            .map(|service| service.name().to_string())
+            .collect()
+    }
+
+    /// Actions taken on units since the program started, for the summary on exit.
+    pub fn session_actions(&self) -> &[(String, LoggedAction)] {
+        self.action_log.session()
+    }
+
+    pub fn find_service(&self, name: &str) -> Option<&Service> {
+        self.services.iter().find(|service| service.name() == name)
+    }
+
+    /// Units whose log `v` opens: the marked ones, or else the selected one.
+    pub fn log_targets(&self) -> Vec<Service> {
+        if self.marked.is_empty() {
+            return self.get_selected_service().cloned().into_iter().collect();
+        }
+        self.marked
+            .iter()
+            .filter_map(|name| self.services.iter().find(|service| service.name() == name))
+            .cloned()
+            .collect()
+    }
+
+    fn prompt_support_bundle(&self) {
+        if self.log_targets().is_empty() {
+            return;
+        }
+        self.sender
+            .send(AppEvent::Action(Actions::OpenPrompt(
+                PromptKind::SupportBundle,
+            )))
+            .unwrap();
+    }
+
+    /// Bundles the units `v` would show for a support ticket, in the background.
+    pub fn write_support_bundle(&mut self, path: &str) {
+        let services = self.log_targets();
+        let count = services.len();
+        self.set_bundle_notice(Some(tr_count("list-bundle-writing", count)));
+        let path = path.to_string();
+        let event_tx = self.sender.clone();
+        runtime::spawn(async move {
+            let written = match bundle::write_support_bundle(&path, &services).await {
+                Ok(path) => Some(path.display().to_string()),
+                Err(e) => {
+                    event_tx
+                        .send(AppEvent::Error(tr_args(
+                            "list-bundle-failed",
+                            &[("error", &e.to_string())],
+                        )))
+                        .expect("Failed to send Error event");
+                    None
+                }
+            };
+            event_tx
+                .send(AppEvent::Action(Actions::SupportBundleWritten(written)))
+                .expect("Failed to send SupportBundleWritten event");
+        });
+    }
+
+    pub fn on_support_bundle_written(&mut self, path: Option<String>) {
+        self.set_bundle_notice(path.map(|path| tr_args("list-bundle-written", &[("path", &path)])));
+    }
+
+    fn set_bundle_notice(&mut self, notice: Option<String>) {
+        self.bundle_notice = notice;
+        self.update_title();
+    }
+
+    fn update_title(&mut self) {
+        let stale = self.store.borrow().units().is_some_and(Stored::is_stale);
+        self.table = self.table.clone().block(table_block(
+            stale,
+            self.failed_only,
+            self.grouping,
+            self.bundle_notice.as_deref(),
+        ));
+    }
+
+    fn toggle_mark(&mut self) {
+        let Some(name) = self.get_selected_service().map(|s| s.name().to_string()) else {
+            return;
+        };
+        if let Some(index) = self.marked.iter().position(|marked| *marked == name) {
+            self.marked.remove(index);
+        } else {
+            self.marked.push(name);
+        }
+        self.generate_rows();
+    }
+
+    fn toggle_favorite(&mut self) {
+        let Some(name) = self.get_selected_service().map(|s| s.name().to_string()) else {
+            return;
+        };
+        self.favorites.toggle(&name);
+        self.refresh_keeping_selection();
+    }
+
+    fn clear_marks(&mut self) {
+        self.marked.clear();
+        self.generate_rows();
+    }
+
+    /// Timer or socket that starts the selected unit, if any.
+    fn selected_activator(&self) -> Option<&str> {
+        let service = self.get_selected_service()?;
+        self.activators
+            .get(service.name())?
+            .first()
+            .map(String::as_str)
+    }
+
+    /// Moves the selection to the timer or socket that starts the selected unit.
+    fn select_activator(&mut self) {
+        let Some(activator) = self.selected_activator().map(str::to_string) else {
+            return;
+        };
+        self.select_unit(&activator);
+    }
+
+    /// Moves the selection to the unit called `name`, expanding its family if needed.
+    pub fn select_unit(&mut self, name: &str) {
+        let section = self
+            .filtered_services
+            .iter()
+            .find(|service| service.name() == name)
+            .map(|service| self.grouping.section_of(service, &self.slices));
+        if let Some(section) = section
+            && self.collapsed.remove(&section)
+        {
+            self.generate_rows();
+        }
+        let family = self.entries.iter().find_map(|entry| match entry {
+            ListEntry::Family(family) if family.contains(name) => Some(family.name().to_string()),
+            _ => None,
+        });
+        if let Some(family) = family
+            && self.expanded.insert(family)
+        {
+            self.generate_rows();
+        }
+
+        match self.entries.iter().position(|entry| {
+            matches!(entry, ListEntry::Unit(service) | ListEntry::Member(service) if service.name() == name)
+        }) {
+            Some(index) => self.table_state.select(Some(index)),
+            None if self.services.iter().any(|service| service.name() == name) => self
+                .sender
+                .send(AppEvent::Error(tr_args(
+                    "list-hidden-by-filter",
+                    &[("unit", name)],
+                )))
+                .unwrap(),
+            None => self
+                .sender
+                .send(AppEvent::Error(tr_args(
+                    "list-no-longer-loaded",
+                    &[("unit", name)],
+                )))
+                .unwrap(),
+        }
     }
 
-    fn filter(&self, filter_text: String, services: Vec<Service>) -> Vec<Service> {
-        let lower_filter = filter_text.to_lowercase();
+    /// Expands or collapses the family of the selected row, keeping it selected.
+    fn toggle_family(&mut self) {
+        let Some(selected_index) = self.table_state.selected() else {
+            return;
+        };
+        let family = match self.entries.get(selected_index) {
+            Some(ListEntry::Section(section)) => return self.toggle_section(section.name.clone()),
+            Some(ListEntry::Family(family)) => family.name().to_string(),
+            Some(ListEntry::Member(service)) => service.stem().to_string(),
+            _ => return,
+        };
+        if !self.expanded.remove(&family) {
+            self.expanded.insert(family.clone());
+        }
+        self.generate_rows();
+
+        let header = self
+            .entries
+            .iter()
+            .position(|entry| matches!(entry, ListEntry::Family(f) if f.name() == family));
+        self.table_state.select(header);
+    }
+
+    /// Marks the unit list outdated and fetches it again in the background.
+    fn fetch_services(&self) {
+        let mut store = self.store.borrow_mut();
+        store.mark_stale(&DataKey::Units);
+        store.fetch_units();
+        if self.grouping == Grouping::Slice {
+            store.fetch_slices();
+        }
+    }
+
+    /// Fetches the units again after systemd reported a change, or once the
+    /// fetch running is done, since it may have been answered before.
+    pub fn on_units_changed(&mut self) {
+        if self.store.borrow().units().is_some_and(Stored::is_stale) {
+            self.changed_while_fetching = true;
+        } else {
+            self.fetch_services();
+        }
+    }
+
+    /// Fetches the unit list again, as the periodic refresh does, unless a
+    /// fetch is still running; returns whether it started one.
+    pub fn refresh_units(&self) -> bool {
+        let fetching = self.store.borrow().units().is_some_and(Stored::is_stale);
+        if !fetching {
+            self.fetch_services();
+        }
+        !fetching
+    }
+
+    /// How long ago the listed units were fetched, on the top border, when
+    /// they are refreshed periodically. It turns yellow once a refresh is
+    /// overdue, as when systemd stops answering.
+    fn render_freshness(&self, frame: &mut Frame, area: Rect) {
+        let Some(interval) = Config::get().refresh_interval() else {
+            return;
+        };
+        let store = self.store.borrow();
+        let Some(units) = store.units().filter(|units| !units.is_stale()) else {
+            return;
+        };
+        let age = units.age();
+        let style = if age > interval * 2 {
+            Style::default().fg(Theme::current().warning)
+        } else {
+            Style::default()
+        };
+        let text = format!(
+            " {} ",
+            tr_args("list-fetched", &[("seconds", &age.as_secs().to_string())])
+        );
+        let border = Rect::new(
+            area.x + 1,
+            area.y,
+            area.width.saturating_sub(2),
+            area.height.min(1),
+        );
+        frame.render_widget(Line::styled(text, style).right_aligned(), border);
+    }
+
+    fn filter(&self, filter: &UnitFilter, services: Vec<Service>) -> Vec<Service> {
+        let unit_types = &Config::get().unit_types;
         services
             .into_iter()
-            .filter(|service| {
-                let name = service.formatted_name();
-                name.to_lowercase().contains(&lower_filter)
-            })
+            .filter(|service| unit_types.contains(&UnitType::from_name(service.name())))
+            .filter(|service| !self.failed_only || service.state().active() == "failed")
+            .filter(|service| filter.matches(service))
             .collect()
     }
 
-    pub fn on_key_event(&mut self, key: KeyEvent) {
-        if self.ignore_key_events {
+    /// Moves the selection by a turn of the wheel, stopping at either end.
+    fn scroll_selection(&mut self, down: bool) {
+        if self.entries.is_empty() {
             return;
         }
+        let selected = self.table_state.selected().unwrap_or(0);
+        let selected = if down {
+            (selected + mouse::WHEEL_ROWS).min(self.entries.len().saturating_sub(1))
+        } else {
+            selected.saturating_sub(mouse::WHEEL_ROWS)
+        };
+        self.table_state.select(Some(selected));
+    }
+
+    /// Index of the entry on the clicked row, if any.
+    fn entry_at(&self, event: &MouseEvent) -> Option<usize> {
+        // Below the top border and the header.
+        let first_row = self.area.y + 2;
+        if event.row < first_row || event.row >= self.area.bottom().saturating_sub(1) {
+            return None;
+        }
+        let index = self.table_state.offset() + usize::from(event.row - first_row);
+        (index < self.entries.len()).then_some(index)
+    }
 
-        match key.code {
-            KeyCode::Down => self.select_next(),
-            KeyCode::Up => self.select_previous(),
-            KeyCode::PageDown => self.select_page_down(),
-            KeyCode::PageUp => self.select_page_up(),
-            KeyCode::Char('r') => self.act_on_selected_service(ServiceAction::Restart),
-            KeyCode::Char('s') => self.act_on_selected_service(ServiceAction::Start),
-            KeyCode::Char('e') => self.act_on_selected_service(ServiceAction::Enable),
-            KeyCode::Char('d') => self.act_on_selected_service(ServiceAction::Disable),
-            KeyCode::Char('u') => self.act_on_selected_service(ServiceAction::RefreshAll),
-            KeyCode::Char('x') => self.act_on_selected_service(ServiceAction::Stop),
-            KeyCode::Char('v') => self.sender.send(AppEvent::Action(Actions::GoLog)).unwrap(),
-            KeyCode::Char('p') => self
-                .sender
+    /// Selects the clicked row, and opens its properties on a double click.
+    fn on_click(&mut self, event: MouseEvent) {
+        let Some(index) = self.entry_at(&event) else {
+            return;
+        };
+        self.table_state.select(Some(index));
+        if !self.double_click.is_double(&event) {
+            return;
+        }
+        if self.get_selected_service().is_some() {
+            self.sender
                 .send(AppEvent::Action(Actions::GoDetails))
-                .unwrap(),
-            _ => {}
+                .unwrap();
+        } else {
+            self.toggle_family();
         }
     }
 
+    /// Selects the clicked row and lists what can be done to it.
+    fn on_right_click(&mut self, event: MouseEvent) {
+        let Some(index) = self.entry_at(&event) else {
+            return;
+        };
+        self.table_state.select(Some(index));
+        self.sender
+            .send(AppEvent::Action(Actions::OpenUnitMenu))
+            .unwrap();
+    }
+
+    pub fn is_favorite(&self, name: &str) -> bool {
+        self.favorites.contains(name)
+    }
+
     fn select_page_down(&mut self) {
+        if self.entries.is_empty() {
+            return;
+        }
         let jump = 10;
         if let Some(selected_index) = self.table_state.selected() {
             let new_index = selected_index + jump;
-            let wrapped_index = if new_index >= self.rows.len() {
-                (new_index) % self.rows.len()
+            let wrapped_index = if new_index >= self.entries.len() {
+                (new_index) % self.entries.len()
             } else {
                 new_index
             };
@@ -229,11 +1617,14 @@ impl TableServices<'_> {
     }
 
     fn select_page_up(&mut self) {
+        if self.entries.is_empty() {
+            return;
+        }
         let jump = 10;
         if let Some(selected_index) = self.table_state.selected() {
             let new_index = selected_index as isize - jump as isize;
             let wrapped_index = if new_index < 0 {
-                (self.rows.len() as isize + new_index % self.rows.len() as isize) as usize
+                (self.entries.len() as isize + new_index % self.entries.len() as isize) as usize
             } else {
                 new_index as usize
             };
@@ -244,8 +1635,11 @@ impl TableServices<'_> {
     }
 
     fn select_next(&mut self) {
+        if self.entries.is_empty() {
+            return;
+        }
         if let Some(selected_index) = self.table_state.selected() {
-            let next_index = if selected_index == self.rows.len() - 1 {
+            let next_index = if selected_index == self.entries.len() - 1 {
                 0
             } else {
                 selected_index + 1
@@ -257,9 +1651,12 @@ impl TableServices<'_> {
     }
 
     fn select_previous(&mut self) {
+        if self.entries.is_empty() {
+            return;
+        }
         if let Some(selected_index) = self.table_state.selected() {
             let prev_index = if selected_index == 0 {
-                self.rows.len() - 1
+                self.entries.len() - 1
             } else {
                 selected_index - 1
             };
@@ -269,48 +1666,492 @@ impl TableServices<'_> {
         }
     }
 
+    /// Asks systemd to stop or restart the selected unit once the units going
+    /// down with it are confirmed, unless `confirm.stop_impact` is off.
+    fn preview_stop_impact(&mut self, action: ServiceAction) {
+        if !Config::get().confirm.stop_impact {
+            self.act_on_selected_service(action);
+        } else if self.get_selected_service().is_some() {
+            self.sender
+                .send(AppEvent::Action(Actions::OpenStopImpact(action)))
+                .unwrap();
+        }
+    }
+
+    pub fn act_on_unit(&mut self, name: &str, action: ServiceAction) {
+        let service = self.find_service(name).cloned();
+        self.act_on_service(service.as_ref(), action);
+    }
+
     fn act_on_selected_service(&mut self, action: ServiceAction) {
-        if let Some(service) = self.get_selected_service() {
+        let service = self.get_selected_service().cloned();
+        self.act_on_service(service.as_ref(), action);
+    }
+
+    /// Sends `action` to systemd in the background; the units are fetched
+    /// again once it answers.
+    fn act_on_service(&mut self, service: Option<&Service>, action: ServiceAction) {
+        let Some(service) = service.filter(|_| action.log_label().is_some()) else {
+            self.fetch_services();
+            return;
+        };
+        let service = service.clone();
+        let sender = self.sender.clone();
+        // Not cancelled by leaving the list: the action goes through anyway.
+        runtime::spawn(async move {
+            let result = action.run(&service).await.map_err(|e| e.to_string());
+            let name = service.name().to_string();
+            let _ = sender.send(AppEvent::Action(Actions::UnitActionDone(
+                name, action, result,
+            )));
+        });
+    }
+
+    /// Runs the plugin `action` on the unit in the background; the units are
+    /// fetched again once it is over, as it likely changed some.
+    pub fn run_plugin_action(&mut self, name: &str, action: PluginExtension) {
+        let name = name.to_string();
+        let sender = self.sender.clone();
+        // Not cancelled by leaving the list, as the actions of systemd.
+        runtime::spawn(async move {
+            let result = Plugins::run_action(&action, &name)
+                .await
+                .map_err(|e| e.to_string());
+            let _ = sender.send(AppEvent::Action(Actions::PluginActionDone(
+                name, action, result,
+            )));
+        });
+    }
+
+    /// Runs the plugin action bound to `key`, such as a script of the config
+    /// file, on the selected unit.
+    fn run_bound_action(&mut self, key: KeyEvent) {
+        let Some(service) = self
+            .get_selected_service()
+            .filter(|service| !service.is_template())
+        else {
+            return;
+        };
+        let Some(action) = Plugins::actions()
+            .into_iter()
+            .find(|action| action.key().is_some_and(|bound| keymap::is_key(key, bound)))
+        else {
+            return;
+        };
+        self.sender
+            .send(AppEvent::Action(Actions::RunPluginAction(
+                service.name().to_string(),
+                action,
+            )))
+            .unwrap();
+    }
+
+    /// Records the plugin `action` on the unit and tells how it went.
+    pub fn on_plugin_action_done(
+        &mut self,
+        name: &str,
+        action: &PluginExtension,
+        result: Result<String, String>,
+    ) {
+        match result {
+            Ok(output) => {
+                self.action_log.record(name, action.title());
+                let output = output.trim();
+                let notice = if output.is_empty() {
+                    tr_args(
+                        "list-action-done",
+                        &[("action", action.title()), ("unit", name)],
+                    )
+                } else {
+                    format!("{} {}: {}", action.title(), name, output)
+                };
+                self.sender.send(AppEvent::Notice(notice)).unwrap();
+            }
+            Err(e) => self.handle_result(Err(format!("{} {}: {}", action.title(), name, e))),
+        }
+        self.fetch_services();
+    }
+
+    /// Records `action` on the unit once systemd took it, following the job it
+    /// queued, if any, or tells why it was refused.
+    pub fn on_unit_action_done(
+        &mut self,
+        name: &str,
+        action: ServiceAction,
+        result: Result<Option<String>, String>,
+    ) {
+        if let Some(label) = action.log_label() {
+            let result = result.map(|job| {
+                self.action_log.record(name, &label);
+                match job {
+                    Some(job) => self.track_job(name, job, label),
+                    // Nothing more to wait for, as with enable and disable.
+                    None => self
+                        .sender
+                        .send(AppEvent::Notice(tr_args(
+                            "list-action-done",
+                            &[("action", &label), ("unit", name)],
+                        )))
+                        .unwrap(),
+                }
+            });
             match action {
-                ServiceAction::Start => self.handle_result(ServicesManager::start_service(service)),
-                ServiceAction::Stop => self.handle_result(ServicesManager::stop_service(service)),
-                ServiceAction::Restart => {
-                    self.handle_result(ServicesManager::restart_service(service))
+                ServiceAction::Start(_) | ServiceAction::Restart => {
+                    self.handle_job_result(name, action, result)
                 }
-                ServiceAction::Enable => {
-                    self.handle_result(ServicesManager::enable_service(service))
+                _ => self.handle_result(result),
+            }
+        }
+        self.fetch_services();
+    }
+
+    /// Shows a spinner on the row of the unit until `job` ends, then how it went.
+    fn track_job(&mut self, name: &str, job: String, label: String) {
+        self.jobs
+            .insert(name.to_string(), JobProgress::new(job.clone(), label));
+        let sender = self.sender.clone();
+        let name = name.to_string();
+        runtime::spawn(async move {
+            // Redraws the spinner until the job ends.
+            let animation = sender.clone();
+            let spinner = runtime::spawn(async move {
+                let mut frames = time::interval(SPINNER_INTERVAL);
+                loop {
+                    frames.tick().await;
+                    if animation
+                        .send(AppEvent::Action(Actions::AnimateJobs))
+                        .is_err()
+                    {
+                        break;
+                    }
                 }
-                ServiceAction::Disable => {
-                    self.handle_result(ServicesManager::disable_service(service))
+            });
+            let result = ServicesManager::wait_for_job(&name, &job)
+                .await
+                .map_err(|e| e.to_string());
+            spinner.cancel();
+            let _ = sender.send(AppEvent::Action(Actions::JobFinished(name, job, result)));
+        });
+    }
+
+    /// Moves the spinners of the running jobs to their next frame.
+    pub fn animate_jobs(&mut self) {
+        let running: HashSet<&str> = self
+            .jobs
+            .iter()
+            .filter(|(_, job)| job.is_running())
+            .map(|(name, _)| name.as_str())
+            .collect();
+        // Only the rows of those units are built again.
+        let entries = &self.entries;
+        self.rows.retain(|index, _| {
+            entries
+                .get(*index)
+                .and_then(ListEntry::service)
+                .is_none_or(|service| !running.contains(service.name()))
+        });
+    }
+
+    /// The action the job was queued for, if it is still the one of the unit.
+    pub fn job_label(&self, name: &str, job: &str) -> Option<String> {
+        self.jobs
+            .get(name)
+            .filter(|progress| progress.job() == job)
+            .map(|progress| progress.label().to_string())
+    }
+
+    /// Shows how `job` on the unit ended, or drops its spinner if that could
+    /// not be told, and fetches the units for the state it left them in. A
+    /// failure is also explained in a popup.
+    pub fn on_job_finished(&mut self, name: &str, job: &str, result: Result<JobResult, String>) {
+        // A later action on the unit replaced the job.
+        if self
+            .jobs
+            .get(name)
+            .is_none_or(|progress| progress.job() != job)
+        {
+            return;
+        }
+        match result {
+            Ok(result) => {
+                if let Some(progress) = self.jobs.get_mut(name) {
+                    if result.is_success() {
+                        self.sender
+                            .send(AppEvent::Notice(format!(
+                                "{} {}: {}",
+                                progress.label(),
+                                name,
+                                result_text(&result)
+                            )))
+                            .unwrap();
+                    } else if let Some(service) = self.services.iter().find(|s| s.name() == name) {
+                        self.sender
+                            .send(AppEvent::Action(Actions::OpenJobFailure(
+                                Box::new(service.clone()),
+                                progress.label().to_string(),
+                                result.clone(),
+                                progress.queued_at(),
+                            )))
+                            .unwrap();
+                    }
+                    progress.finish(result);
                 }
-                ServiceAction::RefreshAll => self.fetch_services(),
+            }
+            Err(_) => {
+                self.jobs.remove(name);
             }
         }
-        self.fetch_and_refresh(self.old_filter_text.clone());
+        self.generate_rows();
+        self.fetch_services();
     }
 
-    fn handle_result(&mut self, result: Result<(), Box<dyn Error>>) {
-        match result {
-            Ok(_) => {}
-            Err(e) => {
-                self.sender.send(AppEvent::Error(e.to_string())).unwrap();
+    fn prompt_instance_name(&mut self) {
+        if let Some(service) = self.get_selected_service()
+            && service.is_template()
+        {
+            let kind = PromptKind::InstantiateTemplate(service.name().to_string());
+            self.sender
+                .send(AppEvent::Action(Actions::OpenPrompt(kind)))
+                .unwrap();
+        }
+    }
+
+    pub fn instantiate_template(&mut self, template: &str, instance: &str) {
+        let Some(service) = self.services.iter().find(|s| s.name() == template).cloned() else {
+            self.fetch_services();
+            return;
+        };
+        let instance = instance.trim().to_string();
+        let sender = self.sender.clone();
+        runtime::spawn(async move {
+            let result = ServicesManager::instantiate_template(&service, &instance)
+                .await
+                .map_err(|e| e.to_string());
+            let template = service.name().to_string();
+            let _ = sender.send(AppEvent::Action(Actions::TemplateInstantiated(
+                template, instance, result,
+            )));
+        });
+    }
+
+    pub fn on_template_instantiated(
+        &mut self,
+        template: &str,
+        instance: &str,
+        result: Result<(), String>,
+    ) {
+        if result.is_ok() {
+            let label = tr_args("list-new-instance", &[("instance", instance)]);
+            self.action_log.record(template, &label);
+        }
+        self.handle_result(result);
+        self.fetch_services();
+    }
+
+    /// Like `handle_result`, offering to cancel the job in the way when a
+    /// queued one conflicts with `action`.
+    fn handle_job_result(&mut self, name: &str, action: ServiceAction, result: Result<(), String>) {
+        if let Err(e) = &result
+            && let Some(conflict) = JobConflict::from_error(e)
+        {
+            let name = name.to_string();
+            self.sender
+                .send(AppEvent::Action(Actions::OpenJobConflict(
+                    name, action, conflict,
+                )))
+                .unwrap();
+            return;
+        }
+        self.handle_result(result);
+    }
+
+    fn handle_result(&mut self, result: Result<(), String>) {
+        if let Err(e) = result {
+            self.sender.send(AppEvent::Error(e)).unwrap();
+        }
+    }
+}
+
+impl Component for TableServices<'_> {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if Config::get().screen_reader {
+            // Lines do not line up with the table rows clicks are matched to.
+            self.area = Rect::default();
+            self.render_linear(frame, area);
+            return;
+        }
+        self.area = area;
+        let widths = column_widths(area, &self.columns);
+        if widths != self.widths {
+            self.widths = widths;
+            self.rows.clear();
+        }
+
+        // Inside the borders and below the header.
+        let height = usize::from(area.height.saturating_sub(3));
+        self.scroll_to_selection(height);
+        let rows = if self.store.borrow().units().is_none() {
+            vec![Row::new(vec![tr("loading")])]
+        } else {
+            self.visible_rows(height)
+        };
+        let offset = self.table_state.offset();
+        let mut viewport = TableState::default()
+            .with_selected(self.table_state.selected().map(|index| index - offset));
+        let table = self
+            .table
+            .clone()
+            .rows(rows)
+            .row_highlight_style(self.highlight_style());
+        frame.render_stateful_widget(table, area, &mut viewport);
+        self.render_freshness(frame, area);
+    }
+
+    fn on_mouse_event(&mut self, event: MouseEvent) {
+        if self.ignore_key_events || !mouse::is_within(&event, self.area) {
+            return;
+        }
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => self.on_click(event),
+            MouseEventKind::Down(MouseButton::Right) => self.on_right_click(event),
+            MouseEventKind::ScrollDown => self.scroll_selection(true),
+            MouseEventKind::ScrollUp => self.scroll_selection(false),
+            _ => {}
+        }
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        if self.ignore_key_events {
+            return;
+        }
+        let Some(key) = self.vim_keys.translate(key) else {
+            return;
+        };
+
+        // Shift aside, as the case of a letter already tells it; letters typed
+        // with Ctrl or Alt are left to the bindings of plugins.
+        match (key.code, key.modifiers - KeyModifiers::SHIFT) {
+            (KeyCode::Down, _) => self.select_next(),
+            (KeyCode::Up, _) => self.select_previous(),
+            (KeyCode::PageDown, _) => self.select_page_down(),
+            (KeyCode::PageUp, _) => self.select_page_up(),
+            (KeyCode::Home, _) if !self.entries.is_empty() => self.table_state.select(Some(0)),
+            (KeyCode::End, _) if !self.entries.is_empty() => {
+                self.table_state.select(Some(self.entries.len() - 1))
+            }
+            (KeyCode::Char('r'), KeyModifiers::NONE) => {
+                self.preview_stop_impact(ServiceAction::Restart)
+            }
+            (KeyCode::Char('s'), KeyModifiers::NONE) => {
+                self.act_on_selected_service(ServiceAction::Start(JobMode::default()))
+            }
+            (KeyCode::Char('e'), KeyModifiers::NONE) => {
+                self.act_on_selected_service(ServiceAction::Enable)
+            }
+            (KeyCode::Char('d'), KeyModifiers::NONE) => {
+                self.act_on_selected_service(ServiceAction::Disable)
+            }
+            (KeyCode::Char('u'), KeyModifiers::NONE) => {
+                self.act_on_selected_service(ServiceAction::RefreshAll)
+            }
+            (KeyCode::Char('x'), KeyModifiers::NONE) => {
+                self.preview_stop_impact(ServiceAction::Stop(JobMode::default()))
             }
+            (KeyCode::Char('n'), KeyModifiers::NONE) => self.prompt_instance_name(),
+            (KeyCode::Char('t'), KeyModifiers::NONE) => self.select_activator(),
+            (KeyCode::Char(' '), KeyModifiers::NONE) => self.toggle_family(),
+            (KeyCode::Char('+'), KeyModifiers::NONE) => self.toggle_mark(),
+            (KeyCode::Char('-'), KeyModifiers::NONE) => self.clear_marks(),
+            (KeyCode::Char('*'), KeyModifiers::NONE) => self.toggle_favorite(),
+            (KeyCode::Char('o'), KeyModifiers::NONE) => self.cycle_sort(),
+            (KeyCode::Char('z'), KeyModifiers::NONE) => self.cycle_grouping(),
+            (KeyCode::Char('f'), KeyModifiers::NONE) => self.toggle_failed_only(),
+            (KeyCode::Char('S'), KeyModifiers::NONE) => self
+                .sender
+                .send(AppEvent::Action(Actions::OpenJobModeMenu))
+                .unwrap(),
+            // Views of a unit have nothing to show for a section header.
+            (KeyCode::Char('T' | 'w' | 'p'), KeyModifiers::NONE)
+                if self.get_selected_service().is_none() => {}
+            (KeyCode::Char('v'), KeyModifiers::NONE) if self.log_targets().is_empty() => {}
+            (KeyCode::Char('T'), KeyModifiers::NONE) => self
+                .sender
+                .send(AppEvent::Action(Actions::GoTmpfiles))
+                .unwrap(),
+            (KeyCode::Char('J'), KeyModifiers::NONE) => self
+                .sender
+                .send(AppEvent::Action(Actions::OpenPrompt(
+                    PromptKind::JournalSearch,
+                )))
+                .unwrap(),
+            (KeyCode::Char('b'), KeyModifiers::NONE) => self.prompt_support_bundle(),
+            (KeyCode::Char('w'), KeyModifiers::NONE) => self
+                .sender
+                .send(AppEvent::Action(Actions::GoTriage))
+                .unwrap(),
+            (KeyCode::Char('c'), KeyModifiers::NONE) => self
+                .sender
+                .send(AppEvent::Action(Actions::OpenColumnChooser))
+                .unwrap(),
+            (KeyCode::Char('m'), KeyModifiers::NONE) => self
+                .sender
+                .send(AppEvent::Action(Actions::OpenRecentUnits))
+                .unwrap(),
+            (KeyCode::Char('v'), KeyModifiers::NONE) => {
+                self.sender.send(AppEvent::Action(Actions::GoLog)).unwrap()
+            }
+            (KeyCode::Char('K'), KeyModifiers::NONE) => self
+                .sender
+                .send(AppEvent::Action(Actions::GoKernelLog))
+                .unwrap(),
+            (KeyCode::Char('I'), KeyModifiers::NONE) => self
+                .sender
+                .send(AppEvent::Action(Actions::GoEarlyBoot))
+                .unwrap(),
+            (KeyCode::Char('B'), KeyModifiers::NONE) => self
+                .sender
+                .send(AppEvent::Action(Actions::GoBootTimes))
+                .unwrap(),
+            (KeyCode::Char('D'), KeyModifiers::NONE) => self
+                .sender
+                .send(AppEvent::Action(Actions::GoDashboard))
+                .unwrap(),
+            (KeyCode::Char('p'), KeyModifiers::NONE) => self
+                .sender
+                .send(AppEvent::Action(Actions::GoDetails))
+                .unwrap(),
+            (KeyCode::Enter, _) if self.get_selected_service().is_none() => self.toggle_family(),
+            (KeyCode::Enter, _) => self
+                .sender
+                .send(AppEvent::Action(Actions::OpenUnitMenu))
+                .unwrap(),
+            _ => self.run_bound_action(key),
         }
     }
 
-    pub fn shortcuts(&mut self) -> Vec<Line<'_>> {
+    fn shortcuts(&self) -> Vec<Line<'static>> {
         let mut help_text: Vec<Line<'_>> = Vec::new();
         if !self.ignore_key_events {
             help_text.push(Line::from(Span::styled(
-                "Actions on the selected service",
+                tr("list-actions-heading"),
                 Style::default()
-                    .fg(Color::LightMagenta)
+                    .fg(Theme::current().heading)
                     .add_modifier(Modifier::BOLD),
             )));
 
-            help_text.push(Line::from(
-                "Navigate: ↑/↓ | Start: s | Stop: x | Restart: r | Enable: e | Disable: d | Refresh all: u | View logs: v | Properties: p"
-            ));
+            let mut actions = tr("list-shortcuts");
+            if let Some(activator) = self.selected_activator() {
+                actions.push_str(&format!(
+                    " | {}: t",
+                    tr_args("list-go-to-activator", &[("activator", activator)])
+                ));
+            }
+            for action in Plugins::actions() {
+                if let Some(key) = action.key() {
+                    actions.push_str(&format!(" | {}: {}", action.title(), key));
+                }
+            }
+            help_text.push(Line::from(actions));
         }
 
         help_text
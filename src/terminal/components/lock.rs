@@ -0,0 +1,73 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Clear, Paragraph},
+};
+
+use crate::config::Config;
+use crate::terminal::components::component::Component;
+use crate::terminal::i18n::tr;
+use crate::terminal::theme::Theme;
+
+/// Blank screen hiding the app, locked with Ctrl + l or after being idle for
+/// `auto_lock_minutes`, until the configured unlock sequence is typed.
+#[derive(Default)]
+pub struct LockScreen {
+    locked: bool,
+    typed: String,
+}
+
+impl LockScreen {
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    pub fn lock(&mut self) {
+        self.locked = true;
+        self.typed.clear();
+    }
+}
+
+impl Component for LockScreen {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.locked {
+            return;
+        }
+
+        frame.render_widget(Clear, area);
+        let [_, message_area, _] = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(2),
+            Constraint::Fill(1),
+        ])
+        .areas(area);
+        let message = Paragraph::new(vec![
+            Line::from(tr("lock-title")).style(Style::default().fg(Theme::current().warning)),
+            Line::from(tr("lock-hint")).style(Style::default().fg(Theme::current().faint)),
+        ])
+        .centered();
+        frame.render_widget(message, message_area);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        let config = Config::get();
+        let sequence = &config.unlock_sequence;
+        match key.code {
+            KeyCode::Char(c) => self.typed.push(c),
+            _ => self.typed.clear(),
+        }
+        if self.typed.ends_with(sequence.as_str()) {
+            self.locked = false;
+            self.typed.clear();
+        } else if self.typed.len() > sequence.len() {
+            let cut = self.typed.len() - sequence.len();
+            let cut = (cut..=self.typed.len())
+                .find(|index| self.typed.is_char_boundary(*index))
+                .unwrap_or(self.typed.len());
+            self.typed.drain(..cut);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use crate::domain::boot::Boot;
+use crate::terminal::i18n::tr;
+use crate::terminal::store::Stored;
+use crate::terminal::theme::Theme;
+
+/// Popup listing the boots recorded in the journal, opened with `b`.
+///
+/// The first row stands for every boot, which is what the log view shows by default.
+#[derive(Default)]
+pub struct BootPicker {
+    open: bool,
+    selected: usize,
+}
+
+impl BootPicker {
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn open(&mut self) {
+        self.open = true;
+        self.selected = 0;
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    pub fn select_next(&mut self, boots: &[Boot]) {
+        self.selected = (self.selected + 1).min(boots.len());
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Boot offset picked by the user, `None` standing for every boot.
+    pub fn choice(&self, boots: &[Boot]) -> Option<i32> {
+        self.selected
+            .checked_sub(1)
+            .and_then(|index| boots.get(index))
+            .map(Boot::offset)
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, boots: Option<&Stored<Vec<Boot>>>) {
+        let width = area.width.saturating_sub(4).min(80);
+        let height = area.height.saturating_sub(4).min(16);
+        let popup_area = Rect::new(
+            area.x + (area.width.saturating_sub(width)) / 2,
+            area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        );
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::current().popup_border))
+            .title(format!(" {} ", tr("log-boots-title")));
+
+        frame.render_widget(Clear, popup_area);
+
+        let Some(boots) = boots else {
+            frame.render_widget(Paragraph::new(tr("loading")).block(block), popup_area);
+            return;
+        };
+
+        let mut items = vec![ListItem::new("All boots")];
+        items.extend(boots.value().iter().map(|boot| {
+            let current = if boot.offset() == 0 { " (current)" } else { "" };
+            let id: String = boot.id().chars().take(8).collect();
+            ListItem::new(format!(
+                "{:>4}  {}  {}{}",
+                boot.offset(),
+                boot.span(),
+                id,
+                current
+            ))
+        }));
+
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(
+                Style::default()
+                    .bg(Theme::current().selection_bg)
+                    .fg(Theme::current().selection_fg)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol(">> ");
+        let mut state = ListState::default().with_selected(Some(self.selected));
+
+        frame.render_stateful_widget(list, popup_area, &mut state);
+    }
+}
@@ -0,0 +1,49 @@
+use std::ops::RangeInclusive;
+
+/// Range of log lines picked with `V` to be copied to the clipboard, from the
+/// line it was started on to the one the cursor is on.
+#[derive(Default)]
+pub struct LineSelection {
+    active: bool,
+    anchor: usize,
+    cursor: usize,
+}
+
+impl LineSelection {
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    pub fn start(&mut self, line: usize) {
+        self.active = true;
+        self.anchor = line;
+        self.cursor = line;
+    }
+
+    pub fn cancel(&mut self) {
+        self.active = false;
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn move_up(&mut self, lines: usize) {
+        self.cursor = self.cursor.saturating_sub(lines);
+    }
+
+    /// Moves the cursor down; [`LineSelection::clamp`] keeps it on a line.
+    pub fn move_down(&mut self, lines: usize) {
+        self.cursor = self.cursor.saturating_add(lines);
+    }
+
+    pub fn clamp(&mut self, line_count: usize) {
+        let last = line_count.saturating_sub(1);
+        self.anchor = self.anchor.min(last);
+        self.cursor = self.cursor.min(last);
+    }
+
+    pub fn range(&self) -> RangeInclusive<usize> {
+        self.anchor.min(self.cursor)..=self.anchor.max(self.cursor)
+    }
+}
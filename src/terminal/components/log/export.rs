@@ -0,0 +1,34 @@
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+
+/// Which entries `w` and `W` write to a file.
+#[derive(Clone, Copy, PartialEq)]
+pub enum ExportScope {
+    /// The lines on screen, in the same order and with the same filters.
+    Displayed,
+    /// Every stored entry of the unit, oldest first like `journalctl` prints them.
+    Full,
+}
+
+/// Writes `lines` to `path`, expanding a leading `~/` to the home directory.
+pub fn write_lines(path: &str, lines: &[String]) -> Result<PathBuf, Box<dyn Error>> {
+    let path = path.trim();
+    if path.is_empty() {
+        return Err("No file given to export the log to".into());
+    }
+    let path = expand_home(path);
+
+    let mut content = lines.join("\n");
+    content.push('\n');
+    fs::write(&path, content).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+/// Expands a leading `~/` of `path` to the home directory.
+pub fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
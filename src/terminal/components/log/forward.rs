@@ -0,0 +1,82 @@
+use std::error::Error;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+
+use super::export::expand_home;
+use crate::terminal::app::AppEvent;
+
+/// Sends the lines of a followed log, as they arrive, to a file they are
+/// appended to or to the standard input of a command (`| COMMAND`, run with
+/// `sh -c`). Writing happens on its own thread, so a command slow to read
+/// does not hold up the interface.
+pub struct LogForwarder {
+    target: String,
+    lines: Sender<String>,
+}
+
+impl LogForwarder {
+    pub fn start(target: &str, event_tx: Sender<AppEvent>) -> Result<Self, Box<dyn Error>> {
+        let target = target.trim();
+        let (writer, target): (Box<dyn Write + Send>, String) =
+            if let Some(command) = target.strip_prefix('|') {
+                let command = command.trim();
+                if command.is_empty() {
+                    return Err("No command given to forward the log to".into());
+                }
+                let mut child = Command::new("sh")
+                    .arg("-c")
+                    .arg(command)
+                    .stdin(Stdio::piped())
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .spawn()
+                    .map_err(|e| format!("{}: {}", command, e))?;
+                let stdin = child
+                    .stdin
+                    .take()
+                    .ok_or("The command has no standard input")?;
+                // Reaped once its input is closed.
+                thread::spawn(move || child.wait());
+                (Box::new(stdin), format!("| {}", command))
+            } else if target.is_empty() {
+                return Err("No file or command given to forward the log to".into());
+            } else {
+                let path = expand_home(target);
+                let file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&path)
+                    .map_err(|e| format!("{}: {}", path.display(), e))?;
+                (Box::new(file), path.display().to_string())
+            };
+
+        let (lines, received) = mpsc::channel::<String>();
+        let name = target.clone();
+        thread::spawn(move || {
+            let mut writer = writer;
+            for line in received {
+                if let Err(e) = writeln!(writer, "{}", line).and_then(|_| writer.flush()) {
+                    let _ = event_tx.send(AppEvent::Error(format!(
+                        "Stopped forwarding the log to {}: {}",
+                        name, e
+                    )));
+                    break;
+                }
+            }
+        });
+        Ok(Self { target, lines })
+    }
+
+    /// File or command the lines go to, as shown in the title of the log.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// Queues `line` to be written, false once writing has failed.
+    pub fn send(&self, line: String) -> bool {
+        self.lines.send(line).is_ok()
+    }
+}
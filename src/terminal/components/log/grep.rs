@@ -0,0 +1,137 @@
+use ratatui::{
+    Frame,
+    layout::{Position, Rect},
+    style::Style,
+    widgets::{Block, Borders, Paragraph},
+};
+use regex::{Regex, RegexBuilder};
+
+use crate::terminal::i18n::tr;
+use crate::terminal::theme::Theme;
+
+/// Compiles `pattern` the way the filter of the log view reads it, telling
+/// whether it had to be taken as plain text. `None` when it is empty.
+pub fn compile_pattern(pattern: &str) -> Option<(Regex, bool)> {
+    if pattern.is_empty() {
+        return None;
+    }
+    let ignore_case = !pattern.chars().any(char::is_uppercase);
+    let build = |pattern: &str| {
+        RegexBuilder::new(pattern)
+            .case_insensitive(ignore_case)
+            .build()
+    };
+    match build(pattern) {
+        Ok(regex) => Some((regex, false)),
+        Err(_) => build(&regex::escape(pattern))
+            .ok()
+            .map(|regex| (regex, true)),
+    }
+}
+
+/// Filter of the log view, started with `&`, that hides every line not matching
+/// its pattern.
+///
+/// The pattern is a regular expression; one that does not compile is matched as
+/// plain text instead. Like the search, it ignores case unless it contains an
+/// uppercase letter.
+#[derive(Default)]
+pub struct LogGrep {
+    pattern: String,
+    editing: bool,
+    regex: Option<Regex>,
+    literal: bool,
+}
+
+impl LogGrep {
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.regex.is_some()
+    }
+
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Starts editing, keeping the current pattern so it can be refined.
+    pub fn start(&mut self) {
+        self.editing = true;
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.pattern.push(c);
+        self.compile();
+    }
+
+    pub fn pop(&mut self) {
+        self.pattern.pop();
+        self.compile();
+    }
+
+    pub fn confirm(&mut self) {
+        self.editing = false;
+    }
+
+    pub fn cancel(&mut self) {
+        self.pattern.clear();
+        self.editing = false;
+        self.compile();
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        self.regex.as_ref().is_none_or(|regex| regex.is_match(text))
+    }
+
+    /// Filters on `pattern` right away, as if it had been typed and confirmed.
+    pub fn set_pattern(&mut self, pattern: &str) {
+        self.pattern = pattern.to_string();
+        self.editing = false;
+        self.compile();
+    }
+
+    fn compile(&mut self) {
+        match compile_pattern(&self.pattern) {
+            Some((regex, literal)) => {
+                self.regex = Some(regex);
+                self.literal = literal;
+            }
+            None => self.regex = None,
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let title = format!(
+            " {} ",
+            tr(if self.literal && self.is_active() {
+                "log-filter-title-text"
+            } else {
+                "log-filter-title"
+            })
+        );
+        let border_color = if self.editing {
+            Theme::current().input
+        } else {
+            Theme::current().muted
+        };
+        let input = Paragraph::new(self.pattern.as_str())
+            .style(Style::default().fg(Theme::current().input))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(border_color))
+                    .title(title),
+            );
+        frame.render_widget(input, area);
+
+        if self.editing {
+            #[allow(clippy::cast_possible_truncation)]
+            frame.set_cursor_position(Position::new(
+                area.x + self.pattern.chars().count() as u16 + 1,
+                area.y + 1,
+            ));
+        }
+    }
+}
@@ -0,0 +1,1210 @@
+use chrono::Utc;
+use crossterm::event::{KeyCode, KeyEvent, MouseEvent, MouseEventKind};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+use std::cell::RefCell;
+use std::iter;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+use std::thread;
+use std::time::Duration;
+
+use crate::config::Config;
+use crate::domain::log_entry::{LogEntry, LogPriority, TimestampFormat};
+use crate::domain::log_query::LogQuery;
+use crate::domain::service::Service;
+use crate::infrastructure::journal::JournalFollower;
+use crate::infrastructure::target::Target;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::clipboard;
+use crate::terminal::components::component::Component;
+use crate::terminal::components::gauge::compact_duration;
+use crate::terminal::components::prompt::PromptKind;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::keymap::VimKeys;
+use crate::terminal::mask::SecretMask;
+use crate::terminal::mouse;
+use crate::terminal::store::{DataKey, Fetched, Store};
+use crate::terminal::theme::Theme;
+use crate::usecases::services_manager::ServicesManager;
+
+mod boots;
+mod copy;
+mod export;
+mod forward;
+mod grep;
+mod search;
+mod time_range;
+
+use boots::BootPicker;
+use copy::LineSelection;
+pub use export::{ExportScope, expand_home};
+use forward::LogForwarder;
+use grep::LogGrep;
+pub use grep::compile_pattern;
+use search::LogSearch;
+pub use time_range::parse_time_range;
+use time_range::{TimeRangeChoice, TimeRangePicker};
+
+/// A clock of the target further than this from this one, and newest entries
+/// dated further than this after the target's current time, are pointed out in
+/// the title, as the order of events across hosts is suspect.
+const CLOCK_SKEW_TOLERANCE: Duration = Duration::from_secs(60);
+
+/// Depths `L` cycles through; `None` is the whole history.
+const DEPTHS: [Option<usize>; 4] = [Some(100), Some(1000), Some(10_000), None];
+
+/// The depth after `depth` in [`DEPTHS`], which need not be one of them when
+/// it comes from the config file.
+fn next_depth(depth: Option<usize>) -> Option<usize> {
+    match depth {
+        Some(depth) => DEPTHS
+            .into_iter()
+            .find(|next| next.is_none_or(|next| next > depth))
+            .flatten(),
+        None => DEPTHS[0],
+    }
+}
+
+/// Number of terminal rows `line` takes once wrapped to `width` columns.
+fn wrapped_height(line: &Line, width: u16) -> u16 {
+    let width = usize::from(width.max(1));
+    line.width().div_ceil(width).max(1) as u16
+}
+
+/// Colors a line after its priority: errors red, warnings yellow, debug dimmed.
+pub fn priority_style(priority: Option<LogPriority>) -> Style {
+    match priority {
+        Some(priority) if priority <= LogPriority::Error => {
+            Style::default().fg(Theme::current().error)
+        }
+        Some(LogPriority::Warning) => Style::default().fg(Theme::current().warning),
+        Some(LogPriority::Debug) => Style::default().add_modifier(Modifier::DIM),
+        _ => Style::default(),
+    }
+}
+
+enum BorderColor {
+    Normal,
+    Following,
+}
+
+impl BorderColor {
+    fn to_color(&self) -> Color {
+        match self {
+            BorderColor::Normal => Theme::current().header,
+            BorderColor::Following => Theme::current().following,
+        }
+    }
+}
+
+pub struct ServiceLog {
+    store: Rc<RefCell<Store>>,
+    border_color: BorderColor,
+    service_name: String,
+    /// Units whose entries are interleaved with those of `service_name`, when
+    /// several units were marked in the list.
+    merged_with: Vec<String>,
+    /// Units last fetched, so a refresh fetches the same ones.
+    services: Vec<Service>,
+    /// Rows between the view and the newest entry, counted after wrapping, so
+    /// 0 is the newest page whichever way the log is ordered.
+    scroll: usize,
+    /// Shows the oldest entry at the top, like `tail`, instead of the newest.
+    oldest_first: bool,
+    /// Long lines wrap by default; otherwise they are cut and scrolled with ←/→.
+    wrap: bool,
+    horizontal_scroll: u16,
+    sender: Sender<AppEvent>,
+    follow: bool,
+    follower: Option<JournalFollower>,
+    /// File or command the followed lines are also sent to, set with `F`.
+    forwarder: Option<LogForwarder>,
+    max_priority: LogPriority,
+    timestamp_format: TimestampFormat,
+    /// Shows the PID, UID, hostname and identifier of each entry in columns.
+    verbose: bool,
+    search: LogSearch,
+    grep: LogGrep,
+    /// Boot and time range the shown entries are restricted to.
+    selection: LogQuery,
+    /// Most recent entries fetched, as last chosen with `L`; the log of a
+    /// journal search match is fetched whole within its window instead.
+    depth: Option<usize>,
+    boot_picker: BootPicker,
+    time_range_picker: TimeRangePicker,
+    /// File the log was last written to, shown until the next key press.
+    exported_to: Option<String>,
+    /// Cursor of the newest entry shown when the followed log was scrolled
+    /// down; newer entries stay hidden until the view is back at the top.
+    paused_at: Option<String>,
+    line_selection: LineSelection,
+    vim_keys: VimKeys,
+    /// Where the log was last drawn, for the wheel.
+    area: Rect,
+    /// First line at the top of the view on the last draw, where `V` starts.
+    first_visible_line: usize,
+    /// Number of lines last copied, shown until the next key press.
+    copied: Option<usize>,
+}
+
+impl ServiceLog {
+    pub fn new(sender: Sender<AppEvent>, store: Rc<RefCell<Store>>) -> Self {
+        Self {
+            store,
+            border_color: BorderColor::Normal,
+            service_name: String::new(),
+            merged_with: vec![],
+            services: vec![],
+            scroll: 0,
+            oldest_first: false,
+            wrap: true,
+            horizontal_scroll: 0,
+            sender,
+            follow: false,
+            follower: None,
+            forwarder: None,
+            max_priority: LogPriority::Debug,
+            timestamp_format: TimestampFormat::default(),
+            verbose: false,
+            search: LogSearch::default(),
+            grep: LogGrep::default(),
+            selection: LogQuery::default().with_lines(Config::get().log_depth()),
+            depth: Config::get().log_depth(),
+            boot_picker: BootPicker::default(),
+            time_range_picker: TimeRangePicker::default(),
+            exported_to: None,
+            paused_at: None,
+            line_selection: LineSelection::default(),
+            vim_keys: VimKeys::default(),
+            area: Rect::default(),
+            first_visible_line: 0,
+            copied: None,
+        }
+    }
+
+    fn render_loading(&mut self, frame: &mut Frame, area: Rect) {
+        let block = Block::default().borders(Borders::ALL);
+
+        frame.render_widget(block.clone(), area);
+
+        let vertical = Layout::default()
+            .direction(Direction::Vertical)
+            .margin(1)
+            .constraints([
+                Constraint::Percentage(45),
+                Constraint::Length(1),
+                Constraint::Percentage(45),
+            ])
+            .split(area);
+
+        let horizontal = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(25),
+                Constraint::Percentage(50),
+                Constraint::Percentage(25),
+            ])
+            .split(vertical[1]);
+
+        let loading = Paragraph::new(tr("loading")).alignment(Alignment::Center);
+
+        frame.render_widget(loading, horizontal[1]);
+    }
+
+    fn toogle_follow(&mut self) {
+        self.set_follow(!self.follow);
+    }
+
+    fn set_follow(&mut self, value: bool) {
+        // Nothing new can show up in a previous boot or a range that has ended,
+        // and only a single unit can be followed.
+        let value = value && self.selection.includes_now() && self.merged_with.is_empty();
+        self.border_color = if value {
+            BorderColor::Following
+        } else {
+            BorderColor::Normal
+        };
+        self.follow = value;
+
+        if !value {
+            self.stop_follower();
+            self.forwarder = None;
+        } else if self.is_loaded() {
+            self.start_follower();
+        }
+    }
+
+    fn is_loaded(&self) -> bool {
+        self.store.borrow().log(&self.service_name).is_some()
+    }
+
+    /// Streams entries newer than the last one shown, so nothing is fetched twice.
+    fn start_follower(&mut self) {
+        self.stop_follower();
+
+        let cursor = self.store.borrow().log_cursor(&self.service_name);
+        let Ok((follower, entries)) =
+            ServicesManager::follow_log(&self.service_name, cursor.as_deref())
+        else {
+            return;
+        };
+        self.follower = Some(follower);
+
+        let event_tx = self.sender.clone();
+        let service_name = self.service_name.clone();
+        thread::spawn(move || {
+            for entry in entries {
+                let fetched = Fetched::NewLogEntries(service_name.clone(), vec![entry]);
+                let event = AppEvent::Action(Actions::Fetched(fetched));
+                if event_tx.send(event).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    fn set_max_priority(&mut self, max_priority: LogPriority) {
+        self.max_priority = max_priority;
+        self.scroll = 0;
+    }
+
+    fn stop_follower(&mut self) {
+        if let Some(mut follower) = self.follower.take() {
+            follower.stop();
+        }
+    }
+
+    pub fn start_follow(&mut self) {
+        self.set_follow(true);
+    }
+
+    /// Leaves the log view. Entries stay in the store so coming back to the
+    /// same unit only fetches what was written in the meantime.
+    pub fn reset(&mut self) {
+        self.set_follow(false);
+        self.scroll = 0;
+        self.search.cancel();
+        self.grep.cancel();
+    }
+
+    fn on_search_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Enter => self.search.confirm(),
+            KeyCode::Esc => self.search.cancel(),
+            KeyCode::Backspace => self.search.pop(),
+            KeyCode::Char(c) => self.search.push(c),
+            _ => {}
+        }
+    }
+
+    fn on_grep_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Enter => self.grep.confirm(),
+            KeyCode::Esc => self.grep.cancel(),
+            KeyCode::Backspace => self.grep.pop(),
+            KeyCode::Char(c) => self.grep.push(c),
+            _ => return,
+        }
+        self.scroll = 0;
+    }
+
+    fn scroll_up(&mut self, rows: usize) {
+        self.scroll = if self.oldest_first {
+            self.scroll.saturating_add(rows)
+        } else {
+            self.scroll.saturating_sub(rows)
+        };
+    }
+
+    fn scroll_down(&mut self, rows: usize) {
+        self.scroll = if self.oldest_first {
+            self.scroll.saturating_sub(rows)
+        } else {
+            self.scroll.saturating_add(rows)
+        };
+    }
+
+    fn on_line_selection_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Up => self.line_selection.move_up(1),
+            KeyCode::Down => self.line_selection.move_down(1),
+            KeyCode::PageUp => self.line_selection.move_up(10),
+            KeyCode::PageDown => self.line_selection.move_down(10),
+            KeyCode::Char('g') | KeyCode::Home => self.line_selection.move_up(usize::MAX),
+            KeyCode::Char('G') | KeyCode::End => self.line_selection.move_down(usize::MAX),
+            KeyCode::Char('y') | KeyCode::Enter => self.copy_selected_lines(),
+            KeyCode::Esc | KeyCode::Char('q') => self.line_selection.cancel(),
+            _ => {}
+        }
+    }
+
+    /// Copies the selected lines, oldest first like `journalctl` prints them,
+    /// to the clipboard.
+    fn copy_selected_lines(&mut self) {
+        let lines: Vec<String> = {
+            let store = self.store.borrow();
+            let Some(mut entries) = self.entries(&store) else {
+                return;
+            };
+            self.hide_new_entries(&mut entries);
+            let texts = self.displayed_lines(&entries);
+            let mut lines: Vec<String> = self
+                .line_selection
+                .range()
+                .filter_map(|index| texts.get(index))
+                .map(|(_, text)| text.clone())
+                .collect();
+            if !self.oldest_first {
+                lines.reverse();
+            }
+            lines
+        };
+        self.line_selection.cancel();
+        let text = lines.join("\n");
+        match clipboard::copy(&SecretMask::from_config().apply(&text)) {
+            Ok(()) => self.copied = Some(lines.len()),
+            Err(e) => self.sender.send(AppEvent::Error(e.to_string())).unwrap(),
+        }
+    }
+
+    fn on_boot_picker_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Up => self.boot_picker.select_previous(),
+            KeyCode::Down => {
+                if let Some(boots) = self.store.borrow().boots() {
+                    self.boot_picker.select_next(boots.value());
+                }
+            }
+            KeyCode::Enter => {
+                let boots = self
+                    .store
+                    .borrow()
+                    .boots()
+                    .map(|boots| boots.value().clone());
+                if let Some(boots) = boots {
+                    self.boot_picker.close();
+                    let selection = self
+                        .selection
+                        .clone()
+                        .with_boot(self.boot_picker.choice(&boots));
+                    self.select(selection);
+                    self.sender
+                        .send(AppEvent::Action(Actions::RefreshLog))
+                        .unwrap();
+                }
+            }
+            KeyCode::Esc | KeyCode::Char('q') => self.boot_picker.close(),
+            _ => {}
+        }
+    }
+
+    fn open_boot_picker(&mut self) {
+        self.boot_picker.open();
+        self.store.borrow_mut().fetch_boots();
+    }
+
+    fn on_time_range_picker_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Up => self.time_range_picker.select_previous(),
+            KeyCode::Down => self.time_range_picker.select_next(),
+            KeyCode::Enter => {
+                self.time_range_picker.close();
+                match self.time_range_picker.choice() {
+                    TimeRangeChoice::Range(since, until) => {
+                        let selection = self.selection.clone().with_time_range(*since, *until);
+                        self.select(selection);
+                        self.sender
+                            .send(AppEvent::Action(Actions::RefreshLog))
+                            .unwrap();
+                    }
+                    TimeRangeChoice::Custom => self
+                        .sender
+                        .send(AppEvent::Action(Actions::OpenPrompt(
+                            PromptKind::LogTimeRange,
+                        )))
+                        .unwrap(),
+                }
+            }
+            KeyCode::Esc | KeyCode::Char('q') => self.time_range_picker.close(),
+            _ => {}
+        }
+    }
+
+    /// Restricts the log to a custom `SINCE..UNTIL` range typed in the prompt.
+    pub fn set_time_range(&mut self, input: &str) {
+        let (since, until) = parse_time_range(input);
+        let selection = self.selection.clone().with_time_range(since, until);
+        self.select(selection);
+    }
+
+    /// Switches the log to another boot or time range. The stored entries belong
+    /// to the previous selection, so they are dropped and fetched again.
+    fn select(&mut self, selection: LogQuery) {
+        if self.selection == selection {
+            return;
+        }
+        let mut store = self.store.borrow_mut();
+        for name in self.unit_names() {
+            store.forget_log(name);
+        }
+        drop(store);
+        self.selection = selection;
+        self.scroll = 0;
+        if self.follow {
+            self.set_follow(true);
+        }
+    }
+
+    fn cycle_depth(&mut self) {
+        self.depth = next_depth(self.selection.lines());
+        let selection = self.selection.clone().with_lines(self.depth);
+        self.select(selection);
+        self.sender
+            .send(AppEvent::Action(Actions::RefreshLog))
+            .unwrap();
+    }
+
+    fn exit(&self) {
+        self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+    }
+
+    /// Fetches the log of `services`, merged into one when there are several.
+    pub fn fetch(&mut self, services: Vec<Service>) {
+        let Some((first, rest)) = services.split_first() else {
+            return;
+        };
+        let merged_with: Vec<String> = rest
+            .iter()
+            .map(|service| service.name().to_string())
+            .collect();
+        if self.service_name != first.name() || self.merged_with != merged_with {
+            self.select(LogQuery::default().with_lines(self.depth));
+        }
+        self.service_name = first.name().to_string();
+        self.merged_with = merged_with;
+        if !self.merged_with.is_empty() {
+            self.set_follow(false);
+        }
+
+        let mut store = self.store.borrow_mut();
+        for service in &services {
+            store.fetch_log(service.clone(), &self.selection);
+        }
+        drop(store);
+        self.services = services;
+    }
+
+    /// Fetches the log of `service` within the window of a journal search,
+    /// showing only the lines matching `pattern`.
+    pub fn fetch_matches(
+        &mut self,
+        service: Service,
+        pattern: &str,
+        since: Option<&str>,
+        until: Option<&str>,
+    ) {
+        // Drops the entries of the previous unit, fetched for another selection.
+        self.select(LogQuery::default().with_lines(self.depth));
+        self.service_name = service.name().to_string();
+        self.merged_with.clear();
+        // The window bounds the entries, so none of the matches is left out.
+        self.select(
+            LogQuery::default()
+                .with_lines(None)
+                .with_time_range(since, until),
+        );
+        self.grep.set_pattern(pattern);
+        self.fetch(vec![service]);
+    }
+
+    pub fn refetch(&mut self) {
+        self.fetch(self.services.clone());
+    }
+
+    fn unit_names(&self) -> impl Iterator<Item = &String> {
+        iter::once(&self.service_name).chain(&self.merged_with)
+    }
+
+    /// Stored entries of every shown unit, oldest first, with the index of their
+    /// unit in [`ServiceLog::unit_names`]. `None` until every log is fetched.
+    fn entries<'s>(&self, store: &'s Store) -> Option<Vec<(usize, &'s LogEntry)>> {
+        let mut entries = vec![];
+        for (unit, name) in self.unit_names().enumerate() {
+            let log = store.log(name)?;
+            entries.extend(log.value().iter().map(|entry| (unit, entry)));
+        }
+        if !self.merged_with.is_empty() {
+            entries.sort_by_key(|(_, entry)| entry.realtime_usec());
+        }
+        Some(entries)
+    }
+
+    /// Drops the entries newer than the one the view was paused at, returning
+    /// how many there were.
+    fn hide_new_entries(&self, entries: &mut Vec<(usize, &LogEntry)>) -> usize {
+        let new_entries = self.paused_at.as_ref().map_or(0, |cursor| {
+            entries
+                .iter()
+                .rposition(|(_, entry)| entry.cursor() == cursor)
+                .map_or(0, |position| entries.len() - position - 1)
+        });
+        entries.truncate(entries.len() - new_entries);
+        new_entries
+    }
+
+    /// Prefixes lines of a merged log with the unit they come from.
+    fn tagged(&self, unit: usize, line: String) -> String {
+        match self.unit_names().nth(unit) {
+            Some(name) if !self.merged_with.is_empty() => format!("[{}] {}", name, line),
+            _ => line,
+        }
+    }
+
+    /// Lines of `entries`, in the order they are shown, that pass the priority and text filters,
+    /// with the priority of the entry they come from.
+    fn displayed_lines(
+        &self,
+        entries: &[(usize, &LogEntry)],
+    ) -> Vec<(Option<LogPriority>, String)> {
+        let mut lines: Vec<_> = entries
+            .iter()
+            .rev()
+            .filter_map(|(unit, entry)| self.displayed_line(*unit, entry))
+            .collect();
+        if self.oldest_first {
+            lines.reverse();
+        }
+        lines
+    }
+
+    /// Line shown for `entry`, unless the priority or text filter hides it.
+    fn displayed_line(
+        &self,
+        unit: usize,
+        entry: &LogEntry,
+    ) -> Option<(Option<LogPriority>, String)> {
+        if !entry.is_within(self.max_priority) {
+            return None;
+        }
+        let line = if self.verbose {
+            entry.verbose_line(self.timestamp_format)
+        } else {
+            entry.line(self.timestamp_format)
+        };
+        let mut line = self.tagged(unit, line);
+        // Priorities are otherwise only told apart by color.
+        if Config::get().screen_reader
+            && let Some(priority) = entry.priority()
+        {
+            line = format!("{}: {}", priority.label(), line);
+        }
+        Some((entry.priority(), line)).filter(|(_, text)| self.grep.is_match(text))
+    }
+
+    /// Writes the log of the shown units to the file at `path`.
+    pub fn export(&mut self, scope: ExportScope, path: &str) {
+        let lines: Vec<String> = {
+            let store = self.store.borrow();
+            let Some(entries) = self.entries(&store) else {
+                return;
+            };
+            match scope {
+                ExportScope::Displayed => self
+                    .displayed_lines(&entries)
+                    .into_iter()
+                    .map(|(_, text)| text)
+                    .collect(),
+                ExportScope::Full => entries
+                    .iter()
+                    .map(|(unit, entry)| self.tagged(*unit, entry.short_line()))
+                    .collect(),
+            }
+        };
+        let mask = SecretMask::from_config();
+        let lines: Vec<String> = lines
+            .iter()
+            .map(|line| mask.apply(line).into_owned())
+            .collect();
+        match export::write_lines(path, &lines) {
+            Ok(path) => self.exported_to = Some(path.display().to_string()),
+            Err(e) => self.sender.send(AppEvent::Error(e.to_string())).unwrap(),
+        }
+    }
+
+    /// Starts sending the lines of the followed log to `target`, a file or
+    /// `| COMMAND`.
+    pub fn forward_to(&mut self, target: &str) {
+        if !self.follow {
+            return;
+        }
+        match LogForwarder::start(target, self.sender.clone()) {
+            Ok(forwarder) => self.forwarder = Some(forwarder),
+            Err(e) => self.sender.send(AppEvent::Error(e.to_string())).unwrap(),
+        }
+    }
+
+    /// Sends the entries of `service_name` that just arrived, as they are
+    /// shown with the current filters, to where the log is forwarded.
+    pub fn forward(&mut self, service_name: &str, entries: &[LogEntry]) {
+        let Some(forwarder) = &self.forwarder else {
+            return;
+        };
+        if service_name != self.service_name {
+            return;
+        }
+        let mask = SecretMask::from_config();
+        let sent = entries
+            .iter()
+            .filter_map(|entry| self.displayed_line(0, entry))
+            .all(|(_, line)| forwarder.send(mask.apply(&line).into_owned()));
+        if !sent {
+            self.forwarder = None;
+        }
+    }
+
+    /// Asks where to forward the followed log, or stops forwarding it.
+    fn toggle_forward(&mut self) {
+        if self.forwarder.take().is_some() {
+            return;
+        }
+        if !self.follow {
+            self.sender
+                .send(AppEvent::Error(
+                    "Only a followed log can be forwarded; press a to follow it first".to_string(),
+                ))
+                .unwrap();
+            return;
+        }
+        self.sender
+            .send(AppEvent::Action(Actions::OpenPrompt(
+                PromptKind::ForwardLog,
+            )))
+            .unwrap();
+    }
+
+    fn open_export_prompt(&self, scope: ExportScope) {
+        self.sender
+            .send(AppEvent::Action(Actions::OpenPrompt(
+                PromptKind::ExportLog(scope),
+            )))
+            .unwrap();
+    }
+
+    /// Starts following once the first entries of the shown unit are stored.
+    pub fn on_log_stored(&mut self, service_name: &str) {
+        if self.follow && self.follower.is_none() && self.service_name == service_name {
+            self.start_follower();
+        }
+    }
+}
+
+impl Component for ServiceLog {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        self.area = area;
+        let store = self.store.borrow();
+        let (Some(log), Some(mut entries)) = (store.log(&self.service_name), self.entries(&store))
+        else {
+            drop(store);
+            self.render_loading(frame, area);
+            return;
+        };
+
+        let area = if self.grep.is_editing() || self.grep.is_active() {
+            let [log_area, grep_area] =
+                Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).areas(area);
+            self.grep.render(frame, grep_area);
+            log_area
+        } else {
+            area
+        };
+
+        // Entries arriving at the top would push the lines being read, or
+        // selected, down.
+        if self.follower.is_none() || (self.scroll == 0 && !self.line_selection.is_active()) {
+            self.paused_at = None;
+        } else if self.paused_at.is_none() {
+            self.paused_at = entries.last().map(|(_, entry)| entry.cursor().to_string());
+        }
+        let new_entries = self.hide_new_entries(&mut entries);
+
+        let texts = self.displayed_lines(&entries);
+        let grep_count = texts.len();
+        self.line_selection.clamp(texts.len());
+
+        let matching_lines: Vec<usize> = if self.search.is_active() {
+            texts
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, text))| !self.search.ranges(text).is_empty())
+                .map(|(index, _)| index)
+                .collect()
+        } else {
+            vec![]
+        };
+        let current_line = self
+            .search
+            .current(matching_lines.len())
+            .map(|current| matching_lines[current]);
+
+        let mut lines: Vec<Line> = texts
+            .into_iter()
+            .enumerate()
+            .map(|(index, (priority, text))| {
+                let line = self
+                    .search
+                    .highlight(text, Some(index) == current_line)
+                    .patch_style(priority_style(priority));
+                if self.line_selection.is_active() && self.line_selection.range().contains(&index) {
+                    line.patch_style(
+                        Style::default()
+                            .bg(Theme::current().selection_bg)
+                            .fg(Theme::current().selection_fg),
+                    )
+                } else {
+                    line
+                }
+            })
+            .collect();
+
+        let width = area.width.saturating_sub(2);
+        let heights: Vec<usize> = if self.wrap {
+            lines
+                .iter()
+                .map(|line| usize::from(wrapped_height(line, width)))
+                .collect()
+        } else {
+            vec![1; lines.len()]
+        };
+
+        // Keep the last page in view rather than scrolling into emptiness; this
+        // is also how `G` lands on the last entry.
+        let view_height = usize::from(area.height.saturating_sub(2));
+        let max_top_row = heights.iter().sum::<usize>().saturating_sub(view_height);
+        self.scroll = self.scroll.min(max_top_row);
+        let mut top_row = if self.oldest_first {
+            max_top_row - self.scroll
+        } else {
+            self.scroll
+        };
+
+        if self.search.take_jump()
+            && let Some(current_line) = current_line
+        {
+            top_row = heights[..current_line].iter().sum();
+        }
+
+        if self.line_selection.is_active() && !heights.is_empty() {
+            let cursor = self.line_selection.cursor();
+            let cursor_row: usize = heights[..cursor].iter().sum();
+            top_row = top_row
+                .min(cursor_row)
+                .max((cursor_row + heights[cursor]).saturating_sub(view_height));
+        }
+
+        top_row = top_row.min(max_top_row);
+        self.scroll = if self.oldest_first {
+            max_top_row - top_row
+        } else {
+            top_row
+        };
+
+        // The paragraph only scrolls by up to u16::MAX rows, so whole lines above
+        // the view are dropped and it is left with the part of the first one.
+        let mut skipped_lines = 0;
+        let mut row_offset = top_row;
+        while skipped_lines < heights.len() && heights[skipped_lines] <= row_offset {
+            row_offset -= heights[skipped_lines];
+            skipped_lines += 1;
+        }
+        lines.drain(..skipped_lines);
+        let row_offset = row_offset as u16;
+        self.first_visible_line = skipped_lines;
+
+        let names = self
+            .unit_names()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" + ");
+        let mut title = format!(
+            " {} ",
+            tr_args(
+                if self.oldest_first {
+                    "log-title-oldest-first"
+                } else {
+                    "log-title-newest-first"
+                },
+                &[
+                    ("units", &names),
+                    ("priority", self.max_priority.label()),
+                    ("seconds", &log.age().as_secs().to_string()),
+                ],
+            )
+        );
+        let mut part = |text: String| title.push_str(&format!("· {} ", text));
+        match self.selection.lines() {
+            Some(lines) => part(tr_args("log-title-last", &[("lines", &lines.to_string())])),
+            // The time range below says which entries are shown.
+            None if self.selection.since().is_some() || self.selection.until().is_some() => {}
+            None => part(tr("log-title-whole-history")),
+        }
+        if let Some(boot) = self.selection.boot() {
+            part(tr_args("log-title-boot", &[("boot", &boot.to_string())]));
+        }
+        if let Some(since) = self.selection.since() {
+            part(tr_args("log-title-since", &[("time", since)]));
+        }
+        if let Some(until) = self.selection.until() {
+            part(tr_args("log-title-until", &[("time", until)]));
+        }
+        if self.verbose {
+            part(tr("log-title-verbose"));
+        }
+        // Following is otherwise only told by the color of the border.
+        if self.follow && Config::get().screen_reader {
+            part(tr("log-title-following"));
+        }
+        if self.grep.is_active() {
+            let shown = entries
+                .iter()
+                .filter(|(_, entry)| entry.is_within(self.max_priority))
+                .count();
+            part(tr_args(
+                "log-title-filter",
+                &[
+                    ("pattern", self.grep.pattern()),
+                    ("matching", &grep_count.to_string()),
+                    ("lines", &shown.to_string()),
+                ],
+            ));
+        }
+        if self.search.is_editing() || self.search.is_active() {
+            let position = matching_lines
+                .iter()
+                .position(|line| Some(*line) == current_line)
+                .map_or(0, |position| position + 1);
+            part(format!(
+                "/{} ({}/{})",
+                self.search.query(),
+                position,
+                matching_lines.len()
+            ));
+        }
+        if self.paused_at.is_some() {
+            part(tr_args(
+                "log-title-paused",
+                &[
+                    ("lines", &new_entries.to_string()),
+                    ("key", if self.oldest_first { "G" } else { "g" }),
+                ],
+            ));
+        }
+        if self.line_selection.is_active() {
+            let lines = self.line_selection.range().count().to_string();
+            part(tr_args("log-title-selected", &[("lines", &lines)]));
+        }
+        if let Some(copied) = self.copied {
+            part(tr_args(
+                "log-title-copied",
+                &[("lines", &copied.to_string())],
+            ));
+        }
+        if let Some(forwarder) = &self.forwarder {
+            part(tr_args(
+                "log-title-forwarding",
+                &[("target", forwarder.target())],
+            ));
+        }
+        if let Some(path) = &self.exported_to {
+            part(tr_args("log-title-written", &[("path", &path.to_string())]));
+        }
+        let target = Target::get();
+        let offset = target.known_clock_offset().unwrap_or(0);
+        let skew = Duration::from_micros(offset.unsigned_abs());
+        if skew > CLOCK_SKEW_TOLERANCE {
+            let host = target.host.as_deref().unwrap_or_default();
+            let skew = compact_duration(u64::try_from(skew.as_nanos()).unwrap_or(u64::MAX));
+            part(tr_args(
+                if offset > 0 {
+                    "log-title-clock-ahead"
+                } else {
+                    "log-title-clock-behind"
+                },
+                &[("host", host), ("skew", &skew)],
+            ));
+        }
+        let target_now = u64::try_from(Utc::now().timestamp_micros() + offset).unwrap_or(0);
+        if let Some((host, ahead)) = entries
+            .iter()
+            .max_by_key(|(_, entry)| entry.realtime_usec())
+            .and_then(|(_, entry)| Some((entry.hostname(), entry.ahead_of(target_now)?)))
+            .filter(|(_, ahead)| *ahead > CLOCK_SKEW_TOLERANCE)
+        {
+            let ahead = compact_duration(u64::try_from(ahead.as_nanos()).unwrap_or(u64::MAX));
+            part(tr_args(
+                "log-title-clock-skew",
+                &[("host", host), ("ahead", &ahead)],
+            ));
+        }
+
+        let log_block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.border_color.to_color()))
+            .title_alignment(Alignment::Center);
+
+        let paragraph = if self.wrap {
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .scroll((row_offset, 0))
+        } else {
+            Paragraph::new(lines).scroll((row_offset, self.horizontal_scroll))
+        };
+        let paragraph = paragraph.block(log_block);
+
+        frame.render_widget(paragraph, area);
+
+        if self.boot_picker.is_open() {
+            self.boot_picker.render(frame, area, store.boots());
+        }
+        if self.time_range_picker.is_open() {
+            self.time_range_picker.render(frame, area);
+        }
+    }
+
+    fn on_mouse_event(&mut self, event: MouseEvent) {
+        if !mouse::is_within(&event, self.area) {
+            return;
+        }
+        match event.kind {
+            MouseEventKind::ScrollDown => self.scroll_down(mouse::WHEEL_ROWS),
+            MouseEventKind::ScrollUp => self.scroll_up(mouse::WHEEL_ROWS),
+            _ => {}
+        }
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        self.exported_to = None;
+        self.copied = None;
+        if self.boot_picker.is_open() {
+            self.on_boot_picker_key_event(key);
+            return;
+        }
+        if self.time_range_picker.is_open() {
+            self.on_time_range_picker_key_event(key);
+            return;
+        }
+        if self.search.is_editing() {
+            self.on_search_key_event(key);
+            return;
+        }
+        if self.grep.is_editing() {
+            self.on_grep_key_event(key);
+            return;
+        }
+        let Some(key) = self.vim_keys.translate(key) else {
+            return;
+        };
+        if self.line_selection.is_active() {
+            self.on_line_selection_key_event(key);
+            return;
+        }
+
+        match key.code {
+            KeyCode::Right if !self.wrap => {
+                self.horizontal_scroll = self.horizontal_scroll.saturating_add(8);
+            }
+            KeyCode::Left if !self.wrap => {
+                self.horizontal_scroll = self.horizontal_scroll.saturating_sub(8);
+            }
+            KeyCode::Right | KeyCode::Tab => {
+                self.reset();
+                self.sender
+                    .send(AppEvent::Action(Actions::GoDetails))
+                    .unwrap();
+            }
+            KeyCode::Left | KeyCode::BackTab => {
+                self.reset();
+                self.sender
+                    .send(AppEvent::Action(Actions::GoSecurity))
+                    .unwrap();
+            }
+            KeyCode::Up => self.scroll_up(1),
+            KeyCode::Down => self.scroll_down(1),
+            KeyCode::PageUp => self.scroll_up(10),
+            KeyCode::PageDown => self.scroll_down(10),
+            // Clamped to the first or last page on the next draw.
+            KeyCode::Char('g') | KeyCode::Home => self.scroll_up(usize::MAX),
+            KeyCode::Char('G') | KeyCode::End => self.scroll_down(usize::MAX),
+            KeyCode::Char('o') => {
+                self.oldest_first = !self.oldest_first;
+                self.line_selection.cancel();
+            }
+            KeyCode::Char('/') => self.search.start(),
+            KeyCode::Char('n') => self.search.next(),
+            KeyCode::Char('N') => self.search.previous(),
+            KeyCode::Char('&') => self.grep.start(),
+            KeyCode::Char('V') => self.line_selection.start(self.first_visible_line),
+            KeyCode::Char('w') => self.open_export_prompt(ExportScope::Displayed),
+            KeyCode::Char('W') => self.open_export_prompt(ExportScope::Full),
+            KeyCode::Char('F') => self.toggle_forward(),
+            KeyCode::Esc if self.search.is_active() => self.search.cancel(),
+            KeyCode::Esc if self.grep.is_active() => {
+                self.grep.cancel();
+                self.scroll = 0;
+            }
+            KeyCode::Char('b') => self.open_boot_picker(),
+            KeyCode::Char('t') => self.time_range_picker.open(),
+            KeyCode::Char('L') => self.cycle_depth(),
+            KeyCode::Char('a') => self.toogle_follow(),
+            KeyCode::Char('T') => self.timestamp_format = self.timestamp_format.next(),
+            KeyCode::Char('M') => self.verbose = !self.verbose,
+            KeyCode::Char('z') => {
+                self.wrap = !self.wrap;
+                self.horizontal_scroll = 0;
+                self.scroll = 0;
+            }
+            KeyCode::Char('1') => self.set_max_priority(LogPriority::Error),
+            KeyCode::Char('2') => self.set_max_priority(LogPriority::Warning),
+            KeyCode::Char('3') => self.set_max_priority(LogPriority::Info),
+            KeyCode::Char('4') => self.set_max_priority(LogPriority::Debug),
+            KeyCode::Char('r') => self
+                .sender
+                .send(AppEvent::Action(Actions::RefreshLog))
+                .unwrap(),
+            KeyCode::Char('s') => self
+                .sender
+                .send(AppEvent::Action(Actions::GoSplit))
+                .unwrap(),
+            KeyCode::Char('q') => {
+                self.reset();
+                self.exit();
+            }
+            _ => {}
+        }
+    }
+
+    fn shortcuts(&self) -> Vec<Line<'static>> {
+        if self.boot_picker.is_open() {
+            return vec![
+                Line::from(vec![Span::styled(
+                    tr("log-boot-heading"),
+                    Style::default()
+                        .fg(Theme::current().heading)
+                        .add_modifier(Modifier::BOLD),
+                )]),
+                Line::from(tr("log-boot-shortcuts")),
+            ];
+        }
+        if self.time_range_picker.is_open() {
+            return vec![
+                Line::from(vec![Span::styled(
+                    tr("log-time-range-heading"),
+                    Style::default()
+                        .fg(Theme::current().heading)
+                        .add_modifier(Modifier::BOLD),
+                )]),
+                Line::from(tr("log-time-range-shortcuts")),
+            ];
+        }
+        if self.search.is_editing() {
+            return vec![
+                Line::from(vec![Span::styled(
+                    tr("log-search-heading"),
+                    Style::default()
+                        .fg(Theme::current().heading)
+                        .add_modifier(Modifier::BOLD),
+                )]),
+                Line::from(tr("log-search-shortcuts")),
+            ];
+        }
+        if self.line_selection.is_active() {
+            return vec![
+                Line::from(vec![Span::styled(
+                    tr("log-select-heading"),
+                    Style::default()
+                        .fg(Theme::current().heading)
+                        .add_modifier(Modifier::BOLD),
+                )]),
+                Line::from(tr("log-select-shortcuts")),
+            ];
+        }
+        if self.grep.is_editing() {
+            return vec![
+                Line::from(vec![Span::styled(
+                    tr("log-filter-heading"),
+                    Style::default()
+                        .fg(Theme::current().heading)
+                        .add_modifier(Modifier::BOLD),
+                )]),
+                Line::from(tr("log-filter-shortcuts")),
+            ];
+        }
+
+        let follow_label = tr(if self.follow {
+            "log-stop-following"
+        } else {
+            "log-follow"
+        });
+
+        let forward_label = tr(if self.forwarder.is_some() {
+            "log-stop-forwarding"
+        } else {
+            "log-forward"
+        });
+
+        let (navigation, wrap_label) = if self.wrap {
+            (tr("log-navigation-wrapped"), tr("log-cut-lines"))
+        } else {
+            (tr("log-navigation"), tr("log-wrap-lines"))
+        };
+
+        let help_text = vec![
+            Line::from(vec![Span::styled(
+                tr("actions-heading"),
+                Style::default()
+                    .fg(Theme::current().heading)
+                    .add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(tr_args(
+                "log-shortcuts",
+                &[
+                    ("navigation", &navigation),
+                    ("follow", &follow_label),
+                    ("forward", &forward_label),
+                    ("wrap", &wrap_label),
+                    ("timestamps", self.timestamp_format.label()),
+                    (
+                        "order",
+                        &tr(if self.oldest_first {
+                            "log-newest-first"
+                        } else {
+                            "log-oldest-first"
+                        }),
+                    ),
+                ],
+            )),
+            Line::from(tr("log-shortcuts-more")),
+        ];
+
+        help_text
+    }
+
+    fn is_typing(&self) -> bool {
+        self.search.is_editing() || self.grep.is_editing()
+    }
+
+    fn cancel_fetches(&mut self) {
+        let mut store = self.store.borrow_mut();
+        for service in &self.services {
+            store.cancel(&DataKey::Log(service.name().to_string()));
+        }
+        store.cancel(&DataKey::Boots);
+    }
+}
@@ -0,0 +1,165 @@
+use std::ops::Range;
+
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+};
+
+use crate::terminal::theme::Theme;
+
+/// Text search inside the log view, started with `/`.
+///
+/// Matching is case-insensitive unless the query contains an uppercase letter.
+#[derive(Default)]
+pub struct LogSearch {
+    query: String,
+    editing: bool,
+    current: usize,
+    jump: bool,
+}
+
+impl LogSearch {
+    pub fn is_editing(&self) -> bool {
+        self.editing
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.query.is_empty()
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn start(&mut self) {
+        self.query.clear();
+        self.editing = true;
+    }
+
+    pub fn push(&mut self, c: char) {
+        self.query.push(c);
+        self.current = 0;
+        self.jump = true;
+    }
+
+    pub fn pop(&mut self) {
+        self.query.pop();
+        self.current = 0;
+        self.jump = true;
+    }
+
+    pub fn confirm(&mut self) {
+        self.editing = false;
+    }
+
+    pub fn cancel(&mut self) {
+        self.query.clear();
+        self.editing = false;
+    }
+
+    pub fn next(&mut self) {
+        self.current = self.current.wrapping_add(1);
+        self.jump = true;
+    }
+
+    pub fn previous(&mut self) {
+        self.current = self.current.wrapping_sub(1);
+        self.jump = true;
+    }
+
+    /// Index into `matching_lines` of the current match, wrapping around both ends.
+    pub fn current(&mut self, match_count: usize) -> Option<usize> {
+        if match_count == 0 {
+            return None;
+        }
+        if self.current == usize::MAX {
+            self.current = match_count - 1;
+        }
+        self.current %= match_count;
+        Some(self.current)
+    }
+
+    /// Whether the view should scroll to the current match, which is only asked for
+    /// once per jump so manual scrolling keeps working.
+    pub fn take_jump(&mut self) -> bool {
+        std::mem::take(&mut self.jump)
+    }
+
+    pub fn ranges(&self, text: &str) -> Vec<Range<usize>> {
+        match_ranges(text, &self.query)
+    }
+
+    /// Splits `text` so every match is highlighted; `current` marks the line
+    /// holding the match jumped to.
+    pub fn highlight(&self, text: String, current: bool) -> Line<'static> {
+        let ranges = self.ranges(&text);
+        if ranges.is_empty() {
+            return Line::raw(text);
+        }
+
+        let match_style = if current {
+            Style::default()
+                .bg(Theme::current().current_match_bg)
+                .fg(Theme::current().match_fg)
+        } else {
+            Style::default()
+                .bg(Theme::current().match_bg)
+                .fg(Theme::current().match_fg)
+        };
+
+        let mut spans = vec![];
+        let mut last = 0;
+        for range in ranges {
+            if range.start > last {
+                spans.push(Span::raw(text[last..range.start].to_string()));
+            }
+            spans.push(Span::styled(text[range.clone()].to_string(), match_style));
+            last = range.end;
+        }
+        if last < text.len() {
+            spans.push(Span::raw(text[last..].to_string()));
+        }
+        Line::from(spans)
+    }
+}
+
+fn match_ranges(text: &str, query: &str) -> Vec<Range<usize>> {
+    if query.is_empty() {
+        return vec![];
+    }
+    let ignore_case = !query.chars().any(char::is_uppercase);
+
+    let mut ranges = vec![];
+    let mut start = 0;
+    while start < text.len() {
+        match matched_len(&text[start..], query, ignore_case) {
+            Some(len) => {
+                ranges.push(start..start + len);
+                start += len;
+            }
+            None => {
+                start += text[start..].chars().next().map_or(1, char::len_utf8);
+            }
+        }
+    }
+    ranges
+}
+
+/// Byte length of `query` matched at the start of `text`, if it matches there.
+fn matched_len(text: &str, query: &str, ignore_case: bool) -> Option<usize> {
+    let mut chars = text.chars();
+    let mut len = 0;
+    for q in query.chars() {
+        let c = chars.next()?;
+        let equal = if ignore_case {
+            c.to_lowercase().eq(q.to_lowercase())
+        } else {
+            c == q
+        };
+        if !equal {
+            return None;
+        }
+        len += c.len_utf8();
+    }
+    Some(len)
+}
@@ -0,0 +1,104 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+};
+
+use crate::terminal::i18n::tr;
+use crate::terminal::theme::Theme;
+
+/// A choice of the time range picker.
+pub enum TimeRangeChoice {
+    Range(Option<&'static str>, Option<&'static str>),
+    Custom,
+}
+
+const PRESETS: [(&str, TimeRangeChoice); 6] = [
+    ("Full history", TimeRangeChoice::Range(None, None)),
+    (
+        "Last 15 minutes",
+        TimeRangeChoice::Range(Some("-15min"), None),
+    ),
+    ("Last hour", TimeRangeChoice::Range(Some("-1h"), None)),
+    ("Last 24 hours", TimeRangeChoice::Range(Some("-24h"), None)),
+    ("Today", TimeRangeChoice::Range(Some("today"), None)),
+    ("Custom since/until...", TimeRangeChoice::Custom),
+];
+
+/// Splits the text typed for a custom range, `SINCE..UNTIL`, where either side
+/// may be left empty.
+pub fn parse_time_range(input: &str) -> (Option<&str>, Option<&str>) {
+    match input.split_once("..") {
+        Some((since, until)) => (Some(since), Some(until)),
+        None => (Some(input), None),
+    }
+}
+
+/// Popup with common time windows for the log view, opened with `t`.
+#[derive(Default)]
+pub struct TimeRangePicker {
+    open: bool,
+    selected: usize,
+}
+
+impl TimeRangePicker {
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn open(&mut self) {
+        self.open = true;
+        self.selected = 0;
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1).min(PRESETS.len() - 1);
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn choice(&self) -> &'static TimeRangeChoice {
+        &PRESETS[self.selected].1
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let width = area.width.saturating_sub(4).min(40);
+        let height = (PRESETS.len() as u16 + 2).min(area.height);
+        let popup_area = Rect::new(
+            area.x + (area.width.saturating_sub(width)) / 2,
+            area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        );
+
+        let items: Vec<ListItem> = PRESETS
+            .iter()
+            .map(|(label, _)| ListItem::new(*label))
+            .collect();
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Theme::current().popup_border))
+                    .title(format!(" {} ", tr("log-time-range-title"))),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(Theme::current().selection_bg)
+                    .fg(Theme::current().selection_fg)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol(">> ");
+        let mut state = ListState::default().with_selected(Some(self.selected));
+
+        frame.render_widget(Clear, popup_area);
+        frame.render_stateful_widget(list, popup_area, &mut state);
+    }
+}
@@ -1,4 +1,27 @@
+pub mod boot_times;
+pub mod columns;
+pub mod component;
+pub mod dashboard;
 pub mod details;
+pub mod early_boot;
 pub mod filter;
+pub mod gauge;
+pub mod help;
+pub mod job_conflict;
+pub mod job_failure;
+pub mod job_mode;
+pub mod job_progress;
+pub mod journal_search;
 pub mod list;
+pub mod lock;
 pub mod log;
+pub mod plugin_view;
+pub mod prompt;
+pub mod recent;
+pub mod security;
+pub mod status_bar;
+pub mod stop_impact;
+pub mod tmpfiles;
+pub mod toasts;
+pub mod triage;
+pub mod unit_menu;
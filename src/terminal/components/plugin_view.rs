@@ -0,0 +1,126 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+use std::sync::mpsc::Sender;
+
+use crate::domain::service::Service;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::theme::Theme;
+use crate::usecases::plugins::{PluginExtension, Plugins};
+use crate::usecases::runtime::{self, Task};
+
+/// What a plugin prints about a unit, opened from the unit menu.
+pub struct PluginView {
+    view: Option<PluginExtension>,
+    service: Option<Service>,
+    /// What the plugin printed, or why it could not.
+    text: Option<Result<String, String>>,
+    scroll: u16,
+    fetch: Option<Task>,
+    sender: Sender<AppEvent>,
+}
+
+impl PluginView {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            view: None,
+            service: None,
+            text: None,
+            scroll: 0,
+            fetch: None,
+            sender,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.text = None;
+        self.scroll = 0;
+    }
+
+    pub fn fetch_and_dispatch(&mut self, view: PluginExtension, service: Service) {
+        self.reset();
+        self.view = Some(view.clone());
+        self.service = Some(service.clone());
+        let event_tx = self.sender.clone();
+        self.cancel_fetches();
+        self.fetch = Some(runtime::spawn(async move {
+            let text = Plugins::get_view(&view, service.name())
+                .await
+                .map_err(|e| e.to_string());
+            event_tx
+                .send(AppEvent::Action(Actions::UpdatePluginView(text)))
+                .expect("Failed to send UpdatePluginView event");
+        }));
+    }
+
+    pub fn update(&mut self, text: Result<String, String>) {
+        self.text = Some(text);
+    }
+}
+
+impl Component for PluginView {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let title = self.view.as_ref().map_or("", PluginExtension::title);
+        let name = self.service.as_ref().map_or("", |service| service.name());
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {}: {} ", title, name))
+            .title_alignment(Alignment::Center);
+        let paragraph = match &self.text {
+            None => Paragraph::new(tr("plugin-view-running")).alignment(Alignment::Center),
+            Some(Ok(text)) => Paragraph::new(text.clone())
+                .wrap(Wrap { trim: false })
+                .scroll((self.scroll, 0)),
+            Some(Err(e)) => Paragraph::new(Line::styled(
+                tr_args("plugin-view-failed", &[("error", e)]),
+                Style::default().fg(Theme::current().error),
+            ))
+            .wrap(Wrap { trim: false }),
+        };
+        frame.render_widget(paragraph.block(block), area);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(10),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(10),
+            KeyCode::Char('r') => {
+                if let (Some(view), Some(service)) = (self.view.clone(), self.service.clone()) {
+                    self.fetch_and_dispatch(view, service);
+                }
+            }
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.reset();
+                self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+            }
+            _ => {}
+        }
+    }
+
+    fn shortcuts(&self) -> Vec<Line<'static>> {
+        vec![
+            Line::from(vec![Span::styled(
+                tr("actions-heading"),
+                Style::default()
+                    .fg(Theme::current().heading)
+                    .add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(tr("plugin-view-shortcuts")),
+        ]
+    }
+
+    fn cancel_fetches(&mut self) {
+        if let Some(fetch) = self.fetch.take() {
+            fetch.cancel();
+        }
+    }
+}
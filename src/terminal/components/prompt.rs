@@ -0,0 +1,169 @@
+use ratatui::{
+    Frame,
+    crossterm::event::{KeyCode, KeyEvent},
+    layout::{Position, Rect},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use std::sync::mpsc::Sender;
+
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::components::log::ExportScope;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::theme::Theme;
+
+/// What the text typed in the prompt will be used for.
+#[derive(Clone, PartialEq)]
+pub enum PromptKind {
+    /// Instantiate the given template unit (e.g. `foo@.service`).
+    InstantiateTemplate(String),
+    /// Restrict the log view to `SINCE..UNTIL`.
+    LogTimeRange,
+    /// Write the log view to the file at the given path.
+    ExportLog(ExportScope),
+    /// Send the lines of the followed log to a file or `| COMMAND` as they arrive.
+    ForwardLog,
+    /// Write a support bundle of the marked or selected units to the given path.
+    SupportBundle,
+    /// Search the journal of the listed units, as `PATTERN @ SINCE..UNTIL`.
+    JournalSearch,
+}
+
+impl PromptKind {
+    fn title(&self) -> String {
+        let title = match self {
+            PromptKind::InstantiateTemplate(template) => {
+                tr_args("prompt-instance-name", &[("template", template)])
+            }
+            PromptKind::LogTimeRange => tr("prompt-time-range"),
+            PromptKind::ExportLog(ExportScope::Displayed) => tr("prompt-export-displayed"),
+            PromptKind::ExportLog(ExportScope::Full) => tr("prompt-export-full"),
+            PromptKind::ForwardLog => tr("prompt-forward-log"),
+            PromptKind::JournalSearch => tr("prompt-journal-search"),
+            PromptKind::SupportBundle => tr("prompt-support-bundle"),
+        };
+        format!(" {} ", title)
+    }
+}
+
+/// A single line text input drawn as a popup over the current screen.
+pub struct Prompt {
+    kind: Option<PromptKind>,
+    input: String,
+    character_index: usize,
+    sender: Sender<AppEvent>,
+}
+
+impl Prompt {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            kind: None,
+            input: String::new(),
+            character_index: 0,
+            sender,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.kind.is_some()
+    }
+
+    pub fn open(&mut self, kind: PromptKind) {
+        self.kind = Some(kind);
+        self.input = String::new();
+        self.character_index = 0;
+    }
+
+    fn close(&mut self) {
+        self.kind = None;
+    }
+
+    fn enter_char(&mut self, new_char: char) {
+        let index = self
+            .input
+            .char_indices()
+            .map(|(i, _)| i)
+            .nth(self.character_index)
+            .unwrap_or(self.input.len());
+        self.input.insert(index, new_char);
+        self.character_index += 1;
+    }
+
+    fn delete_char(&mut self) {
+        if self.character_index == 0 {
+            return;
+        }
+        let before = self.input.chars().take(self.character_index - 1);
+        let after = self.input.chars().skip(self.character_index);
+        self.input = before.chain(after).collect();
+        self.character_index -= 1;
+    }
+
+    fn submit(&mut self) {
+        if let Some(kind) = self.kind.take() {
+            self.sender
+                .send(AppEvent::Action(Actions::SubmitPrompt(
+                    kind,
+                    self.input.clone(),
+                )))
+                .unwrap();
+        }
+    }
+}
+
+impl Component for Prompt {
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Enter => self.submit(),
+            KeyCode::Esc => self.close(),
+            KeyCode::Char(to_insert) => self.enter_char(to_insert),
+            KeyCode::Backspace => self.delete_char(),
+            KeyCode::Left => self.character_index = self.character_index.saturating_sub(1),
+            KeyCode::Right => {
+                self.character_index = (self.character_index + 1).min(self.input.chars().count())
+            }
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let Some(kind) = &self.kind else {
+            return;
+        };
+
+        let width = area.width.saturating_sub(4).min(60);
+        let popup_area = Rect::new(
+            area.x + (area.width.saturating_sub(width)) / 2,
+            area.y + (area.height.saturating_sub(4)) / 2,
+            width,
+            4.min(area.height),
+        );
+
+        frame.render_widget(Clear, popup_area);
+        let input = Paragraph::new(vec![
+            Line::from(self.input.as_str()).fg(Theme::current().input),
+            Line::from(vec![
+                "Enter".bold(),
+                " to confirm, ".into(),
+                "Esc".bold(),
+                " to cancel".into(),
+            ])
+            .fg(Theme::current().muted),
+        ])
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Theme::current().popup_border))
+                .title(kind.title()),
+        );
+        frame.render_widget(input, popup_area);
+
+        #[allow(clippy::cast_possible_truncation)]
+        frame.set_cursor_position(Position::new(
+            popup_area.x + self.character_index as u16 + 1,
+            popup_area.y + 1,
+        ));
+    }
+}
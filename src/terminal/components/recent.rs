@@ -0,0 +1,133 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+use std::sync::mpsc::Sender;
+
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::i18n::tr;
+use crate::terminal::theme::Theme;
+
+const MAX_RECENT_UNITS: usize = 10;
+
+/// Units whose logs or properties were opened during this session, most recent
+/// first, offered in a menu opened with `m` from the list.
+pub struct RecentUnits {
+    units: Vec<String>,
+    open: bool,
+    selected: usize,
+    sender: Sender<AppEvent>,
+}
+
+impl RecentUnits {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            units: vec![],
+            open: false,
+            selected: 0,
+            sender,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn open(&mut self) {
+        self.open = true;
+        // The first entry is usually the unit just left, so the previous one is
+        // what bouncing between two units needs.
+        self.selected = usize::from(self.units.len() > 1);
+    }
+
+    fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// Moves `name` to the top of the list.
+    pub fn visit(&mut self, name: &str) {
+        self.units.retain(|unit| unit != name);
+        self.units.insert(0, name.to_string());
+        self.units.truncate(MAX_RECENT_UNITS);
+    }
+
+    /// Selects the chosen unit in the list, then runs `then` on it if given.
+    fn choose(&mut self, then: Option<Actions>) {
+        let Some(name) = self.units.get(self.selected) else {
+            return;
+        };
+        self.sender
+            .send(AppEvent::Action(Actions::SelectUnit(name.clone())))
+            .unwrap();
+        if let Some(action) = then {
+            self.sender.send(AppEvent::Action(action)).unwrap();
+        }
+        self.close();
+    }
+}
+
+impl Component for RecentUnits {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        if !self.open {
+            return;
+        }
+
+        let width = area.width.saturating_sub(4).min(70);
+        let height = (self.units.len().max(1) as u16 + 2).min(area.height);
+        let popup_area = Rect::new(
+            area.x + (area.width.saturating_sub(width)) / 2,
+            area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        );
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::current().popup_border))
+            .title(format!(" {} ", tr("recent-title")))
+            .title_bottom(Line::from(format!(" {} ", tr("recent-shortcuts"))).right_aligned());
+
+        frame.render_widget(Clear, popup_area);
+
+        if self.units.is_empty() {
+            frame.render_widget(Paragraph::new(tr("recent-empty")).block(block), popup_area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .units
+            .iter()
+            .map(|unit| ListItem::new(unit.as_str()))
+            .collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(
+                Style::default()
+                    .bg(Theme::current().selection_bg)
+                    .fg(Theme::current().selection_fg)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol(">> ");
+        let mut state = ListState::default().with_selected(Some(self.selected));
+
+        frame.render_stateful_widget(list, popup_area, &mut state);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(self.units.len().saturating_sub(1))
+            }
+            KeyCode::Enter => self.choose(None),
+            KeyCode::Char('v') => self.choose(Some(Actions::GoLog)),
+            KeyCode::Char('p') => self.choose(Some(Actions::GoDetails)),
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('m') => self.close(),
+            _ => {}
+        }
+    }
+}
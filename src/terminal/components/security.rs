@@ -0,0 +1,327 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState, Wrap},
+};
+use std::sync::mpsc::Sender;
+
+use crate::domain::access_control::AccessControl;
+use crate::domain::security_report::SecurityReport;
+use crate::domain::service::Service;
+use crate::terminal::app::{Actions, AppEvent, get_user_friendly_error};
+use crate::terminal::components::component::Component;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::theme::Theme;
+use crate::usecases::runtime::{self, Task};
+use crate::usecases::services_manager::ServicesManager;
+
+fn exposure_color(exposure: f32) -> Color {
+    if exposure < 4.0 {
+        Theme::current().ok
+    } else if exposure < 7.0 {
+        Theme::current().warning
+    } else {
+        Theme::current().error
+    }
+}
+
+/// Most recent denials listed above the directives.
+const SHOWN_DENIALS: usize = 5;
+
+fn access_control_lines(access_control: &AccessControl) -> Vec<Line<'static>> {
+    let label = Style::default().add_modifier(Modifier::BOLD);
+    let value = |text: &str, unset: &str| {
+        if text.is_empty() {
+            Span::styled(
+                unset.to_string(),
+                Style::default().fg(Theme::current().faint),
+            )
+        } else {
+            Span::raw(text.to_string())
+        }
+    };
+    let denials = access_control.denials();
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("SELinux context: ", label),
+            value(access_control.selinux_context(), "not set"),
+        ]),
+        Line::from(vec![
+            Span::styled("AppArmor profile: ", label),
+            value(access_control.apparmor_profile(), "not set"),
+        ]),
+        Line::from(vec![
+            Span::styled("Main process label: ", label),
+            value(
+                access_control.process_label().unwrap_or_default(),
+                "not running",
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Denials this boot: ", label),
+            Span::styled(
+                denials.len().to_string(),
+                Style::default().fg(if denials.is_empty() {
+                    Theme::current().ok
+                } else {
+                    Theme::current().error
+                }),
+            ),
+        ]),
+    ];
+    lines.extend(
+        denials[denials.len().saturating_sub(SHOWN_DENIALS)..]
+            .iter()
+            .map(|denial| {
+                Line::styled(
+                    denial.short_line(),
+                    Style::default().fg(Theme::current().error),
+                )
+            }),
+    );
+    lines
+}
+
+pub struct ServiceSecurity {
+    /// The report, or why `systemd-analyze security` could not give it.
+    report: Option<Result<SecurityReport, String>>,
+    access_control: Option<AccessControl>,
+    service_name: String,
+    scroll: usize,
+    fetches: Vec<Task>,
+    sender: Sender<AppEvent>,
+}
+
+impl ServiceSecurity {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            report: None,
+            access_control: None,
+            service_name: String::new(),
+            scroll: 0,
+            fetches: vec![],
+            sender,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.report = None;
+        self.access_control = None;
+        self.scroll = 0;
+    }
+
+    fn exit(&self) {
+        self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+    }
+
+    pub fn fetch_report_and_dispatch(&mut self, service: Service) {
+        self.service_name = service.name().to_string();
+        let event_tx = self.sender.clone();
+        let unit = service.clone();
+        self.cancel_fetches();
+        self.fetches.push(runtime::spawn(async move {
+            match ServicesManager::get_access_control(&unit).await {
+                Ok(access_control) => event_tx
+                    .send(AppEvent::Action(Actions::UpdateAccessControl(
+                        access_control,
+                    )))
+                    .expect("Failed to send UpdateAccessControl event"),
+                Err(e) => event_tx
+                    .send(AppEvent::Error(e.to_string()))
+                    .expect("Failed to send Error event"),
+            }
+        }));
+        let event_tx = self.sender.clone();
+        self.fetches.push(runtime::spawn(async move {
+            let report = ServicesManager::get_security_report(&service)
+                .await
+                .map_err(|e| e.to_string());
+            event_tx
+                .send(AppEvent::Action(Actions::UpdateSecurity(report)))
+                .expect("Failed to send UpdateSecurity event");
+        }));
+    }
+
+    pub fn update(&mut self, report: Result<SecurityReport, String>) {
+        self.report = Some(report);
+    }
+
+    pub fn update_access_control(&mut self, access_control: AccessControl) {
+        self.access_control = Some(access_control);
+    }
+}
+
+impl Component for ServiceSecurity {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let area = match &self.access_control {
+            Some(access_control) => {
+                let lines = access_control_lines(access_control);
+                let [top, rest] = Layout::vertical([
+                    Constraint::Length(lines.len() as u16 + 2),
+                    Constraint::Min(0),
+                ])
+                .areas(area);
+                let paragraph = Paragraph::new(lines).block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(format!(" {} ", tr("security-mac-title"))),
+                );
+                frame.render_widget(paragraph, top);
+                rest
+            }
+            None => area,
+        };
+
+        let report = match &self.report {
+            None => {
+                let loading = Paragraph::new(tr("loading"))
+                    .alignment(Alignment::Center)
+                    .block(Block::default().borders(Borders::ALL));
+                frame.render_widget(loading, area);
+                return;
+            }
+            Some(Err(e)) => {
+                let failed = Paragraph::new(Line::styled(
+                    tr_args("security-failed", &[("error", &get_user_friendly_error(e))]),
+                    Style::default().fg(Theme::current().error),
+                ))
+                .wrap(Wrap { trim: false })
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(format!(
+                            " {} ",
+                            tr_args("security-title", &[("unit", &self.service_name)])
+                        ))
+                        .title_alignment(Alignment::Center),
+                );
+                frame.render_widget(failed, area);
+                return;
+            }
+            Some(Ok(report)) => report,
+        };
+
+        let rows: Vec<Row> = report
+            .directives()
+            .iter()
+            .map(|directive| {
+                let (marker, marker_style) = match directive.passed() {
+                    Some(true) => ("✓", Style::default().fg(Theme::current().ok)),
+                    Some(false) => ("✗", Style::default().fg(Theme::current().error)),
+                    None => (" ", Style::default()),
+                };
+                let exposure = directive
+                    .exposure()
+                    .map(|e| format!("{:.1}", e))
+                    .unwrap_or_default();
+
+                Row::new(vec![
+                    Cell::from(marker).style(marker_style),
+                    Cell::from(directive.name().to_string())
+                        .style(Style::default().add_modifier(Modifier::BOLD)),
+                    Cell::from(directive.description().to_string())
+                        .style(Style::default().fg(Theme::current().muted)),
+                    Cell::from(exposure),
+                ])
+            })
+            .collect();
+
+        self.scroll = self.scroll.min(rows.len().saturating_sub(1));
+        let mut table_state = TableState::default().with_offset(self.scroll);
+
+        let overall = Line::from(vec![
+            Span::raw(format!(
+                " {} ",
+                tr_args("security-title", &[("unit", &self.service_name)])
+            )),
+            Span::styled(
+                format!(
+                    "{:.1} {} ",
+                    report.overall_exposure(),
+                    report.overall_rating()
+                ),
+                Style::default()
+                    .fg(exposure_color(report.overall_exposure()))
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]);
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(1),
+                Constraint::Percentage(30),
+                Constraint::Min(20),
+                Constraint::Length(8),
+            ],
+        )
+        .header(
+            Row::new(["", "Name", "Description", "Exposure"]).style(
+                Style::default()
+                    .fg(Theme::current().header)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(overall)
+                .title_alignment(Alignment::Center),
+        );
+
+        frame.render_stateful_widget(table, area, &mut table_state);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Right | KeyCode::Tab => {
+                self.reset();
+                self.sender.send(AppEvent::Action(Actions::GoLog)).unwrap();
+            }
+            KeyCode::Left | KeyCode::BackTab => {
+                self.reset();
+                self.sender
+                    .send(AppEvent::Action(Actions::GoDetails))
+                    .unwrap();
+            }
+            KeyCode::Up => {
+                self.scroll = self.scroll.saturating_sub(1);
+            }
+            KeyCode::Down => {
+                self.scroll += 1;
+            }
+            KeyCode::PageUp => {
+                self.scroll = self.scroll.saturating_sub(10);
+            }
+            KeyCode::PageDown => {
+                self.scroll += 10;
+            }
+            KeyCode::Char('q') => {
+                self.reset();
+                self.exit();
+            }
+            _ => {}
+        }
+    }
+
+    fn shortcuts(&self) -> Vec<Line<'static>> {
+        vec![
+            Line::from(vec![Span::styled(
+                tr("actions-heading"),
+                Style::default()
+                    .fg(Theme::current().heading)
+                    .add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(tr("security-shortcuts")),
+        ]
+    }
+
+    fn cancel_fetches(&mut self) {
+        for fetch in self.fetches.drain(..) {
+            fetch.cancel();
+        }
+    }
+}
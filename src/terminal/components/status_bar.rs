@@ -0,0 +1,188 @@
+use crossterm::event::KeyEvent;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::mpsc::Sender;
+
+use crate::config::Config;
+use crate::domain::job_result::JobResult;
+use crate::infrastructure::target::Target;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::components::job_progress::result_text;
+use crate::terminal::components::list::ServiceAction;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::store::Store;
+use crate::terminal::theme::Theme;
+use crate::usecases::plugins::PluginExtension;
+use crate::usecases::runtime::{self, Task};
+use crate::usecases::services_manager::ServicesManager;
+
+/// Line at the bottom of every screen with the state of the system, how many
+/// units failed, what is managed and where, and how the last action went.
+pub struct StatusBar {
+    /// `SystemState` of the manager, or why it could not be read.
+    system_state: Option<Result<String, String>>,
+    /// How the last action taken from the list went, and whether it went well.
+    last_action: Option<(String, bool)>,
+    host: String,
+    fetch: Option<Task>,
+    store: Rc<RefCell<Store>>,
+    sender: Sender<AppEvent>,
+}
+
+impl StatusBar {
+    pub fn new(sender: Sender<AppEvent>, store: Rc<RefCell<Store>>) -> Self {
+        let host = match &Target::get().host {
+            Some(host) => host.clone(),
+            None => std::fs::read_to_string("/proc/sys/kernel/hostname")
+                .map(|host| host.trim().to_string())
+                .unwrap_or_else(|_| "localhost".to_string()),
+        };
+        Self {
+            system_state: None,
+            last_action: None,
+            host,
+            fetch: None,
+            store,
+            sender,
+        }
+    }
+
+    /// Reads the state of the system again, as the units it sums up changed.
+    pub fn fetch(&mut self) {
+        let sender = self.sender.clone();
+        if let Some(fetch) = self.fetch.take() {
+            fetch.cancel();
+        }
+        self.fetch = Some(runtime::spawn(async move {
+            let state = ServicesManager::get_system_state()
+                .await
+                .map_err(|e| e.to_string());
+            let _ = sender.send(AppEvent::Action(Actions::UpdateSystemState(state)));
+        }));
+    }
+
+    /// Keeps the state read, returning whether it differs from the one shown.
+    pub fn update(&mut self, state: Result<String, String>) -> bool {
+        let state = Some(state);
+        let changed = self.system_state != state;
+        self.system_state = state;
+        changed
+    }
+
+    /// Remembers how `action` on `name` went, once systemd answered.
+    pub fn on_unit_action_done(
+        &mut self,
+        name: &str,
+        action: ServiceAction,
+        result: &Result<Option<String>, String>,
+    ) {
+        let Some(label) = action.log_label() else {
+            return;
+        };
+        self.last_action = Some(match result {
+            // The job it queued tells how it ends.
+            Ok(Some(_)) => (format!("{} {}: queued", label, name), true),
+            Ok(None) => (format!("{} {}: done", label, name), true),
+            Err(e) => (format!("{} {}: {}", label, name, e), false),
+        });
+    }
+
+    pub fn on_job_finished(&mut self, name: &str, label: &str, result: &Result<JobResult, String>) {
+        self.last_action = Some(match result {
+            Ok(result) => (
+                format!("{} {}: {}", label, name, result_text(result)),
+                result.is_success(),
+            ),
+            Err(e) => (format!("{} {}: {}", label, name, e), false),
+        });
+    }
+
+    pub fn on_plugin_action_done(
+        &mut self,
+        name: &str,
+        action: &PluginExtension,
+        result: &Result<String, String>,
+    ) {
+        self.last_action = Some(match result {
+            Ok(_) => (format!("{} {}: done", action.title(), name), true),
+            Err(e) => (format!("{} {}: {}", action.title(), name, e), false),
+        });
+    }
+
+    pub fn on_template_instantiated(&mut self, instance: &str, result: &Result<(), String>) {
+        self.last_action = Some(match result {
+            Ok(()) => (format!("new instance {}: done", instance), true),
+            Err(e) => (format!("new instance {}: {}", instance, e), false),
+        });
+    }
+}
+
+impl Component for StatusBar {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let theme = Theme::current();
+        let (state, state_color) = match &self.system_state {
+            Some(Ok(state)) if state == "running" => (state.clone(), theme.ok),
+            Some(Ok(state)) if state == "degraded" => (state.clone(), theme.error),
+            Some(Ok(state)) => (state.clone(), theme.warning),
+            Some(Err(_)) => (tr("status-state-unknown"), theme.faint),
+            None => ("...".to_string(), theme.faint),
+        };
+        let failed = self.store.borrow().units().map(|units| {
+            units
+                .value()
+                .iter()
+                .filter(|service| service.state().active() == "failed")
+                .count()
+        });
+        let separator = || Span::styled(" | ", Style::default().fg(theme.faint));
+
+        let mut spans = vec![
+            Span::styled(
+                format!(" ● {}", state),
+                Style::default()
+                    .fg(state_color)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            separator(),
+        ];
+        spans.push(match failed {
+            Some(0) => Span::raw(tr("status-no-failed")),
+            Some(failed) => Span::styled(
+                tr_args("status-failed", &[("count", &failed.to_string())]),
+                Style::default().fg(theme.error),
+            ),
+            None => Span::raw("..."),
+        });
+        spans.extend([
+            separator(),
+            Span::raw(ServicesManager::scope()),
+            separator(),
+            Span::raw(self.host.clone()),
+        ]);
+        if let Some((text, success)) = &self.last_action {
+            let (symbol, color) = match (*success, Config::get().screen_reader) {
+                (true, false) => ("✓".to_string(), theme.ok),
+                (false, false) => ("✗".to_string(), theme.error),
+                (true, true) => (format!("{}:", tr("status-succeeded")), theme.ok),
+                (false, true) => (format!("{}:", tr("status-failed-action")), theme.error),
+            };
+            spans.push(separator());
+            spans.push(Span::styled(
+                format!("{} {}", symbol, text),
+                Style::default().fg(color),
+            ));
+        }
+
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+
+    fn on_key_event(&mut self, _key: KeyEvent) {}
+}
@@ -0,0 +1,166 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+use std::sync::mpsc::Sender;
+
+use crate::domain::service::Service;
+use crate::domain::stop_impact::ImpactedUnit;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::components::list::ServiceAction;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::theme::Theme;
+use crate::usecases::runtime::{self, Task};
+use crate::usecases::services_manager::ServicesManager;
+
+/// Popup listing the units a stop or restart takes down along with the
+/// selected one, asking before doing it. Nothing is asked when no other unit
+/// goes down.
+pub struct StopImpact {
+    /// Unit and action waiting for confirmation.
+    pending: Option<(Service, ServiceAction)>,
+    /// What goes down along, once known.
+    impacted: Option<Vec<ImpactedUnit>>,
+    fetch: Option<Task>,
+    sender: Sender<AppEvent>,
+}
+
+impl StopImpact {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            pending: None,
+            impacted: None,
+            fetch: None,
+            sender,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    pub fn open(&mut self, service: Service, action: ServiceAction) {
+        self.pending = Some((service.clone(), action));
+        self.impacted = None;
+        let event_tx = self.sender.clone();
+        self.fetch = Some(runtime::spawn(async move {
+            let impacted = ServicesManager::get_stop_impact(&service)
+                .await
+                .map_err(|e| e.to_string());
+            event_tx
+                .send(AppEvent::Action(Actions::UpdateStopImpact(
+                    service.name().to_string(),
+                    impacted,
+                )))
+                .expect("Failed to send UpdateStopImpact event");
+        }));
+    }
+
+    fn close(&mut self) {
+        self.pending = None;
+        self.impacted = None;
+        if let Some(fetch) = self.fetch.take() {
+            fetch.cancel();
+        }
+    }
+
+    /// Shows what goes down along with `name`, or goes ahead when nothing does.
+    pub fn update(&mut self, name: &str, impacted: Result<Vec<ImpactedUnit>, String>) {
+        if !matches!(&self.pending, Some((service, _)) if service.name() == name) {
+            return;
+        }
+        match impacted {
+            Ok(impacted) if impacted.is_empty() => self.confirm(),
+            Ok(impacted) => self.impacted = Some(impacted),
+            Err(error) => {
+                self.close();
+                self.sender.send(AppEvent::Error(error)).unwrap();
+            }
+        }
+    }
+
+    fn confirm(&mut self) {
+        if let Some((service, action)) = self.pending.take() {
+            self.sender
+                .send(AppEvent::Action(Actions::RunServiceAction(
+                    service.name().to_string(),
+                    action,
+                )))
+                .unwrap();
+        }
+        self.close();
+    }
+}
+
+impl Component for StopImpact {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let Some((service, action)) = &self.pending else {
+            return;
+        };
+        let (title, impact, shortcuts) = match action {
+            ServiceAction::Restart => (
+                "stop-impact-title-restart",
+                "stop-impact-restarted",
+                "stop-impact-restart-shortcuts",
+            ),
+            _ => (
+                "stop-impact-title-stop",
+                "stop-impact-stopped",
+                "stop-impact-stop-shortcuts",
+            ),
+        };
+
+        let mut text = vec![];
+        match &self.impacted {
+            None => text.push(Line::from(tr("stop-impact-checking"))),
+            Some(impacted) => {
+                text.push(Line::from(tr(impact)));
+                text.push(Line::default());
+                text.extend(impacted.iter().map(|unit| {
+                    Line::from(vec![
+                        Span::styled(
+                            format!("• {}", unit.name()),
+                            Style::default().add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(
+                            format!(" — {}", unit.reason()),
+                            Style::default().fg(Theme::current().muted),
+                        ),
+                    ])
+                }));
+            }
+        }
+
+        let width = area.width.saturating_sub(4).min(90);
+        let height = (text.len() as u16 + 2).min(area.height);
+        let popup_area = Rect::new(
+            area.x + (area.width.saturating_sub(width)) / 2,
+            area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        );
+        let popup = Paragraph::new(text).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Theme::current().popup_border))
+                .title(format!(" {} ", tr_args(title, &[("unit", service.name())])))
+                .title_bottom(Line::from(format!(" {} ", tr(shortcuts))).right_aligned()),
+        );
+
+        frame.render_widget(Clear, popup_area);
+        frame.render_widget(popup, popup_area);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Enter | KeyCode::Char('y') if self.impacted.is_some() => self.confirm(),
+            KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('q') => self.close(),
+            _ => {}
+        }
+    }
+}
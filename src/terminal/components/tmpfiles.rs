@@ -0,0 +1,259 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+use std::sync::mpsc::Sender;
+
+use crate::domain::service::Service;
+use crate::domain::tmpfiles::Tmpfiles;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::theme::Theme;
+use crate::usecases::runtime::{self, Task};
+use crate::usecases::services_manager::ServicesManager;
+
+/// Last run of `systemd-tmpfiles` from the view.
+enum Run {
+    Running { dry_run: bool },
+    Done { dry_run: bool, output: String },
+    Failed(String),
+}
+
+/// Directories of the selected unit and the `tmpfiles.d` lines that create
+/// them, opened with `T` from the list, with `systemd-tmpfiles` at hand to
+/// create the missing ones.
+pub struct UnitTmpfiles {
+    tmpfiles: Option<Tmpfiles>,
+    service: Option<Service>,
+    run: Option<Run>,
+    scroll: u16,
+    fetch: Option<Task>,
+    sender: Sender<AppEvent>,
+}
+
+impl UnitTmpfiles {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            tmpfiles: None,
+            service: None,
+            run: None,
+            scroll: 0,
+            fetch: None,
+            sender,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.tmpfiles = None;
+        self.run = None;
+        self.scroll = 0;
+    }
+
+    fn exit(&self) {
+        self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+    }
+
+    pub fn fetch_and_dispatch(&mut self, service: Service) {
+        self.service = Some(service.clone());
+        let event_tx = self.sender.clone();
+        self.cancel_fetches();
+        self.fetch = Some(runtime::spawn(async move {
+            match ServicesManager::get_tmpfiles(&service).await {
+                Ok(tmpfiles) => event_tx
+                    .send(AppEvent::Action(Actions::UpdateTmpfiles(tmpfiles)))
+                    .expect("Failed to send UpdateTmpfiles event"),
+                Err(e) => event_tx
+                    .send(AppEvent::Error(e.to_string()))
+                    .expect("Failed to send Error event"),
+            }
+        }));
+    }
+
+    pub fn update(&mut self, tmpfiles: Tmpfiles) {
+        self.tmpfiles = Some(tmpfiles);
+    }
+
+    fn run_tmpfiles(&mut self, dry_run: bool) {
+        let Some(tmpfiles) = &self.tmpfiles else {
+            return;
+        };
+        if matches!(self.run, Some(Run::Running { .. })) {
+            return;
+        }
+        self.run = Some(Run::Running { dry_run });
+        let paths = tmpfiles.paths();
+        let event_tx = self.sender.clone();
+        // Not cancelled with the view: the directories get created either way.
+        runtime::spawn(async move {
+            let result = ServicesManager::run_tmpfiles(paths, dry_run)
+                .await
+                .map_err(|e| e.to_string());
+            event_tx
+                .send(AppEvent::Action(Actions::UpdateTmpfilesRun(result)))
+                .expect("Failed to send UpdateTmpfilesRun event");
+        });
+    }
+
+    pub fn on_run_done(&mut self, result: Result<String, String>) {
+        let dry_run = matches!(self.run, Some(Run::Running { dry_run: true }));
+        self.run = Some(match result {
+            Ok(output) => Run::Done { dry_run, output },
+            Err(error) => Run::Failed(error),
+        });
+        // Directories may exist now.
+        if !dry_run && let Some(service) = self.service.clone() {
+            self.fetch_and_dispatch(service);
+        }
+    }
+
+    fn run_lines(&self, heading: Style) -> Vec<Line<'static>> {
+        let Some(run) = &self.run else {
+            return vec![];
+        };
+        let mut lines = vec![Line::default()];
+        match run {
+            Run::Running { dry_run } => lines.push(Line::styled(
+                tr(if *dry_run {
+                    "tmpfiles-dry-running"
+                } else {
+                    "tmpfiles-running"
+                }),
+                heading,
+            )),
+            Run::Done { dry_run, output } => {
+                lines.push(Line::styled(
+                    tr(if *dry_run {
+                        "tmpfiles-dry-run-done"
+                    } else {
+                        "tmpfiles-run-done"
+                    }),
+                    heading,
+                ));
+                if output.trim().is_empty() {
+                    lines.push(Line::from(tr("tmpfiles-run-nothing")));
+                }
+                lines.extend(output.lines().map(|line| Line::from(line.to_string())));
+            }
+            Run::Failed(error) => {
+                lines.push(Line::styled(tr("tmpfiles-run-failed"), heading));
+                lines.extend(error.lines().map(|line| {
+                    Line::styled(
+                        line.to_string(),
+                        Style::default().fg(Theme::current().error),
+                    )
+                }));
+            }
+        }
+        lines
+    }
+}
+
+impl Component for UnitTmpfiles {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let name = self.service.as_ref().map_or("", |service| service.name());
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                " {} ",
+                tr_args("tmpfiles-title", &[("unit", name)])
+            ))
+            .title_alignment(Alignment::Center);
+        let Some(tmpfiles) = &self.tmpfiles else {
+            let loading = Paragraph::new(tr("tmpfiles-reading"))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(loading, area);
+            return;
+        };
+
+        let heading = Style::default()
+            .fg(Theme::current().heading)
+            .add_modifier(Modifier::BOLD);
+        let mut lines = vec![Line::styled(tr("tmpfiles-directories"), heading)];
+        if tmpfiles.directories.is_empty() {
+            lines.push(Line::from(tr("tmpfiles-no-directories")));
+        }
+        for (directory, exists) in &tmpfiles.directories {
+            let (state, color) = if *exists {
+                (tr("tmpfiles-exists"), Theme::current().ok)
+            } else {
+                (tr("tmpfiles-missing"), Theme::current().error)
+            };
+            lines.push(Line::from(vec![
+                Span::raw(format!("{} ", directory)),
+                Span::styled(state, Style::default().fg(color)),
+            ]));
+        }
+
+        lines.push(Line::default());
+        lines.push(Line::styled(tr("tmpfiles-entries"), heading));
+        if tmpfiles.entries.is_empty() {
+            lines.push(Line::from(tr("tmpfiles-no-entries")));
+        }
+        for entry in &tmpfiles.entries {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{} {} ", entry.kind(), entry.path()),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(entry.settings().to_string()),
+                Span::styled(
+                    format!("  {}", entry.source()),
+                    Style::default().fg(Theme::current().muted),
+                ),
+            ]));
+        }
+        lines.extend(self.run_lines(heading));
+
+        let paragraph = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll, 0))
+            .block(block);
+        frame.render_widget(paragraph, area);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(10),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(10),
+            KeyCode::Char('d') => self.run_tmpfiles(true),
+            KeyCode::Char('a') => self.run_tmpfiles(false),
+            KeyCode::Char('r') => {
+                if let Some(service) = self.service.clone() {
+                    self.reset();
+                    self.fetch_and_dispatch(service);
+                }
+            }
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.reset();
+                self.exit();
+            }
+            _ => {}
+        }
+    }
+
+    fn shortcuts(&self) -> Vec<Line<'static>> {
+        vec![
+            Line::from(vec![Span::styled(
+                tr("actions-heading"),
+                Style::default()
+                    .fg(Theme::current().heading)
+                    .add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(tr("tmpfiles-shortcuts")),
+        ]
+    }
+
+    fn cancel_fetches(&mut self) {
+        if let Some(fetch) = self.fetch.take() {
+            fetch.cancel();
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crossterm::event::KeyEvent;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::terminal::components::component::Component;
+use crate::terminal::i18n::tr;
+use crate::terminal::theme::Theme;
+
+/// Toasts shown at once; the others wait their turn.
+const MAX_SHOWN: usize = 3;
+const MAX_WIDTH: u16 = 50;
+const ERROR_DURATION: Duration = Duration::from_secs(8);
+const SUCCESS_DURATION: Duration = Duration::from_secs(4);
+
+#[derive(Clone, Copy, PartialEq)]
+enum ToastKind {
+    Error,
+    Success,
+}
+
+struct Toast {
+    kind: ToastKind,
+    message: String,
+    /// When it was first drawn, so one waiting its turn still gets its time.
+    shown_at: Option<Instant>,
+}
+
+impl Toast {
+    fn duration(&self) -> Duration {
+        match self.kind {
+            ToastKind::Error => ERROR_DURATION,
+            ToastKind::Success => SUCCESS_DURATION,
+        }
+    }
+
+    fn time_left(&self) -> Duration {
+        self.shown_at.map_or(self.duration(), |shown_at| {
+            self.duration().saturating_sub(shown_at.elapsed())
+        })
+    }
+}
+
+/// Errors and successes stacked in the top right corner, each going away on
+/// its own after a few seconds, so they neither wait for a key nor stop the
+/// screen behind from updating.
+#[derive(Default)]
+pub struct Toasts {
+    queue: VecDeque<Toast>,
+}
+
+impl Toasts {
+    pub fn error(&mut self, message: String) {
+        self.push(ToastKind::Error, message);
+    }
+
+    pub fn success(&mut self, message: String) {
+        self.push(ToastKind::Success, message);
+    }
+
+    /// Queues `message`, or shows it for longer if it is already queued, as a
+    /// failing refresh repeats the same error.
+    fn push(&mut self, kind: ToastKind, message: String) {
+        match self
+            .queue
+            .iter_mut()
+            .find(|toast| toast.kind == kind && toast.message == message)
+        {
+            Some(toast) => toast.shown_at = toast.shown_at.map(|_| Instant::now()),
+            None => self.queue.push_back(Toast {
+                kind,
+                message,
+                shown_at: None,
+            }),
+        }
+    }
+
+    /// Time until the first toast shown goes away, for the screen to be drawn
+    /// again then.
+    pub fn time_left(&self) -> Option<Duration> {
+        self.queue
+            .iter()
+            .take(MAX_SHOWN)
+            .map(Toast::time_left)
+            .min()
+    }
+}
+
+impl Component for Toasts {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        self.queue.retain(|toast| !toast.time_left().is_zero());
+
+        let theme = Theme::current();
+        let width = MAX_WIDTH.min(area.width.saturating_sub(2));
+        let mut y = area.y + 1;
+        for toast in self.queue.iter_mut().take(MAX_SHOWN) {
+            toast.shown_at.get_or_insert_with(Instant::now);
+            let lines = wrap(&toast.message, usize::from(width.saturating_sub(2)));
+            #[allow(clippy::cast_possible_truncation)]
+            let height = (lines.len() as u16 + 2).min(area.bottom().saturating_sub(y));
+            if height < 3 {
+                break;
+            }
+            let (title, color) = match toast.kind {
+                ToastKind::Error => (tr("toast-error"), theme.error),
+                ToastKind::Success => (tr("toast-done"), theme.ok),
+            };
+            let toast_area = Rect::new(area.right().saturating_sub(width + 1), y, width, height);
+            let block = Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(color))
+                .title(Span::styled(
+                    format!(" {} ", title),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ));
+
+            frame.render_widget(Clear, toast_area);
+            frame.render_widget(
+                Paragraph::new(lines.into_iter().map(Line::raw).collect::<Vec<_>>()).block(block),
+                toast_area,
+            );
+            y += height;
+        }
+    }
+
+    fn on_key_event(&mut self, _key: KeyEvent) {}
+}
+
+/// `text` cut into lines of at most `width` columns, between words when
+/// possible.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = vec![];
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        if !line.is_empty() && line.chars().count() + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        while word.len() > width {
+            lines.push(word.drain(..width).collect());
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.extend(word);
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
@@ -0,0 +1,224 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+use std::sync::mpsc::Sender;
+
+use crate::domain::service::Service;
+use crate::domain::triage::TriageFacts;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::i18n::{tr, tr_args};
+use crate::terminal::theme::Theme;
+use crate::usecases::runtime::{self, Task};
+use crate::usecases::services_manager::ServicesManager;
+
+/// Likely causes of a unit not starting, most likely first, opened with `w`
+/// from the list.
+pub struct Triage {
+    facts: Option<TriageFacts>,
+    service: Option<Service>,
+    scroll: u16,
+    fetch: Option<Task>,
+    sender: Sender<AppEvent>,
+}
+
+impl Triage {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            facts: None,
+            service: None,
+            scroll: 0,
+            fetch: None,
+            sender,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.facts = None;
+        self.scroll = 0;
+    }
+
+    fn exit(&self) {
+        self.sender.send(AppEvent::Action(Actions::GoList)).unwrap();
+    }
+
+    pub fn fetch_and_dispatch(&mut self, service: Service) {
+        self.service = Some(service.clone());
+        let event_tx = self.sender.clone();
+        self.cancel_fetches();
+        self.fetch = Some(runtime::spawn(async move {
+            match ServicesManager::get_triage_facts(&service).await {
+                Ok(facts) => event_tx
+                    .send(AppEvent::Action(Actions::UpdateTriage(facts)))
+                    .expect("Failed to send UpdateTriage event"),
+                Err(e) => event_tx
+                    .send(AppEvent::Error(e.to_string()))
+                    .expect("Failed to send Error event"),
+            }
+        }));
+    }
+
+    pub fn update(&mut self, facts: TriageFacts) {
+        self.facts = Some(facts);
+    }
+}
+
+impl Component for Triage {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let name = self.service.as_ref().map_or("", |service| service.name());
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", tr_args("triage-title", &[("unit", name)])))
+            .title_alignment(Alignment::Center);
+        let Some(facts) = &self.facts else {
+            let loading = Paragraph::new(tr("triage-gathering"))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(loading, area);
+            return;
+        };
+
+        let heading = Style::default()
+            .fg(Theme::current().heading)
+            .add_modifier(Modifier::BOLD);
+        let findings = facts.diagnose();
+        let mut lines = vec![Line::styled(tr("triage-causes"), heading)];
+        if findings.is_empty() {
+            lines.push(Line::from(tr("triage-no-causes")));
+        }
+        for (rank, finding) in findings.iter().enumerate() {
+            lines.push(Line::from(vec![
+                Span::raw(format!("{}. ", rank + 1)),
+                Span::styled(
+                    finding.cause().to_string(),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!(" — {}", finding.evidence()),
+                    Style::default().fg(Theme::current().muted),
+                ),
+            ]));
+            lines.push(Line::styled(
+                format!("   → {}", finding.suggestion()),
+                Style::default().fg(Theme::current().info),
+            ));
+        }
+
+        if !facts.sysctl_hints.is_empty() {
+            // A file name safe to type, without the escapes of unit names.
+            let stem: String = name
+                .split(['@', '.'])
+                .next()
+                .unwrap_or(name)
+                .replace("\\x2d", "-")
+                .chars()
+                .map(|c| {
+                    if c.is_alphanumeric() || c == '-' {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect();
+            lines.push(Line::default());
+            lines.push(Line::styled(tr("triage-kernel-limits"), heading));
+            for hint in &facts.sysctl_hints {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!(
+                            "{} = {}",
+                            hint.limit().sysctl(),
+                            hint.current().map_or_else(|| tr("unknown"), str::to_string)
+                        ),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!(" — {}", hint.limit().what()),
+                        Style::default().fg(Theme::current().muted),
+                    ),
+                ]));
+                lines.push(Line::styled(
+                    format!(
+                        "   → {}",
+                        tr_args("triage-sysctl-now", &[("command", &hint.sysctl_command())])
+                    ),
+                    Style::default().fg(Theme::current().info),
+                ));
+                lines.push(Line::styled(
+                    format!(
+                        "   → {}",
+                        tr_args(
+                            "triage-sysctl-persist",
+                            &[("line", &hint.sysctl_d_line()), ("stem", &stem)],
+                        )
+                    ),
+                    Style::default().fg(Theme::current().info),
+                ));
+            }
+        }
+
+        if !facts.error_lines.is_empty() {
+            lines.push(Line::default());
+            lines.push(Line::styled(tr("triage-error-lines"), heading));
+            lines.extend(facts.error_lines.iter().map(|entry| {
+                Line::styled(
+                    entry.short_line(),
+                    Style::default().fg(Theme::current().error),
+                )
+            }));
+        }
+
+        let paragraph = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll, 0))
+            .block(block);
+        frame.render_widget(paragraph, area);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Down => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(10),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(10),
+            KeyCode::Char('v') => {
+                self.reset();
+                self.sender.send(AppEvent::Action(Actions::GoLog)).unwrap();
+            }
+            KeyCode::Char('r') => {
+                if let Some(service) = self.service.clone() {
+                    self.reset();
+                    self.fetch_and_dispatch(service);
+                }
+            }
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.reset();
+                self.exit();
+            }
+            _ => {}
+        }
+    }
+
+    fn shortcuts(&self) -> Vec<Line<'static>> {
+        vec![
+            Line::from(vec![Span::styled(
+                tr("actions-heading"),
+                Style::default()
+                    .fg(Theme::current().heading)
+                    .add_modifier(Modifier::BOLD),
+            )]),
+            Line::from(tr("triage-shortcuts")),
+        ]
+    }
+
+    fn cancel_fetches(&mut self) {
+        if let Some(fetch) = self.fetch.take() {
+            fetch.cancel();
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+};
+use std::sync::mpsc::Sender;
+
+use crate::domain::service::Service;
+use crate::terminal::app::{Actions, AppEvent};
+use crate::terminal::components::component::Component;
+use crate::terminal::i18n::tr;
+use crate::terminal::theme::Theme;
+use crate::usecases::plugins::{PluginExtension, Plugins};
+
+/// What choosing an entry of the menu does.
+enum Entry {
+    /// Presses the key in the list, so both always do the same.
+    Key(char),
+    PluginAction(PluginExtension),
+    PluginView(PluginExtension),
+}
+
+/// Menu opened with Enter or a right click on a row of the list, offering
+/// what can be done to the unit, the actions and views of the plugins
+/// included.
+pub struct UnitMenu {
+    service: Option<Service>,
+    items: Vec<(String, Entry)>,
+    selected: usize,
+    sender: Sender<AppEvent>,
+}
+
+impl UnitMenu {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            service: None,
+            items: vec![],
+            selected: 0,
+            sender,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.service.is_some()
+    }
+
+    /// Lists what applies to `service` in its current state.
+    pub fn open(&mut self, service: Service, starred: bool) {
+        let state = service.state();
+        let active = matches!(state.active(), "active" | "activating" | "reloading");
+        let mut items = vec![];
+        if service.is_template() {
+            items.push(("unit-menu-new-instance", 'n'));
+        } else if active {
+            items.push(("unit-menu-stop", 'x'));
+            items.push(("unit-menu-restart", 'r'));
+        } else {
+            items.push(("unit-menu-start", 's'));
+        }
+        if !service.is_template() {
+            items.push(("unit-menu-job-mode", 'S'));
+        }
+        match state.file() {
+            "enabled" => items.push(("unit-menu-disable", 'd')),
+            "disabled" => items.push(("unit-menu-enable", 'e')),
+            _ => {}
+        }
+        items.push(("unit-menu-logs", 'v'));
+        items.push(("unit-menu-properties", 'p'));
+        if !active {
+            items.push(("unit-menu-triage", 'w'));
+        }
+        items.push(("unit-menu-tmpfiles", 'T'));
+        items.push(("unit-menu-mark", '+'));
+        items.push((
+            if starred {
+                "unit-menu-unstar"
+            } else {
+                "unit-menu-star"
+            },
+            '*',
+        ));
+        let mut items: Vec<(String, Entry)> = items
+            .into_iter()
+            .map(|(label, key)| (tr(label), Entry::Key(key)))
+            .collect();
+        if !service.is_template() {
+            items.extend(
+                Plugins::actions()
+                    .into_iter()
+                    .map(|action| (action.title().to_string(), Entry::PluginAction(action))),
+            );
+            items.extend(
+                Plugins::views()
+                    .into_iter()
+                    .map(|view| (view.title().to_string(), Entry::PluginView(view))),
+            );
+        }
+
+        self.service = Some(service);
+        self.items = items;
+        self.selected = 0;
+    }
+
+    fn close(&mut self) {
+        self.service = None;
+    }
+
+    /// Does what the entry at `index` offers.
+    fn choose(&mut self, index: usize) {
+        let (Some(service), Some((_, entry))) = (&self.service, self.items.get(index)) else {
+            return;
+        };
+        let event = match entry {
+            Entry::Key(key) => {
+                let modifiers = if key.is_ascii_uppercase() {
+                    KeyModifiers::SHIFT
+                } else {
+                    KeyModifiers::NONE
+                };
+                AppEvent::Key(KeyEvent::new(KeyCode::Char(*key), modifiers))
+            }
+            Entry::PluginAction(action) => AppEvent::Action(Actions::RunPluginAction(
+                service.name().to_string(),
+                action.clone(),
+            )),
+            Entry::PluginView(view) => AppEvent::Action(Actions::GoPluginView(view.clone())),
+        };
+        self.sender.send(event).unwrap();
+        self.close();
+    }
+}
+
+impl Component for UnitMenu {
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let Some(service) = &self.service else {
+            return;
+        };
+
+        let width = area.width.saturating_sub(4).min(60);
+        let height = (self.items.len() as u16 + 2).min(area.height);
+        let popup_area = Rect::new(
+            area.x + (area.width.saturating_sub(width)) / 2,
+            area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        );
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Theme::current().popup_border))
+            .title(format!(" {} ", service.name()))
+            .title_bottom(Line::from(format!(" {} ", tr("unit-menu-shortcuts"))).right_aligned());
+
+        let items: Vec<ListItem> = self
+            .items
+            .iter()
+            .map(|(label, entry)| {
+                let key = match entry {
+                    Entry::Key(key) => key.to_string(),
+                    Entry::PluginAction(action) => action.key().unwrap_or_default().to_string(),
+                    Entry::PluginView(_) => String::new(),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw(format!("{:<32}", label)),
+                    Span::styled(key, Style::default().fg(Theme::current().muted)),
+                ]))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(
+                Style::default()
+                    .bg(Theme::current().selection_bg)
+                    .fg(Theme::current().selection_fg)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol(">> ");
+        let mut state = ListState::default().with_selected(Some(self.selected));
+
+        frame.render_widget(Clear, popup_area);
+        frame.render_stateful_widget(list, popup_area, &mut state);
+    }
+
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => {
+                self.selected = (self.selected + 1).min(self.items.len().saturating_sub(1))
+            }
+            KeyCode::Enter => self.choose(self.selected),
+            KeyCode::Esc | KeyCode::Char('q') => self.close(),
+            KeyCode::Char(pressed) => {
+                if let Some(index) = self
+                    .items
+                    .iter()
+                    .position(|(_, entry)| matches!(entry, Entry::Key(key) if *key == pressed))
+                {
+                    self.choose(index);
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::config::Config;
+
+const FILE_NAME: &str = "favorites";
+
+/// Units starred with `*` in the list, listed first there. They are kept one
+/// per line in a file of the config directory so they outlive the session.
+pub struct Favorites {
+    units: Vec<String>,
+    path: Option<PathBuf>,
+}
+
+impl Favorites {
+    /// Reads the starred units, none when the file does not exist yet.
+    pub fn load() -> Self {
+        let path = Config::dir().map(|dir| dir.join(FILE_NAME));
+        let units = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|content| {
+                content
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self { units, path }
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.units.iter().any(|unit| unit == name)
+    }
+
+    /// Stars `name`, or unstars it when it already is. Failing to write the
+    /// file only loses the change when the session ends.
+    pub fn toggle(&mut self, name: &str) {
+        if self.contains(name) {
+            self.units.retain(|unit| unit != name);
+        } else {
+            self.units.push(name.to_string());
+        }
+        if let Some(path) = &self.path {
+            let mut content = self.units.join("\n");
+            content.push('\n');
+            let _ = path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(path, content));
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::config::Config;
+
+/// Most texts kept, the oldest being dropped first.
+const MAX_ENTRIES: usize = 100;
+
+/// Texts submitted in an input, oldest first, kept one per line in a file of
+/// the config directory so they outlive the session.
+pub struct History {
+    entries: Vec<String>,
+    path: Option<PathBuf>,
+    /// Entry shown while going through the history with ↑/↓.
+    position: Option<usize>,
+    /// What was typed before going through the history, given back past the
+    /// most recent entry.
+    draft: String,
+}
+
+impl History {
+    /// Reads the history kept in `file_name`, empty when there is none yet.
+    pub fn load(file_name: &str) -> Self {
+        let path = Config::dir().map(|dir| dir.join(file_name));
+        let entries = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|content| content.lines().map(str::to_string).collect())
+            .unwrap_or_default();
+        Self {
+            entries,
+            path,
+            position: None,
+            draft: String::new(),
+        }
+    }
+
+    /// Remembers `text` as the most recent entry. Failing to write the file
+    /// only loses the history of this session.
+    pub fn push(&mut self, text: &str) {
+        self.position = None;
+        let text = text.trim();
+        if text.is_empty() || text.contains('\n') {
+            return;
+        }
+        self.entries.retain(|entry| entry != text);
+        self.entries.push(text.to_string());
+        let excess = self.entries.len().saturating_sub(MAX_ENTRIES);
+        self.entries.drain(..excess);
+        if let Some(path) = &self.path {
+            let _ = path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(path, self.entries.join("\n") + "\n"));
+        }
+    }
+
+    /// The entry before the one shown, `current` being what is typed now.
+    pub fn previous(&mut self, current: &str) -> Option<&str> {
+        let position = match self.position {
+            None => {
+                self.draft = current.to_string();
+                self.entries.len().checked_sub(1)?
+            }
+            Some(position) => position.saturating_sub(1),
+        };
+        self.position = Some(position);
+        self.entries.get(position).map(String::as_str)
+    }
+
+    /// The entry after the one shown, or what was typed past the last one.
+    pub fn next(&mut self) -> Option<&str> {
+        let position = self.position?;
+        if position + 1 < self.entries.len() {
+            self.position = Some(position + 1);
+            self.entries.get(position + 1).map(String::as_str)
+        } else {
+            self.position = None;
+            Some(&self.draft)
+        }
+    }
+
+    /// Stops going through the history, as when the text is edited.
+    pub fn stop_browsing(&mut self) {
+        self.position = None;
+    }
+}
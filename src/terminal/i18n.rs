@@ -0,0 +1,116 @@
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use std::sync::OnceLock;
+use unic_langid::LanguageIdentifier;
+
+use crate::config::Config;
+
+/// Translations shipped with the program, by language tag, English first.
+const LOCALES: [(&str, &str); 2] = [
+    ("en", include_str!("../../locales/en.ftl")),
+    ("pt-BR", include_str!("../../locales/pt-BR.ftl")),
+];
+
+struct Bundles {
+    chosen: FluentBundle<FluentResource>,
+    /// Messages the chosen language has no translation for are taken from it.
+    english: FluentBundle<FluentResource>,
+}
+
+static BUNDLES: OnceLock<Bundles> = OnceLock::new();
+
+fn bundle(tag: &str, source: &str) -> FluentBundle<FluentResource> {
+    let language: LanguageIdentifier = tag.parse().expect("Invalid locale tag");
+    let resource = FluentResource::try_new(source.to_string()).expect("Invalid locale file");
+    let mut bundle = FluentBundle::new_concurrent(vec![language]);
+    // Marks around the values would show in the terminal.
+    bundle.set_use_isolating(false);
+    bundle
+        .add_resource(resource)
+        .expect("Locale file defines a message twice");
+    bundle
+}
+
+/// Locale of `language`, like `pt_BR.UTF-8` or `pt`, among those shipped: the
+/// same language and region, or else the same language.
+fn shipped_locale(language: &str) -> Option<(&'static str, &'static str)> {
+    let tag = language.split(['.', '@']).next()?.replace('_', "-");
+    let wanted: LanguageIdentifier = tag.parse().ok()?;
+    let shipped = |matches: &dyn Fn(&LanguageIdentifier) -> bool| {
+        LOCALES.into_iter().find(|(tag, _)| {
+            tag.parse::<LanguageIdentifier>()
+                .is_ok_and(|locale| matches(&locale))
+        })
+    };
+    shipped(&|locale| locale.language == wanted.language && locale.region == wanted.region)
+        .or_else(|| shipped(&|locale| locale.language == wanted.language))
+}
+
+/// Whether the interface can be shown in `language`.
+pub fn is_shipped(language: &str) -> bool {
+    shipped_locale(language).is_some()
+}
+
+/// Language the interface is shown in: `language` of the config file, or else
+/// the one of `LC_ALL`, `LC_MESSAGES` or `LANG`, English when none is shipped.
+fn chosen_locale() -> (&'static str, &'static str) {
+    let from_env = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty());
+    Config::get()
+        .language
+        .clone()
+        .or(from_env)
+        .and_then(|language| shipped_locale(&language))
+        .unwrap_or(LOCALES[0])
+}
+
+fn bundles() -> &'static Bundles {
+    BUNDLES.get_or_init(|| {
+        let (tag, source) = chosen_locale();
+        Bundles {
+            chosen: bundle(tag, source),
+            english: bundle(LOCALES[0].0, LOCALES[0].1),
+        }
+    })
+}
+
+/// Text of the message `id` in the language of the interface.
+pub fn tr(id: &str) -> String {
+    format(id, None)
+}
+
+/// Text of the message `id` with its `{ $name }` placeholders filled from
+/// `args`.
+pub fn tr_args(id: &str, args: &[(&str, &str)]) -> String {
+    let mut fluent_args = FluentArgs::new();
+    for (name, value) in args {
+        fluent_args.set(*name, *value);
+    }
+    format(id, Some(&fluent_args))
+}
+
+/// Text of the message `id` about `count` things, whose `{ $count -> ... }`
+/// selector picks the plural form of the language.
+pub fn tr_count(id: &str, count: usize) -> String {
+    let mut fluent_args = FluentArgs::new();
+    fluent_args.set("count", count);
+    format(id, Some(&fluent_args))
+}
+
+fn format(id: &str, args: Option<&FluentArgs>) -> String {
+    let bundles = bundles();
+    [&bundles.chosen, &bundles.english]
+        .into_iter()
+        .find_map(|bundle| {
+            let pattern = bundle.get_message(id)?.value()?;
+            let mut errors = vec![];
+            Some(
+                bundle
+                    .format_pattern(pattern, args, &mut errors)
+                    .into_owned(),
+            )
+        })
+        .unwrap_or_else(|| id.to_string())
+}
@@ -0,0 +1,203 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::text::{Line, Span};
+
+use crate::config::{Config, Keymap};
+
+/// Turns vim motions into the keys the list, the log and the properties
+/// already handle, when `keymap = "vim"`: `j`/`k` move by one line,
+/// `Ctrl + d`/`Ctrl + u` by a page, `gg` and `G` go to the top and bottom.
+#[derive(Default)]
+pub struct VimKeys {
+    /// A `g` was pressed, and a second one goes to the top.
+    pending_g: bool,
+}
+
+impl VimKeys {
+    /// The key to handle in place of `key`, or none while waiting for the
+    /// second `g` of `gg`. Keys are given back as they are with the default
+    /// keymap.
+    pub fn translate(&mut self, key: KeyEvent) -> Option<KeyEvent> {
+        if Config::get().keymap != Keymap::Vim {
+            return Some(key);
+        }
+        let pending_g = std::mem::take(&mut self.pending_g);
+        let code = match (key.code, key.modifiers) {
+            (KeyCode::Char('g'), KeyModifiers::NONE) if pending_g => KeyCode::Home,
+            (KeyCode::Char('g'), KeyModifiers::NONE) => {
+                self.pending_g = true;
+                return None;
+            }
+            (KeyCode::Char('G'), _) => KeyCode::End,
+            (KeyCode::Char('j'), KeyModifiers::NONE) => KeyCode::Down,
+            (KeyCode::Char('k'), KeyModifiers::NONE) => KeyCode::Up,
+            (KeyCode::Char('d'), KeyModifiers::CONTROL) => KeyCode::PageDown,
+            (KeyCode::Char('u'), KeyModifiers::CONTROL) => KeyCode::PageUp,
+            _ => return Some(key),
+        };
+        Some(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+}
+
+/// Key pressed in place of `key`, as set in the `[keys]` table of the config
+/// file, or `key` itself. Default keys given another one no longer act, so
+/// the two can also be swapped.
+pub fn remap(key: KeyEvent) -> Option<KeyEvent> {
+    let bindings = bindings();
+    if let Some((default, _)) = bindings.iter().find(|(_, bound)| same_key(*bound, key)) {
+        return Some(*default);
+    }
+    if bindings.iter().any(|(default, _)| same_key(*default, key)) {
+        return None;
+    }
+    Some(key)
+}
+
+/// `line` of the shortcuts box with the keys of its hints replaced by those
+/// bound to them in the config file.
+pub fn bound_keys(line: Line<'static>) -> Line<'static> {
+    let bindings = bindings();
+    if bindings.is_empty() {
+        return line;
+    }
+    let spans = line
+        .spans
+        .into_iter()
+        .map(|span| {
+            let content = span
+                .content
+                .split('|')
+                .map(|hint| rename_keys(hint, &bindings))
+                .collect::<Vec<_>>()
+                .join("|");
+            Span::styled(content, span.style)
+        })
+        .collect::<Vec<_>>();
+    Line::from(spans).style(line.style)
+}
+
+/// `hint`, read as `Label: key`, with its keys renamed. Hints list several
+/// keys as `y or Enter` or `↑/↓`.
+fn rename_keys(hint: &str, bindings: &[(KeyEvent, KeyEvent)]) -> String {
+    let Some((label, keys)) = hint.rsplit_once(": ") else {
+        return hint.to_string();
+    };
+    let trailing = &keys[keys.trim_end().len()..];
+    let keys = keys
+        .trim_end()
+        .split(" or ")
+        .map(|key| match parse_key(key) {
+            Some(_) => rename_key(key, bindings),
+            None => key
+                .split('/')
+                .map(|key| rename_key(key, bindings))
+                .collect::<Vec<_>>()
+                .join("/"),
+        })
+        .collect::<Vec<_>>()
+        .join(" or ");
+    format!("{}: {}{}", label, keys, trailing)
+}
+
+fn rename_key(key: &str, bindings: &[(KeyEvent, KeyEvent)]) -> String {
+    parse_key(key)
+        .and_then(|key| bindings.iter().find(|(default, _)| same_key(*default, key)))
+        .map_or(key.to_string(), |(_, bound)| key_name(*bound))
+}
+
+/// The `[keys]` table of the config file as `(default key, bound key)`,
+/// leaving out what cannot be read.
+fn bindings() -> Vec<(KeyEvent, KeyEvent)> {
+    Config::get()
+        .keys
+        .iter()
+        .filter_map(|(default, bound)| Some((parse_key(default)?, parse_key(bound)?)))
+        .collect()
+}
+
+/// Whether `key` is the one `written` as the shortcuts show it.
+pub fn is_key(key: KeyEvent, written: &str) -> bool {
+    parse_key(written).is_some_and(|bound| same_key(bound, key))
+}
+
+/// Whether `a` and `b` are the same key, Shift aside for characters as the
+/// case already tells it.
+fn same_key(a: KeyEvent, b: KeyEvent) -> bool {
+    let modifiers = |key: KeyEvent| match key.code {
+        KeyCode::Char(_) => key.modifiers - KeyModifiers::SHIFT,
+        _ => key.modifiers,
+    };
+    let code = |key: KeyEvent| match (key.code, key.modifiers.contains(KeyModifiers::CONTROL)) {
+        (KeyCode::Char(c), true) => KeyCode::Char(c.to_ascii_lowercase()),
+        (code, _) => code,
+    };
+    code(a) == code(b) && modifiers(a) == modifiers(b)
+}
+
+/// Reads a key written as the shortcuts show it: `x`, `X`, `Ctrl + d`,
+/// `Enter`, `Esc`, `Tab`, `space`, `↑` (or `Up`), `Page Down`, `Home`, `F5`...
+pub fn parse_key(key: &str) -> Option<KeyEvent> {
+    if let Some(letter) = key.strip_prefix("Ctrl + ") {
+        let mut chars = letter.chars();
+        let letter = chars.next().filter(|_| chars.next().is_none())?;
+        return Some(KeyEvent::new(
+            KeyCode::Char(letter.to_ascii_lowercase()),
+            KeyModifiers::CONTROL,
+        ));
+    }
+    let code = match key {
+        "Enter" => KeyCode::Enter,
+        "Esc" => KeyCode::Esc,
+        "Tab" => KeyCode::Tab,
+        "Backspace" => KeyCode::Backspace,
+        "Delete" => KeyCode::Delete,
+        "space" => KeyCode::Char(' '),
+        "↑" | "Up" => KeyCode::Up,
+        "↓" | "Down" => KeyCode::Down,
+        "←" | "Left" => KeyCode::Left,
+        "→" | "Right" => KeyCode::Right,
+        "Page Up" => KeyCode::PageUp,
+        "Page Down" => KeyCode::PageDown,
+        "Home" => KeyCode::Home,
+        "End" => KeyCode::End,
+        _ => match key.strip_prefix('F').and_then(|number| number.parse().ok()) {
+            Some(number @ 1..=12) => KeyCode::F(number),
+            _ => {
+                let mut chars = key.chars();
+                let key = chars.next().filter(|_| chars.next().is_none())?;
+                KeyCode::Char(key)
+            }
+        },
+    };
+    let modifiers = match code {
+        KeyCode::Char(key) if key.is_ascii_uppercase() => KeyModifiers::SHIFT,
+        _ => KeyModifiers::NONE,
+    };
+    Some(KeyEvent::new(code, modifiers))
+}
+
+/// `key` written as the shortcuts show it, the other way round from
+/// [`parse_key`].
+fn key_name(key: KeyEvent) -> String {
+    match key.code {
+        KeyCode::Char(letter) if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            format!("Ctrl + {}", letter)
+        }
+        KeyCode::Char(' ') => "space".to_string(),
+        KeyCode::Char(key) => key.to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::Delete => "Delete".to_string(),
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::PageUp => "Page Up".to_string(),
+        KeyCode::PageDown => "Page Down".to_string(),
+        KeyCode::Home => "Home".to_string(),
+        KeyCode::End => "End".to_string(),
+        KeyCode::F(number) => format!("F{}", number),
+        code => format!("{:?}", code),
+    }
+}
@@ -0,0 +1,41 @@
+use regex::{Regex, RegexBuilder};
+use std::borrow::Cow;
+
+use crate::config::Config;
+
+const MASK: &str = "••••••";
+
+/// Hides the value of `NAME=value` assignments whose name contains one of the
+/// configured `sensitive_patterns`, such as `DATABASE_PASSWORD=hunter2`, so they
+/// do not leak during a screen share or in a pasted log.
+pub struct SecretMask(Option<Regex>);
+
+impl SecretMask {
+    pub fn from_config() -> Self {
+        let config = Config::get();
+        let patterns: Vec<String> = config
+            .sensitive_patterns
+            .iter()
+            .filter(|pattern| !pattern.is_empty())
+            .map(|pattern| regex::escape(pattern))
+            .collect();
+        if patterns.is_empty() {
+            return Self(None);
+        }
+        let regex = RegexBuilder::new(&format!(
+            r#"\b([\w.-]*(?:{})[\w.-]*)=("[^"]*"|\S+)"#,
+            patterns.join("|")
+        ))
+        .case_insensitive(true)
+        .build()
+        .ok();
+        Self(regex)
+    }
+
+    pub fn apply<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        match &self.0 {
+            Some(regex) => regex.replace_all(text, format!("${{1}}={}", MASK)),
+            None => Cow::Borrowed(text),
+        }
+    }
+}
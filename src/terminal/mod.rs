@@ -1,2 +1,17 @@
+pub mod action_log;
 pub mod app;
+pub mod bundle;
+pub mod clipboard;
+pub mod color;
 pub mod components;
+pub mod favorites;
+pub mod history;
+pub mod i18n;
+pub mod keymap;
+pub mod mask;
+pub mod mouse;
+pub mod reducer;
+pub mod store;
+pub mod summary;
+pub mod theme;
+pub mod truncate;
@@ -0,0 +1,60 @@
+use crossterm::event::{KeyEvent, MouseEvent};
+use ratatui::layout::{Position, Rect};
+use std::time::{Duration, Instant};
+
+use super::keymap::parse_key;
+
+/// Longest time between the two clicks of a double click.
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+
+/// Rows scrolled by one notch of the wheel.
+pub const WHEEL_ROWS: usize = 3;
+
+pub fn is_within(event: &MouseEvent, area: Rect) -> bool {
+    area.contains(Position::new(event.column, event.row))
+}
+
+/// Tells a double click on a row from two separate clicks.
+#[derive(Default)]
+pub struct DoubleClick {
+    last: Option<(Instant, u16)>,
+}
+
+impl DoubleClick {
+    /// Whether `event`, a click, follows one on the same row closely enough
+    /// to make a double click with it.
+    pub fn is_double(&mut self, event: &MouseEvent) -> bool {
+        let double = self
+            .last
+            .is_some_and(|(at, row)| at.elapsed() <= DOUBLE_CLICK_INTERVAL && row == event.row);
+        // A third click starts over rather than making another double click.
+        self.last = if double {
+            None
+        } else {
+            Some((Instant::now(), event.row))
+        };
+        double
+    }
+}
+
+/// Key of the shortcut hint at `column` of a line of the shortcuts box, given
+/// as the symbol of each of its cells. Hints read `Label: key` and are
+/// separated by `|`; the first key is taken when a hint lists several, as in
+/// `y or Enter` or `g/G`.
+pub fn shortcut_at(cells: &[String], column: usize) -> Option<KeyEvent> {
+    if cells.get(column)?.as_str() == "|" {
+        return None;
+    }
+    let start = cells[..column]
+        .iter()
+        .rposition(|cell| cell == "|")
+        .map_or(0, |separator| separator + 1);
+    let end = cells[column..]
+        .iter()
+        .position(|cell| cell == "|")
+        .map_or(cells.len(), |separator| column + separator);
+    let hint = cells[start..end].concat();
+    let (_, keys) = hint.rsplit_once(": ")?;
+    let key = keys.trim().split(" or ").next()?;
+    parse_key(key).or_else(|| parse_key(key.split('/').next()?))
+}
@@ -0,0 +1,891 @@
+use chrono::{DateTime, Local};
+
+use super::app::Actions;
+use super::components::list::ServiceAction;
+use super::components::log::ExportScope;
+use super::components::prompt::PromptKind;
+use super::store::{DataKey, Fetched};
+use crate::config::Column;
+use crate::domain::access_control::AccessControl;
+use crate::domain::boot_time::{BootTime, ChainLink};
+use crate::domain::early_boot::EarlyBoot;
+use crate::domain::job_conflict::{JobConflict, QueuedJob};
+use crate::domain::job_result::JobResult;
+use crate::domain::journal_search::UnitHits;
+use crate::domain::log_entry::LogEntry;
+use crate::domain::overview::Overview;
+use crate::domain::security_report::SecurityReport;
+use crate::domain::service::Service;
+use crate::domain::stop_impact::ImpactedUnit;
+use crate::domain::tmpfiles::Tmpfiles;
+use crate::domain::triage::TriageFacts;
+use crate::usecases::plugins::PluginExtension;
+
+/// Screen currently shown by the app.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    List,
+    Log,
+    Details,
+    Security,
+    EarlyBoot,
+    /// Units by the time they took to start during the current boot, and the
+    /// critical chain of the default target.
+    BootTimes,
+    /// State, uptime and load of the system, and its recently failed units.
+    Dashboard,
+    Triage,
+    JournalSearch,
+    Tmpfiles,
+    /// What a plugin prints about a unit.
+    PluginView,
+    /// The properties and the log of the same unit, side by side or stacked.
+    Split,
+}
+
+impl Status {
+    fn shows_log(self) -> bool {
+        matches!(self, Status::Log | Status::Split)
+    }
+
+    fn shows_details(self) -> bool {
+        matches!(self, Status::Details | Status::Split)
+    }
+}
+
+/// Side effect the app has to perform on its components after an action.
+pub enum Effect {
+    FetchLog,
+    FetchKernelLog,
+    /// Fetches again whatever the log view shows.
+    RefetchLog,
+    FollowLog,
+    Store(Fetched),
+    ReloadList,
+    LogStored(String),
+    LoadDetails,
+    FetchDetails,
+    /// Fetches the properties shown again if the unit is among those changed.
+    RefetchDetails(Vec<String>),
+    UnitsChanged,
+    UpdateSystemState(Result<String, String>),
+    FetchSecurity,
+    UpdateSecurity(Result<SecurityReport, String>),
+    UpdateAccessControl(AccessControl),
+    FetchEarlyBoot,
+    UpdateEarlyBoot(EarlyBoot),
+    FetchBootTimes,
+    UpdateBootTimes(Vec<BootTime>),
+    UpdateCriticalChain(Vec<ChainLink>),
+    FetchOverview,
+    UpdateOverview(Overview),
+    /// Opens the properties of the unit, if the list shows it.
+    OpenDetailsOf(String),
+    FetchTriage,
+    UpdateTriage(TriageFacts),
+    FetchTmpfiles,
+    UpdateTmpfiles(Tmpfiles),
+    UpdateTmpfilesRun(Result<String, String>),
+    FetchPluginView(PluginExtension),
+    UpdatePluginView(Result<String, String>),
+    SearchJournal(String),
+    UpdateJournalSearch(Vec<UnitHits>),
+    /// Opens the log of the unit at the matches of the journal search.
+    FetchSearchHit(String),
+    FilterList(String),
+    SetIgnoreListKeys(bool),
+    OpenPrompt(PromptKind),
+    InstantiateTemplate(String, String),
+    SetLogTimeRange(String),
+    ExportLog(ExportScope, String),
+    ForwardLog(String),
+    ForwardLogEntries(String, Vec<LogEntry>),
+    WriteSupportBundle(String),
+    ShowSupportBundle(Option<String>),
+    RememberVisit,
+    OpenRecentUnits,
+    OpenColumnChooser,
+    ToggleColumn(Column),
+    SelectUnit(String),
+    OpenStopImpact(ServiceAction),
+    UpdateStopImpact(String, Result<Vec<ImpactedUnit>, String>),
+    RunServiceAction(String, ServiceAction),
+    RunPluginAction(String, PluginExtension),
+    OpenJobModeMenu,
+    OpenUnitMenu,
+    OpenJobConflict(String, ServiceAction, JobConflict),
+    UpdateJobConflict(String, Result<Vec<QueuedJob>, String>),
+    ReloadConfig,
+    RefreshUnits,
+    AnimateJobs,
+    FinishJob(String, String, Result<JobResult, String>),
+    FinishUnitAction(String, ServiceAction, Result<Option<String>, String>),
+    FinishPluginAction(String, PluginExtension, Result<String, String>),
+    FinishInstantiation(String, String, Result<(), String>),
+    OpenJobFailure(Box<Service>, String, JobResult, DateTime<Local>),
+    UpdateJobFailure(String, Result<Vec<LogEntry>, String>),
+}
+
+/// Computes the next screen and the effects to run for `action`.
+///
+/// This is the only place where actions are interpreted; it is a pure function
+/// of the current screen so every transition can be reasoned about in isolation.
+pub fn reduce(status: Status, action: Actions) -> (Status, Vec<Effect>) {
+    match action {
+        Actions::GoList => (Status::List, vec![]),
+        Actions::GoLog => (
+            Status::Log,
+            vec![Effect::RememberVisit, Effect::FetchLog, Effect::FollowLog],
+        ),
+        Actions::GoKernelLog => (Status::Log, vec![Effect::FetchKernelLog, Effect::FollowLog]),
+        Actions::GoDetails => (
+            Status::Details,
+            vec![
+                Effect::RememberVisit,
+                Effect::LoadDetails,
+                Effect::FetchDetails,
+            ],
+        ),
+        Actions::GoSplit => (
+            Status::Split,
+            vec![
+                Effect::RememberVisit,
+                Effect::LoadDetails,
+                Effect::FetchDetails,
+                Effect::FetchLog,
+                Effect::FollowLog,
+            ],
+        ),
+        Actions::GoSecurity => (Status::Security, vec![Effect::FetchSecurity]),
+        Actions::GoEarlyBoot => (Status::EarlyBoot, vec![Effect::FetchEarlyBoot]),
+        Actions::GoBootTimes => (Status::BootTimes, vec![Effect::FetchBootTimes]),
+        Actions::GoDashboard => (Status::Dashboard, vec![Effect::FetchOverview]),
+        Actions::GoTriage => (
+            Status::Triage,
+            vec![Effect::RememberVisit, Effect::FetchTriage],
+        ),
+        Actions::UpdateTriage(facts) => (status, vec![Effect::UpdateTriage(facts)]),
+        Actions::GoTmpfiles => (Status::Tmpfiles, vec![Effect::FetchTmpfiles]),
+        Actions::UpdateTmpfiles(tmpfiles) => (status, vec![Effect::UpdateTmpfiles(tmpfiles)]),
+        Actions::UpdateTmpfilesRun(result) => (status, vec![Effect::UpdateTmpfilesRun(result)]),
+        Actions::GoPluginView(view) => (
+            Status::PluginView,
+            vec![Effect::RememberVisit, Effect::FetchPluginView(view)],
+        ),
+        Actions::UpdatePluginView(text) => (status, vec![Effect::UpdatePluginView(text)]),
+        Actions::UpdateJournalSearch(hits) => (status, vec![Effect::UpdateJournalSearch(hits)]),
+        Actions::OpenSearchHit(name) => (
+            Status::Log,
+            vec![Effect::FetchSearchHit(name), Effect::FollowLog],
+        ),
+        Actions::RefreshLog if status.shows_log() => (status, vec![Effect::RefetchLog]),
+        Actions::RefreshLog => (status, vec![]),
+        Actions::UnitsChanged(names) if status.shows_details() => (
+            status,
+            vec![Effect::UnitsChanged, Effect::RefetchDetails(names)],
+        ),
+        Actions::UnitsChanged(_) => (status, vec![Effect::UnitsChanged]),
+        Actions::UpdateSystemState(state) => (status, vec![Effect::UpdateSystemState(state)]),
+        Actions::Fetched(Fetched::NewLogEntries(name, entries)) => (
+            status,
+            vec![
+                Effect::ForwardLogEntries(name.clone(), entries.clone()),
+                Effect::Store(Fetched::NewLogEntries(name, entries)),
+            ],
+        ),
+        Actions::Fetched(fetched) => (status, vec![Effect::Store(fetched)]),
+        Actions::Invalidated(
+            DataKey::Units
+            | DataKey::Resources
+            | DataKey::Activators
+            | DataKey::Slices
+            | DataKey::PluginColumns,
+        ) => (status, vec![Effect::ReloadList]),
+        Actions::Invalidated(DataKey::Log(service_name)) => {
+            (status, vec![Effect::LogStored(service_name)])
+        }
+        // Properties and boots are read from the store on every draw.
+        Actions::Invalidated(DataKey::Properties(_) | DataKey::Boots) => (status, vec![]),
+        Actions::UpdateSecurity(report) => (status, vec![Effect::UpdateSecurity(report)]),
+        Actions::UpdateAccessControl(access_control) => {
+            (status, vec![Effect::UpdateAccessControl(access_control)])
+        }
+        Actions::UpdateEarlyBoot(early_boot) => (status, vec![Effect::UpdateEarlyBoot(early_boot)]),
+        Actions::UpdateBootTimes(times) => (status, vec![Effect::UpdateBootTimes(times)]),
+        Actions::UpdateCriticalChain(chain) => (status, vec![Effect::UpdateCriticalChain(chain)]),
+        Actions::UpdateOverview(overview) => (status, vec![Effect::UpdateOverview(overview)]),
+        // Selected in the list first, which the properties are shown from.
+        Actions::OpenDetailsOf(name) => (Status::List, vec![Effect::OpenDetailsOf(name)]),
+        Actions::Filter(input) => (status, vec![Effect::FilterList(input)]),
+        Actions::UpdateIgnoreListKeys(ignore) => (status, vec![Effect::SetIgnoreListKeys(ignore)]),
+        Actions::OpenPrompt(kind) => (status, vec![Effect::OpenPrompt(kind)]),
+        Actions::OpenRecentUnits => (status, vec![Effect::OpenRecentUnits]),
+        Actions::OpenColumnChooser => (status, vec![Effect::OpenColumnChooser]),
+        Actions::ToggleColumn(column) => (status, vec![Effect::ToggleColumn(column)]),
+        Actions::SelectUnit(name) if status == Status::List => {
+            (status, vec![Effect::SelectUnit(name)])
+        }
+        Actions::SelectUnit(_) => (status, vec![]),
+        Actions::OpenStopImpact(action) => (status, vec![Effect::OpenStopImpact(action)]),
+        Actions::UpdateStopImpact(name, impacted) => {
+            (status, vec![Effect::UpdateStopImpact(name, impacted)])
+        }
+        Actions::RunServiceAction(name, action) => {
+            (status, vec![Effect::RunServiceAction(name, action)])
+        }
+        Actions::RunPluginAction(name, action) => {
+            (status, vec![Effect::RunPluginAction(name, action)])
+        }
+        Actions::PluginActionDone(name, action, result) => (
+            status,
+            vec![Effect::FinishPluginAction(name, action, result)],
+        ),
+        Actions::OpenJobModeMenu => (status, vec![Effect::OpenJobModeMenu]),
+        Actions::OpenUnitMenu => (status, vec![Effect::OpenUnitMenu]),
+        Actions::OpenJobConflict(name, action, conflict) => (
+            status,
+            vec![Effect::OpenJobConflict(name, action, conflict)],
+        ),
+        Actions::UpdateJobConflict(name, jobs) => {
+            (status, vec![Effect::UpdateJobConflict(name, jobs)])
+        }
+        Actions::ReloadConfig => (status, vec![Effect::ReloadConfig]),
+        Actions::RefreshUnits => (status, vec![Effect::RefreshUnits]),
+        Actions::SupportBundleWritten(path) => (status, vec![Effect::ShowSupportBundle(path)]),
+        Actions::UnitActionDone(name, action, result) => {
+            (status, vec![Effect::FinishUnitAction(name, action, result)])
+        }
+        Actions::TemplateInstantiated(template, instance, result) => (
+            status,
+            vec![Effect::FinishInstantiation(template, instance, result)],
+        ),
+        // Spinners are only seen on the list.
+        Actions::AnimateJobs if status == Status::List => (status, vec![Effect::AnimateJobs]),
+        Actions::AnimateJobs => (status, vec![]),
+        Actions::JobFinished(name, job, result) => {
+            (status, vec![Effect::FinishJob(name, job, result)])
+        }
+        Actions::OpenJobFailure(service, label, result, since) => (
+            status,
+            vec![Effect::OpenJobFailure(service, label, result, since)],
+        ),
+        Actions::UpdateJobFailure(name, entries) => {
+            (status, vec![Effect::UpdateJobFailure(name, entries)])
+        }
+        Actions::SubmitPrompt(kind, input) => match kind {
+            PromptKind::InstantiateTemplate(template) => {
+                (status, vec![Effect::InstantiateTemplate(template, input)])
+            }
+            PromptKind::LogTimeRange if status.shows_log() => (
+                status,
+                vec![Effect::SetLogTimeRange(input), Effect::RefetchLog],
+            ),
+            PromptKind::ExportLog(scope) if status.shows_log() => {
+                (status, vec![Effect::ExportLog(scope, input)])
+            }
+            PromptKind::ForwardLog if status.shows_log() => {
+                (status, vec![Effect::ForwardLog(input)])
+            }
+            PromptKind::LogTimeRange | PromptKind::ExportLog(_) | PromptKind::ForwardLog => {
+                (status, vec![])
+            }
+            PromptKind::SupportBundle => (status, vec![Effect::WriteSupportBundle(input)]),
+            PromptKind::JournalSearch => {
+                (Status::JournalSearch, vec![Effect::SearchJournal(input)])
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::job_mode::JobMode;
+    use crate::domain::log_entry::LogEntry;
+    use crate::domain::plugin::Extension;
+    use crate::domain::service_state::ServiceState;
+
+    /// Name of the effect, as `Effect` holds values that cannot be compared.
+    fn name(effect: &Effect) -> &'static str {
+        match effect {
+            Effect::FetchLog => "FetchLog",
+            Effect::FetchKernelLog => "FetchKernelLog",
+            Effect::RefetchLog => "RefetchLog",
+            Effect::FollowLog => "FollowLog",
+            Effect::Store(_) => "Store",
+            Effect::ReloadList => "ReloadList",
+            Effect::LogStored(_) => "LogStored",
+            Effect::LoadDetails => "LoadDetails",
+            Effect::FetchDetails => "FetchDetails",
+            Effect::RefetchDetails(_) => "RefetchDetails",
+            Effect::UnitsChanged => "UnitsChanged",
+            Effect::FetchSecurity => "FetchSecurity",
+            Effect::UpdateSecurity(_) => "UpdateSecurity",
+            Effect::FilterList(_) => "FilterList",
+            Effect::SetIgnoreListKeys(_) => "SetIgnoreListKeys",
+            Effect::OpenPrompt(_) => "OpenPrompt",
+            Effect::InstantiateTemplate(..) => "InstantiateTemplate",
+            Effect::SetLogTimeRange(_) => "SetLogTimeRange",
+            Effect::ExportLog(..) => "ExportLog",
+            Effect::RememberVisit => "RememberVisit",
+            Effect::OpenRecentUnits => "OpenRecentUnits",
+            Effect::SelectUnit(_) => "SelectUnit",
+            Effect::ReloadConfig => "ReloadConfig",
+            Effect::FetchEarlyBoot => "FetchEarlyBoot",
+            Effect::UpdateEarlyBoot(_) => "UpdateEarlyBoot",
+            Effect::OpenColumnChooser => "OpenColumnChooser",
+            Effect::ToggleColumn(_) => "ToggleColumn",
+            Effect::UpdateAccessControl(_) => "UpdateAccessControl",
+            Effect::FetchTriage => "FetchTriage",
+            Effect::UpdateTriage(_) => "UpdateTriage",
+            Effect::WriteSupportBundle(_) => "WriteSupportBundle",
+            Effect::ShowSupportBundle(_) => "ShowSupportBundle",
+            Effect::SearchJournal(_) => "SearchJournal",
+            Effect::UpdateJournalSearch(_) => "UpdateJournalSearch",
+            Effect::FetchSearchHit(_) => "FetchSearchHit",
+            Effect::FetchTmpfiles => "FetchTmpfiles",
+            Effect::UpdateTmpfiles(_) => "UpdateTmpfiles",
+            Effect::UpdateTmpfilesRun(_) => "UpdateTmpfilesRun",
+            Effect::OpenStopImpact(_) => "OpenStopImpact",
+            Effect::UpdateStopImpact(..) => "UpdateStopImpact",
+            Effect::RunServiceAction(..) => "RunServiceAction",
+            Effect::OpenJobModeMenu => "OpenJobModeMenu",
+            Effect::OpenJobConflict(..) => "OpenJobConflict",
+            Effect::UpdateJobConflict(..) => "UpdateJobConflict",
+            Effect::RefreshUnits => "RefreshUnits",
+            Effect::OpenUnitMenu => "OpenUnitMenu",
+            Effect::ForwardLog(_) => "ForwardLog",
+            Effect::ForwardLogEntries(..) => "ForwardLogEntries",
+            Effect::AnimateJobs => "AnimateJobs",
+            Effect::FinishJob(..) => "FinishJob",
+            Effect::OpenJobFailure(..) => "OpenJobFailure",
+            Effect::UpdateJobFailure(..) => "UpdateJobFailure",
+            Effect::FinishUnitAction(..) => "FinishUnitAction",
+            Effect::FinishInstantiation(..) => "FinishInstantiation",
+            Effect::UpdateSystemState(_) => "UpdateSystemState",
+            Effect::FetchPluginView(_) => "FetchPluginView",
+            Effect::UpdatePluginView(_) => "UpdatePluginView",
+            Effect::RunPluginAction(..) => "RunPluginAction",
+            Effect::FinishPluginAction(..) => "FinishPluginAction",
+            Effect::FetchBootTimes => "FetchBootTimes",
+            Effect::UpdateBootTimes(_) => "UpdateBootTimes",
+            Effect::OpenDetailsOf(_) => "OpenDetailsOf",
+            Effect::UpdateCriticalChain(_) => "UpdateCriticalChain",
+            Effect::FetchOverview => "FetchOverview",
+            Effect::UpdateOverview(_) => "UpdateOverview",
+        }
+    }
+
+    /// Screen and names of the effects `action` leads to from `status`.
+    fn reduced(status: Status, action: Actions) -> (Status, Vec<&'static str>) {
+        let (status, effects) = reduce(status, action);
+        (status, effects.iter().map(name).collect())
+    }
+
+    /// Asserts that `action` keeps the screen and only runs `effects`, from
+    /// every screen.
+    fn assert_kept(action: impl Fn() -> Actions, effects: &[&str]) {
+        for status in ALL {
+            assert_eq!(reduced(status, action()), (status, effects.to_vec()));
+        }
+    }
+
+    const ALL: [Status; 12] = [
+        Status::List,
+        Status::Log,
+        Status::Details,
+        Status::Security,
+        Status::EarlyBoot,
+        Status::Triage,
+        Status::JournalSearch,
+        Status::Tmpfiles,
+        Status::PluginView,
+        Status::BootTimes,
+        Status::Dashboard,
+        Status::Split,
+    ];
+
+    fn service() -> Service {
+        Service::new(
+            "nginx.service".to_string(),
+            "A web server".to_string(),
+            ServiceState::new(
+                "loaded".to_string(),
+                "failed".to_string(),
+                "failed".to_string(),
+                "enabled".to_string(),
+            ),
+        )
+    }
+
+    fn extension() -> PluginExtension {
+        PluginExtension::detached(Box::leak(Box::new(Extension::new(
+            "certs".to_string(),
+            "Certificates".to_string(),
+        ))))
+    }
+
+    fn conflict() -> JobConflict {
+        JobConflict::from_error("Transaction for nginx.service/start is destructive").unwrap()
+    }
+
+    #[test]
+    fn screens_are_opened_with_what_they_show() {
+        let cases: [(Actions, Status, &[&str]); 11] = [
+            (Actions::GoList, Status::List, &[]),
+            (
+                Actions::GoLog,
+                Status::Log,
+                &["RememberVisit", "FetchLog", "FollowLog"],
+            ),
+            (
+                Actions::GoKernelLog,
+                Status::Log,
+                &["FetchKernelLog", "FollowLog"],
+            ),
+            (
+                Actions::GoDetails,
+                Status::Details,
+                &["RememberVisit", "LoadDetails", "FetchDetails"],
+            ),
+            (Actions::GoSecurity, Status::Security, &["FetchSecurity"]),
+            (Actions::GoEarlyBoot, Status::EarlyBoot, &["FetchEarlyBoot"]),
+            (
+                Actions::GoTriage,
+                Status::Triage,
+                &["RememberVisit", "FetchTriage"],
+            ),
+            (Actions::GoTmpfiles, Status::Tmpfiles, &["FetchTmpfiles"]),
+            (Actions::GoBootTimes, Status::BootTimes, &["FetchBootTimes"]),
+            (Actions::GoDashboard, Status::Dashboard, &["FetchOverview"]),
+            (
+                Actions::GoSplit,
+                Status::Split,
+                &[
+                    "RememberVisit",
+                    "LoadDetails",
+                    "FetchDetails",
+                    "FetchLog",
+                    "FollowLog",
+                ],
+            ),
+        ];
+        for (action, screen, effects) in cases {
+            assert_eq!(reduced(Status::List, action), (screen, effects.to_vec()));
+        }
+    }
+
+    #[test]
+    fn changed_units_refetch_the_properties_shown() {
+        for status in ALL {
+            let (next, effects) = reduce(
+                status,
+                Actions::UnitsChanged(vec!["nginx.service".to_string()]),
+            );
+            assert_eq!(next, status);
+            if matches!(status, Status::Details | Status::Split) {
+                assert!(matches!(
+                    &effects[..],
+                    [Effect::UnitsChanged, Effect::RefetchDetails(names)] if names == &["nginx.service"]
+                ));
+            } else {
+                assert!(matches!(&effects[..], [Effect::UnitsChanged]));
+            }
+        }
+    }
+
+    #[test]
+    fn log_is_refreshed_only_where_shown() {
+        for status in ALL {
+            let effects: &[&str] = if matches!(status, Status::Log | Status::Split) {
+                &["RefetchLog"]
+            } else {
+                &[]
+            };
+            assert_eq!(
+                reduced(status, Actions::RefreshLog),
+                (status, effects.to_vec())
+            );
+        }
+    }
+
+    #[test]
+    fn fetched_views_are_updated_on_the_screen_shown() {
+        assert_kept(
+            || Actions::UpdateSecurity(Ok(SecurityReport::new(vec![], 9.6, "UNSAFE".to_string()))),
+            &["UpdateSecurity"],
+        );
+        assert_kept(
+            || Actions::UpdateSecurity(Err("timed out".to_string())),
+            &["UpdateSecurity"],
+        );
+        assert_kept(
+            || Actions::UpdateEarlyBoot(EarlyBoot::default()),
+            &["UpdateEarlyBoot"],
+        );
+        assert_kept(
+            || Actions::UpdateAccessControl(AccessControl::default()),
+            &["UpdateAccessControl"],
+        );
+        assert_kept(
+            || Actions::UpdateTriage(TriageFacts::default()),
+            &["UpdateTriage"],
+        );
+        assert_kept(
+            || {
+                Actions::UpdateJournalSearch(vec![UnitHits::new(
+                    "nginx.service".to_string(),
+                    LogEntry::default(),
+                )])
+            },
+            &["UpdateJournalSearch"],
+        );
+        assert_kept(
+            || Actions::UpdateTmpfiles(Tmpfiles::default()),
+            &["UpdateTmpfiles"],
+        );
+        assert_kept(
+            || Actions::UpdateTmpfilesRun(Ok(String::new())),
+            &["UpdateTmpfilesRun"],
+        );
+        assert_kept(
+            || Actions::UpdateSystemState(Ok("degraded".to_string())),
+            &["UpdateSystemState"],
+        );
+        assert_kept(
+            || Actions::UpdatePluginView(Err("timed out".to_string())),
+            &["UpdatePluginView"],
+        );
+        assert_kept(|| Actions::UpdateBootTimes(vec![]), &["UpdateBootTimes"]);
+        assert_kept(
+            || Actions::UpdateCriticalChain(vec![]),
+            &["UpdateCriticalChain"],
+        );
+        assert_kept(
+            || Actions::UpdateOverview(Overview::default()),
+            &["UpdateOverview"],
+        );
+    }
+
+    #[test]
+    fn fetched_data_is_stored() {
+        assert_kept(
+            || Actions::Fetched(Fetched::Units(vec![service()])),
+            &["Store"],
+        );
+        assert_kept(
+            || {
+                Actions::Fetched(Fetched::Log(
+                    "nginx.service".to_string(),
+                    vec![LogEntry::default()],
+                ))
+            },
+            &["Store"],
+        );
+    }
+
+    #[test]
+    fn new_log_entries_are_forwarded_before_being_stored() {
+        let entries = vec![LogEntry::default()];
+        let (status, effects) = reduce(
+            Status::Log,
+            Actions::Fetched(Fetched::NewLogEntries(
+                "nginx.service".to_string(),
+                entries.clone(),
+            )),
+        );
+        assert_eq!(status, Status::Log);
+        assert!(matches!(
+            &effects[..],
+            [
+                Effect::ForwardLogEntries(name, forwarded),
+                Effect::Store(Fetched::NewLogEntries(stored_name, stored)),
+            ] if name == "nginx.service"
+                && stored_name == "nginx.service"
+                && *forwarded == entries
+                && *stored == entries
+        ));
+    }
+
+    #[test]
+    fn invalidated_data_reloads_what_shows_it() {
+        for key in [
+            DataKey::Units,
+            DataKey::Resources,
+            DataKey::Activators,
+            DataKey::Slices,
+            DataKey::PluginColumns,
+        ] {
+            assert_eq!(
+                reduced(Status::List, Actions::Invalidated(key)),
+                (Status::List, vec!["ReloadList"])
+            );
+        }
+        let (_, effects) = reduce(
+            Status::Log,
+            Actions::Invalidated(DataKey::Log("nginx.service".to_string())),
+        );
+        assert!(matches!(&effects[..], [Effect::LogStored(name)] if name == "nginx.service"));
+        assert_kept(
+            || Actions::Invalidated(DataKey::Properties("nginx.service".to_string())),
+            &[],
+        );
+        assert_kept(|| Actions::Invalidated(DataKey::Boots), &[]);
+    }
+
+    #[test]
+    fn list_input_is_passed_to_the_list() {
+        assert_kept(|| Actions::Filter("nginx".to_string()), &["FilterList"]);
+        assert_kept(
+            || Actions::UpdateIgnoreListKeys(true),
+            &["SetIgnoreListKeys"],
+        );
+        assert_kept(
+            || {
+                Actions::OpenPrompt(PromptKind::InstantiateTemplate(
+                    "getty@.service".to_string(),
+                ))
+            },
+            &["OpenPrompt"],
+        );
+        assert_kept(|| Actions::OpenRecentUnits, &["OpenRecentUnits"]);
+        assert_kept(|| Actions::OpenColumnChooser, &["OpenColumnChooser"]);
+        assert_kept(|| Actions::ToggleColumn(Column::Memory), &["ToggleColumn"]);
+        assert_kept(
+            || Actions::OpenPrompt(PromptKind::ForwardLog),
+            &["OpenPrompt"],
+        );
+    }
+
+    #[test]
+    fn units_are_selected_only_on_the_list() {
+        for status in ALL {
+            let (next, effects) = reduce(status, Actions::SelectUnit("nginx.service".to_string()));
+            assert_eq!(next, status);
+            if status == Status::List {
+                assert!(
+                    matches!(&effects[..], [Effect::SelectUnit(name)] if name == "nginx.service")
+                );
+            } else {
+                assert!(effects.is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn template_instances_are_started_from_any_screen() {
+        for status in ALL {
+            let (next, effects) = reduce(
+                status,
+                Actions::SubmitPrompt(
+                    PromptKind::InstantiateTemplate("getty@.service".to_string()),
+                    "tty3".to_string(),
+                ),
+            );
+            assert_eq!(next, status);
+            assert!(matches!(
+                &effects[..],
+                [Effect::InstantiateTemplate(template, instance)]
+                    if template == "getty@.service" && instance == "tty3"
+            ));
+        }
+    }
+
+    #[test]
+    fn log_prompts_apply_only_where_the_log_is_shown() {
+        let cases: [(PromptKind, &[&str]); 3] = [
+            (PromptKind::LogTimeRange, &["SetLogTimeRange", "RefetchLog"]),
+            (PromptKind::ExportLog(ExportScope::Full), &["ExportLog"]),
+            (PromptKind::ForwardLog, &["ForwardLog"]),
+        ];
+        for (kind, effects) in cases {
+            for status in ALL {
+                let expected: &[&str] = if matches!(status, Status::Log | Status::Split) {
+                    effects
+                } else {
+                    &[]
+                };
+                assert_eq!(
+                    reduced(
+                        status,
+                        Actions::SubmitPrompt(kind.clone(), "-1h".to_string())
+                    ),
+                    (status, expected.to_vec())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn app_wide_actions_keep_the_screen() {
+        assert_kept(|| Actions::ReloadConfig, &["ReloadConfig"]);
+        assert_kept(
+            || Actions::SupportBundleWritten(Some("/tmp/bundle.tar.gz".to_string())),
+            &["ShowSupportBundle"],
+        );
+        assert_kept(
+            || Actions::SupportBundleWritten(None),
+            &["ShowSupportBundle"],
+        );
+        assert_kept(|| Actions::RefreshUnits, &["RefreshUnits"]);
+    }
+
+    #[test]
+    fn exported_log_keeps_its_scope_and_path() {
+        let (_, effects) = reduce(
+            Status::Log,
+            Actions::SubmitPrompt(
+                PromptKind::ExportLog(ExportScope::Displayed),
+                "/tmp/nginx.log".to_string(),
+            ),
+        );
+        assert!(matches!(
+            &effects[..],
+            [Effect::ExportLog(ExportScope::Displayed, path)] if path == "/tmp/nginx.log"
+        ));
+    }
+
+    #[test]
+    fn support_bundle_is_written_from_any_screen() {
+        assert_kept(
+            || Actions::SubmitPrompt(PromptKind::SupportBundle, String::new()),
+            &["WriteSupportBundle"],
+        );
+    }
+
+    #[test]
+    fn plugin_view_is_fetched_for_the_extension() {
+        let (status, effects) = reduce(Status::List, Actions::GoPluginView(extension()));
+        assert_eq!(status, Status::PluginView);
+        assert_eq!(effects.len(), 2);
+        assert!(matches!(effects[0], Effect::RememberVisit));
+        assert!(matches!(&effects[1], Effect::FetchPluginView(view) if *view == extension()));
+    }
+
+    #[test]
+    fn opening_a_search_hit_shows_its_log() {
+        let (status, effects) = reduce(
+            Status::JournalSearch,
+            Actions::OpenSearchHit("nginx.service".to_string()),
+        );
+        assert_eq!(status, Status::Log);
+        assert!(matches!(&effects[0], Effect::FetchSearchHit(name) if name == "nginx.service"));
+        assert!(matches!(effects[1], Effect::FollowLog));
+    }
+
+    #[test]
+    fn journal_search_opens_its_screen() {
+        for status in ALL {
+            let (next, effects) = reduce(
+                status,
+                Actions::SubmitPrompt(PromptKind::JournalSearch, "timeout @ -1d".to_string()),
+            );
+            assert_eq!(next, Status::JournalSearch);
+            assert!(matches!(
+                &effects[..],
+                [Effect::SearchJournal(input)] if input == "timeout @ -1d"
+            ));
+        }
+    }
+
+    #[test]
+    fn unit_actions_run_where_asked() {
+        let name = || "nginx.service".to_string();
+        assert_kept(
+            || Actions::OpenStopImpact(ServiceAction::Stop(JobMode::Replace)),
+            &["OpenStopImpact"],
+        );
+        assert_kept(
+            || Actions::UpdateStopImpact(name(), Ok(vec![])),
+            &["UpdateStopImpact"],
+        );
+        assert_kept(
+            || Actions::RunServiceAction(name(), ServiceAction::Restart),
+            &["RunServiceAction"],
+        );
+        assert_kept(
+            || Actions::RunPluginAction(name(), extension()),
+            &["RunPluginAction"],
+        );
+        assert_kept(
+            || Actions::PluginActionDone(name(), extension(), Ok(String::new())),
+            &["FinishPluginAction"],
+        );
+        assert_kept(|| Actions::OpenJobModeMenu, &["OpenJobModeMenu"]);
+        assert_kept(
+            || Actions::OpenJobConflict(name(), ServiceAction::Start(JobMode::Fail), conflict()),
+            &["OpenJobConflict"],
+        );
+        assert_kept(
+            || Actions::UpdateJobConflict(name(), Ok(vec![])),
+            &["UpdateJobConflict"],
+        );
+        assert_kept(|| Actions::OpenUnitMenu, &["OpenUnitMenu"]);
+        assert_kept(
+            || Actions::UnitActionDone(name(), ServiceAction::Enable, Ok(None)),
+            &["FinishUnitAction"],
+        );
+        assert_kept(
+            || {
+                Actions::TemplateInstantiated(
+                    "getty@.service".to_string(),
+                    "tty3".to_string(),
+                    Ok(()),
+                )
+            },
+            &["FinishInstantiation"],
+        );
+        assert_kept(
+            || {
+                Actions::JobFinished(
+                    name(),
+                    "/org/freedesktop/systemd1/job/42".to_string(),
+                    Ok(JobResult::Failed),
+                )
+            },
+            &["FinishJob"],
+        );
+        assert_kept(
+            || {
+                Actions::OpenJobFailure(
+                    Box::new(service()),
+                    "start".to_string(),
+                    JobResult::Failed,
+                    Local::now(),
+                )
+            },
+            &["OpenJobFailure"],
+        );
+        assert_kept(
+            || Actions::UpdateJobFailure(name(), Ok(vec![])),
+            &["UpdateJobFailure"],
+        );
+    }
+
+    #[test]
+    fn jobs_are_animated_only_on_the_list() {
+        for status in ALL {
+            let effects: &[&str] = if status == Status::List {
+                &["AnimateJobs"]
+            } else {
+                &[]
+            };
+            assert_eq!(
+                reduced(status, Actions::AnimateJobs),
+                (status, effects.to_vec())
+            );
+        }
+    }
+
+    #[test]
+    fn details_are_opened_from_the_list() {
+        for status in ALL {
+            let (status, effects) =
+                reduce(status, Actions::OpenDetailsOf("nginx.service".to_string()));
+            assert_eq!(status, Status::List);
+            assert!(
+                matches!(&effects[..], [Effect::OpenDetailsOf(name)] if name == "nginx.service")
+            );
+        }
+    }
+}
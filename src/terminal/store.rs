@@ -0,0 +1,398 @@
+use std::collections::HashMap;
+use std::sync::mpsc::Sender;
+use std::time::{Duration, Instant};
+
+use super::app::{Actions, AppEvent};
+use crate::domain::boot::Boot;
+use crate::domain::log_entry::LogEntry;
+use crate::domain::log_query::LogQuery;
+use crate::domain::resource_usage::ResourceUsage;
+use crate::domain::service::Service;
+use crate::domain::unit_property::UnitProperty;
+use crate::usecases::plugins::Plugins;
+use crate::usecases::runtime::{self, Task};
+use crate::usecases::services_manager::ServicesManager;
+
+/// Identifies one piece of data held by the [`Store`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DataKey {
+    Units,
+    Resources,
+    Activators,
+    Slices,
+    PluginColumns,
+    Properties(String),
+    Log(String),
+    Boots,
+}
+
+/// Result of a background fetch, handed back to the store on the UI thread.
+pub enum Fetched {
+    Units(Vec<Service>),
+    Resources(HashMap<String, ResourceUsage>),
+    Activators(HashMap<String, Vec<String>>),
+    Slices(HashMap<String, String>),
+    PluginColumns(Vec<HashMap<String, String>>),
+    Properties(String, Box<UnitProperty>),
+    Log(String, Vec<LogEntry>),
+    NewLogEntries(String, Vec<LogEntry>),
+    Boots(Vec<Boot>),
+}
+
+/// A value together with when it was fetched and whether it is known to be outdated.
+pub struct Stored<T> {
+    value: T,
+    fetched_at: Instant,
+    stale: bool,
+}
+
+impl<T> Stored<T> {
+    fn new(value: T) -> Self {
+        Self {
+            value,
+            fetched_at: Instant::now(),
+            stale: false,
+        }
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn age(&self) -> Duration {
+        self.fetched_at.elapsed()
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.stale
+    }
+}
+
+impl<T: PartialEq> Stored<T> {
+    /// Replaces the value with `value` fetched again, returning whether it
+    /// differs or the value held was stale.
+    fn renew(&mut self, value: T) -> bool {
+        if self.stale || self.value != value {
+            *self = Stored::new(value);
+            true
+        } else {
+            self.fetched_at = Instant::now();
+            false
+        }
+    }
+}
+
+/// Owns everything fetched from systemd. Screens read from it; fetches run as
+/// background tasks, come back as [`Actions::Fetched`] and, once stored,
+/// are announced with [`Actions::Invalidated`].
+pub struct Store {
+    units: Option<Stored<Vec<Service>>>,
+    resources: Option<Stored<HashMap<String, ResourceUsage>>>,
+    activators: Option<Stored<HashMap<String, Vec<String>>>>,
+    slices: Option<Stored<HashMap<String, String>>>,
+    plugin_columns: Option<Stored<Vec<HashMap<String, String>>>>,
+    properties: HashMap<String, Stored<UnitProperty>>,
+    logs: HashMap<String, Stored<Vec<LogEntry>>>,
+    boots: Option<Stored<Vec<Boot>>>,
+    /// Fetches still running, by what they fetch.
+    fetches: HashMap<DataKey, Task>,
+    sender: Sender<AppEvent>,
+}
+
+impl Store {
+    pub fn new(sender: Sender<AppEvent>) -> Self {
+        Self {
+            units: None,
+            resources: None,
+            activators: None,
+            slices: None,
+            plugin_columns: None,
+            properties: HashMap::new(),
+            logs: HashMap::new(),
+            boots: None,
+            fetches: HashMap::new(),
+            sender,
+        }
+    }
+
+    pub fn units(&self) -> Option<&Stored<Vec<Service>>> {
+        self.units.as_ref()
+    }
+
+    pub fn resources(&self) -> Option<&Stored<HashMap<String, ResourceUsage>>> {
+        self.resources.as_ref()
+    }
+
+    /// Timers and sockets activating each unit, by the name of the activated unit.
+    pub fn activators(&self) -> Option<&Stored<HashMap<String, Vec<String>>>> {
+        self.activators.as_ref()
+    }
+
+    /// Slice of each loaded unit running in one, by unit name, fetched only
+    /// while the list is grouped by slice.
+    pub fn slices(&self) -> Option<&Stored<HashMap<String, String>>> {
+        self.slices.as_ref()
+    }
+
+    /// Values of the plugin columns, by column then by unit name.
+    pub fn plugin_columns(&self) -> Option<&Stored<Vec<HashMap<String, String>>>> {
+        self.plugin_columns.as_ref()
+    }
+
+    pub fn properties(&self, name: &str) -> Option<&Stored<UnitProperty>> {
+        self.properties.get(name)
+    }
+
+    pub fn log(&self, name: &str) -> Option<&Stored<Vec<LogEntry>>> {
+        self.logs.get(name)
+    }
+
+    pub fn boots(&self) -> Option<&Stored<Vec<Boot>>> {
+        self.boots.as_ref()
+    }
+
+    /// Drops the stored log of a unit, e.g. when it is about to be fetched from
+    /// another boot or time range.
+    pub fn forget_log(&mut self, name: &str) {
+        self.logs.remove(name);
+    }
+
+    /// Cursor of the newest stored entry of a unit's log.
+    pub fn log_cursor(&self, name: &str) -> Option<String> {
+        self.logs
+            .get(name)
+            .and_then(|log| log.value.last())
+            .map(|entry| entry.cursor().to_string())
+    }
+
+    pub fn mark_stale(&mut self, key: &DataKey) {
+        let stale = match key {
+            DataKey::Units => self.units.as_mut().map(|units| &mut units.stale),
+            DataKey::Resources => self.resources.as_mut().map(|usage| &mut usage.stale),
+            DataKey::Activators => self
+                .activators
+                .as_mut()
+                .map(|activators| &mut activators.stale),
+            DataKey::Slices => self.slices.as_mut().map(|slices| &mut slices.stale),
+            DataKey::PluginColumns => self.plugin_columns.as_mut().map(|values| &mut values.stale),
+            DataKey::Properties(name) => self.properties.get_mut(name).map(|p| &mut p.stale),
+            DataKey::Log(name) => self.logs.get_mut(name).map(|log| &mut log.stale),
+            DataKey::Boots => self.boots.as_mut().map(|boots| &mut boots.stale),
+        };
+        if let Some(stale) = stale {
+            *stale = true;
+            self.invalidated(key.clone());
+        }
+    }
+
+    /// Runs the fetch of `key` in the background, cancelling one still running
+    /// for it, which would only bring older data.
+    fn spawn(&mut self, key: DataKey, fetch: impl Future<Output = ()> + Send + 'static) {
+        if let Some(previous) = self.fetches.insert(key, runtime::spawn(fetch)) {
+            previous.cancel();
+        }
+    }
+
+    /// Stops waiting for the fetch of `key`, e.g. when the view showing it is left.
+    pub fn cancel(&mut self, key: &DataKey) {
+        if let Some(fetch) = self.fetches.remove(key) {
+            fetch.cancel();
+        }
+    }
+
+    /// Fetches the unit list, then the resource usage of the running ones, the
+    /// units activated by timers and sockets, which take a call per unit, and
+    /// the plugin columns, which arrive later.
+    pub fn fetch_units(&mut self) {
+        let event_tx = self.sender.clone();
+        self.spawn(DataKey::Units, async move {
+            let names = match ServicesManager::list_services().await {
+                Ok(units) => {
+                    let names = units.iter().map(|unit| unit.name().to_string()).collect();
+                    event_tx
+                        .send(AppEvent::Action(Actions::Fetched(Fetched::Units(units))))
+                        .expect("Failed to send Fetched event");
+                    names
+                }
+                Err(e) => {
+                    event_tx
+                        .send(AppEvent::Error(e.to_string()))
+                        .expect("Failed to send Error event");
+                    return;
+                }
+            };
+            if let Ok(usage) = ServicesManager::get_resource_usage().await {
+                event_tx
+                    .send(AppEvent::Action(Actions::Fetched(Fetched::Resources(
+                        usage,
+                    ))))
+                    .expect("Failed to send Fetched event");
+            }
+            if let Ok(activators) = ServicesManager::get_activators().await {
+                event_tx
+                    .send(AppEvent::Action(Actions::Fetched(Fetched::Activators(
+                        activators,
+                    ))))
+                    .expect("Failed to send Fetched event");
+            }
+            if !Plugins::columns().is_empty() {
+                let values = Plugins::get_columns(names).await;
+                event_tx
+                    .send(AppEvent::Action(Actions::Fetched(Fetched::PluginColumns(
+                        values,
+                    ))))
+                    .expect("Failed to send Fetched event");
+            }
+        });
+    }
+
+    /// Fetches the slice of every unit, which takes a call per unit.
+    pub fn fetch_slices(&mut self) {
+        let event_tx = self.sender.clone();
+        self.spawn(DataKey::Slices, async move {
+            if let Ok(slices) = ServicesManager::get_slices().await {
+                event_tx
+                    .send(AppEvent::Action(Actions::Fetched(Fetched::Slices(slices))))
+                    .expect("Failed to send Fetched event");
+            }
+        });
+    }
+
+    pub fn fetch_properties(&mut self, service: Service) {
+        let event_tx = self.sender.clone();
+        let key = DataKey::Properties(service.name().to_string());
+        self.spawn(key, async move {
+            if let Ok(properties) = ServicesManager::get_properties(&service).await {
+                let fetched = Fetched::Properties(service.name().to_string(), Box::new(properties));
+                event_tx
+                    .send(AppEvent::Action(Actions::Fetched(fetched)))
+                    .expect("Failed to send Fetched event");
+            }
+        });
+    }
+
+    /// Fetches the part of the log of `service` selected by `query`, only asking
+    /// for new entries when some of it is already stored.
+    pub fn fetch_log(&mut self, service: Service, query: &LogQuery) {
+        let cursor = self.log_cursor(service.name());
+        let incremental = cursor.is_some();
+        let query = query.clone().with_after_cursor(cursor);
+        let event_tx = self.sender.clone();
+        let key = DataKey::Log(service.name().to_string());
+        self.spawn(key, async move {
+            // For the log to tell whether the target's clock agrees with this
+            // one; only the first log waits for it.
+            let _ = ServicesManager::get_clock_offset().await;
+            match ServicesManager::get_log(&service, &query).await {
+                Ok(log) => {
+                    let service_name = service.name().to_string();
+                    let fetched = if incremental {
+                        Fetched::NewLogEntries(service_name, log)
+                    } else {
+                        Fetched::Log(service_name, log)
+                    };
+                    event_tx
+                        .send(AppEvent::Action(Actions::Fetched(fetched)))
+                        .expect("Failed to send Fetched event");
+                }
+                Err(e) => event_tx
+                    .send(AppEvent::Error(e.to_string()))
+                    .expect("Failed to send Error event"),
+            }
+        });
+    }
+
+    pub fn fetch_boots(&mut self) {
+        let event_tx = self.sender.clone();
+        self.spawn(DataKey::Boots, async move {
+            match ServicesManager::list_boots().await {
+                Ok(boots) => event_tx
+                    .send(AppEvent::Action(Actions::Fetched(Fetched::Boots(boots))))
+                    .expect("Failed to send Fetched event"),
+                Err(e) => event_tx
+                    .send(AppEvent::Error(e.to_string()))
+                    .expect("Failed to send Error event"),
+            }
+        });
+    }
+
+    /// Keeps `fetched`, returning whether that changes what is shown. Data
+    /// fetched again the same is not announced, only dated anew.
+    pub fn store(&mut self, fetched: Fetched) -> bool {
+        let (key, changed) = match fetched {
+            Fetched::Units(units) => (DataKey::Units, put(&mut self.units, units)),
+            Fetched::Resources(usage) => (DataKey::Resources, put(&mut self.resources, usage)),
+            Fetched::Activators(activators) => {
+                (DataKey::Activators, put(&mut self.activators, activators))
+            }
+            Fetched::Slices(slices) => (DataKey::Slices, put(&mut self.slices, slices)),
+            Fetched::PluginColumns(values) => (
+                DataKey::PluginColumns,
+                put(&mut self.plugin_columns, values),
+            ),
+            Fetched::Properties(name, properties) => {
+                self.properties
+                    .insert(name.clone(), Stored::new(*properties));
+                (DataKey::Properties(name), true)
+            }
+            Fetched::Log(name, entries) => {
+                let changed = match self.logs.get_mut(&name) {
+                    Some(log) => log.renew(entries),
+                    None => {
+                        self.logs.insert(name.clone(), Stored::new(entries));
+                        true
+                    }
+                };
+                (DataKey::Log(name), changed)
+            }
+            Fetched::NewLogEntries(name, entries) => {
+                // Entries still in flight for a log that was forgotten meanwhile.
+                let Some(log) = self.logs.get_mut(&name) else {
+                    return false;
+                };
+                let mut changed = std::mem::take(&mut log.stale);
+                for entry in entries {
+                    if !contains(&log.value, &entry) {
+                        log.value.push(entry);
+                        changed = true;
+                    }
+                }
+                log.fetched_at = Instant::now();
+                (DataKey::Log(name), changed)
+            }
+            Fetched::Boots(boots) => (DataKey::Boots, put(&mut self.boots, boots)),
+        };
+        self.fetches.remove(&key);
+        if changed {
+            self.invalidated(key);
+        }
+        changed
+    }
+
+    fn invalidated(&self, key: DataKey) {
+        self.sender
+            .send(AppEvent::Action(Actions::Invalidated(key)))
+            .expect("Failed to send Invalidated event");
+    }
+}
+
+/// Puts `value` in `slot`, returning whether that changes it.
+fn put<T: PartialEq>(slot: &mut Option<Stored<T>>, value: T) -> bool {
+    match slot {
+        Some(stored) => stored.renew(value),
+        None => {
+            *slot = Some(Stored::new(value));
+            true
+        }
+    }
+}
+
+/// Whether `entry` is already part of `entries`; only the tail has to be checked
+/// since entries are ordered by time.
+fn contains(entries: &[LogEntry], entry: &LogEntry) -> bool {
+    entries
+        .iter()
+        .rev()
+        .take_while(|stored| stored.realtime_usec() >= entry.realtime_usec())
+        .any(|stored| stored.cursor() == entry.cursor())
+}
@@ -0,0 +1,67 @@
+use std::fmt;
+
+use crate::terminal::action_log::LoggedAction;
+
+/// What a session changed, printed once the terminal is restored when
+/// `exit_summary` is set, so the scrollback keeps a record of it.
+pub struct SessionSummary {
+    /// Actions taken, oldest first, with their unit.
+    actions: Vec<(String, LoggedAction)>,
+    /// Units failed when the list was last fetched.
+    failed: Vec<String>,
+    /// Units whose unit file changed since systemd read it.
+    pending_reloads: Vec<String>,
+}
+
+impl SessionSummary {
+    pub fn new(
+        actions: Vec<(String, LoggedAction)>,
+        failed: Vec<String>,
+        pending_reloads: Vec<String>,
+    ) -> Self {
+        Self {
+            actions,
+            failed,
+            pending_reloads,
+        }
+    }
+}
+
+impl fmt::Display for SessionSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "systemd-manager-tui session summary")?;
+        if self.actions.is_empty() {
+            writeln!(f, "  No action taken on units")?;
+        } else {
+            writeln!(f, "  Actions taken ({}):", self.actions.len())?;
+            for (unit, action) in &self.actions {
+                writeln!(
+                    f,
+                    "    {}  {} {}",
+                    action.at().format("%H:%M:%S"),
+                    action.action(),
+                    unit
+                )?;
+            }
+        }
+        if self.failed.is_empty() {
+            writeln!(f, "  No failed units")?;
+        } else {
+            writeln!(
+                f,
+                "  Still failed ({}): {}",
+                self.failed.len(),
+                self.failed.join(", ")
+            )?;
+        }
+        if !self.pending_reloads.is_empty() {
+            writeln!(
+                f,
+                "  Changed on disk, waiting for systemctl daemon-reload ({}): {}",
+                self.pending_reloads.len(),
+                self.pending_reloads.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+use ratatui::style::Color;
+
+use crate::config::{Config, ThemeName};
+
+/// Colors of the interface: those of the built-in theme chosen with `theme`
+/// in the config file, with its `[colors]` and `[state_colors]` on top.
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    /// Titles of the shortcuts and of the sections of a screen.
+    pub heading: Color,
+    /// Column headers of tables, and the border of a log not followed.
+    pub header: Color,
+    /// Ordinary text of tables and explanations.
+    pub muted: Color,
+    /// What matters least, like unset values and empty gauges.
+    pub faint: Color,
+    pub selection_fg: Color,
+    pub selection_bg: Color,
+    pub popup_border: Color,
+    /// Text being typed, and the characters of names a filter matched.
+    pub input: Color,
+    /// Stars and marks put on units.
+    pub mark: Color,
+    pub ok: Color,
+    pub warning: Color,
+    pub error: Color,
+    pub info: Color,
+    /// Timer and socket activating a unit.
+    pub link: Color,
+    /// Units started by something else rather than at boot (`static`...).
+    pub indirect: Color,
+    /// Border of a log that is followed.
+    pub following: Color,
+    pub match_fg: Color,
+    pub match_bg: Color,
+    /// Background of the search match the log is on.
+    pub current_match_bg: Color,
+    pub active: Color,
+    /// `activating`, `deactivating`, `reloading` and other passing states.
+    pub transitioning: Color,
+    pub failed: Color,
+    pub inactive: Color,
+}
+
+impl Theme {
+    /// For dark terminals.
+    const DEFAULT: Theme = Theme {
+        heading: Color::LightMagenta,
+        header: Color::White,
+        muted: Color::Gray,
+        faint: Color::DarkGray,
+        selection_fg: Color::White,
+        selection_bg: Color::Blue,
+        popup_border: Color::Yellow,
+        input: Color::Yellow,
+        mark: Color::Yellow,
+        ok: Color::Green,
+        warning: Color::Yellow,
+        error: Color::Red,
+        info: Color::Cyan,
+        link: Color::Magenta,
+        indirect: Color::Blue,
+        following: Color::Rgb(255, 165, 0),
+        match_fg: Color::Black,
+        match_bg: Color::Yellow,
+        current_match_bg: Color::LightRed,
+        active: Color::Green,
+        transitioning: Color::Yellow,
+        failed: Color::Red,
+        inactive: Color::DarkGray,
+    };
+
+    /// For terminals with a white background, where yellow and gray text
+    /// can hardly be read.
+    const LIGHT: Theme = Theme {
+        heading: Color::Magenta,
+        header: Color::Black,
+        muted: Color::Rgb(68, 68, 68),
+        faint: Color::Rgb(138, 138, 138),
+        selection_fg: Color::White,
+        selection_bg: Color::Blue,
+        popup_border: Color::Blue,
+        input: Color::Rgb(0, 95, 175),
+        mark: Color::Rgb(175, 95, 0),
+        ok: Color::Rgb(0, 135, 0),
+        warning: Color::Rgb(175, 95, 0),
+        error: Color::Rgb(175, 0, 0),
+        info: Color::Rgb(0, 135, 135),
+        link: Color::Magenta,
+        indirect: Color::Blue,
+        following: Color::Rgb(215, 95, 0),
+        match_fg: Color::Black,
+        match_bg: Color::Rgb(255, 215, 95),
+        current_match_bg: Color::Rgb(255, 135, 135),
+        active: Color::Rgb(0, 135, 0),
+        transitioning: Color::Rgb(175, 95, 0),
+        failed: Color::Rgb(175, 0, 0),
+        inactive: Color::Rgb(138, 138, 138),
+    };
+
+    /// The colors in effect; read them again rather than keeping them, as they
+    /// change when the config file is reloaded.
+    pub fn current() -> Theme {
+        let config = Config::get();
+        let mut theme = match config.theme {
+            // Colors are dropped when drawing, see `color::adapt_to_terminal`.
+            ThemeName::Default | ThemeName::Monochrome => Self::DEFAULT,
+            ThemeName::Light => Self::LIGHT,
+        };
+        let state_colors = &config.state_colors;
+        let overrides = config
+            .colors
+            .iter()
+            .map(|(role, color)| (role.as_str(), color))
+            .chain(
+                [
+                    ("active", &state_colors.active),
+                    ("transitioning", &state_colors.transitioning),
+                    ("failed", &state_colors.failed),
+                    ("inactive", &state_colors.inactive),
+                ]
+                .into_iter()
+                .filter_map(|(role, color)| Some((role, color.as_ref()?))),
+            );
+        for (role, color) in overrides {
+            if let (Some(slot), Ok(color)) = (theme.role(role), color.parse()) {
+                *slot = color;
+            }
+        }
+        theme
+    }
+
+    /// Color of units whose `ActiveState` is `active_state`.
+    pub fn color_of(&self, active_state: &str) -> Color {
+        match active_state {
+            "active" => self.active,
+            "failed" => self.failed,
+            "inactive" => self.inactive,
+            _ => self.transitioning,
+        }
+    }
+
+    /// Whether `[colors]` can set `role`.
+    pub fn has_role(role: &str) -> bool {
+        let mut theme = Self::DEFAULT;
+        theme.role(role).is_some()
+    }
+
+    fn role(&mut self, role: &str) -> Option<&mut Color> {
+        Some(match role {
+            "heading" => &mut self.heading,
+            "header" => &mut self.header,
+            "muted" => &mut self.muted,
+            "faint" => &mut self.faint,
+            "selection_fg" => &mut self.selection_fg,
+            "selection_bg" => &mut self.selection_bg,
+            "popup_border" => &mut self.popup_border,
+            "input" => &mut self.input,
+            "mark" => &mut self.mark,
+            "ok" => &mut self.ok,
+            "warning" => &mut self.warning,
+            "error" => &mut self.error,
+            "info" => &mut self.info,
+            "link" => &mut self.link,
+            "indirect" => &mut self.indirect,
+            "following" => &mut self.following,
+            "match_fg" => &mut self.match_fg,
+            "match_bg" => &mut self.match_bg,
+            "current_match_bg" => &mut self.current_match_bg,
+            "active" => &mut self.active,
+            "transitioning" => &mut self.transitioning,
+            "failed" => &mut self.failed,
+            "inactive" => &mut self.inactive,
+            _ => return None,
+        })
+    }
+}
@@ -0,0 +1,102 @@
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::config::TruncationSide;
+
+const ELLIPSIS: char = '…';
+
+fn char_width(c: char) -> usize {
+    c.width().unwrap_or(0)
+}
+
+/// Keeps as many leading `chars` as fit in `width` columns.
+fn take_width<T: Copy>(chars: impl Iterator<Item = (char, T)>, width: usize) -> Vec<(char, T)> {
+    let mut used = 0;
+    chars
+        .take_while(|(c, _)| {
+            used += char_width(*c);
+            used <= width
+        })
+        .collect()
+}
+
+/// Shortens `chars` to `width` terminal columns, replacing what is cut with `…`.
+/// Wide characters (CJK, emoji) count as two columns and are never split.
+fn truncate_chars<T: Copy>(
+    chars: Vec<(char, T)>,
+    width: usize,
+    side: TruncationSide,
+) -> Vec<(char, T)> {
+    let total: usize = chars.iter().map(|(c, _)| char_width(*c)).sum();
+    if total <= width {
+        return chars;
+    }
+    let Some(&(_, ellipsis_style)) = chars.first() else {
+        return chars;
+    };
+    if width == 0 {
+        return vec![];
+    }
+    let budget = width - 1;
+
+    match side {
+        TruncationSide::End => {
+            let mut kept = take_width(chars.into_iter(), budget);
+            let style = kept.last().map_or(ellipsis_style, |(_, style)| *style);
+            kept.push((ELLIPSIS, style));
+            kept
+        }
+        TruncationSide::Start => {
+            let mut kept = take_width(chars.into_iter().rev(), budget);
+            kept.reverse();
+            let style = kept.first().map_or(ellipsis_style, |(_, style)| *style);
+            kept.insert(0, (ELLIPSIS, style));
+            kept
+        }
+        TruncationSide::Middle => {
+            let head_width = budget.div_ceil(2);
+            let mut kept = take_width(chars.iter().copied(), head_width);
+            let used: usize = kept.iter().map(|(c, _)| char_width(*c)).sum();
+            let mut tail = take_width(chars.into_iter().rev(), budget - used);
+            tail.reverse();
+            let style = kept.last().map_or(ellipsis_style, |(_, style)| *style);
+            kept.push((ELLIPSIS, style));
+            kept.extend(tail);
+            kept
+        }
+    }
+}
+
+/// Fits `text` in `width` columns, see [`truncate_line`].
+pub fn truncate(text: &str, width: usize, side: TruncationSide) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+    let chars = text.chars().map(|c| (c, ())).collect();
+    truncate_chars(chars, width, side)
+        .into_iter()
+        .map(|(c, _)| c)
+        .collect()
+}
+
+/// Fits a styled line in `width` columns, keeping the style of every character left.
+pub fn truncate_line(line: Line<'static>, width: usize, side: TruncationSide) -> Line<'static> {
+    if line.width() <= width {
+        return line;
+    }
+    let chars: Vec<(char, Style)> = line
+        .spans
+        .iter()
+        .flat_map(|span| span.content.chars().map(move |c| (c, span.style)))
+        .collect();
+
+    let mut spans: Vec<Span<'static>> = vec![];
+    for (c, style) in truncate_chars(chars, width, side) {
+        match spans.last_mut() {
+            Some(span) if span.style == style => span.content.to_mut().push(c),
+            _ => spans.push(Span::styled(c.to_string(), style)),
+        }
+    }
+    Line::from(spans).style(line.style)
+}
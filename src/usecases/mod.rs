@@ -1 +1,4 @@
+pub mod plugins;
+pub mod runtime;
+pub mod scripts;
 pub mod services_manager;
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::OnceLock;
+
+use crate::config::Config;
+use crate::domain::plugin::{Extension, Plugin};
+use crate::infrastructure::script_plugin::ScriptPlugin;
+use crate::usecases::scripts::ScriptedActions;
+use crate::usecases::services_manager::with_timeout;
+
+static PLUGINS: OnceLock<Vec<Box<dyn Plugin>>> = OnceLock::new();
+
+fn plugins() -> &'static [Box<dyn Plugin>] {
+    PLUGINS.get_or_init(Vec::new)
+}
+
+/// An action, view or column together with the plugin adding it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginExtension {
+    /// Index of the plugin among those loaded.
+    plugin: usize,
+    extension: &'static Extension,
+}
+
+impl PluginExtension {
+    pub fn title(&self) -> &'static str {
+        self.extension.title()
+    }
+
+    pub fn key(&self) -> Option<&'static str> {
+        self.extension.key()
+    }
+
+    fn plugin(&self) -> &'static dyn Plugin {
+        plugins()[self.plugin].as_ref()
+    }
+}
+
+#[cfg(test)]
+impl PluginExtension {
+    /// An extension of the first plugin, for tests that only pass it along.
+    pub fn detached(extension: &'static Extension) -> Self {
+        Self {
+            plugin: 0,
+            extension,
+        }
+    }
+}
+
+/// Every extension of the loaded plugins that `extensions` picks, in the order
+/// of the plugins.
+fn extensions(
+    extensions: impl Fn(&'static dyn Plugin) -> &'static [Extension],
+) -> Vec<PluginExtension> {
+    plugins()
+        .iter()
+        .enumerate()
+        .flat_map(|(plugin, loaded)| {
+            extensions(loaded.as_ref())
+                .iter()
+                .map(move |extension| PluginExtension { plugin, extension })
+        })
+        .collect()
+}
+
+pub struct Plugins;
+
+impl Plugins {
+    /// Loads the scripts of the config file and the plugins of the `plugins`
+    /// directory next to it, returning why some could not be. Must be called
+    /// before the interface starts; later calls find none.
+    pub fn load() -> Vec<String> {
+        let dir = Config::dir();
+        let (scripts, mut problems) = ScriptedActions::load(&Config::get().scripts, dir.as_deref());
+        let mut loaded: Vec<Box<dyn Plugin>> = vec![];
+        if !scripts.actions().is_empty() {
+            loaded.push(Box::new(scripts));
+        }
+        if let Some(dir) = dir {
+            let (plugins, plugin_problems) =
+                ScriptPlugin::load_all(&dir.join("plugins"), Config::get().timeouts.plugin());
+            loaded.extend(
+                plugins
+                    .into_iter()
+                    .map(|plugin| Box::new(plugin) as Box<dyn Plugin>),
+            );
+            problems.extend(plugin_problems);
+        }
+        let _ = PLUGINS.set(loaded);
+        problems
+    }
+
+    pub fn actions() -> Vec<PluginExtension> {
+        extensions(|plugin| plugin.actions())
+    }
+
+    pub fn views() -> Vec<PluginExtension> {
+        extensions(|plugin| plugin.views())
+    }
+
+    /// Columns of the plugins, which the list adds after its own.
+    pub fn columns() -> Vec<PluginExtension> {
+        extensions(|plugin| plugin.columns())
+    }
+
+    pub async fn run_action(
+        action: &PluginExtension,
+        unit: &str,
+    ) -> Result<String, Box<dyn Error>> {
+        let (action, unit) = (action.clone(), unit.to_string());
+        let plugin = action.plugin();
+        with_timeout(
+            Config::get().timeouts.plugin(),
+            plugin.name(),
+            format!("{}::action::{}", plugin.name(), action.extension.id()),
+            move || plugin.run_action(action.extension.id(), &unit),
+        )
+        .await
+    }
+
+    pub async fn get_view(view: &PluginExtension, unit: &str) -> Result<String, Box<dyn Error>> {
+        let (view, unit) = (view.clone(), unit.to_string());
+        let plugin = view.plugin();
+        with_timeout(
+            Config::get().timeouts.plugin(),
+            plugin.name(),
+            format!("{}::view::{}", plugin.name(), view.extension.id()),
+            move || plugin.get_view(view.extension.id(), &unit),
+        )
+        .await
+    }
+
+    /// Values of every plugin column for `units`, in the order of
+    /// [`Plugins::columns`]; a column that could not be filled is left empty.
+    pub async fn get_columns(units: Vec<String>) -> Vec<HashMap<String, String>> {
+        let mut values = vec![];
+        for column in Self::columns() {
+            let plugin = column.plugin();
+            let units = units.clone();
+            let filled = with_timeout(
+                Config::get().timeouts.plugin(),
+                plugin.name(),
+                format!("{}::column::{}", plugin.name(), column.extension.id()),
+                move || plugin.get_column(column.extension.id(), &units),
+            )
+            .await;
+            values.push(filled.unwrap_or_default());
+        }
+        values
+    }
+}
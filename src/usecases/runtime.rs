@@ -0,0 +1,49 @@
+use std::sync::OnceLock;
+use tokio::runtime::{Builder, Runtime};
+use tokio::task::AbortHandle;
+
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+/// Threads driving fetches and actions, D-Bus calls included; they only ever
+/// wait.
+const WORKER_THREADS: usize = 2;
+
+/// Threads running journal, plugin and other program calls at once. Calls
+/// beyond them wait for one to end, within their timeout, so views switched
+/// quickly or a bus that stopped answering cannot pile up threads.
+const BLOCKING_THREADS: usize = 16;
+
+/// Runtime every fetch and action of the interface runs on, so the UI thread
+/// only ever waits for events.
+fn runtime() -> &'static Runtime {
+    RUNTIME.get_or_init(|| {
+        Builder::new_multi_thread()
+            .worker_threads(WORKER_THREADS)
+            .max_blocking_threads(BLOCKING_THREADS)
+            .thread_name("systemd-manager-worker")
+            .enable_time()
+            .build()
+            .expect("Failed to start the async runtime")
+    })
+}
+
+/// Work started in the background, e.g. by a view for what it shows.
+pub struct Task(AbortHandle);
+
+impl Task {
+    /// Stops the work, so its result is never sent. A D-Bus call is dropped
+    /// with it; a program already run still ends on its own, unseen.
+    pub fn cancel(&self) {
+        self.0.abort();
+    }
+}
+
+pub fn spawn(future: impl Future<Output = ()> + Send + 'static) -> Task {
+    Task(runtime().spawn(future).abort_handle())
+}
+
+/// Runs `future` to its end on the calling thread, for the few places that
+/// need the answer before going on, like the summary printed on exit.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    runtime().block_on(future)
+}
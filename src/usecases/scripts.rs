@@ -0,0 +1,218 @@
+use rhai::{AST, Array, Dynamic, Engine, EvalAltResult, Map, Scope};
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::config::{Config, Script};
+use crate::domain::job_mode::JobMode;
+use crate::domain::plugin::{Extension, Plugin};
+use crate::infrastructure::target::Target;
+use crate::usecases::runtime::block_on;
+use crate::usecases::services_manager::repository;
+
+type ScriptResult<T> = Result<T, Box<EvalAltResult>>;
+
+/// The `[[scripts]]` of the config file, offered as the actions of a plugin.
+///
+/// A script sees the selected unit as `unit`, its name, and `properties`, a
+/// map of its description and states. It acts with `start`, `stop` and
+/// `restart`, which wait for the job to end, `enable`, `disable`,
+/// `reload_daemon` and `run(program, [args])`, which returns what the
+/// program printed. What it prints and what it returns are shown once done.
+pub struct ScriptedActions {
+    actions: Vec<Extension>,
+    scripts: Vec<AST>,
+}
+
+impl ScriptedActions {
+    /// Compiles `scripts`, along with why those that could not be are left out.
+    pub fn load(scripts: &[Script], dir: Option<&Path>) -> (ScriptedActions, Vec<String>) {
+        let engine = Engine::new();
+        let mut loaded = ScriptedActions {
+            actions: vec![],
+            scripts: vec![],
+        };
+        let mut problems = vec![];
+        for script in scripts {
+            let code = match (&script.code, &script.file) {
+                (Some(code), _) => Ok(code.clone()),
+                (None, Some(file)) => std::fs::read_to_string(
+                    dir.map_or_else(|| Path::new(file).to_path_buf(), |dir| dir.join(file)),
+                )
+                .map_err(|e| e.to_string()),
+                (None, None) => Err("no code".to_string()),
+            };
+            match code.and_then(|code| engine.compile(code).map_err(|e| e.to_string())) {
+                Ok(ast) => {
+                    let id = loaded.scripts.len().to_string();
+                    loaded.actions.push(
+                        Extension::new(id, script.title.clone()).with_key(script.key.clone()),
+                    );
+                    loaded.scripts.push(ast);
+                }
+                Err(e) => problems.push(format!("Script {}: {}", script.title, e)),
+            }
+        }
+        (loaded, problems)
+    }
+}
+
+impl Plugin for ScriptedActions {
+    fn name(&self) -> &str {
+        "scripts"
+    }
+
+    fn actions(&self) -> &[Extension] {
+        &self.actions
+    }
+
+    fn views(&self) -> &[Extension] {
+        &[]
+    }
+
+    fn columns(&self) -> &[Extension] {
+        &[]
+    }
+
+    fn run_action(&self, id: &str, unit: &str) -> Result<String, Box<dyn Error>> {
+        let ast = id
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| self.scripts.get(index))
+            .ok_or("no such script")?;
+        let printed = Arc::new(Mutex::new(vec![]));
+        let engine = engine(printed.clone());
+        let mut scope = Scope::new();
+        scope.push_constant("unit", unit.to_string());
+        scope.push_constant(
+            "properties",
+            properties_of(unit).map_err(|e| e.to_string())?,
+        );
+        let returned: Dynamic = engine
+            .eval_ast_with_scope(&mut scope, ast)
+            .map_err(|e| e.to_string())?;
+
+        let mut output = std::mem::take(&mut *printed.lock().unwrap());
+        if !returned.is_unit() {
+            output.push(returned.to_string());
+        }
+        Ok(output.join("\n"))
+    }
+
+    fn get_view(&self, _id: &str, _unit: &str) -> Result<String, Box<dyn Error>> {
+        Err("scripts have no views".into())
+    }
+
+    fn get_column(
+        &self,
+        _id: &str,
+        _units: &[String],
+    ) -> Result<HashMap<String, String>, Box<dyn Error>> {
+        Err("scripts have no columns".into())
+    }
+}
+
+/// Engine running a script, its prints kept in `printed` and stopped once it
+/// runs for longer than `timeouts.plugin_seconds`.
+fn engine(printed: Arc<Mutex<Vec<String>>>) -> Engine {
+    let mut engine = Engine::new();
+    engine.on_print(move |text| printed.lock().unwrap().push(text.to_string()));
+    let deadline = Instant::now() + Config::get().timeouts.plugin();
+    engine.on_progress(move |_| {
+        (Instant::now() > deadline).then(|| Dynamic::from("timed out".to_string()))
+    });
+
+    engine
+        .register_fn("start", |name: &str| -> ScriptResult<()> {
+            let job = block_on(repository().start_service(name, JobMode::default()))
+                .map_err(|e| e.to_string())?;
+            wait_for_job(name, &job)
+        })
+        .register_fn("stop", |name: &str| -> ScriptResult<()> {
+            let job = block_on(repository().stop_service(name, JobMode::default()))
+                .map_err(|e| e.to_string())?;
+            wait_for_job(name, &job)
+        })
+        .register_fn("restart", |name: &str| -> ScriptResult<()> {
+            let job = block_on(repository().restart_service(name)).map_err(|e| e.to_string())?;
+            wait_for_job(name, &job)
+        })
+        .register_fn("enable", |name: &str| -> ScriptResult<()> {
+            block_on(async {
+                repository().enable_service(name).await?;
+                repository().reload_daemon().await
+            })
+            .map_err(|e| e.to_string().into())
+        })
+        .register_fn("disable", |name: &str| -> ScriptResult<()> {
+            block_on(async {
+                repository().disable_service(name).await?;
+                repository().reload_daemon().await
+            })
+            .map_err(|e| e.to_string().into())
+        })
+        .register_fn("reload_daemon", || -> ScriptResult<()> {
+            block_on(repository().reload_daemon()).map_err(|e| e.to_string().into())
+        })
+        .register_fn("properties_of", properties_of)
+        .register_fn("run", |program: &str| run(program, Array::new()))
+        .register_fn("run", run);
+    engine
+}
+
+fn wait_for_job(name: &str, job: &str) -> ScriptResult<()> {
+    let result = block_on(repository().wait_for_job(name, job)).map_err(|e| e.to_string())?;
+    if result.is_success() {
+        Ok(())
+    } else {
+        Err(format!("{}: {}", name, result.describe()).into())
+    }
+}
+
+/// Description and states of the unit `name`, as the list shows them.
+fn properties_of(name: &str) -> ScriptResult<Map> {
+    let services = block_on(repository().list_services()).map_err(|e| e.to_string())?;
+    let service = services
+        .iter()
+        .find(|service| service.name() == name)
+        .ok_or_else(|| format!("no unit named {}", name))?;
+    let state = service.state();
+    let mut properties = Map::new();
+    for (key, value) in [
+        ("name", service.name()),
+        ("description", service.description()),
+        ("load", state.load()),
+        ("active", state.active()),
+        ("sub", state.sub()),
+        ("file", state.file()),
+        ("path", service.file_path()),
+    ] {
+        properties.insert(key.into(), value.to_string().into());
+    }
+    Ok(properties)
+}
+
+/// What `program` printed, run with `args` on the target; a failure ends the
+/// script with what it printed on stderr.
+fn run(program: &str, args: Array) -> ScriptResult<String> {
+    let mut command = Command::new(program);
+    command.args(args.iter().map(|arg| arg.to_string()));
+    let output = Target::get()
+        .command(&command)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("{}: {}", program, e))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(if stderr.is_empty() {
+            format!("{} exited with {}", program, output.status)
+        } else {
+            format!("{}: {}", program, stderr)
+        }
+        .into());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
@@ -1,63 +1,406 @@
+use crate::config::Config;
+use crate::domain::access_control::AccessControl;
+use crate::domain::boot::Boot;
+use crate::domain::boot_time::{BootTime, ChainLink};
+use crate::domain::early_boot::EarlyBoot;
+use crate::domain::job_conflict::QueuedJob;
+use crate::domain::job_mode::JobMode;
+use crate::domain::job_result::JobResult;
+use crate::domain::journal_search::UnitHits;
+use crate::domain::log_entry::LogEntry;
+use crate::domain::log_query::LogQuery;
+use crate::domain::overview::Overview;
+use crate::domain::resource_usage::ResourceUsage;
+use crate::domain::security_report::SecurityReport;
 use crate::domain::service::Service;
+use crate::domain::stop_impact::ImpactedUnit;
+use crate::domain::tmpfiles::Tmpfiles;
+use crate::domain::triage::TriageFacts;
+use crate::domain::unit_property::UnitProperty;
+use crate::infrastructure::journal::{JournalEntries, JournalFollower};
+use crate::infrastructure::systemd_service_adapter::UnitChanges;
+use crate::infrastructure::target::Target;
+use crate::terminal::i18n::{tr, tr_args};
 use crate::{
     domain::service_repository::ServiceRepository,
+    infrastructure::synthetic_service_adapter::{Dataset, SyntheticServiceAdapter},
     infrastructure::systemd_service_adapter::SystemdServiceAdapter,
 };
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
-use std::thread;
-use std::time::Duration;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+use tokio::{task, time};
+use tracing::{debug, warn};
 
 const SLEEP_DURATION: u64 = 200;
 
+type Repository = Box<dyn ServiceRepository + Send + Sync>;
+
+static SYNTHETIC_UNITS: OnceLock<Dataset> = OnceLock::new();
+static REPOSITORY: OnceLock<Repository> = OnceLock::new();
+
+/// Backend every use case goes through; systemd unless synthetic units were
+/// asked for at startup.
+pub(super) fn repository() -> &'static dyn ServiceRepository {
+    REPOSITORY
+        .get_or_init(|| match SYNTHETIC_UNITS.get() {
+            Some(dataset) => Box::new(SyntheticServiceAdapter::new(*dataset)),
+            None => Box::new(SystemdServiceAdapter),
+        })
+        .as_ref()
+}
+
+/// Stops waiting for `call` after `timeout` and drops it, so a bus or journal
+/// that never answers ends in an error instead of a fetch that never
+/// completes.
+async fn timed<T>(
+    timeout: Duration,
+    waiting_for: &str,
+    name: String,
+    call: impl Future<Output = Result<T, Box<dyn Error>>>,
+) -> Result<T, Box<dyn Error>> {
+    let started = Instant::now();
+    let Ok(result) = time::timeout(timeout, call).await else {
+        warn!(call = %name, "no reply from {} within {:?}", waiting_for, timeout);
+        return Err(tr_args(
+            "error-no-reply-within",
+            &[
+                ("what", waiting_for),
+                ("seconds", &timeout.as_secs().to_string()),
+            ],
+        )
+        .into());
+    };
+    match &result {
+        Ok(_) => debug!(call = %name, elapsed = ?started.elapsed(), "done"),
+        Err(e) => debug!(call = %name, elapsed = ?started.elapsed(), "failed: {}", e),
+    }
+    result
+}
+
+/// Runs the blocking `call` on the runtime's blocking threads and stops
+/// waiting for it after `timeout`, counted from when it is queued. The call
+/// itself runs on until it returns.
+pub(super) async fn with_timeout<T: Send + 'static>(
+    timeout: Duration,
+    waiting_for: &str,
+    name: String,
+    call: impl FnOnce() -> Result<T, Box<dyn Error>> + Send + 'static,
+) -> Result<T, Box<dyn Error>> {
+    let call = task::spawn_blocking(move || call().map_err(|e| e.to_string()));
+    timed(timeout, waiting_for, name, async {
+        match call.await {
+            Ok(result) => result.map_err(Into::into),
+            Err(e) => Err(e.to_string().into()),
+        }
+    })
+    .await
+}
+
+/// Use case `call` was made from, like `ServicesManager::list_services`, for
+/// the debug log.
+fn call_name<F>(call: &F) -> String {
+    let mut name = std::any::type_name_of_val(call);
+    while let Some(outer) = name.strip_suffix("::{{closure}}") {
+        name = outer;
+    }
+    let mut path = name.rsplit("::");
+    match (path.next(), path.next()) {
+        (Some(function), Some(owner)) => format!("{}::{}", owner, function),
+        _ => name.to_string(),
+    }
+}
+
+async fn bus_call<T, F>(
+    call: impl FnOnce(&'static dyn ServiceRepository) -> F,
+) -> Result<T, Box<dyn Error>>
+where
+    F: Future<Output = Result<T, Box<dyn Error>>>,
+{
+    let name = call_name(&call);
+    timed(
+        Config::get().timeouts.dbus(),
+        "systemd",
+        name,
+        call(repository()),
+    )
+    .await
+}
+
+async fn journal_call<T, F>(
+    call: impl FnOnce(&'static dyn ServiceRepository) -> F,
+) -> Result<T, Box<dyn Error>>
+where
+    F: Future<Output = Result<T, Box<dyn Error>>>,
+{
+    let name = call_name(&call);
+    timed(
+        Config::get().timeouts.journal(),
+        "journalctl",
+        name,
+        call(repository()),
+    )
+    .await
+}
+
 pub struct ServicesManager;
 
 impl ServicesManager {
-    pub fn start_service(service: &Service) -> Result<(), Box<dyn Error>> {
-        SystemdServiceAdapter.start_service(service.name())?;
-        thread::sleep(Duration::from_millis(SLEEP_DURATION));
-        Ok(())
+    /// Replaces systemd with made up units and their logs. Must be called
+    /// before anything is fetched.
+    pub fn use_synthetic_units(dataset: Dataset) {
+        let _ = SYNTHETIC_UNITS.set(dataset);
     }
 
-    pub fn stop_service(service: &Service) -> Result<(), Box<dyn Error>> {
-        SystemdServiceAdapter.stop_service(service.name())?;
-        thread::sleep(Duration::from_millis(SLEEP_DURATION));
-        Ok(())
+    fn is_synthetic() -> bool {
+        SYNTHETIC_UNITS.get().is_some()
     }
 
-    pub fn restart_service(service: &Service) -> Result<(), Box<dyn Error>> {
-        SystemdServiceAdapter.restart_service(service.name())?;
-        thread::sleep(Duration::from_millis(SLEEP_DURATION));
+    /// What is managed: the units of the system or of the user, or made up ones.
+    pub fn scope() -> String {
+        if let Some(Dataset::Demo) = SYNTHETIC_UNITS.get() {
+            tr("scope-demo")
+        } else if Self::is_synthetic() {
+            tr("scope-synthetic")
+        } else if Target::get().user {
+            "user".to_string()
+        } else {
+            "system".to_string()
+        }
+    }
+
+    /// Queues a start of the unit, returning the object path of the job.
+    pub async fn start_service(service: &Service, mode: JobMode) -> Result<String, Box<dyn Error>> {
+        let job = bus_call(|repository| repository.start_service(service.name(), mode)).await?;
+        time::sleep(Duration::from_millis(SLEEP_DURATION)).await;
+        Ok(job)
+    }
+
+    /// Queues a stop of the unit, returning the object path of the job.
+    pub async fn stop_service(service: &Service, mode: JobMode) -> Result<String, Box<dyn Error>> {
+        let job = bus_call(|repository| repository.stop_service(service.name(), mode)).await?;
+        time::sleep(Duration::from_millis(SLEEP_DURATION)).await;
+        Ok(job)
+    }
+
+    /// Units that stopping or restarting the unit takes down with it.
+    pub async fn get_stop_impact(service: &Service) -> Result<Vec<ImpactedUnit>, Box<dyn Error>> {
+        bus_call(|repository| repository.get_stop_impact(service.name())).await
+    }
+
+    /// Queues a restart of the unit, returning the object path of the job.
+    pub async fn restart_service(service: &Service) -> Result<String, Box<dyn Error>> {
+        let job = bus_call(|repository| repository.restart_service(service.name())).await?;
+        time::sleep(Duration::from_millis(SLEEP_DURATION)).await;
+        Ok(job)
+    }
+
+    /// Waits until the job queued on the unit ends. Not bounded by
+    /// `timeouts.dbus_seconds`, as jobs take as long as the unit needs.
+    pub async fn wait_for_job(name: &str, job: &str) -> Result<JobResult, Box<dyn Error>> {
+        let started = Instant::now();
+        let result = repository().wait_for_job(name, job).await;
+        debug!(unit = name, job, elapsed = ?started.elapsed(), "job finished: {:?}", result);
+        result
+    }
+
+    pub async fn list_jobs() -> Result<Vec<QueuedJob>, Box<dyn Error>> {
+        bus_call(|repository| repository.list_jobs()).await
+    }
+
+    pub async fn cancel_job(id: u32) -> Result<(), Box<dyn Error>> {
+        bus_call(|repository| repository.cancel_job(id)).await
+    }
+
+    pub async fn instantiate_template(
+        template: &Service,
+        instance: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        let name = template
+            .instantiate(instance)
+            .ok_or_else(|| tr("error-not-a-template"))?;
+        bus_call(|repository| repository.start_service(&name, JobMode::default())).await?;
+        time::sleep(Duration::from_millis(SLEEP_DURATION)).await;
         Ok(())
     }
 
-    pub fn enable_service(service: &Service) -> Result<(), Box<dyn Error>> {
-        SystemdServiceAdapter.enable_service(service.name())?;
-        thread::sleep(Duration::from_millis(SLEEP_DURATION));
-        SystemdServiceAdapter.reload_daemon()?;
+    pub async fn enable_service(service: &Service) -> Result<(), Box<dyn Error>> {
+        bus_call(|repository| repository.enable_service(service.name())).await?;
+        time::sleep(Duration::from_millis(SLEEP_DURATION)).await;
+        bus_call(|repository| repository.reload_daemon()).await?;
         Ok(())
     }
 
-    pub fn disable_service(service: &Service) -> Result<(), Box<dyn Error>> {
-        SystemdServiceAdapter.disable_service(service.name())?;
-        thread::sleep(Duration::from_millis(SLEEP_DURATION));
-        SystemdServiceAdapter.reload_daemon()?;
+    pub async fn disable_service(service: &Service) -> Result<(), Box<dyn Error>> {
+        bus_call(|repository| repository.disable_service(service.name())).await?;
+        time::sleep(Duration::from_millis(SLEEP_DURATION)).await;
+        bus_call(|repository| repository.reload_daemon()).await?;
         Ok(())
     }
 
-    pub fn list_services() -> Result<Vec<Service>, Box<dyn Error>> {
-        let mut services = SystemdServiceAdapter.list_services()?;
-        services.sort_by_key(|a| a.name().to_lowercase());
+    pub async fn list_services() -> Result<Vec<Service>, Box<dyn Error>> {
+        let mut services = bus_call(|repository| repository.list_services()).await?;
+        // Instances are sorted right after their template so they read as a group.
+        services.sort_by_key(|a| {
+            let group = a.template_name().unwrap_or_else(|| a.name().to_string());
+            (
+                group.to_lowercase(),
+                !a.is_template(),
+                a.name().to_lowercase(),
+            )
+        });
         Ok(services)
     }
 
-    pub fn update_properties(service: &mut Service) -> Result<(), Box<dyn Error>> {
-        let service_property = SystemdServiceAdapter.get_service_property(service.name())?;
-        let _ = &service.update_properties(service_property);
-        Ok(())
+    pub async fn get_resource_usage() -> Result<HashMap<String, ResourceUsage>, Box<dyn Error>> {
+        bus_call(|repository| repository.get_resource_usage()).await
+    }
+
+    pub async fn get_activators() -> Result<HashMap<String, Vec<String>>, Box<dyn Error>> {
+        bus_call(|repository| repository.get_activators()).await
     }
 
-    pub fn get_log(service: &Service) -> Result<String, Box<dyn Error>> {
-        let log = SystemdServiceAdapter.get_service_log(service.name())?;
+    pub async fn get_slices() -> Result<HashMap<String, String>, Box<dyn Error>> {
+        bus_call(|repository| repository.get_slices()).await
+    }
+
+    pub async fn get_units_needing_reload() -> Result<Vec<String>, Box<dyn Error>> {
+        bus_call(|repository| repository.get_units_needing_reload()).await
+    }
+
+    /// How far the clock of the target is ahead of this one, read the first
+    /// time only.
+    pub async fn get_clock_offset() -> Result<Option<i64>, Box<dyn Error>> {
+        with_timeout(
+            Config::get().timeouts.dbus(),
+            "date",
+            "ServicesManager::get_clock_offset".to_string(),
+            || Ok(Target::get().clock_offset()),
+        )
+        .await
+    }
+
+    pub async fn get_system_state() -> Result<String, Box<dyn Error>> {
+        bus_call(|repository| repository.get_system_state()).await
+    }
+
+    pub async fn get_overview() -> Result<Overview, Box<dyn Error>> {
+        bus_call(|repository| repository.get_overview()).await
+    }
+
+    pub async fn get_properties(service: &Service) -> Result<UnitProperty, Box<dyn Error>> {
+        let properties =
+            bus_call(|repository| repository.get_unit_property(service.name())).await?;
+        Ok(properties)
+    }
+
+    /// Fetches the latest entries of the unit matching `query`.
+    pub async fn get_log(
+        service: &Service,
+        query: &LogQuery,
+    ) -> Result<Vec<LogEntry>, Box<dyn Error>> {
+        let log =
+            journal_call(|repository| repository.get_service_log(service.name(), query)).await?;
         Ok(log)
     }
+
+    /// Units among `units` whose entries selected by `query` match `pattern`,
+    /// with the most hits first.
+    pub async fn search_journal(
+        units: Vec<String>,
+        pattern: Regex,
+        query: &LogQuery,
+    ) -> Result<Vec<UnitHits>, Box<dyn Error>> {
+        let entries = journal_call(|repository| repository.get_journal(query)).await?;
+
+        let units: HashSet<String> = units.into_iter().collect();
+        let mut hits: HashMap<String, UnitHits> = HashMap::new();
+        for (unit, entry) in entries {
+            if !units.contains(&unit) || !pattern.is_match(entry.message()) {
+                continue;
+            }
+            match hits.get_mut(&unit) {
+                Some(unit_hits) => unit_hits.add(entry),
+                None => {
+                    hits.insert(unit.clone(), UnitHits::new(unit, entry));
+                }
+            }
+        }
+        let mut hits: Vec<UnitHits> = hits.into_values().collect();
+        hits.sort_by(|a, b| {
+            b.count()
+                .cmp(&a.count())
+                .then_with(|| a.name().cmp(b.name()))
+        });
+        Ok(hits)
+    }
+
+    /// Boots recorded in the journal, most recent first.
+    pub async fn list_boots() -> Result<Vec<Boot>, Box<dyn Error>> {
+        journal_call(|repository| repository.list_boots()).await
+    }
+
+    /// Units of the initial ramdisk of the current boot.
+    pub async fn get_early_boot() -> Result<EarlyBoot, Box<dyn Error>> {
+        journal_call(|repository| repository.get_early_boot()).await
+    }
+
+    pub async fn get_boot_times() -> Result<Vec<BootTime>, Box<dyn Error>> {
+        bus_call(|repository| repository.get_boot_times()).await
+    }
+
+    pub async fn get_critical_chain() -> Result<Vec<ChainLink>, Box<dyn Error>> {
+        bus_call(|repository| repository.get_critical_chain()).await
+    }
+
+    pub fn follow_log(
+        service_name: &str,
+        after_cursor: Option<&str>,
+    ) -> Result<(JournalFollower, JournalEntries), Box<dyn Error>> {
+        if Self::is_synthetic() {
+            return Err(tr_args("error-no-following", &[("scope", &Self::scope())]).into());
+        }
+        SystemdServiceAdapter.follow_service_log(service_name, after_cursor)
+    }
+
+    /// Units systemd reports a change of, as they happen.
+    pub async fn watch_units() -> Result<UnitChanges, Box<dyn Error>> {
+        if Self::is_synthetic() {
+            return Err(tr_args("error-no-watching", &[("scope", &Self::scope())]).into());
+        }
+        SystemdServiceAdapter.watch_units().await
+    }
+
+    pub async fn get_access_control(service: &Service) -> Result<AccessControl, Box<dyn Error>> {
+        // Reading the denials from the journal is the slow part.
+        journal_call(|repository| repository.get_access_control(service.name())).await
+    }
+
+    pub async fn get_triage_facts(service: &Service) -> Result<TriageFacts, Box<dyn Error>> {
+        journal_call(|repository| repository.get_triage_facts(service.name())).await
+    }
+
+    pub async fn get_tmpfiles(service: &Service) -> Result<Tmpfiles, Box<dyn Error>> {
+        bus_call(|repository| repository.get_tmpfiles(service.name())).await
+    }
+
+    /// Creates the missing directories among `paths` as `tmpfiles.d` says, or
+    /// only tells what would be done when `dry_run`.
+    pub async fn run_tmpfiles(paths: Vec<String>, dry_run: bool) -> Result<String, Box<dyn Error>> {
+        bus_call(|repository| repository.run_tmpfiles(&paths, dry_run)).await
+    }
+
+    /// Unit file and drop-ins of the unit, as path and content.
+    pub async fn get_unit_files(
+        service: &Service,
+    ) -> Result<Vec<(String, String)>, Box<dyn Error>> {
+        bus_call(|repository| repository.get_unit_files(service.name())).await
+    }
+
+    pub async fn get_security_report(service: &Service) -> Result<SecurityReport, Box<dyn Error>> {
+        let report = bus_call(|repository| repository.get_security_report(service.name())).await?;
+        Ok(report)
+    }
 }